@@ -1,22 +1,43 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
 
 use gpui::prelude::*;
 
 use crate::{
-    components::toast::{ToastGlobal, ToastHost},
-    keymap::{Command, CommandDispatcher, ContextId, FocusTarget, KeyChord, KeymapStack},
-    models::{FilterState, ProjectTree},
-    task::{self, TaskOverview, TaskService, TaskSummary},
-    theme::ActiveTheme,
+    cli::LaunchArgs,
+    components::toast::{ToastGlobal, ToastHost, ToastKind},
+    config::ActiveConfig,
+    keymap::{
+        Command, CommandDispatcher, ContextId, FocusTarget, Key, KeyChord, KeymapLayer, KeymapStack,
+    },
+    models::{DueFilter, FilterState, INBOX_PROJECT, ProjectTree},
+    task::{self, FilterPreset, ImportSummary, TaskFilter, TaskOverview, TaskService, TaskSummary},
+    theme::{ActiveTheme, Theme},
     view::{
         app_layout,
+        calendar_view::{CalendarEvent, CalendarView},
+        command_palette::{
+            CommandPalette, CommandPaletteEvent, PaletteEntry, build_palette_entries,
+        },
+        import_summary::render_import_summary,
+        settings_modal::{SettingsModal, SettingsModalEvent},
+        setup_screen::SetupScreen,
+        shortcuts_overlay::render_shortcuts_overlay,
         sidebar::{Sidebar, SidebarEvent, SidebarSection, TagItem},
-        status_bar::{StatusBar, StatusBarEvent, SyncState},
+        status_bar::{StatusBar, StatusBarEvent, SyncState, TaskCounts},
         task_detail_modal::{TaskDetailModal, TaskDetailModalEvent},
-        task_table::{TaskTable, TaskTableEvent},
+        task_preview::TaskPreviewPane,
+        task_table::{TaskPromptKind, TaskTable, TaskTableEvent},
     },
 };
 
+const AUTO_SYNC_STARTUP_DELAY: Duration = Duration::from_secs(30);
+const AUTO_SYNC_RECENT_PULL: Duration = Duration::from_secs(15 * 60);
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(150);
+const STORAGE_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+const STORAGE_SELF_WRITE_GRACE: Duration = Duration::from_secs(5);
+
 pub(super) struct App {
     pub(super) focus_handle: gpui::FocusHandle,
     pub(super) focus_target: FocusTarget,
@@ -26,18 +47,40 @@ pub(super) struct App {
     pub(super) status_bar: gpui::Entity<StatusBar>,
     pub(super) task_table: gpui::Entity<TaskTable>,
     pub(super) task_detail_modal: gpui::Entity<TaskDetailModal>,
+    pub(super) task_preview: gpui::Entity<TaskPreviewPane>,
+    pub(super) command_palette: gpui::Entity<CommandPalette>,
+    pub(super) calendar_view: gpui::Entity<CalendarView>,
+    pub(super) settings_modal: gpui::Entity<SettingsModal>,
     pub(super) toast_host: gpui::Entity<ToastHost>,
     pub(super) task_service: TaskService,
     pub(super) tasks: Vec<TaskSummary>,
     pub(super) focus_before_modal: FocusTarget,
+    pub(super) shortcuts_open: bool,
+    pub(super) shortcuts_scroll_handle: gpui::ScrollHandle,
+    pub(super) import_summary: Option<(ImportSummary, PathBuf)>,
+    pub(super) auto_sync_task: Option<gpui::Task<()>>,
+    pub(super) last_sync_at: Option<Instant>,
+    pub(super) auto_sync_failing: bool,
+    pub(super) sync_in_flight: bool,
+    pub(super) maintenance_in_flight: bool,
+    pub(super) preview_task: Option<gpui::Task<()>>,
+    pub(super) storage_watch_task: Option<gpui::Task<()>>,
+    pub(super) storage_stamp: Option<SystemTime>,
+    pub(super) storage_change_pending: bool,
+    pub(super) window_title: String,
+    pub(super) window_title_dirty: bool,
 }
 
 impl gpui::Render for App {
     fn render(
         &mut self,
-        _window: &mut gpui::Window,
+        window: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
+        if std::mem::take(&mut self.window_title_dirty) {
+            window.set_window_title(&self.window_title);
+        }
+
         let theme = cx.theme();
 
         let on_root_key_down = cx.listener(|app, event: &gpui::KeyDownEvent, window, cx| {
@@ -46,64 +89,109 @@ impl gpui::Render for App {
         let on_sidebar_mouse_down =
             cx.listener(|app, _event: &gpui::MouseDownEvent, _window, cx| {
                 if !app.focus_target.is_sidebar() {
-                    app.focus_target = FocusTarget::SidebarProjects;
+                    app.set_focus_target(FocusTarget::SidebarProjects, cx);
                     app.sidebar.update(cx, |sidebar, cx| {
                         sidebar.set_section(crate::view::sidebar::SidebarSection::Projects, cx);
                     });
                     cx.notify();
                 }
             });
+        let on_sidebar_resize = cx.listener(
+            |app, event: &gpui::DragMoveEvent<app_layout::ResizeSidebar>, _window, cx| {
+                let width = event.event.position.x - event.bounds.left();
+                app.resize_sidebar(width, cx);
+            },
+        );
+        let on_sidebar_resize_end =
+            cx.listener(|app, _: &app_layout::ResizeSidebar, _window, cx| {
+                app.save_sidebar_config(cx);
+            });
         let on_table_mouse_down = cx.listener(|app, _event: &gpui::MouseDownEvent, _window, cx| {
             if !matches!(app.focus_target, FocusTarget::Table) {
-                app.focus_target = FocusTarget::Table;
+                app.set_focus_target(FocusTarget::Table, cx);
                 cx.notify();
             }
         });
 
-        let modal = if self.task_detail_modal.read(cx).is_open() {
+        let modal = if let Some((summary, path)) = &self.import_summary {
+            let app = cx.entity().downgrade();
+            let on_close = move |_window: &mut gpui::Window, cx: &mut gpui::App| {
+                app.update(cx, |app, cx| {
+                    app.import_summary = None;
+                    cx.notify();
+                })
+                .ok();
+            };
+            Some(render_import_summary(theme, *summary, path, on_close))
+        } else if self.shortcuts_open {
+            let on_close = cx.listener(|app, _event: &gpui::MouseDownEvent, _window, cx| {
+                app.shortcuts_open = false;
+                cx.notify();
+            });
+            Some(render_shortcuts_overlay(
+                theme,
+                &self.keymap.bindings(),
+                &self.shortcuts_scroll_handle,
+                on_close,
+            ))
+        } else if self.command_palette.read(cx).is_open() {
+            Some(self.command_palette.clone().into_any_element())
+        } else if self.calendar_view.read(cx).is_open() {
+            Some(self.calendar_view.clone().into_any_element())
+        } else if self.settings_modal.read(cx).is_open() {
+            Some(self.settings_modal.clone().into_any_element())
+        } else if self.task_detail_modal.read(cx).is_open() {
             Some(self.task_detail_modal.clone().into_any_element())
         } else {
             None
         };
 
+        let sidebar_config = cx.config().sidebar;
+        let sidebar_width = (!sidebar_config.collapsed).then_some(sidebar_config.width);
+
+        let preview = self
+            .task_preview
+            .read(cx)
+            .is_open()
+            .then(|| self.task_preview.clone());
+
         app_layout::render_app_layout(
             theme,
             &self.focus_handle,
             self.focus_target,
             self.sidebar.clone(),
+            sidebar_width,
             self.task_table.clone(),
+            preview,
             self.status_bar.clone(),
             self.toast_host.clone(),
             on_root_key_down,
             on_sidebar_mouse_down,
             on_table_mouse_down,
+            on_sidebar_resize,
+            on_sidebar_resize_end,
             modal,
         )
     }
 }
 
 impl App {
-    fn build_sidebar_data(tasks: &[task::TaskSummary]) -> (Vec<(String, usize)>, Vec<TagItem>) {
-        let mut project_counts: HashMap<String, usize> = HashMap::new();
-        let mut tag_counts: HashMap<String, usize> = HashMap::new();
+    fn build_sidebar_data(
+        tasks: &[task::TaskSummary],
+    ) -> (Vec<(String, usize, usize)>, Vec<TagItem>) {
+        let projects = TaskSummary::project_counts(tasks);
 
+        let mut tag_counts: HashMap<String, usize> = HashMap::new();
         for task in tasks {
             if !matches!(task.status, task::TaskStatus::Pending) {
                 continue;
             }
 
-            if let Some(project) = &task.project {
-                *project_counts.entry(project.clone()).or_insert(0) += 1;
-            }
-
             for tag in &task.tags {
                 *tag_counts.entry(tag.clone()).or_insert(0) += 1;
             }
         }
 
-        let mut projects: Vec<(String, usize)> = project_counts.into_iter().collect();
-        projects.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
-
         let mut tags: Vec<(String, usize)> = tag_counts.into_iter().collect();
         tags.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
 
@@ -115,6 +203,25 @@ impl App {
         (projects, tag_items)
     }
 
+    fn count_inbox_tasks(tasks: &[task::TaskSummary]) -> usize {
+        tasks
+            .iter()
+            .filter(|task| {
+                task.project.is_none()
+                    && matches!(task.status, task::TaskStatus::Pending)
+                    && !task.is_waiting()
+            })
+            .count()
+    }
+
+    fn summarize_tasks(tasks: &[task::Task], cx: &gpui::App) -> Vec<TaskSummary> {
+        let urgency_config = &cx.config().urgency;
+        tasks
+            .iter()
+            .map(|task| TaskSummary::from(task).with_urgency(task.urgency(urgency_config)))
+            .collect()
+    }
+
     fn update_ui_from_tasks(
         &mut self,
         all_tasks: Vec<task::TaskSummary>,
@@ -125,24 +232,134 @@ impl App {
 
         let mut project_tree = ProjectTree::new();
         project_tree.build_from_projects(&projects);
+        let inbox_count = Self::count_inbox_tasks(&self.tasks);
+        let today_count = FilterPreset::today_focus().apply(&self.tasks).len();
 
         self.sidebar.update(cx, |sidebar, cx| {
             sidebar.update_projects(project_tree, cx);
+            sidebar.set_inbox_count(inbox_count, cx);
+            sidebar.set_today_count(today_count, cx);
             sidebar.update_tags(tags, cx);
         });
 
         let tasks = self.tasks.clone();
         self.task_table
             .update(cx, |table, cx| table.reload_tasks_from_all(tasks, cx));
+
+        self.update_status_counts(cx);
+        self.update_window_title(cx);
+        self.update_active_task(cx);
+        self.refresh_replica_stats(cx);
+
+        let selection = self.task_table.read(cx).selected_task_uuid();
+        self.schedule_preview(selection, cx);
     }
 
-    fn reload_tasks(&mut self, cx: &mut gpui::Context<Self>) {
+    fn update_window_title(&mut self, cx: &mut gpui::Context<Self>) {
+        let title = TaskCounts::from_tasks(&self.tasks).window_title();
+        if title != self.window_title {
+            self.window_title = title;
+            self.window_title_dirty = true;
+            cx.notify();
+        }
+    }
+
+    fn refresh_replica_stats(&mut self, cx: &mut gpui::Context<Self>) {
+        match self.task_service.pending_sync_operations() {
+            Ok(count) => {
+                self.status_bar.update(cx, |bar, cx| {
+                    bar.set_pending_sync_ops(count, cx);
+                });
+            }
+            Err(e) => {
+                log::warn!("[App] Failed to count pending sync operations: {}", e);
+            }
+        }
+
+        let pending = TaskCounts::from_tasks(&self.tasks).pending;
+        match self.task_service.working_set_size() {
+            Ok(working_set) => {
+                self.status_bar.update(cx, |bar, cx| {
+                    bar.set_replica_stats(Some((working_set, pending)), cx);
+                });
+            }
+            Err(e) => {
+                log::warn!("[App] Failed to read working set size: {}", e);
+            }
+        }
+    }
+
+    fn update_status_counts(&mut self, cx: &mut gpui::Context<Self>) {
+        let counts = TaskCounts::from_tasks(&self.tasks);
+        let filter_state = self.filter_state.read(cx);
+        let summary = if filter_state.has_active_filters() {
+            let status_only = FilterState {
+                status_filter: filter_state.status_filter,
+                ..FilterState::default()
+            };
+            let total = TaskFilter::from(&status_only).apply(&self.tasks).len();
+            let shown = self.task_table.read(cx).filtered_count();
+            Some((shown, total))
+        } else {
+            None
+        };
+        let breadcrumb = filter_state
+            .selected_project
+            .as_deref()
+            .map(|project| {
+                let shown = self.task_table.read(cx).filtered_count();
+                let label = if project == INBOX_PROJECT {
+                    "Inbox".to_string()
+                } else {
+                    self.sidebar
+                        .read(cx)
+                        .project_tree()
+                        .breadcrumb(project)
+                        .join(" › ")
+                };
+                format!(
+                    "{} ({} {})",
+                    label,
+                    shown,
+                    if shown == 1 { "task" } else { "tasks" }
+                )
+            })
+            .unwrap_or_default();
+
+        self.status_bar.update(cx, |bar, cx| {
+            bar.set_counts(counts, cx);
+            bar.set_filter_summary(summary, cx);
+            bar.set_project_breadcrumb(breadcrumb, cx);
+        });
+    }
+
+    fn update_active_task(&mut self, cx: &mut gpui::Context<Self>) {
+        let active_task = self
+            .tasks
+            .iter()
+            .filter(|task| task.is_active && matches!(task.status, task::TaskStatus::Pending))
+            .filter_map(|task| {
+                let start = task.start?;
+                let label = match task.working_id {
+                    Some(id) => format!("#{} {}", id, task.description),
+                    None => task.description.clone(),
+                };
+                Some((task.uuid, label, start))
+            })
+            .max_by_key(|(_, _, start)| *start);
+
+        self.status_bar.update(cx, |bar, cx| {
+            bar.set_active_task(active_task, cx);
+        });
+    }
+
+    pub(super) fn reload_tasks(&mut self, cx: &mut gpui::Context<Self>) {
         match self.task_service.get_all_tasks() {
             Ok(all_tasks) => {
                 self.status_bar.update(cx, |bar, cx| {
                     bar.clear_error(cx);
                 });
-                let summaries: Vec<TaskSummary> = all_tasks.iter().map(TaskSummary::from).collect();
+                let summaries = Self::summarize_tasks(&all_tasks, cx);
                 self.update_ui_from_tasks(summaries, cx);
             }
             Err(e) => {
@@ -156,27 +373,182 @@ impl App {
     }
 
     pub(super) fn handle_sync(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.sync_in_flight {
+            log::debug!("[App] Sync already running");
+            return;
+        }
+        self.sync_tasks(false, cx);
+        self.schedule_auto_sync(None, cx);
+    }
+
+    fn watch_storage(&mut self, cx: &mut gpui::Context<Self>) {
+        self.storage_stamp = self.task_service.storage_stamp();
+        self.storage_watch_task = Some(cx.spawn(async move |app, cx| {
+            loop {
+                cx.background_executor().timer(STORAGE_WATCH_INTERVAL).await;
+                if app
+                    .update(cx, |app, cx| app.check_storage_changes(cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        }));
+    }
+
+    fn check_storage_changes(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.sync_in_flight || self.maintenance_in_flight {
+            return;
+        }
+        let stamp = self.task_service.storage_stamp();
+        if stamp != self.storage_stamp {
+            self.storage_stamp = stamp;
+            self.storage_change_pending =
+                !self.task_service.written_within(STORAGE_SELF_WRITE_GRACE);
+            return;
+        }
+        if !std::mem::take(&mut self.storage_change_pending) {
+            return;
+        }
+
+        log::info!("[App] Task database changed on disk, reloading");
+        self.task_service.forget_cached_counts();
+        self.reload_tasks(cx);
+        self.toast_host.update(cx, |host, cx| {
+            host.push(ToastKind::Info, "Reloaded (external change)", cx);
+        });
+    }
+
+    fn schedule_auto_sync(
+        &mut self,
+        initial_delay: Option<Duration>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.auto_sync_task = None;
+
+        let minutes = cx
+            .config()
+            .auto_sync_interval_minutes
+            .filter(|minutes| *minutes > 0);
+        self.status_bar.update(cx, |bar, cx| {
+            bar.set_auto_sync_interval(minutes, cx);
+        });
+        let Some(minutes) = minutes else {
+            return;
+        };
+
+        let interval = Duration::from_secs(minutes * 60);
+        let mut delay = initial_delay.unwrap_or(interval);
+        self.auto_sync_task = Some(cx.spawn(async move |app, cx| {
+            loop {
+                cx.background_executor().timer(delay).await;
+                if app.update(cx, |app, cx| app.auto_sync(cx)).is_err() {
+                    break;
+                }
+                delay = interval;
+            }
+        }));
+    }
+
+    fn auto_sync(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.sync_in_flight {
+            return;
+        }
+        let pending_ops = self.task_service.pending_sync_operations().unwrap_or(0);
+        let pulled_recently = self
+            .last_sync_at
+            .is_some_and(|at| at.elapsed() < AUTO_SYNC_RECENT_PULL);
+        if pending_ops == 0 && pulled_recently {
+            log::debug!("[App] Auto-sync skipped: nothing to push");
+            return;
+        }
+
+        self.sync_tasks(true, cx);
+    }
+
+    fn sync_tasks(&mut self, automatic: bool, cx: &mut gpui::Context<Self>) {
+        self.sync_in_flight = true;
         self.status_bar.update(cx, |bar, cx| {
             bar.set_sync_state(SyncState::Syncing, cx);
             bar.set_last_sync_message("Syncing...".to_string(), cx);
         });
 
-        match self.task_service.get_all_tasks() {
-            Ok(all_tasks) => {
-                let summaries: Vec<TaskSummary> = all_tasks.iter().map(TaskSummary::from).collect();
-                self.update_ui_from_tasks(summaries, cx);
+        let mut worker = match self.task_service.open_worker() {
+            Ok(worker) => worker,
+            Err(e) => {
+                let reloaded = self.task_service.get_all_tasks();
+                self.complete_sync(Err(e), reloaded, automatic, cx);
+                return;
+            }
+        };
+        cx.spawn(async move |app, cx| {
+            let (synced, reloaded) = cx
+                .background_executor()
+                .spawn(async move {
+                    let synced = worker.sync();
+                    (synced, worker.get_all_tasks())
+                })
+                .await;
+            app.update(cx, |app, cx| {
+                app.complete_sync(synced, reloaded, automatic, cx);
+            })
+            .ok();
+        })
+        .detach();
+    }
+
+    fn complete_sync(
+        &mut self,
+        synced: task::TaskResult<task::SyncResult>,
+        reloaded: task::TaskResult<Vec<task::Task>>,
+        automatic: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.sync_in_flight = false;
+        self.task_service.mark_written();
+        match (self.finish_sync(synced, reloaded, cx), automatic) {
+            (Ok(()), _) => self.auto_sync_failing = false,
+            (Err(e), false) => log::error!("[App] Sync failed: {}", e),
+            (Err(e), true) => {
+                if !self.auto_sync_failing {
+                    log::warn!("[App] Auto-sync failed: {}", e);
+                }
+                self.auto_sync_failing = true;
+            }
+        }
+    }
+
+    fn finish_sync(
+        &mut self,
+        synced: task::TaskResult<task::SyncResult>,
+        reloaded: task::TaskResult<Vec<task::Task>>,
+        cx: &mut gpui::Context<Self>,
+    ) -> task::TaskResult<()> {
+        if let Ok(all_tasks) = &reloaded {
+            let summaries = Self::summarize_tasks(all_tasks, cx);
+            self.update_ui_from_tasks(summaries, cx);
+        }
 
+        match synced.and_then(|result| reloaded.map(|_| result)) {
+            Ok(result) => {
+                self.last_sync_at = Some(Instant::now());
+                let message = if result.success {
+                    "Synced".to_string()
+                } else {
+                    format!("Refreshed ({})", result.message.to_lowercase())
+                };
                 self.status_bar.update(cx, |bar, cx| {
                     bar.set_sync_state(SyncState::Success, cx);
-                    bar.set_last_sync_message("Synced".to_string(), cx);
+                    bar.set_last_sync_message(message, cx);
                 });
+                Ok(())
             }
             Err(e) => {
-                log::error!("[App] Sync failed: {}", e);
                 self.status_bar.update(cx, |bar, cx| {
                     bar.set_sync_state(SyncState::Error, cx);
-                    bar.set_last_sync_message(format!("Error: {}", e), cx);
+                    bar.set_last_sync_message(format!("Sync failed: {}", e), cx);
                 });
+                Err(e)
             }
         }
     }
@@ -190,6 +562,109 @@ impl App {
         if let Some(chord) = KeyChord::from_gpui(event) {
             let context = self.active_context(cx);
 
+            if self.import_summary.is_some() {
+                if matches!(chord.key, Key::Escape | Key::Enter) {
+                    self.import_summary = None;
+                    cx.notify();
+                }
+                return;
+            }
+
+            if self.shortcuts_open {
+                let command = self.keymap.resolve(context, &chord);
+                if chord.key == Key::Escape || command == Some(Command::ToggleShortcuts) {
+                    self.shortcuts_open = false;
+                    cx.notify();
+                }
+                return;
+            }
+
+            if self.command_palette.read(cx).is_open() {
+                match self.keymap.resolve(context, &chord) {
+                    Some(Command::CloseModal) | Some(Command::TogglePalette) => {
+                        self.command_palette
+                            .update(cx, |palette, cx| palette.close(window, cx));
+                    }
+                    Some(Command::PaletteExecute) => self.execute_palette_selection(window, cx),
+                    Some(Command::SelectNextOption) => {
+                        self.command_palette
+                            .update(cx, |palette, cx| palette.select_next(cx));
+                    }
+                    Some(Command::SelectPrevOption) => {
+                        self.command_palette
+                            .update(cx, |palette, cx| palette.select_prev(cx));
+                    }
+                    _ => {}
+                }
+                return;
+            }
+
+            if self.calendar_view.read(cx).is_open() {
+                match self.keymap.resolve(context, &chord) {
+                    Some(Command::CloseModal) | Some(Command::OpenCalendar) => {
+                        self.calendar_view
+                            .update(cx, |calendar, cx| calendar.close(cx));
+                    }
+                    Some(command) => {
+                        self.calendar_view.update(cx, |calendar, cx| match command {
+                            Command::CalendarNextDay => calendar.move_cursor(1, cx),
+                            Command::CalendarPrevDay => calendar.move_cursor(-1, cx),
+                            Command::CalendarNextWeek => calendar.move_cursor(7, cx),
+                            Command::CalendarPrevWeek => calendar.move_cursor(-7, cx),
+                            Command::CalendarNextMonth => calendar.shift_month(1, cx),
+                            Command::CalendarPrevMonth => calendar.shift_month(-1, cx),
+                            Command::CalendarSelectDay => calendar.select_cursor(cx),
+                            _ => {}
+                        });
+                    }
+                    None => {}
+                }
+                return;
+            }
+
+            if self.settings_modal.read(cx).is_open() {
+                let command = self.keymap.resolve(context, &chord);
+                self.settings_modal
+                    .update(cx, |settings, cx| match command {
+                        Some(Command::CloseModal) | Some(Command::OpenSettings) => {
+                            settings.close(cx)
+                        }
+                        Some(Command::SelectNextOption) => settings.select_next(cx),
+                        Some(Command::SelectPrevOption) => settings.select_prev(cx),
+                        Some(Command::SettingsIncrease) => settings.adjust_selected(1, cx),
+                        Some(Command::SettingsDecrease) => settings.adjust_selected(-1, cx),
+                        _ => {}
+                    });
+                return;
+            }
+
+            if self.task_table.read(cx).is_context_menu_open(cx) {
+                self.task_table.update(cx, |table, cx| {
+                    table.handle_context_menu_key(chord.key, cx);
+                });
+                return;
+            }
+
+            if self.sidebar.read(cx).is_context_menu_open(cx) {
+                self.sidebar.update(cx, |sidebar, cx| {
+                    sidebar.handle_context_menu_key(chord.key, cx);
+                });
+                return;
+            }
+
+            if let Key::Char(ch) = chord.key {
+                let plain = !chord.mods.ctrl && !chord.mods.alt && !chord.mods.platform;
+                if plain
+                    && context == ContextId::FilterBar
+                    && self.keymap.resolve_local(context, &chord).is_none()
+                    && self
+                        .task_table
+                        .update(cx, |table, cx| table.type_ahead_focused_dropdown(ch, cx))
+                {
+                    return;
+                }
+            }
+
             if let Some(command) = self.keymap.resolve(context, &chord) {
                 let modal_is_open = self.task_detail_modal.read(cx).is_open();
 
@@ -199,80 +674,364 @@ impl App {
                         | Command::SaveModal
                         | Command::Sync
                         | Command::ModalScrollUp
-                        | Command::ModalScrollDown => {}
+                        | Command::ModalScrollDown
+                        | Command::ModalJumpOverview
+                        | Command::ModalJumpTags
+                        | Command::ModalJumpDependencies
+                        | Command::ModalJumpAnnotations
+                        | Command::ModalJumpDates
+                        | Command::ModalJumpMetadata
+                        | Command::ModalSearchAnnotations
+                        | Command::CopyTaskUuid
+                        | Command::CopyTaskDescription
+                        | Command::CopyTaskFormatted
+                        | Command::CopyTaskUuidAndDescription
+                        | Command::CopyTaskChecklist
+                        | Command::CopyTaskAsMarkdown => {}
                         _ => return,
                     }
                 }
 
-                match command {
-                    Command::FocusSearch => {
-                        let from_headers = matches!(self.focus_target, FocusTarget::TableHeaders);
-                        self.focus_target = FocusTarget::Table;
-                        self.task_table.update(cx, |table, cx| {
-                            if from_headers {
-                                table.blur_table_headers(cx);
-                            }
-                            table.focus_search_input(window, cx);
-                        });
-                        cx.notify();
+                self.execute_command(command, context, window, cx);
+            }
+        }
+    }
+
+    fn open_command_palette(
+        &mut self,
+        context: ContextId,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let entries = build_palette_entries(&self.keymap.bindings(), context);
+        self.command_palette.update(cx, |palette, cx| {
+            palette.open(entries, context, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn execute_palette_selection(
+        &mut self,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (entry, context) = {
+            let palette = self.command_palette.read(cx);
+            (palette.selected_entry(), palette.context())
+        };
+        self.command_palette
+            .update(cx, |palette, cx| palette.close(window, cx));
+
+        let Some(entry) = entry else {
+            return;
+        };
+        self.run_palette_entry(entry, context, window, cx);
+    }
+
+    fn run_palette_entry(
+        &mut self,
+        entry: PaletteEntry,
+        context: ContextId,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let context = match entry.focus_command() {
+            Some(focus_command) => {
+                self.execute_command(focus_command, context, window, cx);
+                self.active_context(cx)
+            }
+            None => context,
+        };
+        self.execute_command(entry.command, context, window, cx);
+    }
+
+    fn execute_command(
+        &mut self,
+        command: Command,
+        context: ContextId,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        match command {
+            Command::FocusSearch => {
+                let from_headers = matches!(self.focus_target, FocusTarget::TableHeaders);
+                self.set_focus_target(FocusTarget::Table, cx);
+                self.task_table.update(cx, |table, cx| {
+                    if from_headers {
+                        table.blur_table_headers(cx);
                     }
-                    Command::FocusTableHeaders => {
-                        self.focus_target = FocusTarget::TableHeaders;
-                        self.task_table.update(cx, |table, cx| {
-                            table.blur_search_input(window, cx);
-                            table.set_filter_bar_focus(
-                                crate::view::task_table::FilterBarFocus::None,
-                                cx,
-                            );
-                            table.focus_table_headers(window, cx);
-                        });
-                        cx.notify();
+                    table.focus_search_input(window, cx);
+                });
+                cx.notify();
+            }
+            Command::TogglePalette => {
+                self.open_command_palette(context, window, cx);
+            }
+            Command::OpenCalendar => {
+                self.open_calendar(window, cx);
+            }
+            Command::OpenSettings => {
+                self.open_settings(window, cx);
+            }
+            Command::ToggleShortcuts => {
+                self.shortcuts_open = true;
+                self.shortcuts_scroll_handle
+                    .set_offset(gpui::point(gpui::px(0.), gpui::px(0.)));
+                cx.notify();
+            }
+            Command::QuickAdd
+            | Command::AddAnnotation
+            | Command::SetProject
+            | Command::SetDue
+            | Command::SnoozeTask
+            | Command::SetRecurrence
+            | Command::SetUntil
+            | Command::SetDependencies => {
+                let kind = match command {
+                    Command::QuickAdd => TaskPromptKind::QuickAdd,
+                    Command::AddAnnotation => TaskPromptKind::Annotation,
+                    Command::SetProject => TaskPromptKind::Project,
+                    Command::SnoozeTask => TaskPromptKind::Snooze,
+                    Command::SetRecurrence => TaskPromptKind::Recurrence,
+                    Command::SetUntil => TaskPromptKind::Until,
+                    Command::SetDependencies => TaskPromptKind::Dependencies,
+                    _ => TaskPromptKind::Due,
+                };
+                self.set_focus_target(FocusTarget::Table, cx);
+                self.task_table.update(cx, |table, cx| {
+                    table.open_task_prompt(kind, window, cx);
+                });
+                cx.notify();
+            }
+            Command::ExportTasks | Command::ImportTasks => {
+                let kind = if command == Command::ExportTasks {
+                    TaskPromptKind::Export
+                } else {
+                    TaskPromptKind::Import
+                };
+                self.set_focus_target(FocusTarget::Table, cx);
+                self.task_table.update(cx, |table, cx| {
+                    table.open_task_prompt(kind, window, cx);
+                });
+                cx.notify();
+            }
+            Command::RenameProject => {
+                let Some(project) = self.sidebar.read(cx).selected_project() else {
+                    return;
+                };
+                let task_count = TaskFilter::new()
+                    .with_project(project.clone(), true)
+                    .apply(&self.tasks)
+                    .len();
+                self.set_focus_target(FocusTarget::Table, cx);
+                self.task_table.update(cx, |table, cx| {
+                    table.open_project_rename_prompt(project, task_count, window, cx);
+                });
+                cx.notify();
+            }
+            Command::ToggleProjectSearch => {
+                self.set_focus_target(FocusTarget::SidebarProjects, cx);
+                self.sidebar.update(cx, |sidebar, cx| {
+                    sidebar.toggle_project_search(window, cx);
+                });
+                cx.notify();
+            }
+            Command::ModalSearchAnnotations => {
+                self.task_detail_modal.update(cx, |modal, cx| {
+                    modal.focus_annotation_search(window, cx);
+                });
+            }
+            Command::JumpToId => {
+                self.task_table.update(cx, |table, cx| {
+                    table.open_jump_to_id(window, cx);
+                });
+            }
+            Command::SetSecondarySortColumn => {
+                self.task_table.update(cx, |table, cx| {
+                    table.open_secondary_sort_picker(window, cx);
+                });
+            }
+            Command::FocusTableHeaders => {
+                self.set_focus_target(FocusTarget::TableHeaders, cx);
+                self.task_table.update(cx, |table, cx| {
+                    table.blur_search_input(window, cx);
+                    table.focus_table_headers(window, cx);
+                });
+                cx.notify();
+            }
+            Command::FocusTable => {
+                self.set_focus_target(FocusTarget::Table, cx);
+                self.task_table.update(cx, |table, cx| match context {
+                    ContextId::TextInput | ContextId::FilterBar => {
+                        table.blur_search_input(window, cx);
+                        table.set_filter_bar_focus(
+                            crate::view::task_table::FilterBarFocus::None,
+                            cx,
+                        );
                     }
-                    Command::FocusTable => {
-                        self.focus_target = FocusTarget::Table;
-                        self.task_table.update(cx, |table, cx| match context {
-                            ContextId::TextInput | ContextId::FilterBar => {
-                                table.blur_search_input(window, cx);
-                                table.set_filter_bar_focus(
-                                    crate::view::task_table::FilterBarFocus::None,
-                                    cx,
-                                );
-                            }
-                            ContextId::TableHeaders => {
-                                table.blur_table_headers(cx);
-                            }
-                            _ => {}
-                        });
-                        cx.notify();
+                    ContextId::TableHeaders => {
+                        table.blur_table_headers(cx);
                     }
-                    Command::FocusFilterNext | Command::FocusFilterPrev => {
-                        self.task_table.update(cx, |table, cx| {
-                            use crate::view::task_table::FilterBarFocus;
-                            let was_on_input =
-                                matches!(table.get_filter_bar_focus(), FilterBarFocus::SearchInput);
-
-                            if command == Command::FocusFilterNext {
-                                table.focus_filter_next(cx);
-                            } else {
-                                table.focus_filter_prev(cx);
-                            }
-
-                            if was_on_input {
-                                table.blur_search_input(window, cx);
-                            }
-
-                            let now_on_input =
-                                matches!(table.get_filter_bar_focus(), FilterBarFocus::SearchInput);
-                            if now_on_input && !was_on_input {
-                                table.focus_search_input(window, cx);
-                            }
-                        });
+                    _ => {}
+                });
+                cx.notify();
+            }
+            Command::FocusFilterNext | Command::FocusFilterPrev => {
+                self.task_table.update(cx, |table, cx| {
+                    use crate::view::task_table::FilterBarFocus;
+                    let was_on_input =
+                        matches!(table.get_filter_bar_focus(), FilterBarFocus::SearchInput);
+
+                    if command == Command::FocusFilterNext {
+                        table.focus_filter_next(cx);
+                    } else {
+                        table.focus_filter_prev(cx);
                     }
-                    _ => {
-                        self.dispatch(command, cx);
+
+                    if was_on_input {
+                        table.blur_search_input(window, cx);
                     }
-                }
+
+                    let now_on_input =
+                        matches!(table.get_filter_bar_focus(), FilterBarFocus::SearchInput);
+                    if now_on_input && !was_on_input {
+                        table.focus_search_input(window, cx);
+                    }
+                });
+            }
+            _ => {
+                self.dispatch(command, cx);
+            }
+        }
+    }
+
+    fn open_calendar(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        if self.task_detail_modal.read(cx).is_open() {
+            return;
+        }
+
+        self.task_table.update(cx, |table, cx| {
+            table.blur_search_input(window, cx);
+        });
+        window.focus(&self.focus_handle);
+
+        let tasks = self.tasks.clone();
+        self.calendar_view.update(cx, |calendar, cx| {
+            calendar.open(&tasks, cx);
+        });
+        cx.notify();
+    }
+
+    fn open_settings(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        if self.task_detail_modal.read(cx).is_open() {
+            return;
+        }
+
+        self.task_table.update(cx, |table, cx| {
+            table.blur_search_input(window, cx);
+        });
+        window.focus(&self.focus_handle);
+
+        let config = cx.config().clone();
+        self.settings_modal.update(cx, |settings, cx| {
+            settings.open(config, cx);
+        });
+        cx.notify();
+    }
+
+    fn submit_task_prompt(
+        &mut self,
+        kind: TaskPromptKind,
+        uuid: uuid::Uuid,
+        value: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let result = match kind {
+            TaskPromptKind::Annotation => self
+                .task_service
+                .add_annotation(uuid, value)
+                .map(|_| "annotated"),
+            TaskPromptKind::Project => {
+                let project = Some(value).filter(|value| !value.is_empty());
+                self.task_service
+                    .update_task(uuid, None, Some(project), None, None, None, None)
+                    .map(|_| "project updated")
             }
+            TaskPromptKind::Due => {
+                let due = if value.is_empty() {
+                    Ok(None)
+                } else {
+                    task::parse_date_expression(&value)
+                        .map(Some)
+                        .ok_or_else(|| task::TaskError::InvalidDue(value.clone()))
+                };
+                due.and_then(|due| {
+                    self.task_service
+                        .update_task(uuid, None, None, None, None, Some(due), None)
+                })
+                .map(|_| "due date updated")
+            }
+            TaskPromptKind::Snooze => {
+                self.snooze_task(uuid, value, cx);
+                return;
+            }
+            TaskPromptKind::Recurrence => {
+                self.task_service.set_recurrence(uuid, &value).map(|_| {
+                    if value.is_empty() {
+                        "recurrence cleared"
+                    } else {
+                        "recurrence updated"
+                    }
+                })
+            }
+            TaskPromptKind::Until => {
+                let until = if value.is_empty() {
+                    Ok(None)
+                } else {
+                    task::parse_date_expression(&value)
+                        .map(Some)
+                        .ok_or_else(|| task::TaskError::InvalidDue(value.clone()))
+                };
+                until
+                    .and_then(|until| self.task_service.set_until(uuid, until))
+                    .map(|_| "until date updated")
+            }
+            TaskPromptKind::Dependencies => {
+                let task_id = self
+                    .tasks
+                    .iter()
+                    .find(|task| task.uuid == uuid)
+                    .and_then(|task| task.working_id)
+                    .ok_or_else(|| {
+                        task::TaskError::InvalidDependency("task has no working ID".to_string())
+                    });
+                task::parser::parse_working_ids(&value)
+                    .and_then(|ids| {
+                        self.task_service
+                            .set_dependencies_by_working_id(task_id?, &ids)
+                    })
+                    .map(|_| "dependencies updated")
+            }
+            TaskPromptKind::QuickAdd
+            | TaskPromptKind::BulkProject
+            | TaskPromptKind::BulkTag
+            | TaskPromptKind::Export
+            | TaskPromptKind::Import
+            | TaskPromptKind::RenameProject => return,
+        };
+
+        self.finish_task_mutation(uuid, result, cx);
+    }
+
+    fn add_task(&mut self, input: String, cx: &mut gpui::Context<Self>) {
+        let result = task::parser::parse_add_command(&input)
+            .and_then(|draft| self.task_service.create_task(draft));
+        match result {
+            Ok(task) => {
+                self.reload_tasks(cx);
+                self.show_task_toast(ToastKind::Success, task.uuid, "added", cx);
+            }
+            Err(e) => self.finish_task_action(Err(e), cx),
         }
     }
 
@@ -293,6 +1052,37 @@ impl App {
         self.open_task_detail(task_id, window, cx);
     }
 
+    fn apply_launch_args(&mut self, launch: &LaunchArgs, cx: &mut gpui::Context<Self>) {
+        let target = match (launch.task, &launch.search) {
+            (Some(uuid), _) => Some(uuid),
+            (None, Some(query)) => match self.task_service.find_tasks_by_description(query) {
+                Ok(matches) => {
+                    if matches.is_empty() {
+                        self.toast_host.update(cx, |host, cx| {
+                            host.push(ToastKind::Info, format!("No tasks match '{}'", query), cx);
+                        });
+                    }
+                    matches.first().map(|task| task.uuid)
+                }
+                Err(e) => {
+                    log::error!("[App] Failed to search tasks for '{}': {}", query, e);
+                    None
+                }
+            },
+            (None, None) => None,
+        };
+        let Some(uuid) = target else {
+            return;
+        };
+
+        let selected = self
+            .task_table
+            .update(cx, |table, cx| table.select_task(uuid, cx));
+        if !selected && launch.task.is_some() {
+            self.open_task_detail(uuid, None, cx);
+        }
+    }
+
     fn open_task_detail(
         &mut self,
         task_id: uuid::Uuid,
@@ -300,10 +1090,18 @@ impl App {
         cx: &mut gpui::Context<Self>,
     ) {
         self.focus_before_modal = self.focus_target;
+        self.task_table
+            .update(cx, |table, cx| table.release_filter_bar(cx));
 
-        let tasks = self.tasks.clone();
-        match self.task_service.get_task_detail(task_id, &tasks) {
-            Ok(detail) => {
+        match self.load_task_detail(task_id) {
+            Ok(mut detail) => {
+                detail.history = self
+                    .task_service
+                    .get_task_history(task_id)
+                    .unwrap_or_else(|e| {
+                        log::warn!("[App] Failed to load task history: {}", e);
+                        Vec::new()
+                    });
                 self.task_detail_modal.update(cx, |modal, cx| {
                     modal.open_with_detail(detail, window, cx);
                 });
@@ -318,7 +1116,228 @@ impl App {
         cx.notify();
     }
 
+    fn remove_annotation(
+        &mut self,
+        task_id: uuid::Uuid,
+        entry: chrono::DateTime<chrono::Utc>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let result = self
+            .task_service
+            .remove_annotation(task_id, entry)
+            .map(|_| "annotation deleted");
+        self.finish_detail_action(task_id, result, cx);
+    }
+
+    fn set_task_uda(
+        &mut self,
+        task_id: uuid::Uuid,
+        key: String,
+        value: Option<String>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let message = if value.is_some() {
+            "attribute updated"
+        } else {
+            "attribute removed"
+        };
+        let result = self
+            .task_service
+            .set_uda(task_id, &key, value)
+            .map(|_| message);
+        self.finish_detail_action(task_id, result, cx);
+    }
+
+    fn finish_detail_action(
+        &mut self,
+        task_id: uuid::Uuid,
+        result: task::TaskResult<&'static str>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let succeeded = result.is_ok();
+        self.finish_task_mutation(task_id, result, cx);
+        if succeeded {
+            self.refresh_task_detail(task_id, cx);
+        }
+    }
+
+    pub(super) fn refresh_task_detail(
+        &mut self,
+        task_id: uuid::Uuid,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        match self.load_task_detail(task_id) {
+            Ok(mut detail) => {
+                detail.history = self
+                    .task_service
+                    .get_task_history(task_id)
+                    .unwrap_or_default();
+                self.task_detail_modal.update(cx, |modal, cx| {
+                    modal.set_detail(detail, cx);
+                });
+            }
+            Err(e) => {
+                self.task_detail_modal.update(cx, |modal, cx| {
+                    modal.set_error(task_id, e.to_string(), cx);
+                });
+            }
+        }
+    }
+
+    fn load_task_detail(&mut self, task_id: uuid::Uuid) -> task::TaskResult<task::TaskDetailVm> {
+        let mut detail = self.task_service.get_task_detail(task_id, &self.tasks)?;
+        detail.metrics.urgency = self
+            .tasks
+            .iter()
+            .find(|summary| summary.uuid == task_id)
+            .map(|summary| summary.urgency);
+        Ok(detail)
+    }
+
+    pub(super) fn set_focus_target(&mut self, target: FocusTarget, cx: &mut gpui::Context<Self>) {
+        self.focus_target = target;
+        if target != FocusTarget::Table {
+            self.task_table
+                .update(cx, |table, cx| table.release_filter_bar(cx));
+        }
+    }
+
+    pub(super) fn toggle_sidebar(&mut self, cx: &mut gpui::Context<Self>) {
+        cx.update_global::<crate::config::AppConfig, _>(|config, _cx| {
+            config.sidebar.collapsed = !config.sidebar.collapsed;
+        });
+
+        self.save_sidebar_config(cx);
+
+        if cx.config().sidebar.collapsed && self.focus_target.is_sidebar() {
+            self.set_focus_target(FocusTarget::Table, cx);
+        }
+        cx.notify();
+    }
+
+    fn save_sidebar_config(&mut self, cx: &mut gpui::Context<Self>) {
+        if let Err(e) = cx.config().save() {
+            log::error!("[App] Failed to save sidebar layout: {}", e);
+        }
+    }
+
+    fn apply_settings(&mut self, config: crate::config::AppConfig, cx: &mut gpui::Context<Self>) {
+        let previous = cx.config().clone();
+        cx.update_global::<crate::config::AppConfig, _>(|current, _cx| {
+            current.theme = config.theme;
+            current.page_size = config.page_size;
+            current.relative_dates = config.relative_dates;
+            current.show_inline_tags = config.show_inline_tags;
+            current.auto_sync_interval_minutes = config.auto_sync_interval_minutes;
+            current.confirm_destructive = config.confirm_destructive;
+            current.sidebar.width = config.sidebar.width;
+            current.set_urgency_overrides(config.urgency_overrides);
+        });
+
+        if config.theme != previous.theme {
+            cx.set_global(Theme::with_taskrc_overrides(
+                config.theme.theme(),
+                self.task_service.taskrc_colors(),
+            ));
+        }
+        if config.auto_sync_interval_minutes != previous.auto_sync_interval_minutes {
+            self.schedule_auto_sync(None, cx);
+        }
+        if config.urgency_overrides != previous.urgency_overrides {
+            self.task_service
+                .set_urgency_config(cx.config().urgency.clone());
+            self.reload_tasks(cx);
+        }
+        self.task_table.update(cx, |table, cx| {
+            table.apply_config(cx);
+        });
+
+        if let Err(e) = cx.config().save() {
+            log::error!("[App] Failed to save settings: {}", e);
+            self.toast_host.update(cx, |host, cx| {
+                host.push(ToastKind::Error, e.to_string(), cx);
+            });
+        }
+        cx.refresh_windows();
+    }
+
+    fn resize_sidebar(&mut self, width: gpui::Pixels, cx: &mut gpui::Context<Self>) {
+        let current = cx.config().sidebar.width;
+        cx.update_global::<crate::config::AppConfig, _>(|config, _cx| {
+            config.sidebar.set_width(width);
+        });
+
+        if cx.config().sidebar.width != current {
+            cx.notify();
+        }
+    }
+
+    pub(super) fn toggle_preview_pane(&mut self, cx: &mut gpui::Context<Self>) {
+        self.task_preview.update(cx, |pane, cx| {
+            pane.toggle(cx);
+        });
+
+        if self.task_preview.read(cx).is_open() {
+            let selection = self.task_table.read(cx).selected_task_uuid();
+            self.load_preview(selection, cx);
+        } else {
+            self.preview_task = None;
+        }
+        cx.notify();
+    }
+
+    fn schedule_preview(&mut self, selection: Option<uuid::Uuid>, cx: &mut gpui::Context<Self>) {
+        if !self.task_preview.read(cx).is_open() {
+            return;
+        }
+
+        let Some(task_id) = selection else {
+            self.load_preview(None, cx);
+            return;
+        };
+
+        self.preview_task = Some(cx.spawn(async move |app, cx| {
+            cx.background_executor().timer(PREVIEW_DEBOUNCE).await;
+            app.update(cx, |app, cx| app.load_preview(Some(task_id), cx))
+                .ok();
+        }));
+    }
+
+    fn load_preview(&mut self, selection: Option<uuid::Uuid>, cx: &mut gpui::Context<Self>) {
+        self.preview_task = None;
+
+        let Some(task_id) = selection else {
+            self.task_preview.update(cx, |pane, cx| {
+                pane.clear(cx);
+            });
+            return;
+        };
+
+        match self.load_task_detail(task_id) {
+            Ok(detail) => {
+                self.task_preview.update(cx, |pane, cx| {
+                    pane.set_detail(detail, cx);
+                });
+            }
+            Err(e) => {
+                log::warn!("[App] Failed to load task preview: {}", e);
+                self.task_preview.update(cx, |pane, cx| {
+                    pane.set_error(e.to_string(), cx);
+                });
+            }
+        }
+    }
+
     fn active_context(&self, cx: &gpui::Context<Self>) -> ContextId {
+        if self.command_palette.read(cx).is_open() {
+            return ContextId::Palette;
+        }
+        if self.calendar_view.read(cx).is_open() {
+            return ContextId::Calendar;
+        }
+        if self.settings_modal.read(cx).is_open() {
+            return ContextId::Settings;
+        }
         if self.task_detail_modal.read(cx).is_open() {
             return ContextId::Modal;
         }
@@ -331,131 +1350,345 @@ impl App {
         self.focus_target.to_context()
     }
 
-    pub fn run() -> () {
-        let app = gpui::Application::new();
+    fn new(
+        mut task_service: TaskService,
+        launch: &LaunchArgs,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Self {
+        let mut default_filters = cx.config().default_filters.filter_state();
+        if let Some(search) = &launch.search {
+            default_filters.search_text = search.clone();
+        }
+        let default_search = default_filters.search_text.clone();
+        let filter_state = cx.new(|_cx| default_filters);
 
-        app.run(|app: &mut gpui::App| {
-            app.set_global(crate::theme::Theme::dark());
-            app.open_window(
-                gpui::WindowOptions::default(),
-                |window: &mut gpui::Window, app: &mut gpui::App| {
-                    app.new(|cx: &mut gpui::Context<'_, App>| {
-                        let filter_state = cx.new(|_cx| FilterState::new());
-
-                        let mut task_service = TaskService::new()
-                            .unwrap_or_else(|e| panic!("Failed to initialize TaskService: {}", e));
-
-                        let overview = task_service.get_overview().unwrap_or_else(|e| {
-                            log::error!("Failed to load tasks: {}", e);
-                            TaskOverview {
-                                tasks: vec![],
-                                projects: vec![],
-                                tags: vec![],
-                                total_tasks: 0,
-                                pending_tasks: 0,
-                                completed_tasks: 0,
-                            }
-                        });
+        let overview = task_service.get_overview().unwrap_or_else(|e| {
+            log::error!("Failed to load tasks: {}", e);
+            TaskOverview {
+                tasks: vec![],
+                projects: vec![],
+                tags: vec![],
+                total_tasks: 0,
+                pending_tasks: 0,
+                completed_tasks: 0,
+            }
+        });
+
+        let theme =
+            Theme::with_taskrc_overrides(cx.config().theme.theme(), task_service.taskrc_colors());
+        cx.set_global(theme);
 
-                        let task_summaries: Vec<TaskSummary> =
-                            overview.tasks.iter().map(TaskSummary::from).collect();
+        cx.update_global::<crate::config::AppConfig, _>(|config, _cx| {
+            config.set_taskrc_urgency(task_service.urgency_config().clone());
+        });
+        task_service.set_urgency_config(cx.config().urgency.clone());
+        let task_summaries = Self::summarize_tasks(&overview.tasks, cx);
 
-                        let mut project_tree = ProjectTree::new();
-                        project_tree.build_from_projects(&overview.projects);
+        let mut project_tree = ProjectTree::new();
+        project_tree.build_from_projects(&overview.projects);
 
-                        let tags: Vec<TagItem> = overview
-                            .tags
-                            .into_iter()
-                            .map(|(name, task_count)| TagItem { name, task_count })
-                            .collect();
+        let tags: Vec<TagItem> = overview
+            .tags
+            .into_iter()
+            .map(|(name, task_count)| TagItem { name, task_count })
+            .collect();
 
-                        let status_bar = cx.new(|cx| StatusBar::new(cx));
-                        let toast_host = cx.new(|cx| ToastHost::new(cx));
-                        cx.set_global(ToastGlobal {
-                            host: toast_host.clone(),
-                        });
+        let data_location = format!(
+            "{} ({})",
+            task_service.data_location().display(),
+            task_service.data_location_source()
+        );
+        let status_bar = cx.new(|cx| {
+            let mut bar = StatusBar::new(cx);
+            bar.set_data_location(data_location, cx);
+            bar
+        });
+        let toast_duration_ms = cx.config().toast_duration_ms;
+        let toast_host = cx.new(|cx| match toast_duration_ms {
+            Some(ms) => ToastHost::new(cx).with_default_duration(ms),
+            None => ToastHost::new(cx),
+        });
+        cx.set_global(ToastGlobal {
+            host: toast_host.clone(),
+        });
 
-                        let sidebar =
-                            cx.new(|cx| Sidebar::new(project_tree, tags, filter_state.clone(), cx));
+        let inbox_count = Self::count_inbox_tasks(&task_summaries);
+        let today_count = FilterPreset::today_focus().apply(&task_summaries).len();
+        let sidebar = cx.new(|cx| {
+            let mut sidebar = Sidebar::new(project_tree, tags, filter_state.clone(), cx);
+            sidebar.set_inbox_count(inbox_count, cx);
+            sidebar.set_today_count(today_count, cx);
+            sidebar
+        });
 
-                        let task_table = cx
-                            .new(|cx| TaskTable::new("main-task-table", filter_state.clone(), cx));
+        let task_table = cx.new(|cx| TaskTable::new("main-task-table", filter_state.clone(), cx));
 
-                        let task_detail_modal = cx.new(|cx| TaskDetailModal::new(cx));
+        let task_detail_modal = cx.new(|cx| TaskDetailModal::new(cx));
+        let task_preview = cx.new(|cx| TaskPreviewPane::new(cx));
+        let command_palette = cx.new(|cx| CommandPalette::new(cx));
+        let calendar_view = cx.new(|cx| CalendarView::new(cx));
+        let settings_modal = cx.new(|cx| SettingsModal::new(cx));
 
-                        let task_table_events = task_table.clone();
-                        let sidebar_events = sidebar.clone();
-                        let modal_events = task_detail_modal.clone();
+        let task_table_events = task_table.clone();
+        let sidebar_events = sidebar.clone();
+        let modal_events = task_detail_modal.clone();
 
-                        task_table.update(cx, |table, cx| {
-                            table.reload_tasks_from_all(task_summaries.clone(), cx);
-                        });
+        task_table.update(cx, |table, cx| {
+            table.set_search_input(default_search, cx);
+            table.reload_tasks_from_all(task_summaries.clone(), cx);
+        });
 
-                        let mut keymap = KeymapStack::new();
-                        keymap.push_layer(crate::keymap::defaults::build_default_keymap());
-
-                        let app_instance = App {
-                            focus_handle: cx.focus_handle(),
-                            focus_target: FocusTarget::Table,
-                            keymap,
-                            sidebar,
-                            filter_state: filter_state.clone(),
-                            status_bar: status_bar.clone(),
-                            task_table,
-                            task_detail_modal,
-                            toast_host,
-                            task_service,
-                            tasks: task_summaries,
-                            focus_before_modal: FocusTarget::Table,
-                        };
-
-                        window.focus(&app_instance.focus_handle);
-
-                        cx.observe(&filter_state, |app, _, cx| {
-                            app.reload_tasks(cx);
-                        })
-                        .detach();
-
-                        cx.subscribe(&status_bar, |app, _bar, event, cx| match event {
-                            StatusBarEvent::SyncRequested => {
-                                app.handle_sync(cx);
-                            }
-                        })
-                        .detach();
-
-                        cx.subscribe(&sidebar_events, |app, _sidebar, event, cx| match event {
-                            SidebarEvent::Focused(section) => {
-                                app.focus_target = match section {
-                                    SidebarSection::Projects => FocusTarget::SidebarProjects,
-                                    SidebarSection::Tags => FocusTarget::SidebarTags,
-                                };
-                                cx.notify();
-                            }
-                        })
-                        .detach();
-
-                        cx.subscribe(&task_table_events, |app, _table, event, cx| match event {
-                            TaskTableEvent::OpenTask(task_id) => {
-                                if !app.task_detail_modal.read(cx).is_open() {
-                                    app.open_task_detail(*task_id, None, cx);
-                                }
-                            }
-                        })
-                        .detach();
-
-                        cx.subscribe(&modal_events, |app, _modal, event, cx| match event {
-                            TaskDetailModalEvent::Closed => {
-                                app.focus_target = app.focus_before_modal;
-                                cx.notify();
-                            }
-                        })
-                        .detach();
-
-                        app_instance
-                    })
+        let mut keymap = KeymapStack::new();
+        keymap.push_layer(crate::keymap::defaults::build_default_keymap());
+        let user_bindings = cx.config().keybindings.clone();
+        if !user_bindings.is_empty() {
+            keymap.push_layer(KeymapLayer::from_bindings(user_bindings.iter().map(
+                |binding| {
+                    (
+                        binding.context.as_str(),
+                        binding.keys.as_str(),
+                        binding.command.as_str(),
+                    )
                 },
-            )
-            .unwrap();
+            )));
+        }
+        let conflicts = keymap.validate();
+        for conflict in &conflicts {
+            log::warn!(
+                "[Keymap] {} in {} now runs {} instead of {}",
+                conflict.chord,
+                conflict.context.as_str(),
+                conflict.new_command.as_str(),
+                conflict.old_command.as_str()
+            );
+        }
+        if !conflicts.is_empty() {
+            let message = format!(
+                "Custom keybindings replace {} default {} (see log)",
+                conflicts.len(),
+                if conflicts.len() == 1 {
+                    "binding"
+                } else {
+                    "bindings"
+                }
+            );
+            toast_host.update(cx, |host, cx| {
+                host.push(ToastKind::Warning, message, cx);
+            });
+        }
+
+        let mut app_instance = App {
+            focus_handle: cx.focus_handle(),
+            focus_target: FocusTarget::Table,
+            keymap,
+            sidebar,
+            filter_state: filter_state.clone(),
+            status_bar: status_bar.clone(),
+            task_table,
+            task_detail_modal,
+            task_preview,
+            command_palette: command_palette.clone(),
+            calendar_view: calendar_view.clone(),
+            settings_modal: settings_modal.clone(),
+            toast_host,
+            task_service,
+            tasks: task_summaries,
+            focus_before_modal: FocusTarget::Table,
+            shortcuts_open: false,
+            shortcuts_scroll_handle: gpui::ScrollHandle::new(),
+            import_summary: None,
+            auto_sync_task: None,
+            last_sync_at: None,
+            auto_sync_failing: false,
+            sync_in_flight: false,
+            maintenance_in_flight: false,
+            preview_task: None,
+            storage_watch_task: None,
+            storage_stamp: None,
+            storage_change_pending: false,
+            window_title: String::new(),
+            window_title_dirty: false,
+        };
+        app_instance.schedule_auto_sync(Some(AUTO_SYNC_STARTUP_DELAY), cx);
+        app_instance.watch_storage(cx);
+        app_instance.update_status_counts(cx);
+        app_instance.update_window_title(cx);
+        app_instance.refresh_replica_stats(cx);
+        app_instance.warn_missing_default_project(cx);
+        app_instance.apply_launch_args(launch, cx);
+
+        window.focus(&app_instance.focus_handle);
+
+        cx.observe(&filter_state, |app, _, cx| {
+            app.reload_tasks(cx);
+        })
+        .detach();
+
+        cx.subscribe(&status_bar, |app, _bar, event, cx| match event {
+            StatusBarEvent::SyncRequested => {
+                app.handle_sync(cx);
+            }
+            StatusBarEvent::ShowOverdue => {
+                app.filter_state.update(cx, |state, cx| {
+                    state.due_filter = DueFilter::Overdue;
+                    cx.notify();
+                });
+            }
+            StatusBarEvent::OpenTask(task_id) => {
+                app.open_task_detail(*task_id, None, cx);
+            }
+        })
+        .detach();
+
+        cx.subscribe_in(
+            &sidebar_events,
+            window,
+            |app, _sidebar, event, window, cx| match event {
+                SidebarEvent::Focused(section) => {
+                    let target = match section {
+                        SidebarSection::Projects => FocusTarget::SidebarProjects,
+                        SidebarSection::Tags => FocusTarget::SidebarTags,
+                    };
+                    app.set_focus_target(target, cx);
+                    cx.notify();
+                }
+                SidebarEvent::Command(command) => {
+                    app.set_focus_target(FocusTarget::SidebarProjects, cx);
+                    app.execute_command(*command, ContextId::SidebarProjects, window, cx);
+                }
+            },
+        )
+        .detach();
+
+        cx.subscribe_in(
+            &task_table_events,
+            window,
+            |app, _table, event, window, cx| match event {
+                TaskTableEvent::OpenTask(task_id) => {
+                    if !app.task_detail_modal.read(cx).is_open() {
+                        app.open_task_detail(*task_id, None, cx);
+                    }
+                }
+                TaskTableEvent::SelectionChanged(selection) => {
+                    app.schedule_preview(*selection, cx);
+                }
+                TaskTableEvent::Command(command) => {
+                    app.set_focus_target(FocusTarget::Table, cx);
+                    app.execute_command(*command, ContextId::Table, window, cx);
+                }
+                TaskTableEvent::AddTask(input) => {
+                    app.add_task(input.clone(), cx);
+                }
+                TaskTableEvent::PromptSubmitted { kind, uuid, value } => {
+                    app.submit_task_prompt(*kind, *uuid, value.clone(), cx);
+                }
+                TaskTableEvent::Export(path) => {
+                    app.export_tasks(path.clone(), cx);
+                }
+                TaskTableEvent::Import(path) => {
+                    app.import_tasks(path.clone(), cx);
+                }
+                TaskTableEvent::RenameProject { from, to } => {
+                    app.rename_project(from.clone(), to.clone(), cx);
+                }
+                TaskTableEvent::BulkAction { action, uuids } => {
+                    app.request_bulk_action(action.clone(), uuids.clone(), cx);
+                }
+            },
+        )
+        .detach();
+
+        cx.subscribe_in(
+            &command_palette,
+            window,
+            |app, _palette, event, window, cx| match event {
+                CommandPaletteEvent::Execute => {
+                    app.execute_palette_selection(window, cx);
+                }
+            },
+        )
+        .detach();
+
+        cx.subscribe(&calendar_view, |app, _calendar, event, cx| match event {
+            CalendarEvent::SelectDate(date) => {
+                let date = *date;
+                app.set_focus_target(FocusTarget::Table, cx);
+                app.filter_state.update(cx, |state, cx| {
+                    state.due_filter = DueFilter::OnDate(date);
+                    cx.notify();
+                });
+            }
+            CalendarEvent::Closed => {
+                cx.notify();
+            }
+        })
+        .detach();
+
+        cx.subscribe(&settings_modal, |app, _settings, event, cx| match event {
+            SettingsModalEvent::Closed(config) => {
+                app.apply_settings(config.clone(), cx);
+            }
+        })
+        .detach();
+
+        cx.subscribe(&modal_events, |app, _modal, event, cx| match event {
+            TaskDetailModalEvent::Closed => {
+                app.set_focus_target(app.focus_before_modal, cx);
+                cx.notify();
+            }
+            TaskDetailModalEvent::OpenTask(task_id) => {
+                app.open_task_detail(*task_id, None, cx);
+            }
+            TaskDetailModalEvent::AnnotationDeleted(task_id, entry) => {
+                app.remove_annotation(*task_id, *entry, cx);
+            }
+            TaskDetailModalEvent::UdaChanged(task_id, key, value) => {
+                app.set_task_uda(*task_id, key.clone(), value.clone(), cx);
+            }
+        })
+        .detach();
+
+        app_instance
+    }
+
+    pub fn run(launch: LaunchArgs) -> () {
+        let app = gpui::Application::new();
+
+        app.run(move |app: &mut gpui::App| {
+            let config = crate::config::AppConfig::load();
+            app.set_global(config.theme.theme());
+            app.set_global(config);
+            let options = gpui::WindowOptions {
+                titlebar: Some(gpui::TitlebarOptions {
+                    title: Some(crate::ui::WINDOW_TITLE.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+
+            let opened = match TaskService::new() {
+                Ok(task_service) => app
+                    .open_window(options, |window, app| {
+                        app.new(|cx| Self::new(task_service, &launch, window, cx))
+                    })
+                    .map(|_| ()),
+                Err(e) => {
+                    log::error!("[App] Failed to initialize TaskService: {}", e);
+                    let on_ready = move |task_service: TaskService,
+                                         window: &mut gpui::Window,
+                                         cx: &mut gpui::App| {
+                        window.replace_root(cx, |window, cx| {
+                            Self::new(task_service, &launch, window, cx)
+                        });
+                    };
+                    app.open_window(options, |window, app| {
+                        app.new(|cx| SetupScreen::new(e, on_ready, window, cx))
+                    })
+                    .map(|_| ())
+                }
+            };
+            opened.unwrap();
         });
     }
 }
@@ -0,0 +1,80 @@
+pub const USAGE: &str = "Usage: taskwarrior-gpui [--task <uuid>] [--search <query>]";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchArgs {
+    pub task: Option<uuid::Uuid>,
+    pub search: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliAction {
+    Launch(LaunchArgs),
+    Help,
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliAction, String> {
+    let mut launch = LaunchArgs::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(CliAction::Help),
+            "--task" => {
+                let value = inline_value
+                    .map(str::to_string)
+                    .or_else(|| args.next())
+                    .ok_or("--task expects a task uuid")?;
+                let uuid = uuid::Uuid::parse_str(value.trim())
+                    .map_err(|_| format!("Invalid task uuid: {}", value))?;
+                launch.task = Some(uuid);
+            }
+            "--search" => {
+                let value = inline_value
+                    .map(str::to_string)
+                    .or_else(|| args.next())
+                    .ok_or("--search expects a query")?;
+                let query = value.trim();
+                if query.is_empty() {
+                    return Err("--search expects a query".to_string());
+                }
+                launch.search = Some(query.to_string());
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(CliAction::Launch(launch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<CliAction, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_launch_args() {
+        let uuid = uuid::Uuid::new_v4();
+        let task = uuid.to_string();
+
+        assert_eq!(parse(&[]), Ok(CliAction::Launch(LaunchArgs::default())));
+        assert_eq!(
+            parse(&["--task", &task, "--search=buy milk"]),
+            Ok(CliAction::Launch(LaunchArgs {
+                task: Some(uuid),
+                search: Some("buy milk".to_string()),
+            }))
+        );
+        assert_eq!(parse(&["--help"]), Ok(CliAction::Help));
+        assert!(parse(&["--task", "not-a-uuid"]).is_err());
+        assert!(parse(&["--search"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+    }
+}
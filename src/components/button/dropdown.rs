@@ -1,11 +1,16 @@
 use std::sync::Arc;
+use std::time::Instant;
 
 use gpui::prelude::*;
 
 use crate::components::button::Button;
+use crate::components::input::Input;
 use crate::components::label::Label;
 use crate::theme::ActiveTheme;
-use crate::ui::{clickable_control_style, disabled_control_style};
+use crate::ui::{
+    DROPDOWN_MENU_MAX_HEIGHT, DROPDOWN_TYPE_AHEAD_RESET, clickable_control_style,
+    disabled_control_style,
+};
 
 #[derive(Clone, Debug)]
 pub struct DropdownItem {
@@ -39,10 +44,19 @@ pub struct Dropdown {
     items: Vec<DropdownItem>,
     open: bool,
     selected_index: Option<usize>,
+    highlighted_index: Option<usize>,
     disabled: bool,
     loading: bool,
     placeholder: gpui::SharedString,
     label_prefix: Option<gpui::SharedString>,
+    searchable: bool,
+    search_input: Option<gpui::Entity<Input>>,
+    search_query: String,
+    focus_search: bool,
+    restore_focus: Option<gpui::FocusHandle>,
+    menu_scroll_handle: gpui::ScrollHandle,
+    type_ahead: String,
+    type_ahead_at: Option<Instant>,
     on_select: Option<Arc<dyn Fn(usize, &DropdownItem, &mut gpui::Context<Self>) + Send + Sync>>,
 }
 
@@ -54,14 +68,28 @@ impl Dropdown {
             items: Vec::new(),
             open: false,
             selected_index: None,
+            highlighted_index: None,
             disabled: false,
             loading: false,
-            placeholder: "Seleccionar".into(),
+            placeholder: "Select".into(),
             label_prefix: None,
+            searchable: false,
+            search_input: None,
+            search_query: String::new(),
+            focus_search: false,
+            restore_focus: None,
+            menu_scroll_handle: gpui::ScrollHandle::new(),
+            type_ahead: String::new(),
+            type_ahead_at: None,
             on_select: None,
         }
     }
 
+    pub fn with_search(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     pub fn button(mut self, button: Button) -> Self {
         self.button = Some(button);
         self
@@ -148,53 +176,153 @@ impl Dropdown {
     }
 
     fn toggle_open(&mut self, cx: &mut gpui::Context<Self>) {
-        if self.disabled || self.loading || self.items.is_empty() {
-            return;
+        if self.open {
+            self.close(cx);
+        } else {
+            self.open(cx);
         }
-        self.open = !self.open;
-        cx.notify();
     }
 
     pub fn open(&mut self, cx: &mut gpui::Context<Self>) {
         if !self.disabled && !self.loading && !self.items.is_empty() {
             self.open = true;
+            self.highlighted_index = self.selected_index;
+            self.type_ahead.clear();
+            if self.searchable {
+                self.reset_search(cx);
+                self.focus_search = true;
+            }
             cx.notify();
         }
     }
 
+    fn reset_search(&mut self, cx: &mut gpui::Context<Self>) {
+        self.search_query.clear();
+        match &self.search_input {
+            Some(input) => input.update(cx, |input, cx| input.clear(cx)),
+            None => {
+                let dropdown = cx.weak_entity();
+                let input = cx.new(|cx| {
+                    Input::new("dropdown-search", cx, "Search...").with_on_change(Arc::new(
+                        move |value: &str, cx: &mut gpui::Context<Input>| {
+                            let value = value.to_string();
+                            dropdown
+                                .update(cx, |dropdown, cx| dropdown.set_search_query(value, cx))
+                                .ok();
+                        },
+                    ))
+                });
+                self.search_input = Some(input);
+            }
+        }
+    }
+
+    fn set_search_query(&mut self, query: String, cx: &mut gpui::Context<Self>) {
+        self.search_query = query;
+        let visible = self.visible_indices();
+        let highlighted_visible = self
+            .highlighted_index
+            .map(|index| visible.contains(&index))
+            .unwrap_or(false);
+        if !highlighted_visible {
+            self.highlighted_index = visible.first().copied();
+        }
+        self.scroll_to_highlight();
+        cx.notify();
+    }
+
+    fn visible_indices(&self) -> Vec<usize> {
+        matching_indices(&self.items, &self.search_query)
+    }
+
     pub fn close(&mut self, cx: &mut gpui::Context<Self>) {
         self.open = false;
+        self.highlighted_index = None;
         cx.notify();
     }
 
     pub fn select_next_item(&mut self, cx: &mut gpui::Context<Self>) {
-        if self.items.is_empty() {
-            return;
-        }
-        let next_index = self
-            .selected_index
-            .map(|i| (i + 1) % self.items.len())
-            .unwrap_or(0);
-        self.set_selected_index(next_index, cx);
+        self.move_highlight(true, cx);
     }
 
     pub fn select_prev_item(&mut self, cx: &mut gpui::Context<Self>) {
-        if self.items.is_empty() {
-            return;
+        self.move_highlight(false, cx);
+    }
+
+    fn move_highlight(&mut self, forward: bool, cx: &mut gpui::Context<Self>) {
+        let visible = self.visible_indices();
+        if let Some(index) = step_highlight(&visible, self.highlighted_index, forward) {
+            self.highlighted_index = Some(index);
+            self.scroll_to_highlight();
+            cx.notify();
+        }
+    }
+
+    pub fn type_ahead(&mut self, ch: char, cx: &mut gpui::Context<Self>) -> bool {
+        if !self.open {
+            return false;
+        }
+
+        let now = Instant::now();
+        let expired = self
+            .type_ahead_at
+            .map(|at| now.duration_since(at) > DROPDOWN_TYPE_AHEAD_RESET)
+            .unwrap_or(true);
+        if expired {
+            self.type_ahead.clear();
+        }
+        self.type_ahead.push(ch);
+        self.type_ahead_at = Some(now);
+
+        let visible = self.visible_indices();
+        if let Some(index) = type_ahead_match(&self.items, &visible, &self.type_ahead) {
+            self.highlighted_index = Some(index);
+            self.scroll_to_highlight();
+            cx.notify();
+        }
+        true
+    }
+
+    fn scroll_to_highlight(&self) {
+        let visible = self.visible_indices();
+        if let Some(position) = self
+            .highlighted_index
+            .and_then(|index| visible.iter().position(|&i| i == index))
+        {
+            self.menu_scroll_handle.scroll_to_item(position);
+        }
+    }
+
+    fn highlight(&mut self, index: usize, cx: &mut gpui::Context<Self>) {
+        if self.highlighted_index != Some(index) {
+            self.highlighted_index = Some(index);
+            cx.notify();
         }
-        let prev_index = self
-            .selected_index
-            .map(|i| if i == 0 { self.items.len() - 1 } else { i - 1 })
-            .unwrap_or(self.items.len() - 1);
-        self.set_selected_index(prev_index, cx);
     }
 
     pub fn accept_selection(&mut self, cx: &mut gpui::Context<Self>) {
-        if let Some(index) = self.selected_index {
-            self.select_item(index, cx);
+        match self.highlighted_index {
+            Some(index) if self.visible_indices().contains(&index) => self.select_item(index, cx),
+            _ => self.close(cx),
         }
     }
 
+    fn handle_search_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        match event.keystroke.key.as_str() {
+            "enter" => self.accept_selection(cx),
+            "escape" => self.close(cx),
+            "up" => self.select_prev_item(cx),
+            "down" => self.select_next_item(cx),
+            _ => {}
+        }
+        cx.stop_propagation();
+    }
+
     fn select_item(&mut self, index: usize, cx: &mut gpui::Context<Self>) {
         if self.disabled || self.loading {
             return;
@@ -203,17 +331,23 @@ impl Dropdown {
             Some(item) => item,
             None => return,
         };
+        let changed = self.selected_index != Some(index);
         self.selected_index = Some(index);
         self.open = false;
+        self.highlighted_index = None;
+        if !changed {
+            cx.notify();
+            return;
+        }
         if let Some(on_select) = self.on_select.clone() {
             on_select(index, item, cx);
         }
         cx.notify();
     }
 
-    fn handle_trigger_mouse_down(
+    fn handle_trigger_click(
         &mut self,
-        _event: &gpui::MouseDownEvent,
+        _event: &gpui::ClickEvent,
         _window: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) {
@@ -228,10 +362,27 @@ impl Dropdown {
     ) {
         if self.open {
             self.open = false;
+            self.highlighted_index = None;
+            self.restore_focus = None;
             cx.notify();
         }
     }
 
+    fn sync_search_focus(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        if self.open && self.focus_search {
+            self.focus_search = false;
+            if let Some(input) = &self.search_input {
+                self.restore_focus = window.focused(cx);
+                let handle = gpui::Focusable::focus_handle(input.read(cx), cx);
+                window.defer(cx, move |window, _cx| window.focus(&handle));
+            }
+        } else if !self.open {
+            if let Some(handle) = self.restore_focus.take() {
+                window.defer(cx, move |window, _cx| window.focus(&handle));
+            }
+        }
+    }
+
     fn render_menu(&self, cx: &gpui::Context<Self>) -> gpui::AnyElement {
         if !self.open || self.items.is_empty() {
             return gpui::div().into_any_element();
@@ -240,11 +391,12 @@ impl Dropdown {
         let theme = cx.theme();
         let is_disabled = self.disabled || self.loading;
         let items: Vec<gpui::AnyElement> = self
-            .items
-            .iter()
-            .enumerate()
-            .map(|(index, item)| {
+            .visible_indices()
+            .into_iter()
+            .map(|index| {
+                let item = &self.items[index];
                 let is_selected = self.selected_index == Some(index);
+                let is_highlighted = self.highlighted_index == Some(index);
                 let mut row = gpui::div()
                     .id(index)
                     .w_full()
@@ -253,26 +405,28 @@ impl Dropdown {
                     .text_sm()
                     .whitespace_nowrap()
                     .bg(theme.background)
-                    .text_color(if is_selected {
+                    .text_color(if is_highlighted {
                         theme.selection_foreground
+                    } else if is_selected {
+                        theme.accent
                     } else {
                         theme.foreground
                     })
-                    .when(is_selected, |el| el.bg(theme.selection))
-                    .when(!is_disabled, |el| {
-                        el.hover(|s: gpui::StyleRefinement| s.bg(theme.selection))
-                    })
+                    .when(is_selected, |el| el.font_weight(gpui::FontWeight::SEMIBOLD))
+                    .when(is_highlighted, |el| el.bg(theme.selection))
                     .child(item.label.clone());
 
                 if is_disabled {
                     row = row.text_color(theme.muted).cursor_not_allowed();
                 } else {
-                    row = row.cursor_pointer().on_mouse_down(
-                        gpui::MouseButton::Left,
-                        cx.listener(move |this, _event, _window, cx| {
+                    row = row
+                        .cursor_pointer()
+                        .on_mouse_move(cx.listener(move |this, _event, _window, cx| {
+                            this.highlight(index, cx);
+                        }))
+                        .on_click(cx.listener(move |this, _event, _window, cx| {
                             this.select_item(index, cx);
-                        }),
-                    );
+                        }));
                 }
 
                 row.into_any_element()
@@ -294,7 +448,34 @@ impl Dropdown {
             .rounded_md()
             .overflow_hidden()
             .shadow_lg()
-            .children(items)
+            .when_some(self.search_input.clone(), |menu, input| {
+                menu.child(
+                    gpui::div()
+                        .pb_1()
+                        .on_key_down(cx.listener(Self::handle_search_key_down))
+                        .child(input),
+                )
+            })
+            .when(items.is_empty(), |menu| {
+                menu.child(
+                    gpui::div()
+                        .px_2()
+                        .py_1()
+                        .text_sm()
+                        .text_color(theme.muted)
+                        .child("No matches"),
+                )
+            })
+            .child(
+                gpui::div()
+                    .id("dropdown-menu-items")
+                    .flex()
+                    .flex_col()
+                    .max_h(DROPDOWN_MENU_MAX_HEIGHT)
+                    .overflow_y_scroll()
+                    .track_scroll(&self.menu_scroll_handle)
+                    .children(items),
+            )
             .into_any_element()
     }
 }
@@ -302,9 +483,11 @@ impl Dropdown {
 impl gpui::Render for Dropdown {
     fn render(
         &mut self,
-        _window: &mut gpui::Window,
+        window: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) -> impl IntoElement {
+        self.sync_search_focus(window, cx);
+
         let theme = cx.theme();
         let is_disabled = self.disabled || self.loading;
         let disabled = is_disabled || self.items.is_empty();
@@ -342,12 +525,12 @@ impl gpui::Render for Dropdown {
             trigger.into_any_element()
         };
 
-        let mut trigger_wrap = gpui::div().flex_shrink_0().child(trigger);
+        let mut trigger_wrap = gpui::div()
+            .id("dropdown-trigger")
+            .flex_shrink_0()
+            .child(trigger);
         if !disabled {
-            trigger_wrap = trigger_wrap.on_mouse_down(
-                gpui::MouseButton::Left,
-                cx.listener(Self::handle_trigger_mouse_down),
-            );
+            trigger_wrap = trigger_wrap.on_click(cx.listener(Self::handle_trigger_click));
         }
 
         let mut container = gpui::div()
@@ -365,3 +548,85 @@ impl gpui::Render for Dropdown {
         container
     }
 }
+
+fn matching_indices(items: &[DropdownItem], query: &str) -> Vec<usize> {
+    let query = query.trim().to_lowercase();
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| query.is_empty() || item.label.to_lowercase().contains(&query))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn step_highlight(visible: &[usize], current: Option<usize>, forward: bool) -> Option<usize> {
+    if visible.is_empty() {
+        return None;
+    }
+    let position = current.and_then(|index| visible.iter().position(|&i| i == index));
+    let next = match (position, forward) {
+        (Some(pos), true) => (pos + 1) % visible.len(),
+        (Some(0), false) => visible.len() - 1,
+        (Some(pos), false) => pos - 1,
+        (None, true) => 0,
+        (None, false) => visible.len() - 1,
+    };
+    Some(visible[next])
+}
+
+fn type_ahead_match(items: &[DropdownItem], visible: &[usize], prefix: &str) -> Option<usize> {
+    let prefix = prefix.to_lowercase();
+    visible
+        .iter()
+        .copied()
+        .find(|&index| items[index].label.to_lowercase().starts_with(&prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(labels: &[&str]) -> Vec<DropdownItem> {
+        labels
+            .iter()
+            .map(|label| DropdownItem::new(*label))
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let items = items(&["All", "Overdue", "Today"]);
+        assert_eq!(matching_indices(&items, ""), vec![0, 1, 2]);
+        assert_eq!(matching_indices(&items, "  "), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_query_is_case_insensitive_substring() {
+        let items = items(&["All", "Overdue", "Today", "This Week"]);
+        assert_eq!(matching_indices(&items, "DAY"), vec![2]);
+        assert_eq!(matching_indices(&items, "e"), vec![1, 3]);
+        assert!(matching_indices(&items, "xyz").is_empty());
+    }
+
+    #[test]
+    fn test_step_highlight_wraps_within_visible_items() {
+        let visible = [1, 3, 4];
+        assert_eq!(step_highlight(&visible, Some(3), true), Some(4));
+        assert_eq!(step_highlight(&visible, Some(4), true), Some(1));
+        assert_eq!(step_highlight(&visible, Some(1), false), Some(4));
+        assert_eq!(step_highlight(&visible, None, true), Some(1));
+        assert_eq!(step_highlight(&visible, Some(2), false), Some(4));
+        assert_eq!(step_highlight(&[], Some(0), true), None);
+    }
+
+    #[test]
+    fn test_type_ahead_matches_label_prefix() {
+        let items = items(&["All", "Overdue", "Today", "This Week", "2026-10-16"]);
+        let visible = [0, 1, 2, 3, 4];
+        assert_eq!(type_ahead_match(&items, &visible, "t"), Some(2));
+        assert_eq!(type_ahead_match(&items, &visible, "th"), Some(3));
+        assert_eq!(type_ahead_match(&items, &visible, "2026"), Some(4));
+        assert_eq!(type_ahead_match(&items, &visible[..2], "t"), None);
+        assert_eq!(type_ahead_match(&items, &visible, "x"), None);
+    }
+}
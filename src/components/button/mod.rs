@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 use crate::components::icon::{Icon, IconSize};
 use crate::components::label::Label;
+use crate::components::tooltip::Tooltip;
 use crate::theme::ActiveTheme;
 
 fn darken(color: gpui::Rgba, amount: f32) -> gpui::Rgba {
@@ -101,6 +102,7 @@ pub struct Button {
     size: ButtonSize,
     disabled: bool,
     loading: bool,
+    tooltip: Option<SharedString>,
     style: StyleRefinement,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
 }
@@ -115,6 +117,7 @@ impl Button {
             size: ButtonSize::default(),
             disabled: false,
             loading: false,
+            tooltip: None,
             style: StyleRefinement::default(),
             on_click: None,
         }
@@ -138,6 +141,11 @@ impl Button {
         self
     }
 
+    pub fn with_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn with_size(mut self, size: ButtonSize) -> Self {
         self.size = size;
         self
@@ -272,6 +280,10 @@ impl RenderOnce for Button {
             base = base.opacity(0.7);
         }
 
+        if let Some(tooltip) = self.tooltip {
+            base = base.tooltip(Tooltip::text(tooltip));
+        }
+
         if let Some(on_click) = self.on_click {
             let disabled = self.disabled || self.loading;
             base = base.on_click(move |event, window, cx| {
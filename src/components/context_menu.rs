@@ -0,0 +1,357 @@
+use gpui::prelude::*;
+
+use crate::components::label::Label;
+use crate::keymap::{Command, Key};
+use crate::theme::ActiveTheme;
+
+#[derive(Clone, Debug)]
+pub enum ContextMenuItem {
+    Action {
+        label: gpui::SharedString,
+        command: Command,
+    },
+    Submenu {
+        label: gpui::SharedString,
+        items: Vec<ContextMenuItem>,
+    },
+    Separator,
+}
+
+impl ContextMenuItem {
+    fn is_separator(&self) -> bool {
+        matches!(self, Self::Separator)
+    }
+
+    pub fn action(label: impl Into<gpui::SharedString>, command: Command) -> Self {
+        Self::Action {
+            label: label.into(),
+            command,
+        }
+    }
+
+    pub fn submenu(label: impl Into<gpui::SharedString>, items: Vec<ContextMenuItem>) -> Self {
+        Self::Submenu {
+            label: label.into(),
+            items,
+        }
+    }
+}
+
+pub enum ContextMenuEvent {
+    Select(Command),
+}
+
+pub struct ContextMenu {
+    id: gpui::ElementId,
+    items: Vec<ContextMenuItem>,
+    position: Option<gpui::Point<gpui::Pixels>>,
+    open_submenu: Option<usize>,
+    highlighted: Option<usize>,
+    submenu_highlighted: Option<usize>,
+}
+
+impl ContextMenu {
+    pub fn new(id: impl Into<gpui::ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            position: None,
+            open_submenu: None,
+            highlighted: None,
+            submenu_highlighted: None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+
+    pub fn open(
+        &mut self,
+        position: gpui::Point<gpui::Pixels>,
+        items: Vec<ContextMenuItem>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.items = items;
+        self.position = Some(position);
+        self.open_submenu = None;
+        self.highlighted = None;
+        self.submenu_highlighted = None;
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.position.take().is_some() {
+            self.open_submenu = None;
+            self.highlighted = None;
+            self.submenu_highlighted = None;
+            cx.notify();
+        }
+    }
+
+    pub fn highlight_first(&mut self, cx: &mut gpui::Context<Self>) {
+        self.highlighted = step_highlight(&self.items, None, true);
+        cx.notify();
+    }
+
+    pub fn handle_key(&mut self, key: Key, cx: &mut gpui::Context<Self>) {
+        match key {
+            Key::Escape => {
+                if self.open_submenu.is_some() {
+                    self.close_submenu(cx);
+                } else {
+                    self.close(cx);
+                }
+            }
+            Key::ArrowDown | Key::Char('j') => self.move_highlight(true, cx),
+            Key::ArrowUp | Key::Char('k') => self.move_highlight(false, cx),
+            Key::ArrowRight | Key::Char('l') => self.open_highlighted_submenu(cx),
+            Key::ArrowLeft | Key::Char('h') => self.close_submenu(cx),
+            Key::Enter | Key::Space => self.confirm_highlight(cx),
+            _ => {}
+        }
+    }
+
+    fn submenu_items(&self) -> Option<&[ContextMenuItem]> {
+        match self.items.get(self.open_submenu?) {
+            Some(ContextMenuItem::Submenu { items, .. }) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn move_highlight(&mut self, forward: bool, cx: &mut gpui::Context<Self>) {
+        if let Some(items) = self.submenu_items() {
+            self.submenu_highlighted = step_highlight(items, self.submenu_highlighted, forward);
+        } else {
+            self.highlighted = step_highlight(&self.items, self.highlighted, forward);
+        }
+        cx.notify();
+    }
+
+    fn open_highlighted_submenu(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(index) = self.highlighted else {
+            return;
+        };
+        if let Some(ContextMenuItem::Submenu { items, .. }) = self.items.get(index) {
+            self.submenu_highlighted = step_highlight(items, None, true);
+            self.open_submenu = Some(index);
+            cx.notify();
+        }
+    }
+
+    fn close_submenu(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.open_submenu.take().is_some() {
+            self.submenu_highlighted = None;
+            cx.notify();
+        }
+    }
+
+    fn confirm_highlight(&mut self, cx: &mut gpui::Context<Self>) {
+        let item = match self.submenu_items() {
+            Some(items) => self.submenu_highlighted.and_then(|index| items.get(index)),
+            None => self.highlighted.and_then(|index| self.items.get(index)),
+        };
+
+        match item {
+            Some(ContextMenuItem::Action { command, .. }) => {
+                let command = *command;
+                self.select(command, cx);
+            }
+            Some(ContextMenuItem::Submenu { .. }) => self.open_highlighted_submenu(cx),
+            _ => {}
+        }
+    }
+
+    fn select(&mut self, command: Command, cx: &mut gpui::Context<Self>) {
+        self.close(cx);
+        cx.emit(ContextMenuEvent::Select(command));
+    }
+
+    fn toggle_submenu(&mut self, index: usize, cx: &mut gpui::Context<Self>) {
+        self.open_submenu = if self.open_submenu == Some(index) {
+            None
+        } else {
+            Some(index)
+        };
+        self.highlighted = Some(index);
+        self.submenu_highlighted = None;
+        cx.notify();
+    }
+
+    fn render_items(
+        &self,
+        items: &[ContextMenuItem],
+        id_prefix: &'static str,
+        top_level: bool,
+        highlighted: Option<usize>,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Div {
+        let theme = cx.theme();
+
+        let rows = items.iter().enumerate().map(|(index, item)| match item {
+            ContextMenuItem::Separator => gpui::div()
+                .my_1()
+                .h(gpui::px(1.0))
+                .bg(theme.divider)
+                .into_any_element(),
+            ContextMenuItem::Action { label, command } => {
+                let command = *command;
+                let is_highlighted = highlighted == Some(index);
+                gpui::div()
+                    .id((id_prefix, index))
+                    .px_2()
+                    .py_1()
+                    .rounded_sm()
+                    .text_sm()
+                    .whitespace_nowrap()
+                    .text_color(theme.foreground)
+                    .cursor_pointer()
+                    .when(is_highlighted, |el| el.bg(theme.selection))
+                    .hover(|s| s.bg(theme.selection))
+                    .on_click(cx.listener(move |menu, _event, _window, cx| {
+                        menu.select(command, cx);
+                    }))
+                    .child(Label::new(label.clone()))
+                    .into_any_element()
+            }
+            ContextMenuItem::Submenu { label, items } => {
+                let is_open = top_level && self.open_submenu == Some(index);
+                let is_highlighted = highlighted == Some(index);
+                gpui::div()
+                    .relative()
+                    .child(
+                        gpui::div()
+                            .id((id_prefix, index))
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .gap_4()
+                            .px_2()
+                            .py_1()
+                            .rounded_sm()
+                            .text_sm()
+                            .whitespace_nowrap()
+                            .text_color(theme.foreground)
+                            .cursor_pointer()
+                            .when(is_open || is_highlighted, |el| el.bg(theme.selection))
+                            .hover(|s| s.bg(theme.selection))
+                            .on_click(cx.listener(move |menu, _event, _window, cx| {
+                                menu.toggle_submenu(index, cx);
+                            }))
+                            .child(Label::new(label.clone()))
+                            .child(Label::new("▸").text_color(theme.muted)),
+                    )
+                    .when(is_open, |el| {
+                        el.child(
+                            self.render_items(
+                                items,
+                                "context-submenu-item",
+                                false,
+                                self.submenu_highlighted,
+                                cx,
+                            )
+                            .absolute()
+                            .top_0()
+                            .left_full()
+                            .ml_1(),
+                        )
+                    })
+                    .into_any_element()
+            }
+        });
+
+        gpui::div()
+            .flex()
+            .flex_col()
+            .min_w(gpui::rems(11.0))
+            .p_1()
+            .occlude()
+            .border_1()
+            .border_color(theme.border)
+            .bg(theme.background)
+            .rounded_md()
+            .shadow_lg()
+            .children(rows)
+    }
+}
+
+impl gpui::EventEmitter<ContextMenuEvent> for ContextMenu {}
+
+impl gpui::Render for ContextMenu {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl IntoElement {
+        let Some(position) = self.position else {
+            return gpui::div().into_any_element();
+        };
+
+        let panel = gpui::div()
+            .id(self.id.clone())
+            .on_mouse_down_out(cx.listener(|menu, _event, _window, cx| {
+                menu.close(cx);
+            }))
+            .child(self.render_items(&self.items, "context-menu-item", true, self.highlighted, cx));
+
+        gpui::deferred(
+            gpui::anchored()
+                .position(position)
+                .snap_to_window_with_margin(gpui::px(8.0))
+                .child(panel),
+        )
+        .with_priority(1)
+        .into_any_element()
+    }
+}
+
+fn step_highlight(
+    items: &[ContextMenuItem],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+    if len == 0 {
+        return None;
+    }
+
+    let mut index = match current {
+        Some(index) => index,
+        None if forward => len - 1,
+        None => 0,
+    };
+    for _ in 0..len {
+        index = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        if !items[index].is_separator() {
+            return Some(index);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_highlight_skips_separators_and_wraps() {
+        let items = vec![
+            ContextMenuItem::action("Open", Command::OpenSelectedTask),
+            ContextMenuItem::Separator,
+            ContextMenuItem::action("Complete", Command::CompleteTask),
+        ];
+
+        assert_eq!(step_highlight(&items, None, true), Some(0));
+        assert_eq!(step_highlight(&items, Some(0), true), Some(2));
+        assert_eq!(step_highlight(&items, Some(2), true), Some(0));
+        assert_eq!(step_highlight(&items, Some(0), false), Some(2));
+        assert_eq!(
+            step_highlight(&[ContextMenuItem::Separator], None, true),
+            None
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct InputHistory {
+    entries: VecDeque<String>,
+    capacity: usize,
+    cursor: Option<usize>,
+    draft: String,
+}
+
+impl InputHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            cursor: None,
+            draft: String::new(),
+        }
+    }
+
+    pub fn is_browsing(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    pub fn push(&mut self, value: &str) {
+        self.cursor = None;
+        self.draft.clear();
+
+        let value = value.trim();
+        if value.is_empty() {
+            return;
+        }
+        if self.entries.back().map(String::as_str) == Some(value) {
+            return;
+        }
+
+        self.entries.push_back(value.to_string());
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn prev(&mut self, current: &str) -> Option<String> {
+        let index = match self.cursor {
+            Some(index) if self.is_current(index, current) => index.saturating_sub(1),
+            _ => {
+                if self.entries.is_empty() {
+                    return None;
+                }
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+        };
+
+        self.cursor = Some(index);
+        self.entries.get(index).cloned()
+    }
+
+    pub fn next(&mut self, current: &str) -> Option<String> {
+        let index = self.cursor?;
+        if !self.is_current(index, current) {
+            self.cursor = None;
+            return None;
+        }
+
+        if index + 1 < self.entries.len() {
+            self.cursor = Some(index + 1);
+            self.entries.get(index + 1).cloned()
+        } else {
+            self.cursor = None;
+            Some(std::mem::take(&mut self.draft))
+        }
+    }
+
+    pub fn restore_draft(&mut self, current: &str) -> Option<String> {
+        let index = self.cursor.take()?;
+        if !self.is_current(index, current) {
+            return None;
+        }
+        Some(std::mem::take(&mut self.draft))
+    }
+
+    fn is_current(&self, index: usize, current: &str) -> bool {
+        self.entries.get(index).map(String::as_str) == Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(values: &[&str]) -> InputHistory {
+        let mut history = InputHistory::new(10);
+        for value in values {
+            history.push(value);
+        }
+        history
+    }
+
+    #[test]
+    fn test_push_skips_empty_and_consecutive_duplicates() {
+        let history = history(&["work", "work", "  ", "home", "work"]);
+        let entries: Vec<&str> = history.entries.iter().map(String::as_str).collect();
+        assert_eq!(entries, vec!["work", "home", "work"]);
+    }
+
+    #[test]
+    fn test_push_is_capped() {
+        let mut history = InputHistory::new(2);
+        history.push("a");
+        history.push("b");
+        history.push("c");
+        let entries: Vec<&str> = history.entries.iter().map(String::as_str).collect();
+        assert_eq!(entries, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_prev_walks_back_and_stops_at_oldest() {
+        let mut history = history(&["a", "b"]);
+        assert_eq!(history.prev("draft").as_deref(), Some("b"));
+        assert_eq!(history.prev("b").as_deref(), Some("a"));
+        assert_eq!(history.prev("a").as_deref(), Some("a"));
+        assert!(history.is_browsing());
+    }
+
+    #[test]
+    fn test_next_returns_to_draft() {
+        let mut history = history(&["a", "b"]);
+        history.prev("typing");
+        history.prev("b");
+        assert_eq!(history.next("a").as_deref(), Some("b"));
+        assert_eq!(history.next("b").as_deref(), Some("typing"));
+        assert!(!history.is_browsing());
+        assert_eq!(history.next("typing"), None);
+    }
+
+    #[test]
+    fn test_restore_draft() {
+        let mut history = history(&["a", "b"]);
+        history.prev("typing");
+        history.prev("b");
+        assert_eq!(history.restore_draft("a").as_deref(), Some("typing"));
+        assert!(!history.is_browsing());
+        assert_eq!(history.restore_draft("typing"), None);
+    }
+
+    #[test]
+    fn test_editing_a_recalled_entry_starts_a_new_browse() {
+        let mut history = history(&["a", "b"]);
+        history.prev("");
+        assert_eq!(history.next("b edited"), None);
+        assert_eq!(history.prev("b edited").as_deref(), Some("b"));
+        assert_eq!(history.restore_draft("b").as_deref(), Some("b edited"));
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let mut history = InputHistory::new(5);
+        assert_eq!(history.prev("x"), None);
+        assert_eq!(history.next("x"), None);
+        assert!(!history.is_browsing());
+    }
+}
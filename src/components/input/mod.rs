@@ -1,11 +1,21 @@
+mod history;
 mod suggestion;
+mod text;
+mod undo;
 
 use crate::theme::ActiveTheme;
 use gpui::prelude::*;
+use std::ops::Range;
 use std::sync::Arc;
 
+pub use history::InputHistory;
 pub use suggestion::Suggestion;
 
+use text::InputText;
+use undo::UndoHistory;
+
+const UNDO_CAPACITY: usize = 50;
+
 pub struct Input {
     id: gpui::ElementId,
     focus: gpui::FocusHandle,
@@ -13,12 +23,21 @@ pub struct Input {
     placeholder: gpui::SharedString,
 
     cursor_pos: usize,
+    selection_anchor: Option<usize>,
+    is_selecting: bool,
+
+    last_layout: Option<gpui::ShapedLine>,
+    last_bounds: Option<gpui::Bounds<gpui::Pixels>>,
+    scroll_offset: gpui::Pixels,
 
     suggestions: Vec<Suggestion>,
     suggestions_open: bool,
     active_suggestion: usize,
 
-    suggest: Option<Arc<dyn Fn(&str) -> Vec<Suggestion> + Send + Sync>>,
+    history: Option<InputHistory>,
+    undo_history: UndoHistory,
+
+    suggest: Option<Arc<dyn Fn(&str, usize) -> Vec<Suggestion> + Send + Sync>>,
     on_change: Option<Arc<dyn Fn(&str, &mut gpui::Context<Self>) + Send + Sync>>,
     on_submit: Option<Arc<dyn Fn(&str, &mut gpui::Context<Self>) + Send + Sync>>,
 }
@@ -36,18 +55,30 @@ impl Input {
             placeholder: placeholder.into(),
 
             cursor_pos: 0,
+            selection_anchor: None,
+            is_selecting: false,
+
+            last_layout: None,
+            last_bounds: None,
+            scroll_offset: gpui::px(0.),
 
             suggestions: vec![],
             suggestions_open: false,
             active_suggestion: 0,
 
+            history: None,
+            undo_history: UndoHistory::new(UNDO_CAPACITY),
+
             suggest: None,
             on_change: None,
             on_submit: None,
         }
     }
 
-    pub fn with_suggest(mut self, f: Arc<dyn Fn(&str) -> Vec<Suggestion> + Send + Sync>) -> Self {
+    pub fn with_suggest(
+        mut self,
+        f: Arc<dyn Fn(&str, usize) -> Vec<Suggestion> + Send + Sync>,
+    ) -> Self {
         self.suggest = Some(f);
         self
     }
@@ -68,6 +99,11 @@ impl Input {
         self
     }
 
+    pub fn with_history(mut self, capacity: usize) -> Self {
+        self.history = Some(InputHistory::new(capacity));
+        self
+    }
+
     pub fn value(&self) -> &str {
         &self.value
     }
@@ -75,6 +111,8 @@ impl Input {
     pub fn set_value(&mut self, value: impl Into<String>, cx: &mut gpui::Context<Self>) {
         self.value = value.into();
         self.cursor_pos = self.value.len();
+        self.selection_anchor = None;
+        self.undo_history.reset(&self.value, self.cursor_pos);
         self.refresh_suggestions(cx);
         cx.notify();
     }
@@ -93,6 +131,77 @@ impl Input {
         cx.notify();
     }
 
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection_range().map(|range| &self.value[range])
+    }
+
+    fn selection_range(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+        if anchor == self.cursor_pos {
+            return None;
+        }
+        Some(anchor.min(self.cursor_pos)..anchor.max(self.cursor_pos))
+    }
+
+    fn begin_selection(&mut self, selecting: bool) {
+        if selecting {
+            if self.selection_anchor.is_none() {
+                self.selection_anchor = Some(self.cursor_pos);
+            }
+        } else {
+            self.selection_anchor = None;
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection_range() else {
+            self.selection_anchor = None;
+            return false;
+        };
+        self.cursor_pos = range.start;
+        self.value.drain(range);
+        self.selection_anchor = None;
+        true
+    }
+
+    fn emit_change(&mut self, cx: &mut gpui::Context<Self>) {
+        self.undo_history.push(&self.value, self.cursor_pos);
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&self.value, cx);
+        }
+        self.refresh_suggestions(cx);
+        cx.notify();
+    }
+
+    fn copy_selection(&self, cx: &mut gpui::Context<Self>) -> bool {
+        match self.selected_text() {
+            Some(text) => {
+                cx.write_to_clipboard(gpui::ClipboardItem::new_string(text.to_string()));
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn cut_selection(&mut self, cx: &mut gpui::Context<Self>) -> bool {
+        if !self.copy_selection(cx) {
+            return false;
+        }
+        self.delete_selection();
+        self.emit_change(cx);
+        true
+    }
+
+    fn paste(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+        let text = sanitize_pasted_text(&text);
+        if !text.is_empty() {
+            self.insert_text(&text, cx);
+        }
+    }
+
     fn word_start_before(&self, pos: usize) -> usize {
         if pos == 0 {
             return 0;
@@ -130,6 +239,96 @@ impl Input {
         i
     }
 
+    fn word_range_at(&self, pos: usize) -> Range<usize> {
+        let len = self.value.len();
+        if len == 0 {
+            return 0..0;
+        }
+        let bytes = self.value.as_bytes();
+        let probe = pos.min(len - 1);
+        let is_space = bytes[probe].is_ascii_whitespace();
+
+        let mut start = probe;
+        while start > 0 && bytes[start - 1].is_ascii_whitespace() == is_space {
+            start -= 1;
+        }
+
+        let mut end = probe;
+        while end < len && bytes[end].is_ascii_whitespace() == is_space {
+            end += 1;
+        }
+
+        start..end
+    }
+
+    fn index_for_position(&self, position: gpui::Point<gpui::Pixels>) -> usize {
+        if self.value.is_empty() {
+            return 0;
+        }
+        let (Some(bounds), Some(line)) = (self.last_bounds.as_ref(), self.last_layout.as_ref())
+        else {
+            return self.cursor_pos;
+        };
+        if position.y < bounds.top() {
+            return 0;
+        }
+        if position.y > bounds.bottom() {
+            return self.value.len();
+        }
+        line.closest_index_for_x(position.x - bounds.left() + self.scroll_offset)
+            .min(self.value.len())
+    }
+
+    fn handle_mouse_down(
+        &mut self,
+        event: &gpui::MouseDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        window.focus(&self.focus);
+
+        let index = self.index_for_position(event.position);
+        if event.click_count >= 2 {
+            let range = self.word_range_at(index);
+            self.selection_anchor = Some(range.start);
+            self.cursor_pos = range.end;
+            self.is_selecting = false;
+        } else {
+            self.begin_selection(event.modifiers.shift);
+            self.cursor_pos = index;
+            self.is_selecting = true;
+        }
+        cx.notify();
+    }
+
+    fn handle_mouse_move(
+        &mut self,
+        event: &gpui::MouseMoveEvent,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if !self.is_selecting {
+            return;
+        }
+        if event.pressed_button != Some(gpui::MouseButton::Left) {
+            self.is_selecting = false;
+            return;
+        }
+
+        self.begin_selection(true);
+        self.cursor_pos = self.index_for_position(event.position);
+        cx.notify();
+    }
+
+    fn handle_mouse_up(
+        &mut self,
+        _event: &gpui::MouseUpEvent,
+        _window: &mut gpui::Window,
+        _cx: &mut gpui::Context<Self>,
+    ) {
+        self.is_selecting = false;
+    }
+
     fn move_left(&mut self) {
         if self.cursor_pos > 0 {
             let mut new_pos = self.cursor_pos - 1;
@@ -152,7 +351,7 @@ impl Input {
 
     fn refresh_suggestions(&mut self, cx: &mut gpui::Context<Self>) {
         if let Some(suggest) = &self.suggest {
-            self.suggestions = suggest(&self.value);
+            self.suggestions = suggest(&self.value, self.cursor_pos);
             self.active_suggestion = 0;
             self.suggestions_open = !self.suggestions.is_empty() && !self.value.is_empty();
             cx.notify();
@@ -161,7 +360,7 @@ impl Input {
 
     fn open_suggestions(&mut self, cx: &mut gpui::Context<Self>) {
         if let Some(suggest) = &self.suggest {
-            self.suggestions = suggest(&self.value);
+            self.suggestions = suggest(&self.value, self.cursor_pos);
             self.active_suggestion = 0;
             self.suggestions_open = !self.suggestions.is_empty();
             cx.notify();
@@ -173,9 +372,23 @@ impl Input {
             return;
         }
         if let Some(s) = self.suggestions.get(self.active_suggestion).cloned() {
-            self.value = s.insert.to_string();
-            self.cursor_pos = self.value.len();
+            match s.replace.filter(|range| {
+                range.start <= range.end
+                    && self.value.is_char_boundary(range.start)
+                    && self.value.is_char_boundary(range.end)
+            }) {
+                Some(range) => {
+                    self.cursor_pos = range.start + s.insert.len();
+                    self.value.replace_range(range, &s.insert);
+                }
+                None => {
+                    self.value = s.insert.to_string();
+                    self.cursor_pos = self.value.len();
+                }
+            }
+            self.selection_anchor = None;
             self.suggestions_open = false;
+            self.undo_history.push(&self.value, self.cursor_pos);
 
             if let Some(on_change) = self.on_change.clone() {
                 on_change(&self.value, cx);
@@ -197,12 +410,80 @@ impl Input {
 
     fn submit(&mut self, cx: &mut gpui::Context<Self>) {
         self.suggestions_open = false;
+        if let Some(history) = self.history.as_mut() {
+            history.push(&self.value);
+        }
         if let Some(on_submit) = self.on_submit.clone() {
             on_submit(&self.value, cx);
         }
         cx.notify();
     }
 
+    fn recall_history(&mut self, older: bool, cx: &mut gpui::Context<Self>) -> bool {
+        let Some(history) = self.history.as_mut() else {
+            return false;
+        };
+        let recalled = if older {
+            history.prev(&self.value)
+        } else {
+            history.next(&self.value)
+        };
+        match recalled {
+            Some(value) => {
+                self.apply_history_value(value, cx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn apply_history_value(&mut self, value: String, cx: &mut gpui::Context<Self>) {
+        self.value = value;
+        self.cursor_pos = self.value.len();
+        self.selection_anchor = None;
+        self.undo_history.push(&self.value, self.cursor_pos);
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&self.value, cx);
+        }
+        self.suggestions_open = false;
+        cx.notify();
+    }
+
+    pub fn undo(&mut self, cx: &mut gpui::Context<Self>) -> bool {
+        match self.undo_history.undo() {
+            Some(snapshot) => {
+                self.restore_snapshot(snapshot, cx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn redo(&mut self, cx: &mut gpui::Context<Self>) -> bool {
+        match self.undo_history.redo() {
+            Some(snapshot) => {
+                self.restore_snapshot(snapshot, cx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn restore_snapshot(
+        &mut self,
+        (value, cursor_pos): (String, usize),
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.value = value;
+        self.cursor_pos = cursor_pos.min(self.value.len());
+        self.selection_anchor = None;
+        self.suggestions_open = false;
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&self.value, cx);
+        }
+        cx.notify();
+    }
+
     fn move_suggestion(&mut self, delta: isize, cx: &mut gpui::Context<Self>) {
         if !self.suggestions_open || self.suggestions.is_empty() {
             return;
@@ -220,17 +501,20 @@ impl Input {
     }
 
     fn insert_text(&mut self, text: &str, cx: &mut gpui::Context<Self>) {
-        self.value.insert_str(self.cursor_pos, text);
-        self.cursor_pos += text.len();
+        let range = self
+            .selection_range()
+            .unwrap_or(self.cursor_pos..self.cursor_pos);
+        self.cursor_pos = replace_text(&mut self.value, range, text);
+        self.selection_anchor = None;
 
-        if let Some(on_change) = self.on_change.clone() {
-            on_change(&self.value, cx);
-        }
-        self.refresh_suggestions(cx);
-        cx.notify();
+        self.emit_change(cx);
     }
 
     fn delete_backward(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.delete_selection() {
+            self.emit_change(cx);
+            return;
+        }
         if self.cursor_pos == 0 {
             return;
         }
@@ -239,14 +523,14 @@ impl Input {
         self.move_left();
         self.value.drain(self.cursor_pos..old_pos);
 
-        if let Some(on_change) = self.on_change.clone() {
-            on_change(&self.value, cx);
-        }
-        self.refresh_suggestions(cx);
-        cx.notify();
+        self.emit_change(cx);
     }
 
     fn delete_forward(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.delete_selection() {
+            self.emit_change(cx);
+            return;
+        }
         if self.cursor_pos >= self.value.len() {
             return;
         }
@@ -257,14 +541,14 @@ impl Input {
         }
         self.value.drain(self.cursor_pos..end);
 
-        if let Some(on_change) = self.on_change.clone() {
-            on_change(&self.value, cx);
-        }
-        self.refresh_suggestions(cx);
-        cx.notify();
+        self.emit_change(cx);
     }
 
     fn delete_word_backward(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.delete_selection() {
+            self.emit_change(cx);
+            return;
+        }
         if self.cursor_pos == 0 {
             return;
         }
@@ -273,14 +557,14 @@ impl Input {
         self.value.drain(word_start..self.cursor_pos);
         self.cursor_pos = word_start;
 
-        if let Some(on_change) = self.on_change.clone() {
-            on_change(&self.value, cx);
-        }
-        self.refresh_suggestions(cx);
-        cx.notify();
+        self.emit_change(cx);
     }
 
     fn delete_word_forward(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.delete_selection() {
+            self.emit_change(cx);
+            return;
+        }
         if self.cursor_pos >= self.value.len() {
             return;
         }
@@ -288,11 +572,7 @@ impl Input {
         let word_end = self.word_end_after(self.cursor_pos);
         self.value.drain(self.cursor_pos..word_end);
 
-        if let Some(on_change) = self.on_change.clone() {
-            on_change(&self.value, cx);
-        }
-        self.refresh_suggestions(cx);
-        cx.notify();
+        self.emit_change(cx);
     }
 
     fn handle_key_down(
@@ -309,18 +589,41 @@ impl Input {
             return;
         }
 
+        let typed = event
+            .keystroke
+            .key_char
+            .as_deref()
+            .filter(|ch| !ctrl && !matches!(*ch, "\n" | "\r" | "\t"));
+        if consumes_key(key, ctrl, typed.is_some()) {
+            cx.stop_propagation();
+        }
+
         match key {
             "enter" => {
                 if self.suggestions_open {
                     self.accept_suggestion(cx);
+                    cx.stop_propagation();
                 } else {
                     self.submit(cx);
                 }
             }
 
             "escape" => {
-                self.suggestions_open = false;
-                cx.notify();
+                if self.suggestions_open {
+                    self.suggestions_open = false;
+                    cx.stop_propagation();
+                    cx.notify();
+                    return;
+                }
+
+                let draft = self
+                    .history
+                    .as_mut()
+                    .and_then(|history| history.restore_draft(&self.value));
+                if let Some(draft) = draft {
+                    self.apply_history_value(draft, cx);
+                    cx.stop_propagation();
+                }
             }
 
             "tab" => {
@@ -335,37 +638,65 @@ impl Input {
                         self.open_suggestions(cx);
                     }
                 }
+                if self.suggestions_open {
+                    cx.stop_propagation();
+                }
             }
 
-            "up" => self.move_suggestion(-1, cx),
-            "down" => self.move_suggestion(1, cx),
+            "up" | "down" => {
+                let delta = if key == "up" { -1 } else { 1 };
+                if self.suggestions_open {
+                    self.move_suggestion(delta, cx);
+                    cx.stop_propagation();
+                } else if self.recall_history(delta < 0, cx) {
+                    cx.stop_propagation();
+                }
+            }
 
             "left" => {
-                if ctrl {
-                    self.cursor_pos = self.word_start_before(self.cursor_pos);
-                    cx.notify();
-                } else {
-                    self.move_left();
-                    cx.notify();
+                match self.selection_range() {
+                    Some(range) if !shift => {
+                        self.cursor_pos = range.start;
+                        self.selection_anchor = None;
+                    }
+                    _ => {
+                        self.begin_selection(shift);
+                        if ctrl {
+                            self.cursor_pos = self.word_start_before(self.cursor_pos);
+                        } else {
+                            self.move_left();
+                        }
+                    }
                 }
+                cx.notify();
             }
 
             "right" => {
-                if ctrl {
-                    self.cursor_pos = self.word_end_after(self.cursor_pos);
-                    cx.notify();
-                } else {
-                    self.move_right();
-                    cx.notify();
+                match self.selection_range() {
+                    Some(range) if !shift => {
+                        self.cursor_pos = range.end;
+                        self.selection_anchor = None;
+                    }
+                    _ => {
+                        self.begin_selection(shift);
+                        if ctrl {
+                            self.cursor_pos = self.word_end_after(self.cursor_pos);
+                        } else {
+                            self.move_right();
+                        }
+                    }
                 }
+                cx.notify();
             }
 
             "home" => {
+                self.begin_selection(shift);
                 self.cursor_pos = 0;
                 cx.notify();
             }
 
             "end" => {
+                self.begin_selection(shift);
                 self.cursor_pos = self.value.len();
                 cx.notify();
             }
@@ -391,39 +722,56 @@ impl Input {
             }
 
             "a" if ctrl => {
+                self.selection_anchor = None;
                 self.cursor_pos = 0;
                 cx.notify();
             }
 
             "e" if ctrl => {
+                self.selection_anchor = None;
                 self.cursor_pos = self.value.len();
                 cx.notify();
             }
 
+            "c" if ctrl => {
+                if self.copy_selection(cx) {
+                    cx.stop_propagation();
+                }
+            }
+
+            "x" if ctrl => {
+                if self.cut_selection(cx) {
+                    cx.stop_propagation();
+                }
+            }
+
+            "v" | "V" if ctrl => {
+                self.paste(cx);
+            }
+
+            "z" | "Z" if ctrl => {
+                let restored = if shift { self.redo(cx) } else { self.undo(cx) };
+                if restored {
+                    cx.stop_propagation();
+                }
+            }
+
             "u" if ctrl => {
+                self.selection_anchor = None;
                 self.value.drain(0..self.cursor_pos);
                 self.cursor_pos = 0;
-                if let Some(on_change) = self.on_change.clone() {
-                    on_change(&self.value, cx);
-                }
-                self.refresh_suggestions(cx);
-                cx.notify();
+                self.emit_change(cx);
             }
 
             "k" if ctrl => {
+                self.selection_anchor = None;
                 self.value.truncate(self.cursor_pos);
-                if let Some(on_change) = self.on_change.clone() {
-                    on_change(&self.value, cx);
-                }
-                self.refresh_suggestions(cx);
-                cx.notify();
+                self.emit_change(cx);
             }
 
             _ => {
-                if let Some(ch) = event.keystroke.key_char.as_ref() {
-                    if !ctrl && ch != "\n" && ch != "\r" && ch != "\t" {
-                        self.insert_text(ch, cx);
-                    }
+                if let Some(ch) = typed {
+                    self.insert_text(ch, cx);
                 }
             }
         }
@@ -487,71 +835,17 @@ impl gpui::Render for Input {
         let theme = cx.theme();
         let is_focused = self.focus.is_focused(window);
 
-        let show_placeholder = self.value.is_empty();
-
-        let content = if show_placeholder {
-            let cursor = if is_focused {
-                gpui::div().w_px().h_4().bg(theme.accent).into_any_element()
-            } else {
-                gpui::div().into_any_element()
-            };
-
-            gpui::div()
-                .id(self.id.clone())
-                .flex()
-                .flex_row()
-                .items_center()
-                .child(cursor)
-                .child(
-                    gpui::div()
-                        .text_color(theme.muted)
-                        .child(self.placeholder.clone()),
-                )
-                .into_any_element()
-        } else {
-            let before = &self.value[..self.cursor_pos];
-            let after = &self.value[self.cursor_pos..];
-
-            let cursor = if is_focused {
-                gpui::div()
-                    .id(self.id.clone())
-                    .w_px()
-                    .h_4()
-                    .bg(theme.accent)
-                    .into_any_element()
-            } else {
-                gpui::div().id(self.id.clone()).into_any_element()
-            };
-
-            gpui::div()
-                .id(self.id.clone())
-                .flex()
-                .flex_row()
-                .items_center()
-                .child(
-                    gpui::div()
-                        .text_color(theme.foreground)
-                        .child(before.to_string()),
-                )
-                .child(cursor)
-                .child(
-                    gpui::div()
-                        .text_color(theme.foreground)
-                        .child(after.to_string()),
-                )
-                .into_any_element()
-        };
-
-        let focus_handle = self.focus.clone();
-
         gpui::div()
             .id(self.id.clone())
             .key_context("Input")
             .track_focus(&self.focus)
             .on_key_down(cx.listener(Self::handle_key_down))
-            .on_mouse_down(gpui::MouseButton::Left, move |_ev, window, _cx| {
-                window.focus(&focus_handle);
-            })
+            .on_mouse_down(
+                gpui::MouseButton::Left,
+                cx.listener(Self::handle_mouse_down),
+            )
+            .on_mouse_move(cx.listener(Self::handle_mouse_move))
+            .on_mouse_up(gpui::MouseButton::Left, cx.listener(Self::handle_mouse_up))
             .relative()
             .min_w(gpui::rems(12.))
             .border_1()
@@ -564,7 +858,7 @@ impl gpui::Render for Input {
             .rounded_md()
             .p_2()
             .cursor(gpui::CursorStyle::IBeam)
-            .child(content)
+            .child(InputText::new(cx.entity(), is_focused))
             .child(self.render_suggestions(cx))
     }
 }
@@ -574,3 +868,66 @@ impl gpui::Focusable for Input {
         self.focus.clone()
     }
 }
+
+// Editing keys win over keymap bindings: anything listed here never reaches
+// the app-level keymap while an input is focused.
+fn consumes_key(key: &str, ctrl: bool, inserts_text: bool) -> bool {
+    match key {
+        "left" | "right" | "home" | "end" | "backspace" | "delete" => true,
+        "w" | "a" | "e" | "u" | "k" | "v" | "V" if ctrl => true,
+        _ => inserts_text,
+    }
+}
+
+fn sanitize_pasted_text(text: &str) -> String {
+    text.chars()
+        .filter_map(|ch| match ch {
+            '\r' | '\n' | '\t' => Some(' '),
+            ch if ch.is_control() => None,
+            ch => Some(ch),
+        })
+        .collect()
+}
+
+fn replace_text(value: &mut String, range: Range<usize>, text: &str) -> usize {
+    let end = range.start + text.len();
+    value.replace_range(range, text);
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paste_strips_control_characters() {
+        assert_eq!(
+            sanitize_pasted_text("Buy\tmilk\r\nand \u{7}eggs\u{1b}"),
+            "Buy milk  and eggs"
+        );
+    }
+
+    #[test]
+    fn test_paste_replaces_selection_at_cursor() {
+        let mut value = "Call Bob today".to_string();
+        let cursor = replace_text(&mut value, 5..8, &sanitize_pasted_text("Alice\n"));
+        assert_eq!(value, "Call Alice  today");
+        assert_eq!(cursor, 11);
+
+        let cursor = replace_text(&mut value, cursor..cursor, "B.");
+        assert_eq!(value, "Call Alice B. today");
+        assert_eq!(cursor, 13);
+    }
+
+    #[test]
+    fn test_editing_keys_are_consumed_before_the_keymap() {
+        assert!(consumes_key("k", true, false));
+        assert!(consumes_key("backspace", false, false));
+        assert!(consumes_key("j", false, true));
+        assert!(!consumes_key("j", true, false));
+        assert!(!consumes_key("c", true, false));
+        assert!(!consumes_key("enter", false, false));
+        assert!(!consumes_key("escape", false, false));
+        assert!(!consumes_key("tab", false, false));
+    }
+}
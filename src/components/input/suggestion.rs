@@ -1,9 +1,12 @@
+use std::ops::Range;
+
 use gpui::SharedString;
 
 #[derive(Clone, Debug)]
 pub struct Suggestion {
     pub label: SharedString,
     pub insert: SharedString,
+    pub replace: Option<Range<usize>>,
 }
 
 impl Suggestion {
@@ -11,6 +14,7 @@ impl Suggestion {
         Self {
             label: label.into(),
             insert: insert.into(),
+            replace: None,
         }
     }
 
@@ -19,6 +23,12 @@ impl Suggestion {
         Self {
             label: text.clone(),
             insert: text,
+            replace: None,
         }
     }
+
+    pub fn with_replace(mut self, range: Range<usize>) -> Self {
+        self.replace = Some(range);
+        self
+    }
 }
@@ -0,0 +1,258 @@
+use gpui::prelude::*;
+
+use super::Input;
+use crate::theme::ActiveTheme;
+
+const CURSOR_WIDTH: gpui::Pixels = gpui::px(1.);
+
+pub(super) struct InputText {
+    input: gpui::Entity<Input>,
+    focused: bool,
+}
+
+impl InputText {
+    pub(super) fn new(input: gpui::Entity<Input>, focused: bool) -> Self {
+        Self { input, focused }
+    }
+}
+
+pub(super) struct InputTextPrepaint {
+    line: Option<gpui::ShapedLine>,
+    scroll_offset: gpui::Pixels,
+    selection: Option<gpui::PaintQuad>,
+    cursor: Option<gpui::PaintQuad>,
+}
+
+impl IntoElement for InputText {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl gpui::Element for InputText {
+    type RequestLayoutState = ();
+    type PrepaintState = InputTextPrepaint;
+
+    fn id(&self) -> Option<gpui::ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&gpui::GlobalElementId>,
+        _inspector_id: Option<&gpui::InspectorElementId>,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) -> (gpui::LayoutId, Self::RequestLayoutState) {
+        let mut style = gpui::Style::default();
+        style.size.width = gpui::relative(1.).into();
+        style.size.height = window.line_height().into();
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&gpui::GlobalElementId>,
+        _inspector_id: Option<&gpui::InspectorElementId>,
+        bounds: gpui::Bounds<gpui::Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) -> Self::PrepaintState {
+        let theme = cx.theme();
+        let input = self.input.read(cx);
+        let style = window.text_style();
+
+        let (display_text, runs) = if input.value.is_empty() {
+            let placeholder = input.placeholder.clone();
+            let run = gpui::TextRun {
+                len: placeholder.len(),
+                font: style.font(),
+                color: theme.muted.into(),
+                background_color: None,
+                underline: None,
+                strikethrough: None,
+            };
+            (placeholder, vec![run])
+        } else {
+            let value: gpui::SharedString = input.value.clone().into();
+            let run = gpui::TextRun {
+                len: value.len(),
+                font: style.font(),
+                color: theme.foreground.into(),
+                background_color: None,
+                underline: None,
+                strikethrough: None,
+            };
+            let runs = match input.selection_range() {
+                Some(range) => vec![
+                    gpui::TextRun {
+                        len: range.start,
+                        ..run.clone()
+                    },
+                    gpui::TextRun {
+                        len: range.end - range.start,
+                        color: theme.selection_foreground.into(),
+                        ..run.clone()
+                    },
+                    gpui::TextRun {
+                        len: value.len() - range.end,
+                        ..run
+                    },
+                ]
+                .into_iter()
+                .filter(|run| run.len > 0)
+                .collect(),
+                None => vec![run],
+            };
+            (value, runs)
+        };
+
+        let font_size = style.font_size.to_pixels(window.rem_size());
+        let line = window
+            .text_system()
+            .shape_line(display_text, font_size, &runs, None);
+
+        let cursor_x = if input.value.is_empty() {
+            gpui::px(0.)
+        } else {
+            line.x_for_index(input.cursor_pos)
+        };
+        let scroll_offset = scroll_offset_for_cursor(
+            input.scroll_offset,
+            cursor_x,
+            line.width,
+            bounds.size.width - CURSOR_WIDTH,
+        );
+        let origin_x = bounds.left() - scroll_offset;
+
+        let selection = input.selection_range().map(|range| {
+            gpui::fill(
+                gpui::Bounds::from_corners(
+                    gpui::point(origin_x + line.x_for_index(range.start), bounds.top()),
+                    gpui::point(origin_x + line.x_for_index(range.end), bounds.bottom()),
+                ),
+                theme.selection,
+            )
+        });
+
+        let cursor = self.focused.then(|| {
+            gpui::fill(
+                gpui::Bounds::new(
+                    gpui::point(origin_x + cursor_x, bounds.top()),
+                    gpui::size(CURSOR_WIDTH, bounds.size.height),
+                ),
+                theme.accent,
+            )
+        });
+
+        InputTextPrepaint {
+            line: Some(line),
+            scroll_offset,
+            selection,
+            cursor,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&gpui::GlobalElementId>,
+        _inspector_id: Option<&gpui::InspectorElementId>,
+        bounds: gpui::Bounds<gpui::Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        window: &mut gpui::Window,
+        cx: &mut gpui::App,
+    ) {
+        let Some(line) = prepaint.line.take() else {
+            return;
+        };
+        let scroll_offset = prepaint.scroll_offset;
+        let origin = gpui::point(bounds.left() - scroll_offset, bounds.top());
+
+        window.with_content_mask(Some(gpui::ContentMask { bounds }), |window| {
+            if let Some(selection) = prepaint.selection.take() {
+                window.paint_quad(selection);
+            }
+
+            if let Err(err) = line.paint(origin, window.line_height(), window, cx) {
+                log::error!("Input: failed to paint text: {}", err);
+            }
+
+            if let Some(cursor) = prepaint.cursor.take() {
+                window.paint_quad(cursor);
+            }
+        });
+
+        self.input.update(cx, |input, _cx| {
+            input.last_layout = Some(line);
+            input.last_bounds = Some(bounds);
+            input.scroll_offset = scroll_offset;
+        });
+    }
+}
+
+fn scroll_offset_for_cursor(
+    current: gpui::Pixels,
+    cursor_x: gpui::Pixels,
+    text_width: gpui::Pixels,
+    visible_width: gpui::Pixels,
+) -> gpui::Pixels {
+    let visible_width = visible_width.max(gpui::px(0.));
+    let max_offset = (text_width - visible_width).max(gpui::px(0.));
+
+    let mut offset = current.min(max_offset);
+    if cursor_x < offset {
+        offset = cursor_x;
+    } else if cursor_x > offset + visible_width {
+        offset = cursor_x - visible_width;
+    }
+    offset.clamp(gpui::px(0.), max_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_text_does_not_scroll() {
+        let offset =
+            scroll_offset_for_cursor(gpui::px(0.), gpui::px(40.), gpui::px(50.), gpui::px(100.));
+        assert_eq!(offset, gpui::px(0.));
+    }
+
+    #[test]
+    fn test_cursor_past_right_edge_scrolls_forward() {
+        let offset =
+            scroll_offset_for_cursor(gpui::px(0.), gpui::px(250.), gpui::px(300.), gpui::px(100.));
+        assert_eq!(offset, gpui::px(150.));
+    }
+
+    #[test]
+    fn test_cursor_before_left_edge_scrolls_back() {
+        let offset = scroll_offset_for_cursor(
+            gpui::px(150.),
+            gpui::px(20.),
+            gpui::px(300.),
+            gpui::px(100.),
+        );
+        assert_eq!(offset, gpui::px(20.));
+    }
+
+    #[test]
+    fn test_offset_is_clamped_after_deleting_text() {
+        let offset = scroll_offset_for_cursor(
+            gpui::px(200.),
+            gpui::px(120.),
+            gpui::px(120.),
+            gpui::px(100.),
+        );
+        assert_eq!(offset, gpui::px(20.));
+    }
+}
@@ -0,0 +1,117 @@
+#[derive(Debug, Clone)]
+pub struct UndoHistory {
+    history: Vec<(String, usize)>,
+    history_index: usize,
+    capacity: usize,
+}
+
+impl UndoHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            history: vec![(String::new(), 0)],
+            history_index: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn reset(&mut self, value: &str, cursor_pos: usize) {
+        self.history.clear();
+        self.history.push((value.to_string(), cursor_pos));
+        self.history_index = 0;
+    }
+
+    pub fn push(&mut self, value: &str, cursor_pos: usize) {
+        if self.history[self.history_index].0 == value {
+            self.history[self.history_index].1 = cursor_pos;
+            return;
+        }
+
+        self.history.truncate(self.history_index + 1);
+        self.history.push((value.to_string(), cursor_pos));
+        if self.history.len() > self.capacity {
+            self.history.remove(0);
+        }
+        self.history_index = self.history.len() - 1;
+    }
+
+    pub fn undo(&mut self) -> Option<(String, usize)> {
+        if self.history_index == 0 {
+            return None;
+        }
+        self.history_index -= 1;
+        self.history.get(self.history_index).cloned()
+    }
+
+    pub fn redo(&mut self) -> Option<(String, usize)> {
+        if self.history_index + 1 >= self.history.len() {
+            return None;
+        }
+        self.history_index += 1;
+        self.history.get(self.history_index).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(values: &[&str]) -> UndoHistory {
+        let mut history = UndoHistory::new(50);
+        for value in values {
+            history.push(value, value.len());
+        }
+        history
+    }
+
+    #[test]
+    fn test_undo_and_redo_walk_snapshots() {
+        let mut history = typed(&["b", "bu", "buy"]);
+        assert_eq!(history.undo(), Some(("bu".to_string(), 2)));
+        assert_eq!(history.undo(), Some(("b".to_string(), 1)));
+        assert_eq!(history.undo(), Some((String::new(), 0)));
+        assert_eq!(history.undo(), None);
+
+        assert_eq!(history.redo(), Some(("b".to_string(), 1)));
+        assert_eq!(history.redo(), Some(("bu".to_string(), 2)));
+        assert_eq!(history.redo(), Some(("buy".to_string(), 3)));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn test_identical_states_are_not_pushed() {
+        let mut history = typed(&["milk", "milk"]);
+        history.push("milk", 0);
+        assert_eq!(history.undo(), Some((String::new(), 0)));
+        assert_eq!(history.redo(), Some(("milk".to_string(), 0)));
+        assert_eq!(history.redo(), None);
+    }
+
+    #[test]
+    fn test_editing_after_undo_drops_redo() {
+        let mut history = typed(&["a", "ab"]);
+        history.undo();
+        history.push("ac", 2);
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.undo(), Some(("a".to_string(), 1)));
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut history = UndoHistory::new(3);
+        for value in ["a", "ab", "abc", "abcd"] {
+            history.push(value, value.len());
+        }
+        assert_eq!(history.undo(), Some(("abc".to_string(), 3)));
+        assert_eq!(history.undo(), Some(("ab".to_string(), 2)));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn test_reset_starts_from_the_new_value() {
+        let mut history = typed(&["a"]);
+        history.reset("Buy milk", 8);
+        assert_eq!(history.undo(), None);
+        history.push("Buy milk!", 9);
+        assert_eq!(history.undo(), Some(("Buy milk".to_string(), 8)));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod button;
+pub mod context_menu;
 pub mod divider;
 pub mod icon;
 pub mod input;
@@ -7,3 +8,4 @@ pub mod list;
 pub mod modal;
 pub mod panel;
 pub mod toast;
+pub mod tooltip;
@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use gpui::prelude::*;
 
 use crate::components::label::Label;
@@ -10,22 +13,72 @@ pub struct ToastGlobal {
 
 impl gpui::Global for ToastGlobal {}
 
+const MAX_VISIBLE_TOASTS: usize = 3;
+const INFO_TOAST_DURATION: Duration = Duration::from_secs(4);
+const SUCCESS_TOAST_DURATION: Duration = Duration::from_secs(3);
+const WARNING_TOAST_DURATION: Duration = Duration::from_secs(6);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ToastKind {
     Info,
     Success,
+    Warning,
     Error,
 }
 
+impl ToastKind {
+    pub fn auto_dismiss_after(&self) -> Option<Duration> {
+        match self {
+            ToastKind::Info => Some(INFO_TOAST_DURATION),
+            ToastKind::Success => Some(SUCCESS_TOAST_DURATION),
+            ToastKind::Warning => Some(WARNING_TOAST_DURATION),
+            ToastKind::Error => None,
+        }
+    }
+}
+
+pub type ToastActionHandler = Arc<dyn Fn(&mut gpui::Window, &mut gpui::App) + 'static>;
+
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: gpui::SharedString,
+    pub handler: ToastActionHandler,
+}
+
+impl ToastAction {
+    pub fn new(
+        label: impl Into<gpui::SharedString>,
+        handler: impl Fn(&mut gpui::Window, &mut gpui::App) + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            handler: Arc::new(handler),
+        }
+    }
+}
+
 struct Toast {
     id: u64,
     kind: ToastKind,
     message: String,
+    action: Option<ToastAction>,
+    created_at: Instant,
+    duration_ms: Option<u64>,
+    _expiry: Option<gpui::Task<()>>,
+}
+
+impl Toast {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.duration_ms.is_some_and(|duration_ms| {
+            now.duration_since(self.created_at) >= Duration::from_millis(duration_ms)
+        })
+    }
 }
 
 pub struct ToastHost {
     toasts: Vec<Toast>,
     next_id: u64,
+    default_duration_ms: Option<u64>,
 }
 
 impl ToastHost {
@@ -33,18 +86,78 @@ impl ToastHost {
         Self {
             toasts: Vec::new(),
             next_id: 1,
+            default_duration_ms: None,
         }
     }
 
+    pub fn with_default_duration(mut self, ms: u64) -> Self {
+        self.default_duration_ms = Some(ms);
+        self
+    }
+
+    fn duration_ms_for(&self, kind: ToastKind) -> Option<u64> {
+        let duration = kind.auto_dismiss_after()?;
+        Some(
+            self.default_duration_ms
+                .unwrap_or(duration.as_millis() as u64),
+        )
+    }
+
     pub fn push(&mut self, kind: ToastKind, message: impl Into<String>, cx: &mut Context<Self>) {
-        let toast = Toast {
-            id: self.next_id,
-            kind,
-            message: message.into(),
-        };
+        let duration_ms = self.duration_ms_for(kind);
+        self.push_toast(kind, message.into(), None, duration_ms, cx);
+    }
 
+    pub fn push_with_action(
+        &mut self,
+        kind: ToastKind,
+        message: impl Into<String>,
+        action: ToastAction,
+        cx: &mut Context<Self>,
+    ) {
+        let duration_ms = self.duration_ms_for(kind);
+        self.push_toast(kind, message.into(), Some(action), duration_ms, cx);
+    }
+
+    // Confirmations stay until they are answered or dismissed.
+    pub fn push_confirm(
+        &mut self,
+        message: impl Into<String>,
+        action: ToastAction,
+        cx: &mut Context<Self>,
+    ) {
+        self.push_toast(ToastKind::Warning, message.into(), Some(action), None, cx);
+    }
+
+    fn push_toast(
+        &mut self,
+        kind: ToastKind,
+        message: String,
+        action: Option<ToastAction>,
+        duration_ms: Option<u64>,
+        cx: &mut Context<Self>,
+    ) {
+        let id = self.next_id;
         self.next_id += 1;
-        self.toasts.push(toast);
+
+        let expiry = duration_ms.map(|duration_ms| {
+            cx.spawn(async move |host, cx| {
+                cx.background_executor()
+                    .timer(Duration::from_millis(duration_ms))
+                    .await;
+                host.update(cx, |host, cx| host.dismiss_expired(cx)).ok();
+            })
+        });
+
+        self.toasts.push(Toast {
+            id,
+            kind,
+            message,
+            action,
+            created_at: Instant::now(),
+            duration_ms,
+            _expiry: expiry,
+        });
 
         cx.notify();
     }
@@ -54,10 +167,37 @@ impl ToastHost {
         cx.notify();
     }
 
+    fn dismiss_expired(&mut self, cx: &mut Context<Self>) {
+        let now = Instant::now();
+        self.toasts.retain(|toast| !toast.is_expired(now));
+        cx.notify();
+    }
+
+    fn dismiss_hidden(&mut self, cx: &mut Context<Self>) {
+        let hidden = self.toasts.len().saturating_sub(MAX_VISIBLE_TOASTS);
+        self.toasts.drain(..hidden);
+        cx.notify();
+    }
+
+    fn run_action(&mut self, id: u64, window: &mut gpui::Window, cx: &mut Context<Self>) {
+        let Some(action) = self
+            .toasts
+            .iter()
+            .find(|toast| toast.id == id)
+            .and_then(|toast| toast.action.clone())
+        else {
+            return;
+        };
+
+        self.dismiss(id, cx);
+        (action.handler)(window, cx);
+    }
+
     fn kind_color(kind: ToastKind, theme: &Theme) -> gpui::Rgba {
         match kind {
             ToastKind::Info => theme.info,
             ToastKind::Success => theme.success,
+            ToastKind::Warning => theme.warning,
             ToastKind::Error => theme.error,
         }
     }
@@ -69,12 +209,15 @@ impl gpui::Render for ToastHost {
         _window: &mut gpui::Window,
         cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
+        let now = Instant::now();
+        self.toasts.retain(|toast| !toast.is_expired(now));
         if self.toasts.is_empty() {
             return gpui::div().into_any_element();
         }
 
         let theme = cx.theme();
-        let items = self.toasts.iter().map(|toast| {
+        let hidden = self.toasts.len().saturating_sub(MAX_VISIBLE_TOASTS);
+        let items = self.toasts[hidden..].iter().map(|toast| {
             let toast_id = toast.id;
             let accent = Self::kind_color(toast.kind, theme);
 
@@ -96,6 +239,28 @@ impl gpui::Render for ToastHost {
                 )
                 .child(Label::new("X"));
 
+            let action_button = toast.action.as_ref().map(|action| {
+                gpui::div()
+                    .flex_shrink_0()
+                    .px_2()
+                    .py_1()
+                    .rounded_sm()
+                    .border_1()
+                    .border_color(Theme::alpha(accent, 0.6))
+                    .text_sm()
+                    .text_color(accent)
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .cursor_pointer()
+                    .hover(|s| s.bg(Theme::alpha(accent, 0.15)))
+                    .on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(move |host, _event, window, cx| {
+                            host.run_action(toast_id, window, cx);
+                        }),
+                    )
+                    .child(Label::new(action.label.clone()))
+            });
+
             let background = mix_color(theme.background, accent, 0.2);
             let border = Theme::alpha(accent, 0.45);
 
@@ -111,6 +276,13 @@ impl gpui::Render for ToastHost {
                 .bg(background)
                 .rounded_md()
                 .shadow_lg()
+                .cursor_pointer()
+                .on_mouse_down(
+                    gpui::MouseButton::Left,
+                    cx.listener(move |host, _event, _window, cx| {
+                        host.dismiss(toast_id, cx);
+                    }),
+                )
                 .child(
                     gpui::div()
                         .w(gpui::px(6.0))
@@ -119,17 +291,46 @@ impl gpui::Render for ToastHost {
                         .rounded_md(),
                 )
                 .child(
-                    gpui::div().flex_1().min_w(gpui::rems(18.0)).child(
-                        Label::new(toast.message.clone())
-                            .text_sm()
-                            .text_color(theme.foreground)
-                            .font_weight(gpui::FontWeight::MEDIUM),
-                    ),
+                    gpui::div()
+                        .flex_1()
+                        .min_w(gpui::rems(18.0))
+                        .max_w(gpui::rems(28.0))
+                        .child(
+                            Label::new(toast.message.clone())
+                                .text_sm()
+                                .text_color(theme.foreground)
+                                .font_weight(gpui::FontWeight::MEDIUM),
+                        ),
                 )
+                .children(action_button)
                 .child(close_button)
                 .into_any_element()
         });
 
+        let overflow = (hidden > 0).then(|| {
+            gpui::div()
+                .id("toast-overflow")
+                .self_end()
+                .px_3()
+                .py_1()
+                .rounded_md()
+                .occlude()
+                .bg(theme.card)
+                .border_1()
+                .border_color(theme.border)
+                .text_xs()
+                .text_color(theme.muted)
+                .cursor_pointer()
+                .hover(|s| s.text_color(theme.accent))
+                .on_mouse_down(
+                    gpui::MouseButton::Left,
+                    cx.listener(|host, _event, _window, cx| {
+                        host.dismiss_hidden(cx);
+                    }),
+                )
+                .child(Label::new(format!("+{} more", hidden)))
+        });
+
         gpui::div()
             .id("toast-host")
             .absolute()
@@ -138,6 +339,7 @@ impl gpui::Render for ToastHost {
             .flex()
             .flex_col()
             .gap_2()
+            .children(overflow)
             .children(items)
             .into_any_element()
     }
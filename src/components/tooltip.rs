@@ -0,0 +1,49 @@
+use gpui::prelude::*;
+
+use crate::theme::ActiveTheme;
+
+const TOOLTIP_WRAP_THRESHOLD: usize = 60;
+
+pub struct Tooltip {
+    content: gpui::SharedString,
+}
+
+impl Tooltip {
+    pub fn new(content: impl Into<gpui::SharedString>) -> Self {
+        Self {
+            content: content.into(),
+        }
+    }
+
+    pub fn text(
+        content: impl Into<gpui::SharedString>,
+    ) -> impl Fn(&mut gpui::Window, &mut gpui::App) -> gpui::AnyView + 'static {
+        let content = content.into();
+        move |_window, cx| cx.new(|_cx| Self::new(content.clone())).into()
+    }
+}
+
+impl gpui::Render for Tooltip {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl IntoElement {
+        let theme = cx.theme();
+        let wraps = self.content.chars().count() > TOOLTIP_WRAP_THRESHOLD;
+
+        gpui::div()
+            .when(wraps, |el| el.w(gpui::rems(28.0)))
+            .when(!wraps, |el| el.whitespace_nowrap())
+            .px_2()
+            .py_1()
+            .bg(theme.raised)
+            .border_1()
+            .border_color(theme.border)
+            .rounded_md()
+            .shadow_lg()
+            .text_sm()
+            .text_color(theme.foreground)
+            .child(self.content.clone())
+    }
+}
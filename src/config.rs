@@ -0,0 +1,783 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{DueFilter, FilterState, PriorityFilter, SortColumn, SortState, StatusFilter};
+use crate::task::export::DEFAULT_MARKDOWN_TEMPLATE;
+use crate::task::{DEFAULT_DUE_SOON_DAYS, TaskError, TaskResult, UrgencyConfig};
+use crate::theme::Theme;
+use crate::ui::{
+    SIDEBAR_MAX_WIDTH, SIDEBAR_MIN_WIDTH, SIDEBAR_PROJECTS_FRACTION, SIDEBAR_SECTION_MIN_FRACTION,
+    SIDEBAR_WIDTH, TABLE_DESCRIPTION_MAX_LINES, TABLE_PAGE_SIZE, TABLE_PAGE_SIZE_MAX,
+    TABLE_PAGE_SIZE_MIN,
+};
+
+const CONFIG_DIR_NAME: &str = "taskwarrior-gpui";
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub theme: ThemeVariant,
+    pub page_size: usize,
+    pub relative_dates: bool,
+    pub due_soon_days: u32,
+    pub show_inline_tags: bool,
+    pub auto_sync_interval_minutes: Option<u64>,
+    pub toast_duration_ms: Option<u64>,
+    pub confirm_destructive: bool,
+    pub urgency: UrgencyConfig,
+    pub urgency_overrides: UrgencyOverrides,
+    taskrc_urgency: UrgencyConfig,
+    pub table: TableConfig,
+    pub table_density: TableDensity,
+    pub wrap_descriptions: bool,
+    pub max_description_display_lines: usize,
+    pub extra_columns: Vec<String>,
+    pub pinned_tasks: HashSet<uuid::Uuid>,
+    pub sidebar: SidebarConfig,
+    pub keybindings: Vec<KeyBindingOverride>,
+    pub default_filters: DefaultFilters,
+    pub markdown_template: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBindingOverride {
+    pub context: String,
+    pub keys: String,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeVariant {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl ThemeVariant {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            Self::Dark => Self::Light,
+            Self::Light => Self::Dark,
+        }
+    }
+
+    pub fn theme(&self) -> Theme {
+        match self {
+            Self::Dark => Theme::dark(),
+            Self::Light => Theme::light(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableDensity {
+    Compact,
+    #[default]
+    Normal,
+    Comfortable,
+}
+
+impl TableDensity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Compact => "Compact",
+            Self::Normal => "Normal",
+            Self::Comfortable => "Comfortable",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Compact => Self::Normal,
+            Self::Normal => Self::Comfortable,
+            Self::Comfortable => Self::Compact,
+        }
+    }
+
+    pub fn shows_column(&self, column: SortColumn) -> bool {
+        !(*self == Self::Compact && column == SortColumn::Project)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DefaultFilters {
+    pub project: Option<String>,
+    pub tags: Vec<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub due: Option<String>,
+    pub search: String,
+    pub hide_scheduled: bool,
+}
+
+impl DefaultFilters {
+    pub fn filter_state(&self) -> FilterState {
+        let mut state = FilterState {
+            selected_project: self
+                .project
+                .as_deref()
+                .map(str::trim)
+                .filter(|project| !project.is_empty())
+                .map(str::to_string),
+            active_tags: self
+                .tags
+                .iter()
+                .map(|tag| tag.trim().trim_start_matches('+').to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+            search_text: self.search.trim().to_string(),
+            hide_scheduled: self.hide_scheduled,
+            ..FilterState::default()
+        };
+
+        if let Some(status) = &self.status {
+            match StatusFilter::from_value(status) {
+                Some(status) => state.status_filter = status,
+                None => log::warn!("[Config] Unknown default status filter: {}", status),
+            }
+        }
+        if let Some(priority) = &self.priority {
+            match PriorityFilter::from_value(priority) {
+                Some(priority) => state.priority_filter = priority,
+                None => log::warn!("[Config] Unknown default priority filter: {}", priority),
+            }
+        }
+        if let Some(due) = &self.due {
+            match DueFilter::from_value(due.trim()) {
+                Some(due) => state.due_filter = due,
+                None => log::warn!("[Config] Unknown default due filter: {}", due),
+            }
+        }
+
+        state
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrgencyOverrides {
+    pub priority_high: Option<f32>,
+    pub priority_medium: Option<f32>,
+    pub priority_low: Option<f32>,
+    pub due: Option<f32>,
+    pub blocking: Option<f32>,
+    pub blocked: Option<f32>,
+    pub active: Option<f32>,
+    pub tags: Option<f32>,
+    pub project: Option<f32>,
+    pub annotations: Option<f32>,
+    pub age: Option<f32>,
+}
+
+impl UrgencyOverrides {
+    pub fn apply(&self, base: &UrgencyConfig) -> UrgencyConfig {
+        let mut urgency = base.clone();
+        let fields = [
+            (self.priority_high, &mut urgency.priority_high),
+            (self.priority_medium, &mut urgency.priority_medium),
+            (self.priority_low, &mut urgency.priority_low),
+            (self.due, &mut urgency.due),
+            (self.blocking, &mut urgency.blocking),
+            (self.blocked, &mut urgency.blocked),
+            (self.active, &mut urgency.active),
+            (self.tags, &mut urgency.tags),
+            (self.project, &mut urgency.project),
+            (self.annotations, &mut urgency.annotations),
+            (self.age, &mut urgency.age),
+        ];
+        for (value, field) in fields {
+            if let Some(value) = value.filter(|value| value.is_finite()) {
+                *field = value;
+            }
+        }
+        urgency
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SidebarConfig {
+    pub collapsed: bool,
+    pub width: gpui::Pixels,
+    pub projects_height_fraction: f32,
+}
+
+impl Default for SidebarConfig {
+    fn default() -> Self {
+        Self {
+            collapsed: false,
+            width: SIDEBAR_WIDTH,
+            projects_height_fraction: SIDEBAR_PROJECTS_FRACTION,
+        }
+    }
+}
+
+impl SidebarConfig {
+    pub fn set_width(&mut self, width: gpui::Pixels) {
+        self.width = width.clamp(SIDEBAR_MIN_WIDTH, SIDEBAR_MAX_WIDTH);
+    }
+
+    pub fn set_projects_height_fraction(&mut self, fraction: f32) {
+        self.projects_height_fraction = if fraction.is_finite() {
+            fraction.clamp(
+                SIDEBAR_SECTION_MIN_FRACTION,
+                1.0 - SIDEBAR_SECTION_MIN_FRACTION,
+            )
+        } else {
+            SIDEBAR_PROJECTS_FRACTION
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableConfig {
+    pub visible_columns: Vec<SortColumn>,
+    pub column_order: Vec<SortColumn>,
+    pub sort: Vec<SortState>,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        Self {
+            visible_columns: SortColumn::ALL
+                .into_iter()
+                .filter(|column| {
+                    !matches!(
+                        column,
+                        SortColumn::Scheduled | SortColumn::EntryDate | SortColumn::ModifiedDate
+                    )
+                })
+                .collect(),
+            column_order: SortColumn::ALL.to_vec(),
+            sort: SortState::default_levels(),
+        }
+    }
+}
+
+impl TableConfig {
+    pub fn columns(&self) -> Vec<SortColumn> {
+        let mut columns: Vec<SortColumn> = self
+            .column_order
+            .iter()
+            .copied()
+            .filter(|column| self.is_visible(*column))
+            .collect();
+
+        if !columns.contains(&SortColumn::Description) {
+            columns.push(SortColumn::Description);
+        }
+        columns
+    }
+
+    pub fn is_visible(&self, column: SortColumn) -> bool {
+        column == SortColumn::Description || self.visible_columns.contains(&column)
+    }
+
+    pub fn toggle_column(&mut self, column: SortColumn) {
+        if column == SortColumn::Description {
+            return;
+        }
+
+        if let Some(pos) = self.visible_columns.iter().position(|c| *c == column) {
+            self.visible_columns.remove(pos);
+        } else {
+            self.visible_columns.push(column);
+        }
+    }
+
+    pub fn move_column(&mut self, column: SortColumn, target: SortColumn) {
+        let Some(from) = self.column_order.iter().position(|c| *c == column) else {
+            return;
+        };
+        let Some(to) = self.column_order.iter().position(|c| *c == target) else {
+            return;
+        };
+        if from == to {
+            return;
+        }
+
+        let column = self.column_order.remove(from);
+        self.column_order.insert(to, column);
+    }
+
+    pub fn shift_column(&mut self, column: SortColumn, offset: isize) {
+        let Some(from) = self.column_order.iter().position(|c| *c == column) else {
+            return;
+        };
+        let to = from as isize + offset;
+        if to < 0 || to as usize >= self.column_order.len() {
+            return;
+        }
+
+        self.column_order.swap(from, to as usize);
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            theme: ThemeVariant::default(),
+            page_size: TABLE_PAGE_SIZE,
+            relative_dates: true,
+            due_soon_days: DEFAULT_DUE_SOON_DAYS,
+            show_inline_tags: true,
+            auto_sync_interval_minutes: None,
+            toast_duration_ms: None,
+            confirm_destructive: true,
+            urgency: UrgencyConfig::default(),
+            urgency_overrides: UrgencyOverrides::default(),
+            taskrc_urgency: UrgencyConfig::default(),
+            table: TableConfig::default(),
+            table_density: TableDensity::default(),
+            wrap_descriptions: false,
+            max_description_display_lines: TABLE_DESCRIPTION_MAX_LINES,
+            extra_columns: Vec::new(),
+            pinned_tasks: HashSet::new(),
+            sidebar: SidebarConfig::default(),
+            keybindings: Vec::new(),
+            default_filters: DefaultFilters::default(),
+            markdown_template: DEFAULT_MARKDOWN_TEMPLATE.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct StoredConfig {
+    theme: ThemeVariant,
+    page_size: usize,
+    relative_dates: bool,
+    due_soon_days: u32,
+    show_inline_tags: bool,
+    auto_sync_interval_minutes: Option<u64>,
+    toast_duration_ms: Option<u64>,
+    confirm_destructive: bool,
+    urgency: UrgencyOverrides,
+    table_density: TableDensity,
+    wrap_descriptions: bool,
+    max_description_display_lines: usize,
+    visible_columns: Vec<String>,
+    column_order: Vec<String>,
+    sort: Vec<String>,
+    sidebar_width: f32,
+    sidebar_collapsed: bool,
+    sidebar_projects_fraction: f32,
+    extra_columns: Vec<String>,
+    pinned_tasks: Vec<String>,
+    default_filters: DefaultFilters,
+    markdown_template: String,
+    keybindings: Vec<KeyBindingOverride>,
+}
+
+impl Default for StoredConfig {
+    fn default() -> Self {
+        StoredConfig::from(&AppConfig::default())
+    }
+}
+
+impl From<&AppConfig> for StoredConfig {
+    fn from(config: &AppConfig) -> Self {
+        Self {
+            theme: config.theme,
+            page_size: config.page_size,
+            relative_dates: config.relative_dates,
+            due_soon_days: config.due_soon_days,
+            show_inline_tags: config.show_inline_tags,
+            auto_sync_interval_minutes: config.auto_sync_interval_minutes,
+            toast_duration_ms: config.toast_duration_ms,
+            confirm_destructive: config.confirm_destructive,
+            urgency: config.urgency_overrides,
+            table_density: config.table_density,
+            wrap_descriptions: config.wrap_descriptions,
+            max_description_display_lines: config.max_description_display_lines,
+            visible_columns: config
+                .table
+                .visible_columns
+                .iter()
+                .map(|column| column.value().to_string())
+                .collect(),
+            column_order: config
+                .table
+                .column_order
+                .iter()
+                .map(|column| column.value().to_string())
+                .collect(),
+            sort: config.table.sort.iter().map(SortState::value).collect(),
+            sidebar_width: f32::from(config.sidebar.width),
+            sidebar_collapsed: config.sidebar.collapsed,
+            sidebar_projects_fraction: config.sidebar.projects_height_fraction,
+            extra_columns: config.extra_columns.clone(),
+            pinned_tasks: {
+                let mut pinned: Vec<String> = config
+                    .pinned_tasks
+                    .iter()
+                    .map(|uuid| uuid.to_string())
+                    .collect();
+                pinned.sort();
+                pinned
+            },
+            default_filters: config.default_filters.clone(),
+            markdown_template: config.markdown_template.clone(),
+            keybindings: config.keybindings.clone(),
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn global(app: &gpui::App) -> &Self {
+        app.global::<Self>()
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(CONFIG_FILE_NAME))
+    }
+
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => Self::from_toml(&contents).unwrap_or_else(|e| {
+                log::error!("AppConfig::load: {}: {}", path.display(), e);
+                Self::default()
+            }),
+            Err(e) => {
+                log::error!("AppConfig::load: Failed to read {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> TaskResult<()> {
+        let path =
+            Self::path().ok_or_else(|| TaskError::Config("Cannot find config directory".into()))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                TaskError::Config(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+
+        std::fs::write(&path, self.to_toml()?).map_err(|e| {
+            log::error!("AppConfig::save: Failed to write {}: {}", path.display(), e);
+            TaskError::Config(format!("Failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size.clamp(TABLE_PAGE_SIZE_MIN, TABLE_PAGE_SIZE_MAX);
+    }
+
+    pub fn due_soon_threshold(&self) -> chrono::Duration {
+        chrono::Duration::days(i64::from(self.due_soon_days))
+    }
+
+    pub fn toggle_pinned(&mut self, uuid: uuid::Uuid) -> bool {
+        if self.pinned_tasks.remove(&uuid) {
+            false
+        } else {
+            self.pinned_tasks.insert(uuid);
+            true
+        }
+    }
+
+    // The taskrc supplies the base coefficients and the GUI overrides sit on top.
+    pub fn set_taskrc_urgency(&mut self, urgency: UrgencyConfig) {
+        self.taskrc_urgency = urgency;
+        self.urgency = self.urgency_overrides.apply(&self.taskrc_urgency);
+    }
+
+    pub fn set_urgency_overrides(&mut self, overrides: UrgencyOverrides) {
+        self.urgency_overrides = overrides;
+        self.urgency = self.urgency_overrides.apply(&self.taskrc_urgency);
+    }
+
+    pub fn set_extra_columns(&mut self, columns: impl IntoIterator<Item = String>) {
+        self.extra_columns.clear();
+        for column in columns {
+            let column = column.trim();
+            if !column.is_empty() && !self.extra_columns.iter().any(|c| c == column) {
+                self.extra_columns.push(column.to_string());
+            }
+        }
+    }
+
+    fn from_toml(contents: &str) -> TaskResult<Self> {
+        let stored: StoredConfig = toml::from_str(contents)
+            .map_err(|e| TaskError::Config(format!("Invalid config: {}", e)))?;
+
+        let mut config = Self {
+            theme: stored.theme,
+            relative_dates: stored.relative_dates,
+            due_soon_days: stored.due_soon_days,
+            show_inline_tags: stored.show_inline_tags,
+            auto_sync_interval_minutes: stored.auto_sync_interval_minutes,
+            toast_duration_ms: stored.toast_duration_ms,
+            confirm_destructive: stored.confirm_destructive,
+            table_density: stored.table_density,
+            wrap_descriptions: stored.wrap_descriptions,
+            max_description_display_lines: stored.max_description_display_lines.max(1),
+            ..Self::default()
+        };
+        config.set_page_size(stored.page_size);
+        config.set_urgency_overrides(stored.urgency);
+        config.sidebar.collapsed = stored.sidebar_collapsed;
+        config.sidebar.set_width(gpui::px(stored.sidebar_width));
+        config
+            .sidebar
+            .set_projects_height_fraction(stored.sidebar_projects_fraction);
+        config.set_extra_columns(stored.extra_columns);
+        config.pinned_tasks = stored
+            .pinned_tasks
+            .iter()
+            .filter_map(|uuid| uuid::Uuid::parse_str(uuid).ok())
+            .collect();
+        let sort: Vec<SortState> = stored
+            .sort
+            .iter()
+            .filter_map(|level| {
+                let parsed = SortState::from_value(level);
+                if parsed.is_none() {
+                    log::warn!("[Config] Unknown sort level: {}", level);
+                }
+                parsed
+            })
+            .collect();
+        if !sort.is_empty() {
+            config.table.sort = sort;
+        }
+        config.table.visible_columns = parse_columns(&stored.visible_columns);
+        // Columns added after the config was written go to the end of the order.
+        let mut order = parse_columns(&stored.column_order);
+        for column in SortColumn::ALL {
+            if !order.contains(&column) {
+                order.push(column);
+            }
+        }
+        config.table.column_order = order;
+        config.keybindings = stored.keybindings;
+        config.default_filters = stored.default_filters;
+        if !stored.markdown_template.trim().is_empty() {
+            config.markdown_template = stored.markdown_template;
+        }
+        Ok(config)
+    }
+
+    fn to_toml(&self) -> TaskResult<String> {
+        toml::to_string_pretty(&StoredConfig::from(self))
+            .map_err(|e| TaskError::Config(format!("Failed to serialize config: {}", e)))
+    }
+}
+
+fn parse_columns(values: &[String]) -> Vec<SortColumn> {
+    let mut columns = Vec::new();
+    for value in values {
+        match SortColumn::from_value(value) {
+            Some(column) if !columns.contains(&column) => columns.push(column),
+            Some(_) => {}
+            None => log::warn!("[Config] Unknown table column: {}", value),
+        }
+    }
+    columns
+}
+
+impl gpui::Global for AppConfig {}
+
+pub trait ActiveConfig {
+    fn config(&self) -> &AppConfig;
+}
+
+impl ActiveConfig for gpui::App {
+    #[inline(always)]
+    fn config(&self) -> &AppConfig {
+        AppConfig::global(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description_is_always_visible() {
+        let mut config = TableConfig::default();
+        config.toggle_column(SortColumn::Description);
+        config.toggle_column(SortColumn::Status);
+        config.visible_columns.clear();
+
+        assert!(config.is_visible(SortColumn::Description));
+        assert_eq!(config.columns(), vec![SortColumn::Description]);
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let mut config = AppConfig::default();
+        config.theme = ThemeVariant::Light;
+        config.set_page_size(50);
+        config.relative_dates = false;
+        config.due_soon_days = 7;
+        config.auto_sync_interval_minutes = Some(15);
+        config.toast_duration_ms = Some(2500);
+        config.confirm_destructive = false;
+        config.table_density = TableDensity::Compact;
+        config.wrap_descriptions = true;
+        config.max_description_display_lines = 5;
+        config.table.sort = vec![SortState::default()];
+        config.table.toggle_column(SortColumn::Project);
+        config.table.toggle_column(SortColumn::EntryDate);
+        config.table.move_column(SortColumn::Status, SortColumn::Id);
+        config.sidebar.set_width(gpui::px(320.0));
+        config.sidebar.set_projects_height_fraction(0.7);
+        config.set_extra_columns(["estimate".to_string()]);
+        config.toggle_pinned(uuid::Uuid::new_v4());
+        config.default_filters.project = Some("Work".to_string());
+        config.set_urgency_overrides(UrgencyOverrides {
+            due: Some(20.0),
+            ..UrgencyOverrides::default()
+        });
+        config.markdown_template = "- {description} ({project})".to_string();
+        config.keybindings.push(KeyBindingOverride {
+            context: "table".to_string(),
+            keys: "ctrl+d".to_string(),
+            command: "DeleteTask".to_string(),
+        });
+
+        let loaded = AppConfig::from_toml(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(StoredConfig::from(&loaded), StoredConfig::from(&config));
+        assert_eq!(loaded.table, config.table);
+    }
+
+    #[test]
+    fn test_urgency_overrides_sit_on_top_of_taskrc() {
+        let mut config = AppConfig::from_toml("[urgency]\ndue = 20.0\n").unwrap();
+        config.set_taskrc_urgency(UrgencyConfig {
+            due: 5.0,
+            active: 9.0,
+            ..UrgencyConfig::default()
+        });
+
+        assert_eq!(config.urgency.due, 20.0);
+        assert_eq!(config.urgency.active, 9.0);
+
+        config.set_urgency_overrides(UrgencyOverrides::default());
+        assert_eq!(config.urgency.due, 5.0);
+    }
+
+    #[test]
+    fn test_column_order_keeps_missing_columns() {
+        let config = AppConfig::from_toml(
+            "visible_columns = [\"due\", \"bogus\", \"due\"]\ncolumn_order = [\"due\", \"id\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.table.visible_columns, vec![SortColumn::Due]);
+        assert_eq!(config.table.column_order.len(), SortColumn::ALL.len());
+        assert_eq!(
+            config.table.columns(),
+            vec![SortColumn::Due, SortColumn::Description]
+        );
+    }
+
+    #[test]
+    fn test_partial_toml_keeps_defaults_and_clamps() {
+        let config = AppConfig::from_toml(
+            "page_size = 100000\nsidebar_width = 10.0\nsidebar_projects_fraction = 1.0\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.page_size, TABLE_PAGE_SIZE_MAX);
+        assert_eq!(config.sidebar.width, SIDEBAR_MIN_WIDTH);
+        assert_eq!(
+            config.sidebar.projects_height_fraction,
+            1.0 - SIDEBAR_SECTION_MIN_FRACTION
+        );
+        assert_eq!(config.theme, ThemeVariant::Dark);
+        assert!(config.relative_dates);
+        assert!(config.confirm_destructive);
+        assert!(AppConfig::from_toml("page_size = \"many\"").is_err());
+    }
+
+    #[test]
+    fn test_extra_columns_are_trimmed_and_deduplicated() {
+        let config = AppConfig::from_toml(
+            "extra_columns = [\" client \", \"\", \"client\", \"estimate\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.extra_columns, vec!["client", "estimate"]);
+    }
+
+    #[test]
+    fn test_default_filters_build_the_initial_filter_state() {
+        let config = AppConfig::from_toml(
+            r#"
+[default_filters]
+project = "Work"
+tags = ["+next"]
+status = "waiting"
+priority = "H"
+due = "overdue"
+"#,
+        )
+        .unwrap();
+        let state = config.default_filters.filter_state();
+
+        assert_eq!(state.selected_project.as_deref(), Some("Work"));
+        assert!(state.active_tags.contains("next"));
+        assert_eq!(state.status_filter, StatusFilter::Waiting);
+        assert_eq!(state.priority_filter, PriorityFilter::High);
+        assert_eq!(state.due_filter, DueFilter::Overdue);
+
+        let soon = AppConfig::from_toml("[default_filters]\ndue = \"soon\"\n").unwrap();
+        assert_eq!(
+            soon.default_filters.filter_state().due_filter,
+            DueFilter::Soon
+        );
+
+        let invalid = AppConfig::from_toml("[default_filters]\ndue = \"bogus\"\n").unwrap();
+        assert_eq!(
+            invalid.default_filters.filter_state().due_filter,
+            DueFilter::All
+        );
+        assert!(
+            !AppConfig::default()
+                .default_filters
+                .filter_state()
+                .has_active_filters()
+        );
+    }
+
+    #[test]
+    fn test_columns_follow_order_and_visibility() {
+        let mut config = TableConfig::default();
+        config.toggle_column(SortColumn::Project);
+        config.move_column(SortColumn::Status, SortColumn::Id);
+        config.shift_column(SortColumn::Urgency, -1);
+
+        assert_eq!(
+            config.columns(),
+            vec![
+                SortColumn::Status,
+                SortColumn::Id,
+                SortColumn::Description,
+                SortColumn::Due,
+                SortColumn::Urgency,
+                SortColumn::Priority,
+            ]
+        );
+    }
+}
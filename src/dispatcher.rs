@@ -1,8 +1,30 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use crate::{
     app::App,
+    components::toast::{ToastAction, ToastKind},
+    config::{ActiveConfig, AppConfig},
     keymap::{Command, CommandDispatcher, FocusTarget},
+    task::{
+        self, ExportFormat, FilterPreset, TaskAnnotation, TaskError, TaskResult, TaskSummary,
+        export::{self, ClipboardFormat},
+        import,
+    },
+    ui::DATE_FORMAT,
+    view::{task_detail_modal::DetailSection, task_table::BulkAction},
 };
 
+fn clipboard_format(command: Command) -> Option<ClipboardFormat> {
+    match command {
+        Command::CopyTaskUuid => Some(ClipboardFormat::Uuid),
+        Command::CopyTaskDescription => Some(ClipboardFormat::Description),
+        Command::CopyTaskUuidAndDescription => Some(ClipboardFormat::UuidAndDescription),
+        Command::CopyTaskChecklist => Some(ClipboardFormat::Checklist),
+        _ => None,
+    }
+}
+
 impl App {
     fn close_task_detail(&mut self, cx: &mut gpui::Context<Self>) {
         self.task_detail_modal.update(cx, |modal, cx| {
@@ -15,6 +37,649 @@ impl App {
             modal.scroll(delta, cx);
         });
     }
+
+    fn copy_task_detail(&self, command: Command, cx: &mut gpui::Context<Self>) {
+        let Some(detail) = self.task_detail_modal.read(cx).detail() else {
+            self.copy_selected_task(command, cx);
+            return;
+        };
+
+        let (text, message) = match (command, clipboard_format(command)) {
+            (Command::CopyTaskFormatted, _) => (
+                detail.formatted_summary(),
+                "Task summary copied".to_string(),
+            ),
+            (Command::CopyTaskAsMarkdown, _) => (
+                export::to_markdown(detail, &cx.config().markdown_template),
+                "Task copied as Markdown".to_string(),
+            ),
+            (_, Some(format)) => (
+                format.line(
+                    detail.identity.uuid,
+                    &detail.overview.description,
+                    &detail.overview.status,
+                ),
+                format!("Task {} copied", format.label()),
+            ),
+            _ => return,
+        };
+
+        cx.write_to_clipboard(gpui::ClipboardItem::new_string(text));
+        self.toast_host.update(cx, |host, cx| {
+            host.push(ToastKind::Info, message, cx);
+        });
+    }
+
+    fn copy_selected_task(&self, command: Command, cx: &mut gpui::Context<Self>) {
+        let Some(format) = clipboard_format(command) else {
+            return;
+        };
+
+        let table = self.task_table.read(cx);
+        let marked = table.marked_uuids();
+        let tasks: Vec<&TaskSummary> = if marked.is_empty() {
+            table.selected_task().into_iter().collect()
+        } else {
+            table
+                .visible_tasks()
+                .iter()
+                .filter(|task| marked.contains(&task.uuid))
+                .collect()
+        };
+
+        let message = match tasks.as_slice() {
+            [] => return,
+            [task] => match task.working_id {
+                Some(id) => format!("Copied {} of task {}", format.label(), id),
+                None => format!("Task {} copied", format.label()),
+            },
+            tasks => format!("Copied {} of {} tasks", format.label(), tasks.len()),
+        };
+        let text = export::tasks_to_clipboard(&tasks, format);
+
+        cx.write_to_clipboard(gpui::ClipboardItem::new_string(text));
+        self.toast_host.update(cx, |host, cx| {
+            host.push(ToastKind::Info, message, cx);
+        });
+    }
+
+    fn toggle_pin_selected(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(uuid) = self.task_table.read(cx).selected_task_uuid() else {
+            return;
+        };
+
+        let pinned = cx.update_global::<AppConfig, _>(|config, _cx| config.toggle_pinned(uuid));
+        if let Err(e) = cx.config().save() {
+            log::error!("[App] Failed to save pinned tasks: {}", e);
+            self.toast_host.update(cx, |host, cx| {
+                host.push(ToastKind::Error, e.to_string(), cx);
+            });
+        }
+
+        let tasks = self.tasks.clone();
+        self.task_table
+            .update(cx, |table, cx| table.reload_tasks_from_all(tasks, cx));
+        self.toast_host.update(cx, |host, cx| {
+            let message = if pinned {
+                "Task pinned"
+            } else {
+                "Task unpinned"
+            };
+            host.push(ToastKind::Info, message, cx);
+        });
+    }
+
+    fn cycle_table_density(&mut self, cx: &mut gpui::Context<Self>) {
+        let density = cx.update_global::<AppConfig, _>(|config, _cx| {
+            config.table_density = config.table_density.next();
+            config.table_density
+        });
+        if let Err(e) = cx.config().save() {
+            log::error!("[App] Failed to save table density: {}", e);
+        }
+
+        self.status_bar
+            .update(cx, |bar, cx| bar.set_table_density(density, cx));
+        self.task_table.update(cx, |_, cx| cx.notify());
+    }
+
+    fn toggle_wrap_descriptions(&mut self, cx: &mut gpui::Context<Self>) {
+        let wrap = cx.update_global::<AppConfig, _>(|config, _cx| {
+            config.wrap_descriptions = !config.wrap_descriptions;
+            config.wrap_descriptions
+        });
+        if let Err(e) = cx.config().save() {
+            log::error!("[App] Failed to save description wrapping: {}", e);
+        }
+
+        self.task_table.update(cx, |_, cx| cx.notify());
+        self.toast_host.update(cx, |host, cx| {
+            let message = if wrap {
+                "Wrapping long descriptions"
+            } else {
+                "Truncating long descriptions"
+            };
+            host.push(ToastKind::Info, message, cx);
+        });
+    }
+
+    fn reset_to_default_filters(&mut self, cx: &mut gpui::Context<Self>) {
+        let defaults = cx.config().default_filters.filter_state();
+        let search = defaults.search_text.clone();
+        self.filter_state.update(cx, |state, cx| {
+            *state = defaults;
+            cx.notify();
+        });
+        self.task_table
+            .update(cx, |table, cx| table.set_search_input(search, cx));
+        self.warn_missing_default_project(cx);
+    }
+
+    pub(super) fn warn_missing_default_project(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(project) = cx.config().default_filters.project.clone() else {
+            return;
+        };
+        let prefix = format!("{}.", project);
+        let exists = self.tasks.iter().any(|task| {
+            task.project
+                .as_deref()
+                .is_some_and(|name| name == project || name.starts_with(&prefix))
+        });
+        if !exists {
+            log::warn!("[App] Default project '{}' has no tasks", project);
+            self.toast_host.update(cx, |host, cx| {
+                host.push(
+                    ToastKind::Warning,
+                    format!("Default project '{}' has no tasks", project),
+                    cx,
+                );
+            });
+        }
+    }
+
+    fn apply_task_action(&mut self, command: Command, cx: &mut gpui::Context<Self>) {
+        let Some(uuid) = self.task_table.read(cx).selected_task_uuid() else {
+            return;
+        };
+        if command == Command::DeleteTask && cx.config().confirm_destructive {
+            self.confirm_task_delete(uuid, cx);
+            return;
+        }
+
+        let priority = match command {
+            Command::SetPriorityHigh => Some("H"),
+            Command::SetPriorityMedium => Some("M"),
+            Command::SetPriorityLow => Some("L"),
+            Command::SetPriorityNone => Some(""),
+            _ => None,
+        };
+
+        let service = &mut self.task_service;
+        let result = match command {
+            Command::CompleteTask => service.complete_task(uuid).map(|_| "completed"),
+            Command::StartTask => service.start_task(uuid).map(|_| "started"),
+            Command::StopTask => service.stop_task(uuid).map(|_| "stopped"),
+            Command::DeleteTask => service.delete_task(uuid).map(|_| "deleted"),
+            _ => match priority {
+                Some(priority) => service
+                    .update_task(
+                        uuid,
+                        None,
+                        None,
+                        Some(priority.to_string()),
+                        None,
+                        None,
+                        None,
+                    )
+                    .map(|_| "priority updated"),
+                None => return,
+            },
+        };
+
+        self.finish_task_mutation(uuid, result, cx);
+    }
+
+    fn confirm_task_delete(&mut self, uuid: uuid::Uuid, cx: &mut gpui::Context<Self>) {
+        let app = cx.entity().downgrade();
+        let delete = ToastAction::new("Delete", move |_window, cx| {
+            app.update(cx, |app, cx| {
+                let result = app.task_service.delete_task(uuid).map(|_| "deleted");
+                app.finish_task_mutation(uuid, result, cx);
+            })
+            .ok();
+        });
+        let message = format!("Delete {}?", self.task_label(uuid));
+        self.toast_host.update(cx, |host, cx| {
+            host.push_confirm(message, delete, cx);
+        });
+    }
+
+    pub(super) fn finish_task_action(
+        &mut self,
+        result: TaskResult<&'static str>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (kind, message) = match result {
+            Ok(message) => {
+                self.reload_tasks(cx);
+                (ToastKind::Success, message.to_string())
+            }
+            Err(e) => {
+                log::error!("[App] Task action failed: {}", e);
+                (ToastKind::Error, e.to_string())
+            }
+        };
+
+        self.toast_host.update(cx, |host, cx| {
+            host.push(kind, message, cx);
+        });
+    }
+
+    pub(super) fn finish_task_mutation(
+        &mut self,
+        uuid: uuid::Uuid,
+        result: TaskResult<&'static str>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        match result {
+            Ok(action) => {
+                self.show_task_toast(ToastKind::Success, uuid, action, cx);
+                self.reload_tasks(cx);
+            }
+            Err(e) => {
+                log::error!("[App] Task action failed: {}", e);
+                self.show_task_toast(ToastKind::Error, uuid, &format!("failed: {}", e), cx);
+            }
+        }
+    }
+
+    pub(super) fn show_task_toast(
+        &mut self,
+        kind: ToastKind,
+        uuid: uuid::Uuid,
+        action: &str,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let message = format!("{} {}", self.task_label(uuid), action);
+        self.toast_host.update(cx, |host, cx| {
+            host.push(kind, message, cx);
+        });
+    }
+
+    fn task_label(&self, uuid: uuid::Uuid) -> String {
+        let working_id = self
+            .tasks
+            .iter()
+            .find(|task| task.uuid == uuid)
+            .and_then(|task| task.working_id.or(task.id));
+        match working_id {
+            Some(id) => format!("Task #{}", id),
+            None => format!("Task {}", &uuid.to_string()[..8]),
+        }
+    }
+
+    pub(super) fn confirm_maintenance(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.maintenance_in_flight {
+            self.toast_host.update(cx, |host, cx| {
+                host.push(ToastKind::Info, "Maintenance is already running", cx);
+            });
+            return;
+        }
+
+        let app = cx.entity().downgrade();
+        let run = ToastAction::new("Renumber", move |_window, cx| {
+            app.update(cx, |app, cx| app.run_maintenance(cx)).ok();
+        });
+        self.toast_host.update(cx, |host, cx| {
+            host.push_confirm(
+                "Expire old tasks and renumber the working set? Task IDs will change.",
+                run,
+                cx,
+            );
+        });
+    }
+
+    fn run_maintenance(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.maintenance_in_flight {
+            return;
+        }
+        self.maintenance_in_flight = true;
+        let before = self.task_service.working_set_size().unwrap_or_default();
+        let worker = self.task_service.open_worker();
+        cx.spawn(async move |app, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut service = worker?;
+                    service.expire_tasks()?;
+                    service.rebuild_working_set(true)?;
+                    service.working_set_size()
+                })
+                .await;
+            app.update(cx, |app, cx| app.finish_maintenance(before, result, cx))
+                .ok();
+        })
+        .detach();
+    }
+
+    fn finish_maintenance(
+        &mut self,
+        before: usize,
+        result: TaskResult<usize>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.maintenance_in_flight = false;
+        self.task_service.mark_written();
+        let (kind, message) = match result {
+            Ok(after) => {
+                self.reload_tasks(cx);
+                let open_detail = self
+                    .task_detail_modal
+                    .read(cx)
+                    .detail()
+                    .map(|d| d.identity.uuid);
+                if let Some(task_id) = open_detail {
+                    self.refresh_task_detail(task_id, cx);
+                }
+                (
+                    ToastKind::Success,
+                    format!("Working set renumbered: {} → {} IDs", before, after),
+                )
+            }
+            Err(e) => {
+                log::error!("[App] Maintenance failed: {}", e);
+                (ToastKind::Error, format!("Maintenance failed: {}", e))
+            }
+        };
+
+        self.toast_host.update(cx, |host, cx| {
+            host.push(kind, message, cx);
+        });
+    }
+
+    pub(super) fn snooze_task(
+        &mut self,
+        uuid: uuid::Uuid,
+        value: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let previous_wait = self
+            .tasks
+            .iter()
+            .find(|task| task.uuid == uuid)
+            .and_then(|task| task.wait);
+        let result = task::parse_date_expression(&value)
+            .ok_or_else(|| TaskError::InvalidWait(value.clone()))
+            .and_then(|wait| {
+                self.task_service
+                    .update_task(uuid, None, None, None, None, None, Some(Some(wait)))
+                    .map(|_| wait)
+            });
+
+        let wait = match result {
+            Ok(wait) => wait,
+            Err(e) => {
+                log::error!("[App] Failed to snooze task: {}", e);
+                self.toast_host.update(cx, |host, cx| {
+                    host.push(ToastKind::Error, e.to_string(), cx);
+                });
+                return;
+            }
+        };
+
+        self.reload_tasks(cx);
+        let app = cx.entity().downgrade();
+        let undo = ToastAction::new("Undo", move |_window, cx| {
+            app.update(cx, |app, cx| {
+                let result = app
+                    .task_service
+                    .update_task(uuid, None, None, None, None, None, Some(previous_wait))
+                    .map(|_| "snooze undone");
+                app.finish_task_mutation(uuid, result, cx);
+            })
+            .ok();
+        });
+        let message = format!(
+            "Snoozed until {}",
+            wait.with_timezone(&chrono::Local).format(DATE_FORMAT)
+        );
+        self.toast_host.update(cx, |host, cx| {
+            host.push_with_action(ToastKind::Success, message, undo, cx);
+        });
+    }
+
+    pub(super) fn request_bulk_action(
+        &mut self,
+        action: BulkAction,
+        uuids: Vec<uuid::Uuid>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if action != BulkAction::Delete || !cx.config().confirm_destructive {
+            self.apply_bulk_action(action, uuids, cx);
+            return;
+        }
+
+        let message = format!(
+            "Delete {} {}?",
+            uuids.len(),
+            if uuids.len() == 1 { "task" } else { "tasks" }
+        );
+        let app = cx.entity().downgrade();
+        let delete = ToastAction::new("Delete", move |_window, cx| {
+            app.update(cx, |app, cx| {
+                app.apply_bulk_action(BulkAction::Delete, uuids.clone(), cx)
+            })
+            .ok();
+        });
+        self.toast_host.update(cx, |host, cx| {
+            host.push_confirm(message, delete, cx);
+        });
+    }
+
+    fn apply_bulk_action(
+        &mut self,
+        action: BulkAction,
+        uuids: Vec<uuid::Uuid>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.task_table.update(cx, |table, cx| {
+            table.set_bulk_busy(true, cx);
+        });
+
+        let worker = self.task_service.open_worker();
+        cx.spawn(async move |app, cx| {
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut service = worker?;
+                    match action {
+                        BulkAction::Complete => service.bulk_complete(&uuids),
+                        BulkAction::Delete => service.bulk_delete(&uuids),
+                        BulkAction::SetProject(project) => {
+                            service.bulk_set_project(&uuids, project)
+                        }
+                        BulkAction::AddTag(tag) => service.bulk_add_tag(&uuids, &tag),
+                    }
+                })
+                .await;
+            app.update(cx, |app, cx| app.finish_bulk_action(result, cx))
+                .ok();
+        })
+        .detach();
+    }
+
+    fn finish_bulk_action(
+        &mut self,
+        result: TaskResult<task::BulkResult>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.task_service.mark_written();
+        self.task_table.update(cx, |table, cx| {
+            table.set_bulk_busy(false, cx);
+            table.clear_marks(cx);
+        });
+
+        let (kind, message) = match result {
+            Ok(result) => {
+                if result.succeeded > 0 {
+                    self.reload_tasks(cx);
+                }
+                if result.failed > 0 {
+                    (
+                        ToastKind::Error,
+                        format!("{} updated, {} failed", result.succeeded, result.failed),
+                    )
+                } else {
+                    (
+                        ToastKind::Success,
+                        format!("Updated {} tasks", result.succeeded),
+                    )
+                }
+            }
+            Err(e) => {
+                log::error!("[App] Bulk action failed: {}", e);
+                (ToastKind::Error, e.to_string())
+            }
+        };
+
+        self.toast_host.update(cx, |host, cx| {
+            host.push(kind, message, cx);
+        });
+    }
+
+    pub(super) fn export_tasks(&mut self, path: PathBuf, cx: &mut gpui::Context<Self>) {
+        let table = self.task_table.read(cx);
+        let count = table.visible_tasks().len();
+        let contents = match ExportFormat::from_path(&path) {
+            ExportFormat::Csv => table.visible_rows_csv(cx),
+            ExportFormat::Json => {
+                let tasks = table.visible_tasks().to_vec();
+                let annotations: HashMap<uuid::Uuid, Vec<TaskAnnotation>> =
+                    match self.task_service.get_all_tasks() {
+                        Ok(all) => all
+                            .into_iter()
+                            .filter(|task| !task.annotations.is_empty())
+                            .map(|task| (task.uuid, task.annotations))
+                            .collect(),
+                        Err(e) => {
+                            log::warn!("[App] Export without annotations: {}", e);
+                            HashMap::new()
+                        }
+                    };
+                export::tasks_to_json(&tasks, &annotations)
+            }
+        };
+
+        let (kind, message) = match export::write_export(&path, &contents) {
+            Ok(()) => (
+                ToastKind::Success,
+                format!("Exported {} tasks to {}", count, path.display()),
+            ),
+            Err(e) => (ToastKind::Error, e.to_string()),
+        };
+
+        self.toast_host.update(cx, |host, cx| {
+            host.push(kind, message, cx);
+        });
+    }
+
+    pub(super) fn import_tasks(&mut self, path: PathBuf, cx: &mut gpui::Context<Self>) {
+        let worker = self.task_service.open_worker();
+        cx.spawn(async move |app, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move {
+                        let mut service = worker?;
+                        let contents = std::fs::read_to_string(&path)
+                            .map_err(|e| TaskError::Import(format!("{}: {}", path.display(), e)))?;
+                        let (tasks, invalid) = import::parse_taskwarrior_json(&contents)?;
+                        service.import_tasks(&tasks).map(|mut summary| {
+                            summary.errors += invalid;
+                            summary
+                        })
+                    }
+                })
+                .await;
+            app.update(cx, |app, cx| app.finish_import(path, result, cx))
+                .ok();
+        })
+        .detach();
+    }
+
+    fn finish_import(
+        &mut self,
+        path: PathBuf,
+        result: TaskResult<task::ImportSummary>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.task_service.mark_written();
+        match result {
+            Ok(summary) => {
+                if summary.imported > 0 {
+                    self.reload_tasks(cx);
+                }
+                self.import_summary = Some((summary, path));
+                cx.notify();
+            }
+            Err(e) => {
+                log::error!("[App] Import failed: {}", e);
+                self.toast_host.update(cx, |host, cx| {
+                    host.push(ToastKind::Error, e.to_string(), cx);
+                });
+            }
+        }
+    }
+
+    pub(super) fn rename_project(
+        &mut self,
+        from: String,
+        to: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let to = to.trim().to_string();
+        match self.task_service.rename_project(&from, &to) {
+            Ok(count) => {
+                self.filter_state.update(cx, |state, cx| {
+                    let renamed = state
+                        .selected_project
+                        .as_deref()
+                        .and_then(|project| task::renamed_project(project, &from, &to));
+                    if renamed.is_some() {
+                        state.selected_project = renamed;
+                        cx.notify();
+                    }
+                });
+                self.reload_tasks(cx);
+                self.toast_host.update(cx, |host, cx| {
+                    host.push(
+                        ToastKind::Success,
+                        format!(
+                            "Renamed {} to {} ({} {})",
+                            from,
+                            to,
+                            count,
+                            if count == 1 { "task" } else { "tasks" }
+                        ),
+                        cx,
+                    );
+                });
+            }
+            Err(e) => {
+                log::error!("[App] Failed to rename project {}: {}", from, e);
+                self.toast_host.update(cx, |host, cx| {
+                    host.push(ToastKind::Error, e.to_string(), cx);
+                });
+            }
+        }
+    }
+
+    fn jump_task_detail(&self, section: DetailSection, cx: &mut gpui::Context<Self>) {
+        self.task_detail_modal.update(cx, |modal, cx| {
+            modal.jump_to_section(section, cx);
+        });
+    }
 }
 
 impl CommandDispatcher for App {
@@ -26,7 +691,8 @@ impl CommandDispatcher for App {
             }
             Command::FocusSearch => false,
             Command::FocusTable => {
-                self.focus_target = match self.focus_target {
+                let target = match self.focus_target {
+                    _ if cx.config().sidebar.collapsed => FocusTarget::Table,
                     FocusTarget::Table => {
                         self.sidebar.update(cx, |sidebar, cx| {
                             sidebar.set_section(crate::view::sidebar::SidebarSection::Projects, cx);
@@ -41,11 +707,13 @@ impl CommandDispatcher for App {
                     }
                     _ => FocusTarget::Table,
                 };
+                self.set_focus_target(target, cx);
                 cx.notify();
                 true
             }
             Command::FocusSidebar => {
-                self.focus_target = match self.focus_target {
+                let target = match self.focus_target {
+                    _ if cx.config().sidebar.collapsed => FocusTarget::Table,
                     FocusTarget::Table => {
                         self.sidebar.update(cx, |sidebar, cx| {
                             sidebar.set_section(crate::view::sidebar::SidebarSection::Tags, cx);
@@ -60,11 +728,17 @@ impl CommandDispatcher for App {
                     }
                     _ => FocusTarget::Table,
                 };
+                self.set_focus_target(target, cx);
                 cx.notify();
                 true
             }
+            Command::FocusSidebarProjects | Command::FocusSidebarTags
+                if cx.config().sidebar.collapsed =>
+            {
+                false
+            }
             Command::FocusSidebarProjects => {
-                self.focus_target = FocusTarget::SidebarProjects;
+                self.set_focus_target(FocusTarget::SidebarProjects, cx);
                 self.sidebar.update(cx, |sidebar, cx| {
                     sidebar.set_section(crate::view::sidebar::SidebarSection::Projects, cx);
                 });
@@ -72,7 +746,7 @@ impl CommandDispatcher for App {
                 true
             }
             Command::FocusSidebarTags => {
-                self.focus_target = FocusTarget::SidebarTags;
+                self.set_focus_target(FocusTarget::SidebarTags, cx);
                 self.sidebar.update(cx, |sidebar, cx| {
                     sidebar.set_section(crate::view::sidebar::SidebarSection::Tags, cx);
                 });
@@ -123,6 +797,59 @@ impl CommandDispatcher for App {
                 self.scroll_task_detail(2, cx);
                 true
             }
+            Command::CopyTaskUuid
+            | Command::CopyTaskDescription
+            | Command::CopyTaskFormatted
+            | Command::CopyTaskUuidAndDescription
+            | Command::CopyTaskChecklist
+            | Command::CopyTaskAsMarkdown => {
+                self.copy_task_detail(command, cx);
+                true
+            }
+            Command::CompleteTask
+            | Command::StartTask
+            | Command::StopTask
+            | Command::DeleteTask
+            | Command::SetPriorityHigh
+            | Command::SetPriorityMedium
+            | Command::SetPriorityLow
+            | Command::SetPriorityNone => {
+                self.apply_task_action(command, cx);
+                true
+            }
+            Command::TogglePin => {
+                self.toggle_pin_selected(cx);
+                true
+            }
+            Command::ModalJumpOverview => {
+                self.jump_task_detail(DetailSection::Overview, cx);
+                true
+            }
+            Command::ModalJumpTags => {
+                self.jump_task_detail(DetailSection::Tags, cx);
+                true
+            }
+            Command::ModalJumpDependencies => {
+                self.jump_task_detail(DetailSection::Dependencies, cx);
+                true
+            }
+            Command::ModalJumpAnnotations => {
+                self.jump_task_detail(DetailSection::Annotations, cx);
+                true
+            }
+            Command::ModalJumpDates => {
+                self.jump_task_detail(DetailSection::Dates, cx);
+                true
+            }
+            Command::ModalJumpMetadata => {
+                self.jump_task_detail(DetailSection::Metadata, cx);
+                true
+            }
+            Command::SidebarExpandProjects | Command::SidebarExpandTags => {
+                self.sidebar
+                    .update(cx, |sidebar, cx| sidebar.dispatch(command, cx));
+                true
+            }
             Command::ExpandProject | Command::CollapseProject => {
                 match self.focus_target {
                     FocusTarget::SidebarProjects => {
@@ -136,7 +863,12 @@ impl CommandDispatcher for App {
                 }
                 true
             }
-            Command::NextPage | Command::PrevPage | Command::ClearSelection => {
+            Command::NextPage
+            | Command::PrevPage
+            | Command::ClearSelection
+            | Command::ToggleRowExpansion
+            | Command::ToggleTaskMark
+            | Command::OpenContextMenu => {
                 self.task_table
                     .update(cx, |table, cx| table.dispatch(command, cx));
                 true
@@ -170,6 +902,51 @@ impl CommandDispatcher for App {
                 });
                 true
             }
+            Command::TodayFocus => {
+                self.filter_state.update(cx, |state, cx| {
+                    state.toggle_preset(FilterPreset::TodayFocus);
+                    cx.notify();
+                });
+                true
+            }
+            Command::ResetToDefaultFilters => {
+                self.reset_to_default_filters(cx);
+                true
+            }
+            Command::ToggleSidebar => {
+                self.toggle_sidebar(cx);
+                true
+            }
+            Command::CycleTableDensity => {
+                self.cycle_table_density(cx);
+                true
+            }
+            Command::ToggleWrapDescriptions => {
+                self.toggle_wrap_descriptions(cx);
+                true
+            }
+            Command::RunMaintenance => {
+                self.confirm_maintenance(cx);
+                true
+            }
+            Command::TogglePreviewPane => {
+                self.toggle_preview_pane(cx);
+                true
+            }
+            Command::ToggleAnnotationSearch => {
+                self.filter_state.update(cx, |state, cx| {
+                    state.toggle_search_annotations();
+                    cx.notify();
+                });
+                true
+            }
+            Command::ToggleTagsMode => {
+                self.filter_state.update(cx, |state, cx| {
+                    state.toggle_tags_mode();
+                    cx.notify();
+                });
+                true
+            }
             Command::ClearSearchAndDropdowns => {
                 self.filter_state.update(cx, |state, cx| {
                     state.clear_search_and_dropdowns();
@@ -17,6 +17,7 @@ pub enum Key {
     PageDown,
     Home,
     End,
+    Menu,
     F1,
     F2,
     F3,
@@ -48,6 +49,7 @@ impl Key {
             "pagedown" => Some(Self::PageDown),
             "home" => Some(Self::Home),
             "end" => Some(Self::End),
+            "menu" | "contextmenu" => Some(Self::Menu),
             "f1" => Some(Self::F1),
             "f2" => Some(Self::F2),
             "f3" => Some(Self::F3),
@@ -84,6 +86,7 @@ impl fmt::Display for Key {
             Self::PageDown => write!(f, "PageDown"),
             Self::Home => write!(f, "Home"),
             Self::End => write!(f, "End"),
+            Self::Menu => write!(f, "Menu"),
             Self::F1 => write!(f, "F1"),
             Self::F2 => write!(f, "F2"),
             Self::F3 => write!(f, "F3"),
@@ -134,6 +137,14 @@ impl Mods {
         }
     }
 
+    pub fn ctrl_shift() -> Self {
+        Self {
+            ctrl: true,
+            shift: true,
+            ..Default::default()
+        }
+    }
+
     pub fn platform() -> Self {
         Self {
             platform: true,
@@ -200,6 +211,7 @@ impl KeyChord {
             "pagedown" => Some(Key::PageDown),
             "home" => Some(Key::Home),
             "end" => Some(Key::End),
+            "menu" | "contextmenu" | "apps" => Some(Key::Menu),
             "f1" => Some(Key::F1),
             "f2" => Some(Key::F2),
             "f3" => Some(Key::F3),
@@ -8,10 +8,41 @@ pub enum Command {
     NextPage,
     PrevPage,
     ClearSelection,
+    ToggleRowExpansion,
+    ToggleTaskMark,
 
     // Actions
     OpenSelectedTask,
+    OpenContextMenu,
+    JumpToId,
     Sync,
+    RunMaintenance,
+    ToggleShortcuts,
+    TogglePalette,
+    TogglePreviewPane,
+    ToggleSidebar,
+    ExportTasks,
+    ImportTasks,
+    PaletteExecute,
+
+    // Task actions
+    QuickAdd,
+    CompleteTask,
+    StartTask,
+    StopTask,
+    DeleteTask,
+    AddAnnotation,
+    SetProject,
+    SetDue,
+    SnoozeTask,
+    SetRecurrence,
+    SetUntil,
+    SetDependencies,
+    SetPriorityHigh,
+    SetPriorityMedium,
+    SetPriorityLow,
+    SetPriorityNone,
+    TogglePin,
 
     // Focus
     FocusSearch,
@@ -27,6 +58,19 @@ pub enum Command {
     SaveModal,
     ModalScrollUp,
     ModalScrollDown,
+    ModalJumpOverview,
+    ModalJumpTags,
+    ModalJumpDependencies,
+    ModalJumpAnnotations,
+    ModalJumpDates,
+    ModalJumpMetadata,
+    ModalSearchAnnotations,
+    CopyTaskUuid,
+    CopyTaskDescription,
+    CopyTaskFormatted,
+    CopyTaskUuidAndDescription,
+    CopyTaskChecklist,
+    CopyTaskAsMarkdown,
 
     // Filter
     ApplySearch,
@@ -34,6 +78,12 @@ pub enum Command {
     ClearAllFilters,
     ClearProjectFilter,
     ClearTagFilter,
+    TodayFocus,
+    ResetToDefaultFilters,
+    CycleTableDensity,
+    ToggleWrapDescriptions,
+    ToggleTagsMode,
+    ToggleAnnotationSearch,
     ClearSearchAndDropdowns,
     FocusFilterNext,
     FocusFilterPrev,
@@ -44,14 +94,141 @@ pub enum Command {
     // Projects
     ExpandProject,
     CollapseProject,
+    ToggleProjectSearch,
+    RenameProject,
+    SidebarExpandProjects,
+    SidebarExpandTags,
 
     // Table Headers
     HeaderMoveNext,
     HeaderMovePrev,
     HeaderCycleSortOrder,
+    SetSecondarySortColumn,
+
+    // Calendar
+    OpenCalendar,
+    CalendarNextDay,
+    CalendarPrevDay,
+    CalendarNextWeek,
+    CalendarPrevWeek,
+    CalendarNextMonth,
+    CalendarPrevMonth,
+    CalendarSelectDay,
+
+    // Settings
+    OpenSettings,
+    SettingsIncrease,
+    SettingsDecrease,
 }
 
 impl Command {
+    pub const ALL: [Command; 100] = [
+        Self::SelectNextRow,
+        Self::SelectPrevRow,
+        Self::SelectFirstRow,
+        Self::SelectLastRow,
+        Self::NextPage,
+        Self::PrevPage,
+        Self::ClearSelection,
+        Self::ToggleRowExpansion,
+        Self::ToggleTaskMark,
+        Self::OpenSelectedTask,
+        Self::OpenContextMenu,
+        Self::JumpToId,
+        Self::Sync,
+        Self::RunMaintenance,
+        Self::ToggleShortcuts,
+        Self::TogglePalette,
+        Self::TogglePreviewPane,
+        Self::ToggleSidebar,
+        Self::ExportTasks,
+        Self::ImportTasks,
+        Self::PaletteExecute,
+        Self::QuickAdd,
+        Self::CompleteTask,
+        Self::StartTask,
+        Self::StopTask,
+        Self::DeleteTask,
+        Self::AddAnnotation,
+        Self::SetProject,
+        Self::SetDue,
+        Self::SnoozeTask,
+        Self::SetRecurrence,
+        Self::SetUntil,
+        Self::SetDependencies,
+        Self::SetPriorityHigh,
+        Self::SetPriorityMedium,
+        Self::SetPriorityLow,
+        Self::SetPriorityNone,
+        Self::TogglePin,
+        Self::FocusSearch,
+        Self::FocusTable,
+        Self::FocusTableHeaders,
+        Self::FocusSidebar,
+        Self::FocusSidebarProjects,
+        Self::FocusSidebarTags,
+        Self::BlurInput,
+        Self::CloseModal,
+        Self::SaveModal,
+        Self::ModalScrollUp,
+        Self::ModalScrollDown,
+        Self::ModalJumpOverview,
+        Self::ModalJumpTags,
+        Self::ModalJumpDependencies,
+        Self::ModalJumpAnnotations,
+        Self::ModalJumpDates,
+        Self::ModalJumpMetadata,
+        Self::ModalSearchAnnotations,
+        Self::CopyTaskUuid,
+        Self::CopyTaskDescription,
+        Self::CopyTaskFormatted,
+        Self::CopyTaskUuidAndDescription,
+        Self::CopyTaskChecklist,
+        Self::CopyTaskAsMarkdown,
+        Self::ApplySearch,
+        Self::ClearFilters,
+        Self::ClearAllFilters,
+        Self::ClearProjectFilter,
+        Self::ClearTagFilter,
+        Self::TodayFocus,
+        Self::ResetToDefaultFilters,
+        Self::CycleTableDensity,
+        Self::ToggleWrapDescriptions,
+        Self::ToggleTagsMode,
+        Self::ToggleAnnotationSearch,
+        Self::ClearSearchAndDropdowns,
+        Self::FocusFilterNext,
+        Self::FocusFilterPrev,
+        Self::ToggleDropdown,
+        Self::SelectNextOption,
+        Self::SelectPrevOption,
+        Self::ExpandProject,
+        Self::CollapseProject,
+        Self::ToggleProjectSearch,
+        Self::RenameProject,
+        Self::SidebarExpandProjects,
+        Self::SidebarExpandTags,
+        Self::HeaderMoveNext,
+        Self::HeaderMovePrev,
+        Self::HeaderCycleSortOrder,
+        Self::SetSecondarySortColumn,
+        Self::OpenCalendar,
+        Self::CalendarNextDay,
+        Self::CalendarPrevDay,
+        Self::CalendarNextWeek,
+        Self::CalendarPrevWeek,
+        Self::CalendarNextMonth,
+        Self::CalendarPrevMonth,
+        Self::CalendarSelectDay,
+        Self::OpenSettings,
+        Self::SettingsIncrease,
+        Self::SettingsDecrease,
+    ];
+
+    pub fn all() -> &'static [Command] {
+        &Self::ALL
+    }
+
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "SelectNextRow" => Some(Self::SelectNextRow),
@@ -61,8 +238,37 @@ impl Command {
             "NextPage" => Some(Self::NextPage),
             "PrevPage" => Some(Self::PrevPage),
             "ClearSelection" => Some(Self::ClearSelection),
+            "ToggleRowExpansion" => Some(Self::ToggleRowExpansion),
+            "ToggleTaskMark" => Some(Self::ToggleTaskMark),
             "OpenSelectedTask" => Some(Self::OpenSelectedTask),
+            "OpenContextMenu" => Some(Self::OpenContextMenu),
+            "JumpToId" => Some(Self::JumpToId),
+            "ToggleShortcuts" => Some(Self::ToggleShortcuts),
+            "TogglePalette" => Some(Self::TogglePalette),
+            "TogglePreviewPane" => Some(Self::TogglePreviewPane),
+            "ToggleSidebar" => Some(Self::ToggleSidebar),
+            "ExportTasks" => Some(Self::ExportTasks),
+            "ImportTasks" => Some(Self::ImportTasks),
+            "PaletteExecute" => Some(Self::PaletteExecute),
+            "CompleteTask" => Some(Self::CompleteTask),
+            "StartTask" => Some(Self::StartTask),
+            "StopTask" => Some(Self::StopTask),
+            "DeleteTask" => Some(Self::DeleteTask),
+            "QuickAdd" => Some(Self::QuickAdd),
+            "AddAnnotation" => Some(Self::AddAnnotation),
+            "SetProject" => Some(Self::SetProject),
+            "SetDue" => Some(Self::SetDue),
+            "SnoozeTask" => Some(Self::SnoozeTask),
+            "SetRecurrence" => Some(Self::SetRecurrence),
+            "SetUntil" => Some(Self::SetUntil),
+            "SetDependencies" => Some(Self::SetDependencies),
+            "SetPriorityHigh" => Some(Self::SetPriorityHigh),
+            "SetPriorityMedium" => Some(Self::SetPriorityMedium),
+            "SetPriorityLow" => Some(Self::SetPriorityLow),
+            "SetPriorityNone" => Some(Self::SetPriorityNone),
+            "TogglePin" => Some(Self::TogglePin),
             "Sync" => Some(Self::Sync),
+            "RunMaintenance" => Some(Self::RunMaintenance),
             "FocusSearch" => Some(Self::FocusSearch),
             "FocusTable" => Some(Self::FocusTable),
             "FocusTableHeaders" => Some(Self::FocusTableHeaders),
@@ -74,11 +280,30 @@ impl Command {
             "SaveModal" => Some(Self::SaveModal),
             "ModalScrollUp" => Some(Self::ModalScrollUp),
             "ModalScrollDown" => Some(Self::ModalScrollDown),
+            "ModalJumpOverview" => Some(Self::ModalJumpOverview),
+            "ModalJumpTags" => Some(Self::ModalJumpTags),
+            "ModalJumpDependencies" => Some(Self::ModalJumpDependencies),
+            "ModalJumpAnnotations" => Some(Self::ModalJumpAnnotations),
+            "ModalJumpDates" => Some(Self::ModalJumpDates),
+            "ModalJumpMetadata" => Some(Self::ModalJumpMetadata),
+            "ModalSearchAnnotations" => Some(Self::ModalSearchAnnotations),
+            "CopyTaskUuid" => Some(Self::CopyTaskUuid),
+            "CopyTaskDescription" => Some(Self::CopyTaskDescription),
+            "CopyTaskFormatted" => Some(Self::CopyTaskFormatted),
+            "CopyTaskUuidAndDescription" => Some(Self::CopyTaskUuidAndDescription),
+            "CopyTaskChecklist" => Some(Self::CopyTaskChecklist),
+            "CopyTaskAsMarkdown" => Some(Self::CopyTaskAsMarkdown),
             "ApplySearch" => Some(Self::ApplySearch),
             "ClearFilters" => Some(Self::ClearFilters),
             "ClearAllFilters" => Some(Self::ClearAllFilters),
             "ClearProjectFilter" => Some(Self::ClearProjectFilter),
             "ClearTagFilter" => Some(Self::ClearTagFilter),
+            "TodayFocus" => Some(Self::TodayFocus),
+            "ResetToDefaultFilters" => Some(Self::ResetToDefaultFilters),
+            "CycleTableDensity" => Some(Self::CycleTableDensity),
+            "ToggleWrapDescriptions" => Some(Self::ToggleWrapDescriptions),
+            "ToggleTagsMode" => Some(Self::ToggleTagsMode),
+            "ToggleAnnotationSearch" => Some(Self::ToggleAnnotationSearch),
             "ClearSearchAndDropdowns" => Some(Self::ClearSearchAndDropdowns),
             "FocusFilterNext" => Some(Self::FocusFilterNext),
             "FocusFilterPrev" => Some(Self::FocusFilterPrev),
@@ -87,9 +312,25 @@ impl Command {
             "SelectPrevOption" => Some(Self::SelectPrevOption),
             "ExpandProject" => Some(Self::ExpandProject),
             "CollapseProject" => Some(Self::CollapseProject),
+            "ToggleProjectSearch" => Some(Self::ToggleProjectSearch),
+            "RenameProject" => Some(Self::RenameProject),
+            "SidebarExpandProjects" => Some(Self::SidebarExpandProjects),
+            "SidebarExpandTags" => Some(Self::SidebarExpandTags),
             "HeaderMoveNext" => Some(Self::HeaderMoveNext),
             "HeaderMovePrev" => Some(Self::HeaderMovePrev),
             "HeaderCycleSortOrder" => Some(Self::HeaderCycleSortOrder),
+            "SetSecondarySortColumn" => Some(Self::SetSecondarySortColumn),
+            "OpenCalendar" => Some(Self::OpenCalendar),
+            "CalendarNextDay" => Some(Self::CalendarNextDay),
+            "CalendarPrevDay" => Some(Self::CalendarPrevDay),
+            "CalendarNextWeek" => Some(Self::CalendarNextWeek),
+            "CalendarPrevWeek" => Some(Self::CalendarPrevWeek),
+            "CalendarNextMonth" => Some(Self::CalendarNextMonth),
+            "CalendarPrevMonth" => Some(Self::CalendarPrevMonth),
+            "CalendarSelectDay" => Some(Self::CalendarSelectDay),
+            "OpenSettings" => Some(Self::OpenSettings),
+            "SettingsIncrease" => Some(Self::SettingsIncrease),
+            "SettingsDecrease" => Some(Self::SettingsDecrease),
             _ => None,
         }
     }
@@ -103,8 +344,37 @@ impl Command {
             Self::NextPage => "NextPage",
             Self::PrevPage => "PrevPage",
             Self::ClearSelection => "ClearSelection",
+            Self::ToggleRowExpansion => "ToggleRowExpansion",
+            Self::ToggleTaskMark => "ToggleTaskMark",
             Self::OpenSelectedTask => "OpenSelectedTask",
+            Self::OpenContextMenu => "OpenContextMenu",
+            Self::JumpToId => "JumpToId",
+            Self::ToggleShortcuts => "ToggleShortcuts",
+            Self::TogglePalette => "TogglePalette",
+            Self::TogglePreviewPane => "TogglePreviewPane",
+            Self::ToggleSidebar => "ToggleSidebar",
+            Self::ExportTasks => "ExportTasks",
+            Self::ImportTasks => "ImportTasks",
+            Self::PaletteExecute => "PaletteExecute",
+            Self::CompleteTask => "CompleteTask",
+            Self::StartTask => "StartTask",
+            Self::StopTask => "StopTask",
+            Self::DeleteTask => "DeleteTask",
+            Self::QuickAdd => "QuickAdd",
+            Self::AddAnnotation => "AddAnnotation",
+            Self::SetProject => "SetProject",
+            Self::SetDue => "SetDue",
+            Self::SnoozeTask => "SnoozeTask",
+            Self::SetRecurrence => "SetRecurrence",
+            Self::SetUntil => "SetUntil",
+            Self::SetDependencies => "SetDependencies",
+            Self::SetPriorityHigh => "SetPriorityHigh",
+            Self::SetPriorityMedium => "SetPriorityMedium",
+            Self::SetPriorityLow => "SetPriorityLow",
+            Self::SetPriorityNone => "SetPriorityNone",
+            Self::TogglePin => "TogglePin",
             Self::Sync => "Sync",
+            Self::RunMaintenance => "RunMaintenance",
             Self::FocusSearch => "FocusSearch",
             Self::FocusTable => "FocusTable",
             Self::FocusTableHeaders => "FocusTableHeaders",
@@ -116,11 +386,30 @@ impl Command {
             Self::SaveModal => "SaveModal",
             Self::ModalScrollUp => "ModalScrollUp",
             Self::ModalScrollDown => "ModalScrollDown",
+            Self::ModalJumpOverview => "ModalJumpOverview",
+            Self::ModalJumpTags => "ModalJumpTags",
+            Self::ModalJumpDependencies => "ModalJumpDependencies",
+            Self::ModalJumpAnnotations => "ModalJumpAnnotations",
+            Self::ModalJumpDates => "ModalJumpDates",
+            Self::ModalJumpMetadata => "ModalJumpMetadata",
+            Self::ModalSearchAnnotations => "ModalSearchAnnotations",
+            Self::CopyTaskUuid => "CopyTaskUuid",
+            Self::CopyTaskDescription => "CopyTaskDescription",
+            Self::CopyTaskFormatted => "CopyTaskFormatted",
+            Self::CopyTaskUuidAndDescription => "CopyTaskUuidAndDescription",
+            Self::CopyTaskChecklist => "CopyTaskChecklist",
+            Self::CopyTaskAsMarkdown => "CopyTaskAsMarkdown",
             Self::ApplySearch => "ApplySearch",
             Self::ClearFilters => "ClearFilters",
             Self::ClearAllFilters => "ClearAllFilters",
             Self::ClearProjectFilter => "ClearProjectFilter",
             Self::ClearTagFilter => "ClearTagFilter",
+            Self::TodayFocus => "TodayFocus",
+            Self::ResetToDefaultFilters => "ResetToDefaultFilters",
+            Self::CycleTableDensity => "CycleTableDensity",
+            Self::ToggleWrapDescriptions => "ToggleWrapDescriptions",
+            Self::ToggleTagsMode => "ToggleTagsMode",
+            Self::ToggleAnnotationSearch => "ToggleAnnotationSearch",
             Self::ClearSearchAndDropdowns => "ClearSearchAndDropdowns",
             Self::FocusFilterNext => "FocusFilterNext",
             Self::FocusFilterPrev => "FocusFilterPrev",
@@ -129,9 +418,57 @@ impl Command {
             Self::SelectPrevOption => "SelectPrevOption",
             Self::ExpandProject => "ExpandProject",
             Self::CollapseProject => "CollapseProject",
+            Self::ToggleProjectSearch => "ToggleProjectSearch",
+            Self::RenameProject => "RenameProject",
+            Self::SidebarExpandProjects => "SidebarExpandProjects",
+            Self::SidebarExpandTags => "SidebarExpandTags",
             Self::HeaderMoveNext => "HeaderMoveNext",
             Self::HeaderMovePrev => "HeaderMovePrev",
             Self::HeaderCycleSortOrder => "HeaderCycleSortOrder",
+            Self::SetSecondarySortColumn => "SetSecondarySortColumn",
+            Self::OpenCalendar => "OpenCalendar",
+            Self::CalendarNextDay => "CalendarNextDay",
+            Self::CalendarPrevDay => "CalendarPrevDay",
+            Self::CalendarNextWeek => "CalendarNextWeek",
+            Self::CalendarPrevWeek => "CalendarPrevWeek",
+            Self::CalendarNextMonth => "CalendarNextMonth",
+            Self::CalendarPrevMonth => "CalendarPrevMonth",
+            Self::CalendarSelectDay => "CalendarSelectDay",
+            Self::OpenSettings => "OpenSettings",
+            Self::SettingsIncrease => "SettingsIncrease",
+            Self::SettingsDecrease => "SettingsDecrease",
+        }
+    }
+
+    pub fn display_name(&self) -> String {
+        let mut name = String::new();
+        for (i, ch) in self.as_str().chars().enumerate() {
+            if i > 0 && ch.is_uppercase() {
+                name.push(' ');
+                name.extend(ch.to_lowercase());
+            } else {
+                name.push(ch);
+            }
+        }
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_name_splits_words() {
+        assert_eq!(Command::SelectNextRow.display_name(), "Select next row");
+        assert_eq!(Command::Sync.display_name(), "Sync");
+        assert_eq!(Command::JumpToId.display_name(), "Jump to id");
+    }
+
+    #[test]
+    fn test_all_round_trips_through_str() {
+        for command in Command::all() {
+            assert_eq!(Command::from_str(command.as_str()), Some(*command));
         }
     }
 }
@@ -8,9 +8,30 @@ pub enum ContextId {
     Modal,
     FilterBar,
     TextInput,
+    Palette,
+    Calendar,
+    Settings,
 }
 
 impl ContextId {
+    pub const ALL: [ContextId; 11] = [
+        Self::Global,
+        Self::Table,
+        Self::TableHeaders,
+        Self::SidebarProjects,
+        Self::SidebarTags,
+        Self::Modal,
+        Self::FilterBar,
+        Self::TextInput,
+        Self::Palette,
+        Self::Calendar,
+        Self::Settings,
+    ];
+
+    pub fn index(&self) -> usize {
+        Self::ALL.iter().position(|c| c == self).unwrap_or(0)
+    }
+
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "global" | "Global" => Some(Self::Global),
@@ -21,6 +42,9 @@ impl ContextId {
             "modal" | "Modal" => Some(Self::Modal),
             "filterbar" | "FilterBar" => Some(Self::FilterBar),
             "textinput" | "TextInput" => Some(Self::TextInput),
+            "palette" | "Palette" => Some(Self::Palette),
+            "calendar" | "Calendar" => Some(Self::Calendar),
+            "settings" | "Settings" => Some(Self::Settings),
             _ => None,
         }
     }
@@ -35,6 +59,9 @@ impl ContextId {
             Self::Modal => "Modal",
             Self::FilterBar => "FilterBar",
             Self::TextInput => "TextInput",
+            Self::Palette => "Palette",
+            Self::Calendar => "Calendar",
+            Self::Settings => "Settings",
         }
     }
 }
@@ -9,6 +9,11 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Char('r'), Mods::ctrl()),
         Command::Sync,
     );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('r'), Mods::ctrl_shift()),
+        Command::RunMaintenance,
+    );
     layer.bind(
         ContextId::Global,
         KeyChord::new(Key::Char('f'), Mods::ctrl()),
@@ -44,11 +49,85 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Char('t'), Mods::ctrl()),
         Command::ClearTagFilter,
     );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('t'), Mods::ctrl_shift()),
+        Command::TodayFocus,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('x'), Mods::ctrl_shift()),
+        Command::ResetToDefaultFilters,
+    );
     layer.bind(
         ContextId::Global,
         KeyChord::new(Key::Char('x'), Mods::ctrl()),
         Command::ClearSearchAndDropdowns,
     );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('a'), Mods::ctrl_shift()),
+        Command::ToggleAnnotationSearch,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('p'), Mods::ctrl_shift()),
+        Command::TogglePalette,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('k'), Mods::ctrl_shift()),
+        Command::OpenCalendar,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('b'), Mods::ctrl()),
+        Command::ToggleSidebar,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('d'), Mods::ctrl_shift()),
+        Command::CycleTableDensity,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('w'), Mods::ctrl_shift()),
+        Command::ToggleWrapDescriptions,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('e'), Mods::ctrl()),
+        Command::ExportTasks,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char('i'), Mods::ctrl()),
+        Command::ImportTasks,
+    );
+    layer.bind(
+        ContextId::Global,
+        KeyChord::new(Key::Char(','), Mods::ctrl()),
+        Command::OpenSettings,
+    );
+
+    for context in [
+        ContextId::Table,
+        ContextId::TableHeaders,
+        ContextId::SidebarProjects,
+        ContextId::SidebarTags,
+        ContextId::FilterBar,
+    ] {
+        layer.bind(
+            context,
+            KeyChord::new(Key::Char('?'), Mods::none()),
+            Command::ToggleShortcuts,
+        );
+        layer.bind(
+            context,
+            KeyChord::new(Key::Char('?'), Mods::shift()),
+            Command::ToggleShortcuts,
+        );
+    }
 
     // Table navigation
     layer.bind(
@@ -86,6 +165,81 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Char('g'), Mods::none()),
         Command::SelectFirstRow,
     );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('e'), Mods::none()),
+        Command::ToggleRowExpansion,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Space, Mods::none()),
+        Command::ToggleTaskMark,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('p'), Mods::none()),
+        Command::TogglePreviewPane,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char(','), Mods::none()),
+        Command::OpenSettings,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('.'), Mods::none()),
+        Command::OpenContextMenu,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('z'), Mods::none()),
+        Command::SnoozeTask,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('r'), Mods::none()),
+        Command::SetRecurrence,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('b'), Mods::none()),
+        Command::SetDependencies,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('*'), Mods::none()),
+        Command::TogglePin,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('*'), Mods::shift()),
+        Command::TogglePin,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('y'), Mods::none()),
+        Command::CopyTaskDescription,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('y'), Mods::shift()),
+        Command::CopyTaskUuidAndDescription,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('y'), Mods::ctrl()),
+        Command::CopyTaskChecklist,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Menu, Mods::none()),
+        Command::OpenContextMenu,
+    );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('a'), Mods::none()),
+        Command::QuickAdd,
+    );
     layer.bind(
         ContextId::Table,
         KeyChord::new(Key::Char('g'), Mods::shift()),
@@ -131,6 +285,11 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Enter, Mods::none()),
         Command::OpenSelectedTask,
     );
+    layer.bind(
+        ContextId::Table,
+        KeyChord::new(Key::Char('/'), Mods::none()),
+        Command::JumpToId,
+    );
     layer.bind(
         ContextId::Table,
         KeyChord::new(Key::ArrowLeft, Mods::none()),
@@ -183,6 +342,11 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Space, Mods::none()),
         Command::HeaderCycleSortOrder,
     );
+    layer.bind(
+        ContextId::TableHeaders,
+        KeyChord::new(Key::Char('s'), Mods::ctrl()),
+        Command::SetSecondarySortColumn,
+    );
     layer.bind(
         ContextId::TableHeaders,
         KeyChord::new(Key::Char('j'), Mods::ctrl()),
@@ -265,6 +429,16 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Space, Mods::none()),
         Command::OpenSelectedTask,
     );
+    layer.bind(
+        ContextId::SidebarProjects,
+        KeyChord::new(Key::Char('/'), Mods::none()),
+        Command::ToggleProjectSearch,
+    );
+    layer.bind(
+        ContextId::SidebarProjects,
+        KeyChord::new(Key::Char('r'), Mods::none()),
+        Command::RenameProject,
+    );
 
     // Sidebar Tags navigation
     layer.bind(
@@ -317,6 +491,31 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Space, Mods::none()),
         Command::OpenSelectedTask,
     );
+    layer.bind(
+        ContextId::SidebarTags,
+        KeyChord::new(Key::Char('m'), Mods::none()),
+        Command::ToggleTagsMode,
+    );
+    layer.bind(
+        ContextId::SidebarProjects,
+        KeyChord::new(Key::Char('p'), Mods::shift()),
+        Command::SidebarExpandProjects,
+    );
+    layer.bind(
+        ContextId::SidebarProjects,
+        KeyChord::new(Key::Char('t'), Mods::shift()),
+        Command::SidebarExpandTags,
+    );
+    layer.bind(
+        ContextId::SidebarTags,
+        KeyChord::new(Key::Char('p'), Mods::shift()),
+        Command::SidebarExpandProjects,
+    );
+    layer.bind(
+        ContextId::SidebarTags,
+        KeyChord::new(Key::Char('t'), Mods::shift()),
+        Command::SidebarExpandTags,
+    );
 
     // TextInput / FilterBar
     layer.bind(
@@ -339,6 +538,16 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Char('h'), Mods::ctrl()),
         Command::FocusFilterPrev,
     );
+    layer.bind(
+        ContextId::TextInput,
+        KeyChord::new(Key::Tab, Mods::none()),
+        Command::FocusFilterNext,
+    );
+    layer.bind(
+        ContextId::TextInput,
+        KeyChord::new(Key::Tab, Mods::shift()),
+        Command::FocusFilterPrev,
+    );
     layer.bind(
         ContextId::TextInput,
         KeyChord::new(Key::Char('j'), Mods::ctrl()),
@@ -386,6 +595,16 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Char('h'), Mods::ctrl()),
         Command::FocusFilterPrev,
     );
+    layer.bind(
+        ContextId::FilterBar,
+        KeyChord::new(Key::Tab, Mods::none()),
+        Command::FocusFilterNext,
+    );
+    layer.bind(
+        ContextId::FilterBar,
+        KeyChord::new(Key::Tab, Mods::shift()),
+        Command::FocusFilterPrev,
+    );
     layer.bind(
         ContextId::FilterBar,
         KeyChord::new(Key::Char('j'), Mods::ctrl()),
@@ -397,6 +616,38 @@ pub fn build_default_keymap() -> KeymapLayer {
         Command::BlurInput,
     );
 
+    // Command palette
+    layer.bind(
+        ContextId::Palette,
+        KeyChord::new(Key::Escape, Mods::none()),
+        Command::CloseModal,
+    );
+    layer.bind(
+        ContextId::Palette,
+        KeyChord::new(Key::Enter, Mods::none()),
+        Command::PaletteExecute,
+    );
+    layer.bind(
+        ContextId::Palette,
+        KeyChord::new(Key::ArrowDown, Mods::none()),
+        Command::SelectNextOption,
+    );
+    layer.bind(
+        ContextId::Palette,
+        KeyChord::new(Key::ArrowUp, Mods::none()),
+        Command::SelectPrevOption,
+    );
+    layer.bind(
+        ContextId::Palette,
+        KeyChord::new(Key::Char('n'), Mods::ctrl()),
+        Command::SelectNextOption,
+    );
+    layer.bind(
+        ContextId::Palette,
+        KeyChord::new(Key::Char('p'), Mods::ctrl()),
+        Command::SelectPrevOption,
+    );
+
     // Modal
     layer.bind(
         ContextId::Modal,
@@ -428,6 +679,190 @@ pub fn build_default_keymap() -> KeymapLayer {
         KeyChord::new(Key::Enter, Mods::ctrl()),
         Command::SaveModal,
     );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('1'), Mods::none()),
+        Command::ModalJumpOverview,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('2'), Mods::none()),
+        Command::ModalJumpTags,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('3'), Mods::none()),
+        Command::ModalJumpDependencies,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('4'), Mods::none()),
+        Command::ModalJumpAnnotations,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('5'), Mods::none()),
+        Command::ModalJumpDates,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('6'), Mods::none()),
+        Command::ModalJumpMetadata,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('f'), Mods::ctrl()),
+        Command::ModalSearchAnnotations,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('c'), Mods::ctrl_shift()),
+        Command::CopyTaskUuid,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('d'), Mods::ctrl_shift()),
+        Command::CopyTaskDescription,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('f'), Mods::ctrl_shift()),
+        Command::CopyTaskFormatted,
+    );
+    layer.bind(
+        ContextId::Modal,
+        KeyChord::new(Key::Char('m'), Mods::ctrl()),
+        Command::CopyTaskAsMarkdown,
+    );
+
+    // Calendar
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Escape, Mods::none()),
+        Command::CloseModal,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Enter, Mods::none()),
+        Command::CalendarSelectDay,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::ArrowRight, Mods::none()),
+        Command::CalendarNextDay,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::ArrowLeft, Mods::none()),
+        Command::CalendarPrevDay,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::ArrowDown, Mods::none()),
+        Command::CalendarNextWeek,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::ArrowUp, Mods::none()),
+        Command::CalendarPrevWeek,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Char('l'), Mods::none()),
+        Command::CalendarNextDay,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Char('h'), Mods::none()),
+        Command::CalendarPrevDay,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Char('j'), Mods::none()),
+        Command::CalendarNextWeek,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Char('k'), Mods::none()),
+        Command::CalendarPrevWeek,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::PageDown, Mods::none()),
+        Command::CalendarNextMonth,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::PageUp, Mods::none()),
+        Command::CalendarPrevMonth,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Char('n'), Mods::none()),
+        Command::CalendarNextMonth,
+    );
+    layer.bind(
+        ContextId::Calendar,
+        KeyChord::new(Key::Char('p'), Mods::none()),
+        Command::CalendarPrevMonth,
+    );
+
+    // Settings
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Escape, Mods::none()),
+        Command::CloseModal,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Enter, Mods::none()),
+        Command::CloseModal,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::ArrowDown, Mods::none()),
+        Command::SelectNextOption,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::ArrowUp, Mods::none()),
+        Command::SelectPrevOption,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Char('j'), Mods::none()),
+        Command::SelectNextOption,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Char('k'), Mods::none()),
+        Command::SelectPrevOption,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::ArrowRight, Mods::none()),
+        Command::SettingsIncrease,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::ArrowLeft, Mods::none()),
+        Command::SettingsDecrease,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Char('l'), Mods::none()),
+        Command::SettingsIncrease,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Char('h'), Mods::none()),
+        Command::SettingsDecrease,
+    );
+    layer.bind(
+        ContextId::Settings,
+        KeyChord::new(Key::Space, Mods::none()),
+        Command::SettingsIncrease,
+    );
 
     layer
 }
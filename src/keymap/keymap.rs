@@ -27,6 +27,36 @@ impl KeymapLayer {
             .and_then(|map| map.get(chord))
             .copied()
     }
+
+    pub fn bindings(&self) -> impl Iterator<Item = (ContextId, KeyChord, Command)> + '_ {
+        self.bindings.iter().flat_map(|(context, map)| {
+            map.iter()
+                .map(move |(chord, command)| (*context, *chord, *command))
+        })
+    }
+
+    pub fn from_bindings<'a>(
+        bindings: impl IntoIterator<Item = (&'a str, &'a str, &'a str)>,
+    ) -> Self {
+        let mut layer = Self::new();
+        for (context, keys, command) in bindings {
+            let parsed = (
+                ContextId::from_str(context),
+                KeyChord::parse(keys),
+                Command::from_str(command),
+            );
+            match parsed {
+                (Some(context), Some(chord), Some(command)) => layer.bind(context, chord, command),
+                _ => log::warn!(
+                    "[Keymap] Ignoring invalid binding: context={} keys={} command={}",
+                    context,
+                    keys,
+                    command
+                ),
+            }
+        }
+        layer
+    }
 }
 
 impl Default for KeymapLayer {
@@ -35,6 +65,14 @@ impl Default for KeymapLayer {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeymapConflict {
+    pub context: ContextId,
+    pub chord: KeyChord,
+    pub old_command: Command,
+    pub new_command: Command,
+}
+
 #[derive(Debug, Clone)]
 pub struct KeymapStack {
     layers: Vec<KeymapLayer>,
@@ -70,6 +108,62 @@ impl KeymapStack {
 
         None
     }
+
+    pub fn resolve_local(&self, context: ContextId, chord: &KeyChord) -> Option<Command> {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.resolve(context, chord))
+    }
+
+    pub fn validate(&self) -> Vec<KeymapConflict> {
+        let mut conflicts = Vec::new();
+        for (idx, layer) in self.layers.iter().enumerate() {
+            for (context, chord, new_command) in layer.bindings() {
+                let old_command = self.layers[..idx]
+                    .iter()
+                    .rev()
+                    .find_map(|lower| lower.resolve(context, &chord));
+                if let Some(old_command) = old_command.filter(|old| *old != new_command) {
+                    conflicts.push(KeymapConflict {
+                        context,
+                        chord,
+                        old_command,
+                        new_command,
+                    });
+                }
+            }
+        }
+
+        conflicts.sort_by(|a, b| {
+            a.context
+                .index()
+                .cmp(&b.context.index())
+                .then_with(|| a.chord.to_string().cmp(&b.chord.to_string()))
+        });
+        conflicts
+    }
+
+    pub fn bindings(&self) -> Vec<(ContextId, KeyChord, Command)> {
+        let mut effective: HashMap<(ContextId, KeyChord), Command> = HashMap::new();
+        for layer in &self.layers {
+            for (context, chord, command) in layer.bindings() {
+                effective.insert((context, chord), command);
+            }
+        }
+
+        let mut bindings: Vec<(ContextId, KeyChord, Command)> = effective
+            .into_iter()
+            .map(|((context, chord), command)| (context, chord, command))
+            .collect();
+        bindings.sort_by(|a, b| {
+            a.0.index()
+                .cmp(&b.0.index())
+                .then_with(|| a.2.as_str().cmp(b.2.as_str()))
+                .then_with(|| a.1.to_string().cmp(&b.1.to_string()))
+        });
+        bindings
+    }
 }
 
 impl Default for KeymapStack {
@@ -110,6 +204,23 @@ mod tests {
         assert_eq!(stack.resolve(ContextId::Table, &chord), Some(Command::Sync));
     }
 
+    #[test]
+    fn test_resolve_local_skips_global_fallback() {
+        let mut stack = KeymapStack::new();
+        let mut layer = KeymapLayer::new();
+
+        let chord = KeyChord::new(Key::Char('a'), Mods::none());
+        layer.bind(ContextId::Global, chord, Command::Sync);
+
+        stack.push_layer(layer);
+
+        assert_eq!(stack.resolve_local(ContextId::FilterBar, &chord), None);
+        assert_eq!(
+            stack.resolve_local(ContextId::Global, &chord),
+            Some(Command::Sync)
+        );
+    }
+
     #[test]
     fn test_stack_user_override() {
         let mut stack = KeymapStack::new();
@@ -128,4 +239,62 @@ mod tests {
             Some(Command::SelectPrevRow)
         );
     }
+
+    #[test]
+    fn test_validate_reports_user_bindings_that_replace_defaults() {
+        let mut stack = KeymapStack::new();
+        let j = KeyChord::new(Key::Char('j'), Mods::none());
+        let k = KeyChord::new(Key::Char('k'), Mods::none());
+
+        let mut default_layer = KeymapLayer::new();
+        default_layer.bind(ContextId::Table, j, Command::SelectNextRow);
+        default_layer.bind(ContextId::Table, k, Command::SelectPrevRow);
+        stack.push_layer(default_layer);
+
+        let user_layer = KeymapLayer::from_bindings([
+            ("table", "j", "SelectPrevRow"),
+            ("table", "k", "SelectPrevRow"),
+            ("sidebarprojects", "j", "SelectPrevRow"),
+            ("table", "ctrl+nope", "SelectPrevRow"),
+        ]);
+        stack.push_layer(user_layer);
+
+        assert_eq!(
+            stack.validate(),
+            vec![KeymapConflict {
+                context: ContextId::Table,
+                chord: j,
+                old_command: Command::SelectNextRow,
+                new_command: Command::SelectPrevRow,
+            }]
+        );
+        assert_eq!(
+            stack.resolve(ContextId::Table, &j),
+            Some(Command::SelectPrevRow)
+        );
+    }
+
+    #[test]
+    fn test_stack_bindings_apply_overrides_and_group_by_context() {
+        let mut stack = KeymapStack::new();
+        let j = KeyChord::new(Key::Char('j'), Mods::none());
+        let r = KeyChord::new(Key::Char('r'), Mods::ctrl());
+
+        let mut default_layer = KeymapLayer::new();
+        default_layer.bind(ContextId::Table, j, Command::SelectNextRow);
+        default_layer.bind(ContextId::Global, r, Command::Sync);
+        stack.push_layer(default_layer);
+
+        let mut user_layer = KeymapLayer::new();
+        user_layer.bind(ContextId::Table, j, Command::SelectPrevRow);
+        stack.push_layer(user_layer);
+
+        assert_eq!(
+            stack.bindings(),
+            vec![
+                (ContextId::Global, r, Command::Sync),
+                (ContextId::Table, j, Command::SelectPrevRow),
+            ]
+        );
+    }
 }
@@ -11,7 +11,7 @@ pub use chord::{Key, KeyChord, Mods};
 pub use command::Command;
 pub use context::ContextId;
 pub use dispatcher::CommandDispatcher;
-pub use keymap::{KeymapLayer, KeymapStack};
+pub use keymap::{KeymapConflict, KeymapLayer, KeymapStack};
 
 // Legacy compatibility - will be removed after refactor
 use gpui::Modifiers;
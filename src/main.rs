@@ -1,11 +1,15 @@
 use crate::app::App;
 
 mod app;
+mod cli;
 mod components;
+mod config;
 mod dispatcher;
 mod keymap;
 mod models;
 mod task;
+#[cfg(test)]
+mod test_support;
 mod theme;
 mod ui;
 mod view;
@@ -19,6 +23,18 @@ fn main() {
     }
     logger.init();
 
+    let launch = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(cli::CliAction::Launch(launch)) => launch,
+        Ok(cli::CliAction::Help) => {
+            println!("{}", cli::USAGE);
+            return;
+        }
+        Err(e) => {
+            eprintln!("{}\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
     log::info!("Starting Task Warrior GPUI");
-    App::run();
+    App::run(launch);
 }
@@ -2,16 +2,50 @@ use std::collections::HashSet;
 
 use chrono::NaiveDate;
 
+use crate::task::{FilterPreset, TagsFilterMode};
 use crate::ui::DATE_FORMAT;
 
+pub const INBOX_PROJECT: &str = "";
+
 #[derive(Debug, Clone, Default)]
 pub struct FilterState {
     pub selected_project: Option<String>,
+    pub exclude_subprojects: bool,
     pub active_tags: HashSet<String>,
+    pub tags_mode: TagsFilterMode,
     pub search_text: String,
+    pub search_annotations: bool,
     pub status_filter: StatusFilter,
     pub priority_filter: PriorityFilter,
     pub due_filter: DueFilter,
+    pub hide_scheduled: bool,
+    pub preset: Option<FilterPreset>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterChip {
+    Preset(FilterPreset),
+    Project(String),
+    Tag(String),
+    Status(StatusFilter),
+    Priority(PriorityFilter),
+    Due(DueFilter),
+    Search(String),
+}
+
+impl FilterChip {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Preset(preset) => preset.label().to_string(),
+            Self::Project(project) if project == INBOX_PROJECT => "Project: Inbox".to_string(),
+            Self::Project(project) => format!("Project: {}", project),
+            Self::Tag(tag) => format!("+{}", tag),
+            Self::Status(status) => format!("Status: {}", status.as_str()),
+            Self::Priority(priority) => format!("Priority: {}", priority.as_str()),
+            Self::Due(due) => format!("Due: {}", due.label()),
+            Self::Search(text) => format!("\"{}\"", text),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +64,17 @@ impl Default for StatusFilter {
 }
 
 impl StatusFilter {
+    pub fn from_value(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "all" => Some(Self::All),
+            "pending" => Some(Self::Pending),
+            "completed" => Some(Self::Completed),
+            "waiting" => Some(Self::Waiting),
+            "deleted" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::All => "All",
@@ -69,6 +114,8 @@ pub enum PriorityFilter {
     Medium,
     Low,
     None,
+    Blocked,
+    Blocking,
 }
 
 impl Default for PriorityFilter {
@@ -78,6 +125,19 @@ impl Default for PriorityFilter {
 }
 
 impl PriorityFilter {
+    pub fn from_value(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "all" => Some(Self::All),
+            "high" | "h" => Some(Self::High),
+            "medium" | "m" => Some(Self::Medium),
+            "low" | "l" => Some(Self::Low),
+            "none" => Some(Self::None),
+            "blocked" => Some(Self::Blocked),
+            "blocking" => Some(Self::Blocking),
+            _ => None,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::All => "All",
@@ -85,11 +145,21 @@ impl PriorityFilter {
             Self::Medium => "Medium (M)",
             Self::Low => "Low (L)",
             Self::None => "None",
+            Self::Blocked => "Blocked ⊘",
+            Self::Blocking => "Blocking ◆",
         }
     }
 
     pub fn all_variants() -> &'static [Self] {
-        &[Self::All, Self::High, Self::Medium, Self::Low, Self::None]
+        &[
+            Self::All,
+            Self::High,
+            Self::Medium,
+            Self::Low,
+            Self::None,
+            Self::Blocked,
+            Self::Blocking,
+        ]
     }
 
     pub fn from_index(index: usize) -> Self {
@@ -109,9 +179,14 @@ pub enum DueFilter {
     All,
     Overdue,
     Today,
+    Soon,
     ThisWeek,
+    NextWeek,
+    NextMonth,
     NoDate,
     OnDate(NaiveDate),
+    ScheduledToday,
+    ScheduledThisWeek,
 }
 
 impl Default for DueFilter {
@@ -126,8 +201,13 @@ impl DueFilter {
             Self::All,
             Self::Overdue,
             Self::Today,
+            Self::Soon,
             Self::ThisWeek,
+            Self::NextWeek,
+            Self::NextMonth,
             Self::NoDate,
+            Self::ScheduledToday,
+            Self::ScheduledThisWeek,
         ]
     }
 
@@ -147,9 +227,14 @@ impl DueFilter {
             Self::All => "All".to_string(),
             Self::Overdue => "Overdue".to_string(),
             Self::Today => "Today".to_string(),
+            Self::Soon => "Due Soon".to_string(),
             Self::ThisWeek => "This Week".to_string(),
+            Self::NextWeek => "Next Week".to_string(),
+            Self::NextMonth => "Next Month".to_string(),
             Self::NoDate => "No Date".to_string(),
             Self::OnDate(date) => date.format(DATE_FORMAT).to_string(),
+            Self::ScheduledToday => "Scheduled Today".to_string(),
+            Self::ScheduledThisWeek => "Scheduled This Week".to_string(),
         }
     }
 
@@ -158,9 +243,14 @@ impl DueFilter {
             Self::All => "all".to_string(),
             Self::Overdue => "overdue".to_string(),
             Self::Today => "today".to_string(),
+            Self::Soon => "soon".to_string(),
             Self::ThisWeek => "this_week".to_string(),
+            Self::NextWeek => "next_week".to_string(),
+            Self::NextMonth => "next_month".to_string(),
             Self::NoDate => "none".to_string(),
             Self::OnDate(date) => format!("date:{}", date.format(DATE_FORMAT)),
+            Self::ScheduledToday => "scheduled_today".to_string(),
+            Self::ScheduledThisWeek => "scheduled_this_week".to_string(),
         }
     }
 
@@ -169,8 +259,13 @@ impl DueFilter {
             "all" => Some(Self::All),
             "overdue" => Some(Self::Overdue),
             "today" => Some(Self::Today),
+            "soon" => Some(Self::Soon),
             "this_week" => Some(Self::ThisWeek),
+            "next_week" => Some(Self::NextWeek),
+            "next_month" => Some(Self::NextMonth),
             "none" => Some(Self::NoDate),
+            "scheduled_today" => Some(Self::ScheduledToday),
+            "scheduled_this_week" => Some(Self::ScheduledThisWeek),
             _ => value.strip_prefix("date:").and_then(|date| {
                 NaiveDate::parse_from_str(date, "%Y-%m-%d")
                     .ok()
@@ -197,7 +292,31 @@ impl FilterState {
         }
     }
 
+    pub fn toggle_exclude_subprojects(&mut self) {
+        self.exclude_subprojects = !self.exclude_subprojects;
+    }
+
+    pub fn toggle_hide_scheduled(&mut self) {
+        self.hide_scheduled = !self.hide_scheduled;
+    }
+
+    pub fn toggle_search_annotations(&mut self) {
+        self.search_annotations = !self.search_annotations;
+    }
+
+    pub fn toggle_tags_mode(&mut self) {
+        self.tags_mode = match self.tags_mode {
+            TagsFilterMode::And => TagsFilterMode::Or,
+            TagsFilterMode::Or => TagsFilterMode::And,
+        };
+    }
+
+    pub fn toggle_preset(&mut self, preset: FilterPreset) {
+        self.preset = (self.preset != Some(preset)).then_some(preset);
+    }
+
     pub fn clear(&mut self) {
+        self.preset = None;
         self.selected_project = None;
         self.active_tags.clear();
         self.search_text.clear();
@@ -221,8 +340,56 @@ impl FilterState {
         self.due_filter = DueFilter::default();
     }
 
+    pub fn active_chips(&self) -> Vec<FilterChip> {
+        let mut chips = Vec::new();
+
+        if let Some(preset) = self.preset {
+            chips.push(FilterChip::Preset(preset));
+        }
+
+        if let Some(project) = &self.selected_project {
+            chips.push(FilterChip::Project(project.clone()));
+        }
+
+        let mut tags: Vec<&String> = self.active_tags.iter().collect();
+        tags.sort();
+        chips.extend(tags.into_iter().map(|t| FilterChip::Tag(t.clone())));
+
+        if self.status_filter != StatusFilter::default() {
+            chips.push(FilterChip::Status(self.status_filter));
+        }
+        if self.priority_filter != PriorityFilter::default() {
+            chips.push(FilterChip::Priority(self.priority_filter));
+        }
+        if self.due_filter != DueFilter::default() {
+            chips.push(FilterChip::Due(self.due_filter));
+        }
+
+        let search = self.search_text.trim();
+        if !search.is_empty() {
+            chips.push(FilterChip::Search(search.to_string()));
+        }
+
+        chips
+    }
+
+    pub fn remove_chip(&mut self, chip: &FilterChip) {
+        match chip {
+            FilterChip::Preset(_) => self.preset = None,
+            FilterChip::Project(_) => self.clear_project(),
+            FilterChip::Tag(tag) => {
+                self.active_tags.remove(tag);
+            }
+            FilterChip::Status(_) => self.status_filter = StatusFilter::default(),
+            FilterChip::Priority(_) => self.priority_filter = PriorityFilter::default(),
+            FilterChip::Due(_) => self.due_filter = DueFilter::default(),
+            FilterChip::Search(_) => self.search_text.clear(),
+        }
+    }
+
     pub fn has_active_filters(&self) -> bool {
-        self.selected_project.is_some()
+        self.preset.is_some()
+            || self.selected_project.is_some()
             || !self.active_tags.is_empty()
             || !self.search_text.is_empty()
             || self.status_filter != StatusFilter::default()
@@ -230,3 +397,53 @@ impl FilterState {
             || self.due_filter != DueFilter::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_chips_by_default() {
+        assert!(FilterState::new().active_chips().is_empty());
+    }
+
+    #[test]
+    fn test_active_chips_order_and_labels() {
+        let mut state = FilterState::new();
+        state.select_project(Some("Work.Backend".to_string()));
+        state.toggle_tag("urgent".to_string());
+        state.toggle_tag("billing".to_string());
+        state.due_filter = DueFilter::Overdue;
+        state.search_text = "invoice".to_string();
+
+        let labels: Vec<String> = state.active_chips().iter().map(FilterChip::label).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Project: Work.Backend",
+                "+billing",
+                "+urgent",
+                "Due: Overdue",
+                "\"invoice\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_remove_chip_only_clears_that_constraint() {
+        let mut state = FilterState::new();
+        state.select_project(Some("Work".to_string()));
+        state.toggle_tag("urgent".to_string());
+        state.toggle_tag("billing".to_string());
+        state.priority_filter = PriorityFilter::High;
+
+        state.remove_chip(&FilterChip::Tag("urgent".to_string()));
+        assert!(state.active_tags.contains("billing"));
+        assert!(!state.active_tags.contains("urgent"));
+        assert_eq!(state.selected_project.as_deref(), Some("Work"));
+
+        state.remove_chip(&FilterChip::Priority(PriorityFilter::High));
+        assert_eq!(state.priority_filter, PriorityFilter::All);
+        assert_eq!(state.active_chips().len(), 2);
+    }
+}
@@ -1,5 +1,7 @@
 pub mod filter_state;
 pub mod project_tree;
+pub mod sort;
 
 pub use filter_state::*;
 pub use project_tree::*;
+pub use sort::*;
@@ -6,6 +6,7 @@ pub struct ProjectNode {
     pub full_path: String,
     pub task_count: usize,
     pub direct_task_count: usize,
+    pub completed_task_count: usize,
     pub level: usize,
     pub children_indices: Vec<usize>,
     pub is_expanded: bool,
@@ -18,6 +19,7 @@ impl ProjectNode {
             full_path,
             task_count: 0,
             direct_task_count: 0,
+            completed_task_count: 0,
             level,
             children_indices: Vec::new(),
             is_expanded: false,
@@ -28,6 +30,18 @@ impl ProjectNode {
     pub fn has_children(&self) -> bool {
         !self.children_indices.is_empty()
     }
+
+    pub fn total_task_count(&self) -> usize {
+        self.task_count + self.completed_task_count
+    }
+
+    pub fn completion_ratio(&self) -> f32 {
+        let total = self.total_task_count();
+        if total == 0 {
+            return 0.0;
+        }
+        self.completed_task_count as f32 / total as f32
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -48,18 +62,18 @@ impl ProjectTree {
         }
     }
 
-    pub fn build_from_projects(&mut self, projects: &[(String, usize)]) {
+    pub fn build_from_projects(&mut self, projects: &[(String, usize, usize)]) {
         self.nodes.clear();
         self.root_indices.clear();
         self.path_to_index.clear();
 
-        for (project_path, task_count) in projects {
+        for (project_path, task_count, completed_count) in projects {
             if project_path.is_empty() {
                 continue;
             }
 
             let segments: Vec<&str> = project_path.split('.').collect();
-            self.insert_project(&segments, task_count);
+            self.insert_project(&segments, *task_count, *completed_count);
         }
 
         self.root_indices.sort_by(|a, b| {
@@ -88,7 +102,7 @@ impl ProjectTree {
         }
     }
 
-    fn insert_project(&mut self, segments: &[&str], task_count: &usize) {
+    fn insert_project(&mut self, segments: &[&str], task_count: usize, completed_count: usize) {
         if segments.is_empty() {
             return;
         }
@@ -117,6 +131,7 @@ impl ProjectTree {
                     full_path: current_path.clone(),
                     task_count: 0,
                     direct_task_count: 0,
+                    completed_task_count: 0,
                     level,
                     children_indices: Vec::new(),
                     is_expanded,
@@ -135,6 +150,7 @@ impl ProjectTree {
             };
 
             self.nodes[node_idx].task_count += task_count;
+            self.nodes[node_idx].completed_task_count += completed_count;
 
             if level == segments.len() - 1 {
                 self.nodes[node_idx].direct_task_count += task_count;
@@ -209,6 +225,26 @@ impl ProjectTree {
             .and_then(|&idx| self.nodes.get(idx))
     }
 
+    pub fn find_parent(&self, path: &str) -> Option<&ProjectNode> {
+        let (parent, _) = path.rsplit_once('.')?;
+        self.find_by_path(parent)
+    }
+
+    pub fn breadcrumb<'a>(&'a self, path: &'a str) -> Vec<&'a str> {
+        let Some(node) = self.find_by_path(path) else {
+            return path.split('.').collect();
+        };
+
+        let mut segments = vec![node.name.as_str()];
+        let mut current = node;
+        while let Some(parent) = self.find_parent(&current.full_path) {
+            segments.push(parent.name.as_str());
+            current = parent;
+        }
+        segments.reverse();
+        segments
+    }
+
     pub fn iter_visible(&self) -> Vec<(usize, &ProjectNode)> {
         let mut result = Vec::new();
         for &root_idx in &self.root_indices {
@@ -217,6 +253,43 @@ impl ProjectTree {
         result
     }
 
+    pub fn iter_matching(&self, query: &str) -> Vec<(usize, &ProjectNode)> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return self.iter_visible();
+        }
+
+        let mut result = Vec::new();
+        for &root_idx in &self.root_indices {
+            self.collect_matching(root_idx, &query, &mut result);
+        }
+        result
+    }
+
+    fn collect_matching<'a>(
+        &'a self,
+        idx: usize,
+        query: &str,
+        result: &mut Vec<(usize, &'a ProjectNode)>,
+    ) -> bool {
+        let Some(node) = self.nodes.get(idx) else {
+            return false;
+        };
+
+        let start = result.len();
+        result.push((idx, node));
+
+        let mut has_match = node.full_path.to_lowercase().contains(query);
+        for &child_idx in &node.children_indices {
+            has_match |= self.collect_matching(child_idx, query, result);
+        }
+
+        if !has_match {
+            result.truncate(start);
+        }
+        has_match
+    }
+
     fn collect_visible<'a>(&'a self, idx: usize, result: &mut Vec<(usize, &'a ProjectNode)>) {
         if let Some(node) = self.nodes.get(idx) {
             result.push((idx, node));
@@ -243,7 +316,7 @@ mod tests {
     #[test]
     fn test_build_simple_tree() {
         let mut tree = ProjectTree::new();
-        tree.build_from_projects(&[("Work".to_string(), 5), ("Home".to_string(), 3)]);
+        tree.build_from_projects(&[("Work".to_string(), 5, 0), ("Home".to_string(), 3, 0)]);
 
         assert_eq!(tree.root_indices().len(), 2);
     }
@@ -252,9 +325,9 @@ mod tests {
     fn test_build_nested_tree() {
         let mut tree = ProjectTree::new();
         tree.build_from_projects(&[
-            ("Work.Backend.API".to_string(), 2),
-            ("Work.Backend.DB".to_string(), 3),
-            ("Work.Frontend".to_string(), 5),
+            ("Work.Backend.API".to_string(), 2, 0),
+            ("Work.Backend.DB".to_string(), 3, 0),
+            ("Work.Frontend".to_string(), 5, 0),
         ]);
 
         let work_node = tree.find_by_path("Work").unwrap();
@@ -265,10 +338,39 @@ mod tests {
         assert_eq!(backend_node.task_count, 5);
     }
 
+    #[test]
+    fn test_completed_counts_roll_up() {
+        let mut tree = ProjectTree::new();
+        tree.build_from_projects(&[
+            ("Work.Backend".to_string(), 3, 1),
+            ("Work.Frontend".to_string(), 1, 3),
+        ]);
+
+        let work_node = tree.find_by_path("Work").unwrap();
+        assert_eq!(work_node.task_count, 4);
+        assert_eq!(work_node.completed_task_count, 4);
+        assert_eq!(work_node.completion_ratio(), 0.5);
+
+        let backend_node = tree.find_by_path("Work.Backend").unwrap();
+        assert_eq!(backend_node.completed_task_count, 1);
+        assert_eq!(backend_node.completion_ratio(), 0.25);
+    }
+
+    #[test]
+    fn test_fully_completed_project_is_kept() {
+        let mut tree = ProjectTree::new();
+        tree.build_from_projects(&[("Archive".to_string(), 0, 5)]);
+
+        let node = tree.find_by_path("Archive").unwrap();
+        assert_eq!(node.task_count, 0);
+        assert_eq!(node.total_task_count(), 5);
+        assert_eq!(node.completion_ratio(), 1.0);
+    }
+
     #[test]
     fn test_toggle_expansion() {
         let mut tree = ProjectTree::new();
-        tree.build_from_projects(&[("Work.Backend".to_string(), 5)]);
+        tree.build_from_projects(&[("Work.Backend".to_string(), 5, 0)]);
 
         assert!(!tree.find_by_path("Work").unwrap().is_expanded);
 
@@ -278,4 +380,47 @@ mod tests {
         tree.toggle_expansion("Work");
         assert!(!tree.find_by_path("Work").unwrap().is_expanded);
     }
+
+    #[test]
+    fn test_matching_keeps_ancestors_of_matches() {
+        let mut tree = ProjectTree::new();
+        tree.build_from_projects(&[
+            ("Work.Backend.API".to_string(), 2, 0),
+            ("Work.Frontend".to_string(), 1, 0),
+            ("Home.Garden".to_string(), 1, 0),
+        ]);
+
+        let paths = |query: &str| -> Vec<String> {
+            tree.iter_matching(query)
+                .into_iter()
+                .map(|(_, node)| node.full_path.clone())
+                .collect()
+        };
+
+        assert_eq!(
+            paths("api"),
+            vec!["Work", "Work.Backend", "Work.Backend.API"]
+        );
+        assert_eq!(paths("garden"), vec!["Home", "Home.Garden"]);
+        assert!(paths("nothing").is_empty());
+        assert_eq!(paths(" "), vec!["Home", "Work"]);
+    }
+
+    #[test]
+    fn test_find_parent_and_breadcrumb() {
+        let mut tree = ProjectTree::new();
+        tree.build_from_projects(&[("Work.Backend.API".to_string(), 5, 0)]);
+
+        assert_eq!(
+            tree.find_parent("Work.Backend.API")
+                .map(|node| node.full_path.as_str()),
+            Some("Work.Backend")
+        );
+        assert!(tree.find_parent("Work").is_none());
+        assert_eq!(
+            tree.breadcrumb("Work.Backend.API"),
+            vec!["Work", "Backend", "API"]
+        );
+        assert_eq!(tree.breadcrumb("Home.Garden"), vec!["Home", "Garden"]);
+    }
 }
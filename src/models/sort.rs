@@ -0,0 +1,140 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Id,
+    Description,
+    Project,
+    Due,
+    Scheduled,
+    EntryDate,
+    ModifiedDate,
+    Priority,
+    Urgency,
+    Status,
+}
+
+impl SortColumn {
+    pub const ALL: [Self; 10] = [
+        Self::Id,
+        Self::Description,
+        Self::Project,
+        Self::Due,
+        Self::Scheduled,
+        Self::EntryDate,
+        Self::ModifiedDate,
+        Self::Priority,
+        Self::Urgency,
+        Self::Status,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortColumn::Id => "ID",
+            SortColumn::Description => "Description",
+            SortColumn::Project => "Project",
+            SortColumn::Due => "Due",
+            SortColumn::Scheduled => "Scheduled",
+            SortColumn::EntryDate => "Entry",
+            SortColumn::ModifiedDate => "Modified",
+            SortColumn::Priority => "Priority",
+            SortColumn::Urgency => "Urg",
+            SortColumn::Status => "Status",
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            SortColumn::Id => "id",
+            SortColumn::Description => "description",
+            SortColumn::Project => "project",
+            SortColumn::Due => "due",
+            SortColumn::Scheduled => "scheduled",
+            SortColumn::EntryDate => "entry",
+            SortColumn::ModifiedDate => "modified",
+            SortColumn::Priority => "priority",
+            SortColumn::Urgency => "urgency",
+            SortColumn::Status => "status",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|column| column.value().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn toggle(&self) -> Self {
+        match self {
+            SortDirection::Asc => SortDirection::Desc,
+            SortDirection::Desc => SortDirection::Asc,
+        }
+    }
+
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "↑",
+            SortDirection::Desc => "↓",
+        }
+    }
+}
+
+impl Default for SortDirection {
+    fn default() -> Self {
+        Self::Desc
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortState {
+    pub column: SortColumn,
+    pub direction: SortDirection,
+}
+
+impl SortState {
+    pub fn default_levels() -> Vec<Self> {
+        vec![
+            Self::default(),
+            Self {
+                column: SortColumn::Id,
+                direction: SortDirection::Asc,
+            },
+        ]
+    }
+
+    pub fn value(&self) -> String {
+        let direction = match self.direction {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        };
+        format!("{}:{}", self.column.value(), direction)
+    }
+
+    pub fn from_value(value: &str) -> Option<Self> {
+        let (column, direction) = value.split_once(':').unwrap_or((value, "desc"));
+        let direction = match direction.trim().to_ascii_lowercase().as_str() {
+            "asc" => SortDirection::Asc,
+            "desc" => SortDirection::Desc,
+            _ => return None,
+        };
+        Some(Self {
+            column: SortColumn::from_value(column)?,
+            direction,
+        })
+    }
+}
+
+impl Default for SortState {
+    fn default() -> Self {
+        Self {
+            column: SortColumn::Priority,
+            direction: SortDirection::Desc,
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use chrono::{
+    DateTime, Datelike, Days, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Utc, Weekday,
+};
+
+use crate::task::import::parse_taskwarrior_date;
+use crate::ui::{DATE_FORMAT, DATE_TIME_FORMAT};
+
+const ISO_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+pub const DATE_KEYWORDS: &[&str] = &[
+    "today",
+    "tomorrow",
+    "yesterday",
+    "now",
+    "eod",
+    "eow",
+    "eom",
+    "eoy",
+    "sow",
+    "som",
+    "soy",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+pub fn parse_date_expression(input: &str) -> Option<DateTime<Utc>> {
+    resolve_date_expression(input, Local::now())
+}
+
+pub fn resolve_date_expression<Tz: TimeZone>(
+    input: &str,
+    now: DateTime<Tz>,
+) -> Option<DateTime<Utc>> {
+    let raw = input.trim();
+    let value = raw.to_lowercase();
+    if value.is_empty() {
+        return None;
+    }
+
+    let today = now.date_naive();
+    let local = match value.as_str() {
+        "now" => return Some(now.with_timezone(&Utc)),
+        "today" | "sod" => start_of_day(today),
+        "tomorrow" => start_of_day(today.checked_add_days(Days::new(1))?),
+        "yesterday" => start_of_day(today.checked_sub_days(Days::new(1))?),
+        "eod" => end_of_day(today),
+        "eow" => end_of_day(end_of_week(today)?),
+        "sow" => start_of_day(end_of_week(today)?.checked_add_days(Days::new(1))?),
+        "eom" => end_of_day(end_of_month(today)?),
+        "som" => start_of_day(end_of_month(today)?.checked_add_days(Days::new(1))?),
+        "eoy" => end_of_day(NaiveDate::from_ymd_opt(today.year(), 12, 31)?),
+        "soy" => start_of_day(NaiveDate::from_ymd_opt(today.year() + 1, 1, 1)?),
+        other => {
+            if let Ok(weekday) = other.parse::<Weekday>() {
+                start_of_day(next_weekday(today, weekday)?)
+            } else if let Some(offset) = parse_offset(other) {
+                return offset.apply(now);
+            } else if let Some(date) = parse_taskwarrior_date(raw) {
+                return Some(date);
+            } else if let Ok(date) = NaiveDate::parse_from_str(raw, DATE_FORMAT) {
+                start_of_day(date)
+            } else {
+                NaiveDateTime::parse_from_str(raw, ISO_DATE_TIME_FORMAT)
+                    .or_else(|_| NaiveDateTime::parse_from_str(raw, DATE_TIME_FORMAT))
+                    .ok()?
+            }
+        }
+    };
+
+    now.timezone()
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+fn start_of_day(date: NaiveDate) -> NaiveDateTime {
+    date.and_time(NaiveTime::MIN)
+}
+
+fn end_of_day(date: NaiveDate) -> NaiveDateTime {
+    date.and_hms_opt(23, 59, 59)
+        .unwrap_or_else(|| start_of_day(date))
+}
+
+fn end_of_week(date: NaiveDate) -> Option<NaiveDate> {
+    let remaining = 6 - date.weekday().num_days_from_monday();
+    date.checked_add_days(Days::new(remaining as u64))
+}
+
+fn end_of_month(date: NaiveDate) -> Option<NaiveDate> {
+    date.with_day(1)?
+        .checked_add_months(Months::new(1))?
+        .checked_sub_days(Days::new(1))
+}
+
+fn next_weekday(date: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let current = date.weekday().num_days_from_monday();
+    let target = weekday.num_days_from_monday();
+    let ahead = match (7 + target - current) % 7 {
+        0 => 7,
+        days => days,
+    };
+    date.checked_add_days(Days::new(ahead as u64))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Offset {
+    Hours(i64),
+    Days(i64),
+    Months(i64),
+}
+
+impl Offset {
+    fn apply<Tz: TimeZone>(self, now: DateTime<Tz>) -> Option<DateTime<Utc>> {
+        let today = start_of_day(now.date_naive());
+        let local = match self {
+            Offset::Hours(hours) => return Some(now.with_timezone(&Utc) + Duration::hours(hours)),
+            Offset::Days(days) => today.checked_add_signed(Duration::days(days))?,
+            Offset::Months(months) if months >= 0 => {
+                today.checked_add_months(Months::new(months as u32))?
+            }
+            Offset::Months(months) => {
+                today.checked_sub_months(Months::new(months.unsigned_abs() as u32))?
+            }
+        };
+
+        now.timezone()
+            .from_local_datetime(&local)
+            .earliest()
+            .map(|date| date.with_timezone(&Utc))
+    }
+}
+
+fn parse_offset(value: &str) -> Option<Offset> {
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+
+    let split = rest.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = rest.split_at(split);
+    let amount: i64 = amount.parse().ok()?;
+    let amount = sign * amount;
+
+    match unit {
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(Offset::Hours(amount)),
+        "d" | "day" | "days" => Some(Offset::Days(amount)),
+        "w" | "wk" | "wks" | "week" | "weeks" => Some(Offset::Days(amount * 7)),
+        "mo" | "mos" | "month" | "months" => Some(Offset::Months(amount)),
+        "y" | "yr" | "yrs" | "year" | "years" => Some(Offset::Months(amount * 12)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn resolve(input: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        resolve_date_expression(input, now)
+    }
+
+    // 2026-10-16 is a Friday.
+    fn friday() -> DateTime<Utc> {
+        at(2026, 10, 16, 14, 30)
+    }
+
+    #[test]
+    fn test_relative_day_keywords() {
+        let now = friday();
+        assert_eq!(resolve("today", now), Some(at(2026, 10, 16, 0, 0)));
+        assert_eq!(resolve("Tomorrow", now), Some(at(2026, 10, 17, 0, 0)));
+        assert_eq!(resolve("yesterday", now), Some(at(2026, 10, 15, 0, 0)));
+        assert_eq!(resolve(" now ", now), Some(now));
+        assert_eq!(
+            resolve("eod", now),
+            Utc.with_ymd_and_hms(2026, 10, 16, 23, 59, 59).single()
+        );
+    }
+
+    #[test]
+    fn test_weekday_names_pick_the_next_occurrence() {
+        let now = friday();
+        assert_eq!(resolve("monday", now), Some(at(2026, 10, 19, 0, 0)));
+        assert_eq!(resolve("sat", now), Some(at(2026, 10, 17, 0, 0)));
+        assert_eq!(resolve("sunday", now), Some(at(2026, 10, 18, 0, 0)));
+        assert_eq!(resolve("friday", now), Some(at(2026, 10, 23, 0, 0)));
+        assert_eq!(resolve("thu", now), Some(at(2026, 10, 22, 0, 0)));
+    }
+
+    #[test]
+    fn test_week_boundaries() {
+        let end = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 23, 59, 59).single();
+
+        assert_eq!(resolve("eow", friday()), end(2026, 10, 18));
+        assert_eq!(resolve("eow", at(2026, 10, 18, 9, 0)), end(2026, 10, 18));
+        assert_eq!(resolve("eow", at(2026, 10, 19, 9, 0)), end(2026, 10, 25));
+        assert_eq!(resolve("eow", at(2026, 12, 30, 9, 0)), end(2027, 1, 3));
+        assert_eq!(resolve("sow", friday()), Some(at(2026, 10, 19, 0, 0)));
+        assert_eq!(
+            resolve("sow", at(2026, 10, 19, 9, 0)),
+            Some(at(2026, 10, 26, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_month_and_year_ends() {
+        let end = |y, m, d| Utc.with_ymd_and_hms(y, m, d, 23, 59, 59).single();
+
+        assert_eq!(resolve("eom", friday()), end(2026, 10, 31));
+        assert_eq!(resolve("eom", at(2026, 11, 30, 9, 0)), end(2026, 11, 30));
+        assert_eq!(resolve("eom", at(2028, 2, 10, 9, 0)), end(2028, 2, 29));
+        assert_eq!(resolve("eom", at(2027, 2, 10, 9, 0)), end(2027, 2, 28));
+        assert_eq!(resolve("eom", at(2026, 12, 31, 9, 0)), end(2026, 12, 31));
+        assert_eq!(
+            resolve("som", at(2026, 12, 15, 9, 0)),
+            Some(at(2027, 1, 1, 0, 0))
+        );
+        assert_eq!(resolve("eoy", friday()), end(2026, 12, 31));
+        assert_eq!(resolve("soy", friday()), Some(at(2027, 1, 1, 0, 0)));
+    }
+
+    #[test]
+    fn test_offsets() {
+        let now = friday();
+        assert_eq!(resolve("+3d", now), Some(at(2026, 10, 19, 0, 0)));
+        assert_eq!(resolve("3d", now), Some(at(2026, 10, 19, 0, 0)));
+        assert_eq!(resolve("-1d", now), Some(at(2026, 10, 15, 0, 0)));
+        assert_eq!(resolve("+2w", now), Some(at(2026, 10, 30, 0, 0)));
+        assert_eq!(resolve("+4h", now), Some(at(2026, 10, 16, 18, 30)));
+        assert_eq!(
+            resolve("+3d", at(2026, 10, 30, 9, 0)),
+            Some(at(2026, 11, 2, 0, 0))
+        );
+        assert_eq!(
+            resolve("+1mo", at(2026, 1, 31, 9, 0)),
+            Some(at(2026, 2, 28, 0, 0))
+        );
+        assert_eq!(resolve("+1y", now), Some(at(2027, 10, 16, 0, 0)));
+    }
+
+    #[test]
+    fn test_absolute_dates() {
+        let now = friday();
+        assert_eq!(resolve("2024-07-01", now), Some(at(2024, 7, 1, 0, 0)));
+        assert_eq!(
+            resolve("2024-07-01T09:30", now),
+            Some(at(2024, 7, 1, 9, 30))
+        );
+        assert_eq!(
+            resolve("2024-07-01 09:30", now),
+            Some(at(2024, 7, 1, 9, 30))
+        );
+        assert_eq!(
+            resolve("20240701T093000Z", now),
+            Some(at(2024, 7, 1, 9, 30))
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_expressions() {
+        let now = friday();
+        for input in ["", "  ", "someday", "+3x", "+d", "2024-13-01", "due:today"] {
+            assert_eq!(resolve(input, now), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_anchors_to_local_midnight() {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let now = offset.with_ymd_and_hms(2026, 10, 16, 1, 0, 0).unwrap();
+
+        assert_eq!(
+            resolve_date_expression("today", now),
+            Some(at(2026, 10, 15, 22, 0))
+        );
+        assert_eq!(
+            resolve_date_expression("tomorrow", now),
+            Some(at(2026, 10, 16, 22, 0))
+        );
+    }
+}
@@ -5,6 +5,7 @@ pub enum TaskError {
     Storage(String),
     Config(String),
     NotFound(uuid::Uuid),
+    InvalidDescription(String),
     InvalidTag(String),
     InvalidProject(String),
     InvalidPriority(String),
@@ -12,6 +13,10 @@ pub enum TaskError {
     InvalidWait(String),
     InvalidAnnotation(String),
     InvalidDependency(String),
+    InvalidRecurrence(String),
+    InvalidAttribute(String),
+    Export(String),
+    Import(String),
 }
 
 impl fmt::Display for TaskError {
@@ -20,6 +25,9 @@ impl fmt::Display for TaskError {
             TaskError::Storage(msg) => write!(f, "Storage error: {}", msg),
             TaskError::Config(msg) => write!(f, "Configuration error: {}", msg),
             TaskError::NotFound(id) => write!(f, "Task not found: {}", id),
+            TaskError::InvalidDescription(description) => {
+                write!(f, "Invalid description: {}", description)
+            }
             TaskError::InvalidTag(tag) => write!(f, "Invalid tag: {}", tag),
             TaskError::InvalidProject(project) => write!(f, "Invalid project: {}", project),
             TaskError::InvalidPriority(priority) => write!(f, "Invalid priority: {}", priority),
@@ -31,6 +39,12 @@ impl fmt::Display for TaskError {
             TaskError::InvalidDependency(dependency) => {
                 write!(f, "Invalid dependency: {}", dependency)
             }
+            TaskError::InvalidRecurrence(recur) => write!(f, "Invalid recurrence: {}", recur),
+            TaskError::InvalidAttribute(attribute) => {
+                write!(f, "Invalid attribute: {}", attribute)
+            }
+            TaskError::Export(msg) => write!(f, "Export failed: {}", msg),
+            TaskError::Import(msg) => write!(f, "Import failed: {}", msg),
         }
     }
 }
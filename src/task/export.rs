@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value, json};
+
+use crate::task::{
+    TaskAnnotation, TaskDetailVm, TaskError, TaskPriority, TaskResult, TaskStatus, TaskSummary,
+};
+use crate::ui::DATE_FORMAT;
+
+pub const DEFAULT_EXPORT_PATH: &str = "~/tasks-export.json";
+pub const TASKWARRIOR_DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+pub const DEFAULT_MARKDOWN_TEMPLATE: &str = "- [{check}] #{id} {description}
+  - **Project:** {project}
+  - **Priority:** {priority}
+  - **Due:** {due}
+  - **Tags:** {tags}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Self::Csv,
+            _ => Self::Json,
+        }
+    }
+}
+
+pub fn expand_path(value: &str) -> PathBuf {
+    let value = value.trim();
+    if let Some(rest) = value.strip_prefix("~/") {
+        if let Some(home) = dirs::home_dir() {
+            return home.join(rest);
+        }
+    }
+    PathBuf::from(value)
+}
+
+fn format_date(date: &DateTime<Utc>) -> String {
+    date.format(TASKWARRIOR_DATE_FORMAT).to_string()
+}
+
+fn status_key(status: &TaskStatus) -> String {
+    match status {
+        TaskStatus::Pending => "pending".to_string(),
+        TaskStatus::Completed => "completed".to_string(),
+        TaskStatus::Deleted => "deleted".to_string(),
+        TaskStatus::Recurring => "recurring".to_string(),
+        TaskStatus::Unknown(reason) => reason.clone(),
+    }
+}
+
+pub fn tasks_to_json(
+    tasks: &[TaskSummary],
+    annotations: &HashMap<uuid::Uuid, Vec<TaskAnnotation>>,
+) -> String {
+    let entries: Vec<Value> = tasks
+        .iter()
+        .map(|task| {
+            let mut entry = Map::new();
+            entry.insert("uuid".into(), json!(task.uuid.to_string()));
+            entry.insert("description".into(), json!(task.description));
+            entry.insert("status".into(), json!(status_key(&task.status)));
+            if let Some(project) = &task.project {
+                entry.insert("project".into(), json!(project));
+            }
+            if !task.tags.is_empty() {
+                let mut tags: Vec<&String> = task.tags.iter().collect();
+                tags.sort();
+                entry.insert("tags".into(), json!(tags));
+            }
+            if let Some(due) = &task.due {
+                entry.insert("due".into(), json!(format_date(due)));
+            }
+            if let Some(notes) = annotations
+                .get(&task.uuid)
+                .filter(|notes| !notes.is_empty())
+            {
+                let notes: Vec<Value> = notes
+                    .iter()
+                    .map(|note| {
+                        json!({
+                            "entry": format_date(&note.entry),
+                            "description": note.content,
+                        })
+                    })
+                    .collect();
+                entry.insert("annotations".into(), Value::Array(notes));
+            }
+            Value::Object(entry)
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn rows_to_csv(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut output = String::new();
+    let header_line: Vec<String> = headers.iter().map(|header| csv_field(header)).collect();
+    output.push_str(&header_line.join(","));
+    output.push('\n');
+
+    for row in rows {
+        let line: Vec<String> = row.iter().map(|value| csv_field(value)).collect();
+        output.push_str(&line.join(","));
+        output.push('\n');
+    }
+
+    output
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipboardFormat {
+    Uuid,
+    Description,
+    UuidAndDescription,
+    Checklist,
+}
+
+impl ClipboardFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Uuid => "UUID",
+            Self::Description => "description",
+            Self::UuidAndDescription => "UUID and description",
+            Self::Checklist => "checklist item",
+        }
+    }
+
+    pub fn line(&self, uuid: uuid::Uuid, description: &str, status: &TaskStatus) -> String {
+        match self {
+            Self::Uuid => uuid.to_string(),
+            Self::Description => description.to_string(),
+            Self::UuidAndDescription => format!("{} {}", uuid, description),
+            Self::Checklist => {
+                let mark = if matches!(status, TaskStatus::Completed) {
+                    'x'
+                } else {
+                    ' '
+                };
+                format!("- [{}] {}", mark, description)
+            }
+        }
+    }
+}
+
+pub fn tasks_to_clipboard(tasks: &[&TaskSummary], format: ClipboardFormat) -> String {
+    tasks
+        .iter()
+        .map(|task| format.line(task.uuid, &task.description, &task.status))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn markdown_field(detail: &TaskDetailVm, key: &str) -> Option<String> {
+    let date = |date: Option<DateTime<Utc>>| {
+        date.map(|date| date.format(DATE_FORMAT).to_string())
+            .unwrap_or_default()
+    };
+
+    let value = match key {
+        "check" => match detail.overview.status {
+            TaskStatus::Completed => "x".to_string(),
+            _ => " ".to_string(),
+        },
+        "id" => detail
+            .identity
+            .working_id
+            .or(detail.identity.id)
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| detail.identity.uuid.to_string()),
+        "uuid" => detail.identity.uuid.to_string(),
+        "description" => detail.overview.description.clone(),
+        "status" => status_key(&detail.overview.status),
+        "project" => detail.overview.project.clone().unwrap_or_default(),
+        "priority" => match detail.overview.priority {
+            TaskPriority::None => String::new(),
+            priority => priority.to_string(),
+        },
+        "due" => date(detail.dates.due),
+        "scheduled" => date(detail.dates.scheduled),
+        "tags" => detail.tags.tags.join(", "),
+        _ => return None,
+    };
+    Some(value)
+}
+
+// Lines whose placeholders all expand to nothing are dropped, so the default
+// template skips "Project:" for a task without a project.
+pub fn to_markdown(detail: &TaskDetailVm, template: &str) -> String {
+    template
+        .lines()
+        .filter_map(|line| {
+            let mut output = String::new();
+            let mut rest = line;
+            let mut placeholders = 0;
+            let mut filled = 0;
+
+            while let Some(start) = rest.find('{') {
+                let Some(len) = rest[start..].find('}') else {
+                    break;
+                };
+                let key = &rest[start + 1..start + len];
+                output.push_str(&rest[..start]);
+                match markdown_field(detail, key) {
+                    Some(value) => {
+                        placeholders += 1;
+                        if !value.trim().is_empty() {
+                            filled += 1;
+                        }
+                        output.push_str(&value);
+                    }
+                    None => output.push_str(&rest[start..=start + len]),
+                }
+                rest = &rest[start + len + 1..];
+            }
+            output.push_str(rest);
+
+            (placeholders == 0 || filled > 0).then_some(output)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn write_export(path: &Path, contents: &str) -> TaskResult<()> {
+    std::fs::write(path, contents).map_err(|e| {
+        log::error!(
+            "export::write_export: Failed to write {}: {}",
+            path.display(),
+            e
+        );
+        TaskError::Export(format!("{}: {}", path.display(), e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn summary(description: &str) -> TaskSummary {
+        TaskSummary {
+            uuid: uuid::Uuid::new_v4(),
+            description: description.to_string(),
+            ..TaskSummary::default()
+        }
+    }
+
+    #[test]
+    fn test_clipboard_formats_one_line_per_task() {
+        let first = summary("Buy milk");
+        let mut second = summary("Call mom");
+        second.status = TaskStatus::Completed;
+        let tasks = [&first, &second];
+
+        assert_eq!(
+            tasks_to_clipboard(&tasks, ClipboardFormat::Description),
+            "Buy milk\nCall mom"
+        );
+        assert_eq!(
+            tasks_to_clipboard(&tasks, ClipboardFormat::Checklist),
+            "- [ ] Buy milk\n- [x] Call mom"
+        );
+        assert_eq!(
+            tasks_to_clipboard(&tasks[..1], ClipboardFormat::UuidAndDescription),
+            format!("{} Buy milk", first.uuid)
+        );
+    }
+
+    #[test]
+    fn test_markdown_skips_empty_fields() {
+        let task = crate::task::Task {
+            uuid: uuid::Uuid::new_v4(),
+            id: Some(42),
+            status: TaskStatus::Pending,
+            description: "Buy milk".to_string(),
+            project: Some("Shopping".to_string()),
+            priority: TaskPriority::High,
+            tags: ["urgent".to_string(), "personal".to_string()].into(),
+            due: Some(Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap()),
+            scheduled: None,
+            wait: None,
+            entry: None,
+            modified: None,
+            start: None,
+            end: None,
+            until: None,
+            recur: None,
+            annotations: vec![],
+            dependencies: Default::default(),
+            is_active: false,
+            is_blocked: false,
+            is_blocking: false,
+            working_id: Some(42),
+            udas: HashMap::new(),
+        };
+        let mut detail = TaskDetailVm::from_task(&task, &[]);
+
+        assert_eq!(
+            to_markdown(&detail, DEFAULT_MARKDOWN_TEMPLATE),
+            "- [ ] #42 Buy milk\n  - **Project:** Shopping\n  - **Priority:** High\n  - **Due:** 2025-06-15\n  - **Tags:** personal, urgent"
+        );
+
+        detail.overview.project = None;
+        detail.overview.status = TaskStatus::Completed;
+        assert_eq!(
+            to_markdown(&detail, "- [{check}] {description} {unknown}\n  {project}"),
+            "- [x] Buy milk {unknown}"
+        );
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("tasks.CSV")),
+            ExportFormat::Csv
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("tasks.json")),
+            ExportFormat::Json
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("tasks")),
+            ExportFormat::Json
+        );
+    }
+
+    #[test]
+    fn test_json_uses_taskwarrior_fields() {
+        let mut task = summary("Write report");
+        task.project = Some("work".to_string());
+        task.tags = ["b".to_string(), "a".to_string()].into_iter().collect();
+        task.due = Some(Utc.with_ymd_and_hms(2026, 10, 16, 9, 30, 0).unwrap());
+        let annotations = HashMap::from([(
+            task.uuid,
+            vec![TaskAnnotation {
+                entry: Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap(),
+                content: "draft sent".to_string(),
+            }],
+        )]);
+
+        let value: Value = serde_json::from_str(&tasks_to_json(&[task], &annotations)).unwrap();
+        let entry = &value[0];
+        assert_eq!(entry["status"], "pending");
+        assert_eq!(entry["project"], "work");
+        assert_eq!(entry["tags"], json!(["a", "b"]));
+        assert_eq!(entry["due"], "20261016T093000Z");
+        assert_eq!(entry["annotations"][0]["description"], "draft sent");
+    }
+
+    #[test]
+    fn test_csv_escapes_fields() {
+        let csv = rows_to_csv(
+            &["ID", "Description"],
+            &[vec!["1".to_string(), "Say \"hi\", then leave".to_string()]],
+        );
+        assert_eq!(csv, "ID,Description\n1,\"Say \"\"hi\"\", then leave\"\n");
+    }
+}
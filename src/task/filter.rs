@@ -1,9 +1,13 @@
 use std::collections::HashSet;
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
 
 use super::model::{TaskPriority, TaskStatus, TaskSummary};
+use super::parser::parse_search_query;
 use crate::models::{DueFilter, FilterState, PriorityFilter, StatusFilter};
+use crate::ui::DATE_FORMAT;
+
+pub const DEFAULT_DUE_SOON_DAYS: u32 = 3;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TagsFilterMode {
@@ -16,14 +20,23 @@ pub enum TagsFilterMode {
 pub enum DueDateFilter {
     Overdue,
     Today,
+    Soon,
     ThisWeek,
+    NextWeek,
+    NextMonth,
     NoDate,
     Before(DateTime<Utc>),
     After(DateTime<Utc>),
     OnDate(NaiveDate),
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduledFilter {
+    Today,
+    ThisWeek,
+}
+
+#[derive(Debug, Clone)]
 pub struct TaskFilter {
     pub status: Option<TaskStatus>,
     pub project: Option<String>,
@@ -32,9 +45,79 @@ pub struct TaskFilter {
     pub tags_mode: TagsFilterMode,
     pub priority: Option<TaskPriority>,
     pub due_filter: Option<DueDateFilter>,
+    pub due_soon: Duration,
+    pub scheduled_filter: Option<ScheduledFilter>,
+    pub hide_scheduled: bool,
     pub search_text: Option<String>,
+    pub search_annotations: bool,
     pub is_active: Option<bool>,
     pub is_blocked: Option<bool>,
+    pub blocks_others: Option<bool>,
+    pub is_waiting: Option<bool>,
+    pub pinned: HashSet<uuid::Uuid>,
+    pub show_pinned: bool,
+    pub any_of: Vec<TaskFilter>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterPreset {
+    TodayFocus,
+}
+
+impl FilterPreset {
+    pub fn label(&self) -> &'static str {
+        match self {
+            FilterPreset::TodayFocus => "Today's focus",
+        }
+    }
+
+    pub fn filter(&self) -> TaskFilter {
+        match self {
+            FilterPreset::TodayFocus => Self::today_focus(),
+        }
+    }
+
+    pub fn today_focus() -> TaskFilter {
+        let soon = Utc::now() + chrono::Duration::days(3);
+        let mut active = TaskFilter::new();
+        active.is_active = Some(true);
+
+        let mut filter = TaskFilter::new().with_status(TaskStatus::Pending);
+        filter.any_of = vec![
+            TaskFilter::new().with_due(DueDateFilter::Today),
+            active,
+            TaskFilter::new()
+                .with_priority(TaskPriority::High)
+                .with_due(DueDateFilter::Before(soon)),
+        ];
+        filter
+    }
+}
+
+impl Default for TaskFilter {
+    fn default() -> Self {
+        Self {
+            status: None,
+            project: None,
+            project_include_children: false,
+            tags: HashSet::new(),
+            tags_mode: TagsFilterMode::default(),
+            priority: None,
+            due_filter: None,
+            due_soon: Duration::days(i64::from(DEFAULT_DUE_SOON_DAYS)),
+            scheduled_filter: None,
+            hide_scheduled: false,
+            search_text: None,
+            search_annotations: false,
+            is_active: None,
+            is_blocked: None,
+            blocks_others: None,
+            is_waiting: None,
+            pinned: HashSet::new(),
+            show_pinned: true,
+            any_of: Vec::new(),
+        }
+    }
 }
 
 impl TaskFilter {
@@ -75,6 +158,120 @@ impl TaskFilter {
         }
         self
     }
+
+    pub fn with_annotation_search(mut self, enabled: bool) -> Self {
+        self.search_annotations = enabled;
+        self
+    }
+
+    pub fn overlay(&mut self, other: TaskFilter) {
+        if other.status.is_some() {
+            self.status = other.status;
+        }
+        if other.project.is_some() {
+            self.project = other.project;
+            self.project_include_children = other.project_include_children;
+        }
+        self.tags.extend(other.tags);
+        if other.priority.is_some() {
+            self.priority = other.priority;
+        }
+        if other.due_filter.is_some() {
+            self.due_filter = other.due_filter;
+        }
+        if other.scheduled_filter.is_some() {
+            self.scheduled_filter = other.scheduled_filter;
+        }
+        if other.search_text.is_some() {
+            self.search_text = other.search_text;
+        }
+        self.any_of.extend(other.any_of);
+    }
+}
+
+impl TaskFilter {
+    pub fn to_display_string(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(status) = &self.status {
+            parts.push(status.to_string());
+        }
+        if self.is_waiting == Some(true) {
+            parts.push("Waiting".to_string());
+        }
+        if self.is_active == Some(true) {
+            parts.push("active".to_string());
+        }
+
+        if let Some(project) = &self.project {
+            let key = if self.project_include_children {
+                "project"
+            } else {
+                "project.is"
+            };
+            parts.push(format!("{}:{}", key, project));
+        }
+
+        if !self.tags.is_empty() {
+            let mut tags: Vec<&str> = self.tags.iter().map(String::as_str).collect();
+            tags.sort_unstable();
+            let separator = match self.tags_mode {
+                TagsFilterMode::And => "+",
+                TagsFilterMode::Or => ",",
+            };
+            parts.push(format!("tags:{}", tags.join(separator)));
+        }
+
+        if let Some(priority) = self.priority {
+            parts.push(format!("priority:{}", priority));
+        }
+        if self.is_blocked == Some(true) {
+            parts.push("blocked".to_string());
+        }
+        if self.blocks_others == Some(true) {
+            parts.push("blocking".to_string());
+        }
+
+        if let Some(due) = self.due_filter {
+            parts.push(match due {
+                DueDateFilter::Overdue => "due:Overdue".to_string(),
+                DueDateFilter::Today => "due:Today".to_string(),
+                DueDateFilter::Soon => "due:Soon".to_string(),
+                DueDateFilter::ThisWeek => "due:This week".to_string(),
+                DueDateFilter::NextWeek => "due:Next week".to_string(),
+                DueDateFilter::NextMonth => "due:Next month".to_string(),
+                DueDateFilter::NoDate => "due:none".to_string(),
+                DueDateFilter::Before(date) => format!("due.before:{}", date.format(DATE_FORMAT)),
+                DueDateFilter::After(date) => format!("due.after:{}", date.format(DATE_FORMAT)),
+                DueDateFilter::OnDate(date) => format!("due:{}", date.format(DATE_FORMAT)),
+            });
+        }
+        if let Some(scheduled) = self.scheduled_filter {
+            parts.push(match scheduled {
+                ScheduledFilter::Today => "scheduled:Today".to_string(),
+                ScheduledFilter::ThisWeek => "scheduled:This week".to_string(),
+            });
+        }
+
+        if !self.any_of.is_empty() {
+            let alternatives: Vec<String> = self
+                .any_of
+                .iter()
+                .map(TaskFilter::to_display_string)
+                .collect();
+            parts.push(format!("({})", alternatives.join(" or ")));
+        }
+
+        if let Some(text) = &self.search_text {
+            if self.search_annotations {
+                parts.push(format!("\"{}\" (incl. annotations)", text));
+            } else {
+                parts.push(format!("\"{}\"", text));
+            }
+        }
+
+        parts.join(" | ")
+    }
 }
 
 impl From<&FilterState> for TaskFilter {
@@ -85,39 +282,68 @@ impl From<&FilterState> for TaskFilter {
             StatusFilter::All => None,
             StatusFilter::Pending => Some(TaskStatus::Pending),
             StatusFilter::Completed => Some(TaskStatus::Completed),
-            StatusFilter::Waiting => Some(TaskStatus::Pending),
+            StatusFilter::Waiting => None,
             StatusFilter::Deleted => Some(TaskStatus::Deleted),
         };
+        if state.status_filter == StatusFilter::Waiting {
+            filter.is_waiting = Some(true);
+        }
 
         if let Some(ref project) = state.selected_project {
             filter.project = Some(project.clone());
-            filter.project_include_children = true;
+            filter.project_include_children = !state.exclude_subprojects;
         }
 
         if !state.active_tags.is_empty() {
             filter.tags = state.active_tags.clone();
-            filter.tags_mode = TagsFilterMode::And;
+            filter.tags_mode = state.tags_mode;
         }
 
+        filter.search_annotations = state.search_annotations;
+
         filter.priority = match state.priority_filter {
             PriorityFilter::All => None,
             PriorityFilter::High => Some(TaskPriority::High),
             PriorityFilter::Medium => Some(TaskPriority::Medium),
             PriorityFilter::Low => Some(TaskPriority::Low),
             PriorityFilter::None => Some(TaskPriority::None),
+            PriorityFilter::Blocked | PriorityFilter::Blocking => None,
         };
+        match state.priority_filter {
+            PriorityFilter::Blocked => filter.is_blocked = Some(true),
+            PriorityFilter::Blocking => filter.blocks_others = Some(true),
+            _ => {}
+        }
 
         filter.due_filter = match state.due_filter {
             DueFilter::All => None,
             DueFilter::Overdue => Some(DueDateFilter::Overdue),
             DueFilter::Today => Some(DueDateFilter::Today),
+            DueFilter::Soon => Some(DueDateFilter::Soon),
             DueFilter::ThisWeek => Some(DueDateFilter::ThisWeek),
+            DueFilter::NextWeek => Some(DueDateFilter::NextWeek),
+            DueFilter::NextMonth => Some(DueDateFilter::NextMonth),
             DueFilter::NoDate => Some(DueDateFilter::NoDate),
             DueFilter::OnDate(date) => Some(DueDateFilter::OnDate(date)),
+            DueFilter::ScheduledToday | DueFilter::ScheduledThisWeek => None,
+        };
+        filter.scheduled_filter = match state.due_filter {
+            DueFilter::ScheduledToday => Some(ScheduledFilter::Today),
+            DueFilter::ScheduledThisWeek => Some(ScheduledFilter::ThisWeek),
+            _ => None,
         };
+        filter.hide_scheduled =
+            state.hide_scheduled && state.status_filter == StatusFilter::Pending;
+
+        if let Some(preset) = state.preset {
+            filter.overlay(preset.filter());
+        }
+
+        let (query, free_text) = parse_search_query(&state.search_text);
+        filter.overlay(query);
 
-        if !state.search_text.is_empty() {
-            filter.search_text = Some(state.search_text.to_lowercase());
+        if !free_text.is_empty() {
+            filter.search_text = Some(free_text.to_lowercase());
         }
 
         filter
@@ -135,10 +361,7 @@ impl TaskFilter {
         if let Some(status) = &self.status {
             match status {
                 TaskStatus::Pending => {
-                    if !matches!(task.status, TaskStatus::Pending) {
-                        return false;
-                    }
-                    if task.wait.map(|w| w > Utc::now()).unwrap_or(false) {
+                    if !matches!(task.status, TaskStatus::Pending) || task.is_waiting() {
                         return false;
                     }
                 }
@@ -150,15 +373,19 @@ impl TaskFilter {
             }
         }
 
+        if let Some(is_waiting) = self.is_waiting {
+            if task.is_waiting() != is_waiting {
+                return false;
+            }
+        }
+
         if let Some(project) = &self.project {
             match &task.project {
+                None if project.is_empty() => {}
                 None => return false,
+                Some(_) if project.is_empty() => return false,
                 Some(task_project) => {
-                    if self.project_include_children {
-                        if !task_project.starts_with(project) {
-                            return false;
-                        }
-                    } else if task_project != project {
+                    if !project_matches(task_project, project, self.project_include_children) {
                         return false;
                     }
                 }
@@ -201,6 +428,11 @@ impl TaskFilter {
                         return false;
                     }
                 }
+                DueDateFilter::Soon => {
+                    if !task.is_due_soon(self.due_soon) {
+                        return false;
+                    }
+                }
                 DueDateFilter::ThisWeek => {
                     let is_due_this_week = task
                         .due
@@ -215,6 +447,22 @@ impl TaskFilter {
                         return false;
                     }
                 }
+                DueDateFilter::NextWeek | DueDateFilter::NextMonth => {
+                    let today = Utc::now().date_naive();
+                    let (start, end) = if *due_filter == DueDateFilter::NextWeek {
+                        next_week_range(today)
+                    } else {
+                        next_month_range(today)
+                    };
+                    let in_range = task
+                        .due
+                        .map(|d| d.date_naive())
+                        .is_some_and(|date| date >= start && date <= end);
+
+                    if !in_range {
+                        return false;
+                    }
+                }
                 DueDateFilter::NoDate => {
                     if task.due.is_some() {
                         return false;
@@ -238,6 +486,26 @@ impl TaskFilter {
             }
         }
 
+        if let Some(scheduled_filter) = &self.scheduled_filter {
+            let today = Utc::now().date_naive();
+            let Some(scheduled) = task.scheduled.map(|s| s.date_naive()) else {
+                return false;
+            };
+            let in_range = match scheduled_filter {
+                ScheduledFilter::Today => scheduled == today,
+                ScheduledFilter::ThisWeek => {
+                    scheduled >= today && scheduled <= today + chrono::Duration::days(7)
+                }
+            };
+            if !in_range {
+                return false;
+            }
+        }
+
+        if self.hide_scheduled && task.is_scheduled_future() {
+            return false;
+        }
+
         if let Some(search) = &self.search_text {
             let desc_match = task.description.to_lowercase().contains(search);
             let proj_match = task
@@ -246,8 +514,13 @@ impl TaskFilter {
                 .map(|p| p.to_lowercase().contains(search))
                 .unwrap_or(false);
             let tag_match = task.tags.iter().any(|t| t.to_lowercase().contains(search));
+            let annotation_match = self.search_annotations
+                && task
+                    .annotations
+                    .iter()
+                    .any(|a| a.to_lowercase().contains(search));
 
-            if !desc_match && !proj_match && !tag_match {
+            if !desc_match && !proj_match && !tag_match && !annotation_match {
                 return false;
             }
         }
@@ -264,10 +537,555 @@ impl TaskFilter {
             }
         }
 
+        if let Some(blocks_others) = self.blocks_others {
+            if task.is_blocking != blocks_others {
+                return false;
+            }
+        }
+
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|filter| filter.matches(task)) {
+            return false;
+        }
+
         true
     }
 
+    fn keeps_pinned(&self, task: &TaskSummary) -> bool {
+        self.show_pinned
+            && self.pinned.contains(&task.uuid)
+            && !matches!(task.status, TaskStatus::Deleted)
+    }
+
     pub fn apply(&self, tasks: &[TaskSummary]) -> Vec<TaskSummary> {
-        tasks.iter().filter(|t| self.matches(t)).cloned().collect()
+        tasks
+            .iter()
+            .filter(|t| self.matches(t) || self.keeps_pinned(t))
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn next_week_range(today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let week = (today + Duration::days(7)).iso_week();
+    let monday =
+        NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon).unwrap_or(today);
+    (monday, monday + Duration::days(6))
+}
+
+pub fn next_month_range(today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let (year, month) = if today.month() == 12 {
+        (today.year() + 1, 1)
+    } else {
+        (today.year(), today.month() + 1)
+    };
+    let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(today);
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let last = NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .map(|date| date - Duration::days(1))
+        .unwrap_or(first);
+    (first, last)
+}
+
+fn project_matches(task_project: &str, project: &str, include_children: bool) -> bool {
+    if task_project == project {
+        return true;
+    }
+
+    include_children
+        && task_project
+            .strip_prefix(project)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_project_matches_only_on_segment_boundary() {
+        assert!(project_matches("Work", "Work", true));
+        assert!(project_matches("Work.Backend.API", "Work", true));
+        assert!(!project_matches("Workshop", "Work", true));
+        assert!(!project_matches("Work.Backend", "Work", false));
+        assert!(project_matches("Work", "Work", false));
+    }
+
+    #[test]
+    fn test_next_week_and_next_month_ranges() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            next_week_range(date(2026, 10, 14)),
+            (date(2026, 10, 19), date(2026, 10, 25))
+        );
+        assert_eq!(
+            next_week_range(date(2026, 10, 18)),
+            (date(2026, 10, 19), date(2026, 10, 25))
+        );
+        assert_eq!(
+            next_week_range(date(2026, 12, 30)),
+            (date(2027, 1, 4), date(2027, 1, 10))
+        );
+
+        assert_eq!(
+            next_month_range(date(2026, 1, 31)),
+            (date(2026, 2, 1), date(2026, 2, 28))
+        );
+        assert_eq!(
+            next_month_range(date(2026, 11, 15)),
+            (date(2026, 12, 1), date(2026, 12, 31))
+        );
+        assert_eq!(
+            next_month_range(date(2026, 12, 3)),
+            (date(2027, 1, 1), date(2027, 1, 31))
+        );
+    }
+
+    fn scheduled_task(days_from_now: Option<i64>) -> TaskSummary {
+        TaskSummary {
+            uuid: uuid::Uuid::new_v4(),
+            description: "scheduled".to_string(),
+            scheduled: days_from_now.map(|days| Utc::now() + chrono::Duration::days(days)),
+            ..TaskSummary::default()
+        }
+    }
+
+    #[test]
+    fn test_apply_on_summaries_matches_full_tasks() {
+        use crate::task::{Task, TaskAnnotation};
+
+        let mut work = Task {
+            uuid: uuid::Uuid::new_v4(),
+            description: "Ship release".to_string(),
+            project: Some("Work.Backend".to_string()),
+            priority: TaskPriority::High,
+            tags: HashSet::from(["next".to_string()]),
+            due: Some(Utc::now() - chrono::Duration::days(1)),
+            ..Task::default()
+        };
+        work.annotations.push(TaskAnnotation {
+            entry: Utc::now(),
+            content: "waiting on QA sign-off".to_string(),
+        });
+        let home = Task {
+            uuid: uuid::Uuid::new_v4(),
+            description: "Water plants".to_string(),
+            project: Some("Home".to_string()),
+            wait: Some(Utc::now() + chrono::Duration::days(2)),
+            ..Task::default()
+        };
+        let done = Task {
+            uuid: uuid::Uuid::new_v4(),
+            description: "Old chore".to_string(),
+            status: TaskStatus::Completed,
+            ..Task::default()
+        };
+
+        let tasks = [work.clone(), home.clone(), done.clone()];
+        let summaries: Vec<TaskSummary> = tasks.iter().map(TaskSummary::from).collect();
+        let matched = |filter: TaskFilter| -> Vec<uuid::Uuid> {
+            filter
+                .apply(&summaries)
+                .into_iter()
+                .map(|task| task.uuid)
+                .collect()
+        };
+
+        assert_eq!(
+            matched(TaskFilter::new().with_status(TaskStatus::Pending)),
+            vec![work.uuid]
+        );
+        assert_eq!(
+            matched(TaskFilter::new().with_project("Work".to_string(), true)),
+            vec![work.uuid]
+        );
+        assert_eq!(
+            matched(TaskFilter::new().with_due(DueDateFilter::Overdue)),
+            vec![work.uuid]
+        );
+        assert_eq!(
+            matched(TaskFilter::new().with_priority(TaskPriority::High)),
+            vec![work.uuid]
+        );
+        assert_eq!(
+            matched(TaskFilter::new().with_status(TaskStatus::Completed)),
+            vec![done.uuid]
+        );
+        assert!(matched(TaskFilter::new().with_search("qa".to_string())).is_empty());
+        assert_eq!(
+            matched(
+                TaskFilter::new()
+                    .with_search("qa".to_string())
+                    .with_annotation_search(true)
+            ),
+            vec![work.uuid]
+        );
+        assert_eq!(matched(TaskFilter::new()).len(), tasks.len());
+    }
+
+    #[test]
+    fn test_annotation_search_matches_within_one_annotation() {
+        let task = TaskSummary {
+            annotations: vec!["buy milk".to_string(), "call Bob".to_string()],
+            ..scheduled_task(None)
+        };
+        let matches = |search: &str| {
+            TaskFilter::new()
+                .with_search(search.to_string())
+                .with_annotation_search(true)
+                .matches(&task)
+        };
+
+        assert!(matches("milk"));
+        assert!(matches("call bob"));
+        assert!(!matches("milk | call"));
+        assert!(!matches("|"));
+    }
+
+    #[test]
+    fn test_waiting_status_shows_only_future_wait_dates() {
+        let waiting_until = |days: Option<i64>| TaskSummary {
+            wait: days.map(|days| Utc::now() + chrono::Duration::days(days)),
+            ..scheduled_task(None)
+        };
+        let waiting = FilterState {
+            status_filter: StatusFilter::Waiting,
+            ..FilterState::default()
+        };
+        let filter = TaskFilter::from(&waiting);
+
+        assert!(filter.matches(&waiting_until(Some(2))));
+        assert!(!filter.matches(&waiting_until(Some(-2))));
+        assert!(!filter.matches(&waiting_until(None)));
+        assert!(!filter.matches(&TaskSummary {
+            status: TaskStatus::Completed,
+            ..waiting_until(Some(2))
+        }));
+
+        let pending = TaskFilter::from(&FilterState::default());
+        assert!(!pending.matches(&waiting_until(Some(2))));
+        assert!(pending.matches(&waiting_until(Some(-2))));
+    }
+
+    #[test]
+    fn test_pinned_tasks_bypass_the_filter() {
+        let pinned = TaskSummary {
+            status: TaskStatus::Completed,
+            ..scheduled_task(None)
+        };
+        let deleted = TaskSummary {
+            status: TaskStatus::Deleted,
+            ..scheduled_task(None)
+        };
+        let other = TaskSummary {
+            status: TaskStatus::Completed,
+            ..scheduled_task(None)
+        };
+        let tasks = vec![pinned.clone(), deleted.clone(), other];
+
+        let mut filter = TaskFilter::new().with_status(TaskStatus::Pending);
+        filter.pinned = HashSet::from([pinned.uuid, deleted.uuid]);
+        let kept: Vec<uuid::Uuid> = filter.apply(&tasks).iter().map(|t| t.uuid).collect();
+        assert_eq!(kept, vec![pinned.uuid]);
+
+        filter.show_pinned = false;
+        assert!(filter.apply(&tasks).is_empty());
+    }
+
+    #[test]
+    fn test_inbox_project_matches_only_tasks_without_project() {
+        let state = FilterState {
+            selected_project: Some(String::new()),
+            ..FilterState::default()
+        };
+        let filter = TaskFilter::from(&state);
+
+        assert!(filter.matches(&scheduled_task(None)));
+        assert!(!filter.matches(&TaskSummary {
+            project: Some("Work".to_string()),
+            ..scheduled_task(None)
+        }));
+    }
+
+    #[test]
+    fn test_due_soon_includes_the_threshold_boundary() {
+        let due_in_hours = |hours: i64| TaskSummary {
+            due: Some(Utc::now() + Duration::hours(hours)),
+            ..scheduled_task(None)
+        };
+        let filter = TaskFilter::new().with_due(DueDateFilter::Soon);
+
+        assert!(filter.matches(&due_in_hours(1)));
+        assert!(filter.matches(&due_in_hours(72)));
+        assert!(!filter.matches(&due_in_hours(73)));
+        assert!(!filter.matches(&due_in_hours(-1)));
+        assert!(!filter.matches(&scheduled_task(None)));
+
+        let week = TaskFilter {
+            due_soon: Duration::days(7),
+            ..filter
+        };
+        assert!(week.matches(&due_in_hours(73)));
+    }
+
+    #[test]
+    fn test_today_focus_matches_any_urgent_subset() {
+        let filter = TaskFilter::from(&FilterState {
+            preset: Some(FilterPreset::TodayFocus),
+            ..FilterState::default()
+        });
+        let now = Utc::now();
+
+        assert!(filter.matches(&TaskSummary {
+            is_active: true,
+            ..scheduled_task(None)
+        }));
+        assert!(filter.matches(&TaskSummary {
+            priority: TaskPriority::High,
+            due: Some(now + chrono::Duration::days(2)),
+            ..scheduled_task(None)
+        }));
+        assert!(!filter.matches(&TaskSummary {
+            priority: TaskPriority::High,
+            due: Some(now + chrono::Duration::days(5)),
+            ..scheduled_task(None)
+        }));
+        assert!(!filter.matches(&TaskSummary {
+            priority: TaskPriority::Low,
+            due: Some(now + chrono::Duration::days(2)),
+            ..scheduled_task(None)
+        }));
+        assert!(!filter.matches(&TaskSummary {
+            status: TaskStatus::Completed,
+            is_active: true,
+            ..scheduled_task(None)
+        }));
+        assert!(!filter.matches(&scheduled_task(None)));
+    }
+
+    #[test]
+    fn test_blocked_priority_filter_keeps_only_blocked_tasks() {
+        let state = FilterState {
+            priority_filter: PriorityFilter::Blocked,
+            ..FilterState::default()
+        };
+        let filter = TaskFilter::from(&state);
+
+        assert!(filter.matches(&TaskSummary {
+            is_blocked: true,
+            priority: TaskPriority::High,
+            ..scheduled_task(None)
+        }));
+        assert!(!filter.matches(&scheduled_task(None)));
+    }
+
+    #[test]
+    fn test_hide_scheduled_only_hides_future_tasks() {
+        let state = FilterState {
+            hide_scheduled: true,
+            ..FilterState::default()
+        };
+        let filter = TaskFilter::from(&state);
+
+        assert!(filter.matches(&scheduled_task(None)));
+        assert!(filter.matches(&scheduled_task(Some(-1))));
+        assert!(!filter.matches(&scheduled_task(Some(3))));
+    }
+
+    #[test]
+    fn test_scheduled_this_week_filter() {
+        let state = FilterState {
+            due_filter: DueFilter::ScheduledThisWeek,
+            ..FilterState::default()
+        };
+        let filter = TaskFilter::from(&state);
+
+        assert!(filter.due_filter.is_none());
+        assert!(filter.matches(&scheduled_task(Some(2))));
+        assert!(!filter.matches(&scheduled_task(Some(10))));
+        assert!(!filter.matches(&scheduled_task(None)));
+    }
+
+    #[test]
+    fn test_display_string_summarizes_active_filters() {
+        let state = FilterState {
+            selected_project: Some("Work".to_string()),
+            active_tags: ["urgent", "blocker"]
+                .iter()
+                .map(|tag| tag.to_string())
+                .collect(),
+            due_filter: DueFilter::Today,
+            ..FilterState::default()
+        };
+        assert_eq!(
+            TaskFilter::from(&state).to_display_string(),
+            "Pending | project:Work | tags:blocker+urgent | due:Today"
+        );
+
+        let state = FilterState {
+            status_filter: StatusFilter::All,
+            priority_filter: PriorityFilter::Blocked,
+            search_text: "report".to_string(),
+            ..FilterState::default()
+        };
+        assert_eq!(
+            TaskFilter::from(&state).to_display_string(),
+            "blocked | \"report\""
+        );
+    }
+
+    fn fixture_task(description: &str, project: Option<&str>, tags: &[&str]) -> TaskSummary {
+        TaskSummary {
+            description: description.to_string(),
+            project: project.map(str::to_string),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..scheduled_task(None)
+        }
+    }
+
+    fn filter_fixture() -> Vec<TaskSummary> {
+        vec![
+            TaskSummary {
+                priority: TaskPriority::High,
+                due: Some(Utc::now() + chrono::Duration::days(1)),
+                ..fixture_task("Ship release", Some("Work.Backend"), &["next", "review"])
+            },
+            fixture_task("Prep workshop", Some("Workshop"), &["next"]),
+            TaskSummary {
+                status: TaskStatus::Completed,
+                ..fixture_task("File report", Some("Work"), &[])
+            },
+            TaskSummary {
+                wait: Some(Utc::now() + chrono::Duration::days(2)),
+                ..fixture_task("Water plants", Some("Home"), &[])
+            },
+            TaskSummary {
+                due: Some(Utc.with_ymd_and_hms(2025, 3, 14, 23, 30, 0).unwrap()),
+                ..fixture_task("Pay rent", Some("Home"), &[])
+            },
+            fixture_task("Call Sam", None, &["errand"]),
+            TaskSummary {
+                status: TaskStatus::Deleted,
+                ..fixture_task("Dropped idea", Some("Work"), &[])
+            },
+        ]
+    }
+
+    fn snapshot(state: FilterState) -> Vec<String> {
+        let mut matched: Vec<String> = TaskFilter::from(&state)
+            .apply(&filter_fixture())
+            .into_iter()
+            .map(|task| task.description)
+            .collect();
+        matched.sort();
+        matched
+    }
+
+    #[test]
+    fn test_filter_state_snapshots() {
+        let all = FilterState {
+            status_filter: StatusFilter::All,
+            ..FilterState::default()
+        };
+        let pending = FilterState {
+            status_filter: StatusFilter::Pending,
+            ..FilterState::default()
+        };
+        let tags = |names: &[&str], mode: TagsFilterMode| FilterState {
+            active_tags: names.iter().map(|tag| tag.to_string()).collect(),
+            tags_mode: mode,
+            ..pending.clone()
+        };
+        let on_date = |day: u32| FilterState {
+            due_filter: DueFilter::OnDate(NaiveDate::from_ymd_opt(2025, 3, day).unwrap()),
+            ..pending.clone()
+        };
+        let search = |text: &str| FilterState {
+            search_text: text.to_string(),
+            ..pending.clone()
+        };
+
+        assert_eq!(snapshot(all.clone()).len(), 7);
+        assert_eq!(
+            snapshot(pending.clone()),
+            ["Call Sam", "Pay rent", "Prep workshop", "Ship release"]
+        );
+        assert_eq!(
+            snapshot(FilterState {
+                status_filter: StatusFilter::Waiting,
+                ..FilterState::default()
+            }),
+            ["Water plants"]
+        );
+        assert_eq!(
+            snapshot(FilterState {
+                selected_project: Some("Work".to_string()),
+                ..all.clone()
+            }),
+            ["Dropped idea", "File report", "Ship release"]
+        );
+        assert_eq!(
+            snapshot(FilterState {
+                selected_project: Some("Work".to_string()),
+                exclude_subprojects: true,
+                ..all.clone()
+            }),
+            ["Dropped idea", "File report"]
+        );
+        assert_eq!(
+            snapshot(tags(&["next", "review"], TagsFilterMode::And)),
+            ["Ship release"]
+        );
+        assert_eq!(
+            snapshot(tags(&["next", "review"], TagsFilterMode::Or)),
+            ["Prep workshop", "Ship release"]
+        );
+        assert_eq!(snapshot(on_date(14)), ["Pay rent"]);
+        assert!(snapshot(on_date(15)).is_empty());
+        assert_eq!(snapshot(search("errand")), ["Call Sam"]);
+        assert_eq!(snapshot(search("work")), ["Prep workshop", "Ship release"]);
+    }
+
+    #[test]
+    fn test_filter_state_combinations() {
+        let combined = FilterState {
+            status_filter: StatusFilter::Pending,
+            selected_project: Some("Work".to_string()),
+            active_tags: HashSet::from(["next".to_string()]),
+            priority_filter: PriorityFilter::High,
+            due_filter: DueFilter::Soon,
+            search_text: "ship".to_string(),
+            ..FilterState::default()
+        };
+        assert_eq!(snapshot(combined.clone()), ["Ship release"]);
+        assert!(
+            snapshot(FilterState {
+                search_text: "workshop".to_string(),
+                ..combined.clone()
+            })
+            .is_empty()
+        );
+        assert_eq!(
+            snapshot(FilterState {
+                status_filter: StatusFilter::Completed,
+                selected_project: Some("Work".to_string()),
+                ..FilterState::default()
+            }),
+            ["File report"]
+        );
+        assert_eq!(
+            snapshot(FilterState {
+                status_filter: StatusFilter::Pending,
+                selected_project: Some("Home".to_string()),
+                due_filter: DueFilter::NoDate,
+                ..FilterState::default()
+            }),
+            Vec::<String>::new()
+        );
     }
 }
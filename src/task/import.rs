@@ -0,0 +1,184 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+
+use crate::task::export::TASKWARRIOR_DATE_FORMAT;
+use crate::task::{TaskAnnotation, TaskError, TaskResult, TaskStatus};
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportedTask {
+    pub uuid: Option<uuid::Uuid>,
+    pub description: String,
+    pub status: TaskStatus,
+    pub project: Option<String>,
+    pub priority: Option<String>,
+    pub tags: Vec<String>,
+    pub entry: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub due: Option<DateTime<Utc>>,
+    pub scheduled: Option<DateTime<Utc>>,
+    pub wait: Option<DateTime<Utc>>,
+    pub annotations: Vec<TaskAnnotation>,
+    pub depends: Vec<uuid::Uuid>,
+}
+
+pub fn parse_taskwarrior_date(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value.trim(), TASKWARRIOR_DATE_FORMAT)
+        .ok()
+        .map(|date| date.and_utc())
+}
+
+fn string_field(object: &serde_json::Map<String, Value>, key: &str) -> Option<String> {
+    object
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn date_field(object: &serde_json::Map<String, Value>, key: &str) -> Option<DateTime<Utc>> {
+    object
+        .get(key)
+        .and_then(Value::as_str)
+        .and_then(parse_taskwarrior_date)
+}
+
+fn parse_depends(value: Option<&Value>) -> Vec<uuid::Uuid> {
+    let raw: Vec<&str> = match value {
+        Some(Value::String(list)) => list.split(',').collect(),
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+
+    raw.into_iter()
+        .filter_map(|uuid| uuid::Uuid::parse_str(uuid.trim()).ok())
+        .collect()
+}
+
+fn parse_task(value: &Value) -> Option<ImportedTask> {
+    let object = value.as_object()?;
+    let description = string_field(object, "description")?;
+
+    let tags = object
+        .get("tags")
+        .and_then(Value::as_array)
+        .map(|tags| {
+            tags.iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let annotations = object
+        .get("annotations")
+        .and_then(Value::as_array)
+        .map(|notes| {
+            notes
+                .iter()
+                .filter_map(|note| {
+                    let note = note.as_object()?;
+                    Some(TaskAnnotation {
+                        entry: date_field(note, "entry")?,
+                        content: string_field(note, "description")?,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(ImportedTask {
+        uuid: string_field(object, "uuid").and_then(|uuid| uuid::Uuid::parse_str(&uuid).ok()),
+        description,
+        status: string_field(object, "status")
+            .map(TaskStatus::from)
+            .unwrap_or_default(),
+        project: string_field(object, "project"),
+        priority: string_field(object, "priority"),
+        tags,
+        entry: date_field(object, "entry"),
+        end: date_field(object, "end"),
+        due: date_field(object, "due"),
+        scheduled: date_field(object, "scheduled"),
+        wait: date_field(object, "wait"),
+        annotations,
+        depends: parse_depends(object.get("depends")),
+    })
+}
+
+pub fn parse_taskwarrior_json(contents: &str) -> TaskResult<(Vec<ImportedTask>, usize)> {
+    let value: Value = serde_json::from_str(contents)
+        .map_err(|e| TaskError::Import(format!("Invalid JSON: {}", e)))?;
+
+    let entries = match value {
+        Value::Array(entries) => entries,
+        Value::Object(_) => vec![value],
+        _ => {
+            return Err(TaskError::Import("Expected an array of tasks".to_string()));
+        }
+    };
+
+    let mut tasks = Vec::with_capacity(entries.len());
+    let mut invalid = 0;
+    for entry in &entries {
+        match parse_task(entry) {
+            Some(task) => tasks.push(task),
+            None => invalid += 1,
+        }
+    }
+
+    Ok((tasks, invalid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_taskwarrior_export() {
+        let json = r#"[
+            {
+                "uuid": "0b4c7f7a-5d1c-4a4e-9a35-1f0f2c9b8d11",
+                "description": "Write report",
+                "status": "pending",
+                "project": "work",
+                "tags": ["next", "office"],
+                "due": "20261016T093000Z",
+                "depends": "5c2d7bb4-77a4-4c1e-a0b5-6f1d2e3c4b5a,not-a-uuid",
+                "annotations": [
+                    {"entry": "20261001T000000Z", "description": "draft sent"}
+                ]
+            },
+            {"uuid": "c3f1e3c6-0a3f-4f34-8d67-2f5a0e6d9a01"}
+        ]"#;
+
+        let (tasks, invalid) = parse_taskwarrior_json(json).unwrap();
+        assert_eq!(invalid, 1);
+        assert_eq!(tasks.len(), 1);
+
+        let task = &tasks[0];
+        assert_eq!(task.description, "Write report");
+        assert_eq!(task.project.as_deref(), Some("work"));
+        assert_eq!(task.tags, vec!["next", "office"]);
+        assert_eq!(
+            task.due,
+            Some(Utc.with_ymd_and_hms(2026, 10, 16, 9, 30, 0).unwrap())
+        );
+        assert_eq!(task.depends.len(), 1);
+        assert_eq!(task.annotations[0].content, "draft sent");
+    }
+
+    #[test]
+    fn test_depends_accepts_array() {
+        let json = r#"{"description": "x", "depends": ["5c2d7bb4-77a4-4c1e-a0b5-6f1d2e3c4b5a"]}"#;
+        let (tasks, _) = parse_taskwarrior_json(json).unwrap();
+        assert_eq!(tasks[0].depends.len(), 1);
+        assert!(tasks[0].uuid.is_none());
+    }
+
+    #[test]
+    fn test_rejects_non_json() {
+        assert!(parse_taskwarrior_json("not json").is_err());
+    }
+}
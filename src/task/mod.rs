@@ -1,12 +1,27 @@
+pub mod date_expr;
 pub mod error;
+pub mod export;
 pub mod filter;
+pub mod import;
 pub mod model;
+pub mod parser;
+pub mod recurrence;
 pub mod service;
+pub mod taskrc;
+pub mod urgency;
 
+pub use date_expr::parse_date_expression;
 pub use error::{TaskError, TaskResult};
-pub use filter::{DueDateFilter, TagsFilterMode, TaskFilter};
+pub use export::ExportFormat;
+pub use filter::{
+    DEFAULT_DUE_SOON_DAYS, DueDateFilter, FilterPreset, ScheduledFilter, TagsFilterMode,
+    TaskFilter, next_month_range, next_week_range,
+};
+pub use import::ImportedTask;
 pub use model::{
-    Task, TaskAnnotation, TaskDetailState, TaskDetailVm, TaskOverview, TaskPriority, TaskStatus,
-    TaskSummary, TaskUpdate,
+    Task, TaskAnnotation, TaskDetailState, TaskDetailVm, TaskDraft, TaskHistoryEntry, TaskOverview,
+    TaskPriority, TaskStatus, TaskSummary, TaskUpdate, renamed_project,
 };
-pub use service::{SyncResult, TaskService};
+pub use recurrence::{Recurrence, RecurrenceFrequency};
+pub use service::{BulkResult, ImportSummary, SyncResult, TaskService};
+pub use urgency::UrgencyConfig;
@@ -2,6 +2,64 @@ use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Duration, Utc};
 
+use super::urgency::{UrgencyConfig, count_factor, due_factor};
+use crate::ui::DATE_FORMAT;
+
+const STANDARD_ATTRIBUTES: &[&str] = &[
+    "depends",
+    "description",
+    "due",
+    "end",
+    "entry",
+    "id",
+    "imask",
+    "mask",
+    "modified",
+    "parent",
+    "priority",
+    "project",
+    "recur",
+    "rtype",
+    "scheduled",
+    "start",
+    "status",
+    "tags",
+    "until",
+    "urgency",
+    "uuid",
+    "wait",
+];
+
+const STANDARD_ATTRIBUTE_PREFIXES: &[&str] = &["annotation_", "dep_", "tag_"];
+
+pub fn is_user_defined_attribute(key: &str) -> bool {
+    let lower = key.to_lowercase();
+    !STANDARD_ATTRIBUTES.contains(&lower.as_str())
+        && !STANDARD_ATTRIBUTE_PREFIXES
+            .iter()
+            .any(|prefix| lower.starts_with(prefix))
+}
+
+pub fn user_defined_attributes<'a>(
+    values: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> HashMap<String, String> {
+    values
+        .into_iter()
+        .filter(|(key, value)| !value.is_empty() && is_user_defined_attribute(key))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+pub fn renamed_project(project: &str, old: &str, new_name: &str) -> Option<String> {
+    if project == old {
+        return Some(new_name.to_string());
+    }
+    project
+        .strip_prefix(old)
+        .filter(|rest| rest.starts_with('.'))
+        .map(|rest| format!("{}{}", new_name, rest))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TaskPriority {
     High,
@@ -160,14 +218,21 @@ pub struct Task {
     pub priority: TaskPriority,
     pub tags: HashSet<String>,
     pub due: Option<DateTime<Utc>>,
+    pub scheduled: Option<DateTime<Utc>>,
     pub wait: Option<DateTime<Utc>>,
     pub entry: Option<DateTime<Utc>>,
     pub modified: Option<DateTime<Utc>>,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub recur: Option<String>,
     pub annotations: Vec<TaskAnnotation>,
     pub dependencies: HashSet<uuid::Uuid>,
     pub is_active: bool,
     pub is_blocked: bool,
+    pub is_blocking: bool,
     pub working_id: Option<usize>,
+    pub udas: HashMap<String, String>,
 }
 
 impl Task {
@@ -180,13 +245,19 @@ impl Task {
         priority: TaskPriority,
         tags: HashSet<String>,
         due: Option<DateTime<Utc>>,
+        scheduled: Option<DateTime<Utc>>,
         wait: Option<DateTime<Utc>>,
         entry: Option<DateTime<Utc>>,
         modified: Option<DateTime<Utc>>,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+        recur: Option<String>,
         annotations: Vec<TaskAnnotation>,
         dependencies: HashSet<uuid::Uuid>,
         is_active: bool,
         is_blocked: bool,
+        is_blocking: bool,
         working_id: Option<usize>,
     ) -> Self {
         Self {
@@ -197,15 +268,22 @@ impl Task {
             priority,
             tags,
             due,
+            scheduled,
             wait,
             entry,
             modified,
+            start,
+            end,
+            until,
+            recur,
             annotations,
             dependencies,
             is_active,
             is_blocked,
+            is_blocking,
             id,
             working_id,
+            udas: HashMap::new(),
         }
     }
 
@@ -217,9 +295,49 @@ impl Task {
         self.due
             .map_or(false, |due| due.date_naive() == Utc::now().date_naive())
     }
+
+    pub fn urgency(&self, config: &UrgencyConfig) -> f32 {
+        if matches!(self.status, TaskStatus::Completed | TaskStatus::Deleted) {
+            return 0.0;
+        }
+
+        let now = Utc::now();
+        let mut urgency = config.priority_coefficient(self.priority);
+
+        if let Some(due) = self.due {
+            urgency += config.due * due_factor(due, now);
+        }
+        if self.is_blocking {
+            urgency += config.blocking;
+        }
+        if self.is_blocked {
+            urgency += config.blocked;
+        }
+        if self.is_active {
+            urgency += config.active;
+        }
+        if self.project.is_some() {
+            urgency += config.project;
+        }
+        if !self.annotations.is_empty() {
+            urgency += config.annotations * count_factor(self.annotations.len());
+        }
+        if let Some(entry) = self.entry {
+            urgency += config.age * config.age_factor(entry, now);
+        }
+
+        urgency += config.tags * count_factor(self.tags.len());
+        urgency += self
+            .tags
+            .iter()
+            .map(|tag| config.tag_coefficient(tag))
+            .sum::<f32>();
+
+        urgency
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TaskSummary {
     pub uuid: uuid::Uuid,
     pub id: Option<usize>,
@@ -230,13 +348,29 @@ pub struct TaskSummary {
     pub priority: TaskPriority,
     pub tags: HashSet<String>,
     pub due: Option<DateTime<Utc>>,
+    pub scheduled: Option<DateTime<Utc>>,
     pub wait: Option<DateTime<Utc>>,
+    pub start: Option<DateTime<Utc>>,
+    pub entry: Option<DateTime<Utc>>,
+    pub modified: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub recur: Option<String>,
     pub dependencies: HashSet<uuid::Uuid>,
     pub is_active: bool,
     pub is_blocked: bool,
+    pub is_blocking: bool,
+    pub annotation_count: usize,
+    pub annotations: Vec<String>,
+    pub urgency: f32,
+    pub udas: HashMap<String, String>,
 }
 
 impl TaskSummary {
+    pub fn with_urgency(mut self, urgency: f32) -> Self {
+        self.urgency = urgency;
+        self
+    }
+
     pub fn is_overdue(&self) -> bool {
         self.due.map_or(false, |due| due < Utc::now())
     }
@@ -245,6 +379,60 @@ impl TaskSummary {
         self.due
             .map_or(false, |due| due.date_naive() == Utc::now().date_naive())
     }
+
+    pub fn is_due_soon(&self, threshold: chrono::Duration) -> bool {
+        self.due.map_or(false, |due| {
+            let now = Utc::now();
+            due >= now && due <= now + threshold
+        })
+    }
+
+    pub fn is_scheduled_future(&self) -> bool {
+        self.scheduled
+            .map_or(false, |scheduled| scheduled > Utc::now())
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        matches!(self.status, TaskStatus::Pending)
+            && self.wait.map_or(false, |wait| wait > Utc::now())
+    }
+
+    pub fn mark_blocking(tasks: &mut [TaskSummary]) {
+        let depended_on: HashSet<uuid::Uuid> = tasks
+            .iter()
+            .filter(|task| matches!(task.status, TaskStatus::Pending))
+            .flat_map(|task| task.dependencies.iter().copied())
+            .collect();
+
+        for task in tasks.iter_mut() {
+            task.is_blocking =
+                matches!(task.status, TaskStatus::Pending) && depended_on.contains(&task.uuid);
+        }
+    }
+
+    // (project, pending, completed) per exact project name, sorted by name.
+    pub fn project_counts(tasks: &[TaskSummary]) -> Vec<(String, usize, usize)> {
+        let mut counts: HashMap<&str, (usize, usize)> = HashMap::new();
+        for task in tasks {
+            let Some(project) = task.project.as_deref() else {
+                continue;
+            };
+            let entry = counts.entry(project).or_insert((0, 0));
+            match task.status {
+                TaskStatus::Pending => entry.0 += 1,
+                TaskStatus::Completed => entry.1 += 1,
+                _ => {}
+            }
+        }
+
+        let mut projects: Vec<(String, usize, usize)> = counts
+            .into_iter()
+            .filter(|(_, (pending, completed))| pending + completed > 0)
+            .map(|(name, (pending, completed))| (name.to_string(), pending, completed))
+            .collect();
+        projects.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        projects
+    }
 }
 
 impl From<&Task> for TaskSummary {
@@ -259,10 +447,25 @@ impl From<&Task> for TaskSummary {
             priority: task.priority,
             tags: task.tags.clone(),
             due: task.due,
+            scheduled: task.scheduled,
             wait: task.wait,
+            start: task.start,
+            entry: task.entry,
+            modified: task.modified,
+            until: task.until,
+            recur: task.recur.clone(),
             dependencies: task.dependencies.clone(),
             is_active: task.is_active,
             is_blocked: task.is_blocked,
+            is_blocking: false,
+            annotation_count: task.annotations.len(),
+            annotations: task
+                .annotations
+                .iter()
+                .map(|annotation| annotation.content.clone())
+                .collect(),
+            urgency: 0.0,
+            udas: task.udas.clone(),
         }
     }
 }
@@ -281,6 +484,7 @@ pub struct TaskOverviewVm {
     pub project: Option<String>,
     pub priority: TaskPriority,
     pub is_active: bool,
+    pub recur: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -331,6 +535,7 @@ pub struct TaskDetailVm {
     pub annotations: Vec<TaskAnnotation>,
     pub udas: Vec<(String, String)>,
     pub metrics: TaskMetricsVm,
+    pub history: Vec<TaskHistoryEntry>,
 }
 
 impl TaskDetailVm {
@@ -393,6 +598,9 @@ impl TaskDetailVm {
         if !blocking.is_empty() {
             virtual_tags.push("BLOCKING".to_string());
         }
+        if task.recur.is_some() {
+            virtual_tags.push("RECURRING".to_string());
+        }
         if let Some(due) = task.due {
             let today = Utc::now().date_naive();
             let tomorrow = (Utc::now() + Duration::days(1)).date_naive();
@@ -409,6 +617,13 @@ impl TaskDetailVm {
         let mut annotations = task.annotations.clone();
         annotations.sort_by(|a, b| a.entry.cmp(&b.entry));
 
+        let mut udas: Vec<(String, String)> = task
+            .udas
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        udas.sort();
+
         TaskDetailVm {
             identity: TaskIdentityVm {
                 uuid: task.uuid,
@@ -421,16 +636,17 @@ impl TaskDetailVm {
                 project: task.project.clone(),
                 priority: task.priority,
                 is_active: task.is_active,
+                recur: task.recur.clone(),
             },
             dates: TaskDatesVm {
                 entry: task.entry,
                 modified: task.modified,
-                start: None,
-                end: None,
+                start: task.start,
+                end: task.end,
                 due: task.due,
-                scheduled: None,
+                scheduled: task.scheduled,
                 wait: task.wait,
-                until: None,
+                until: task.until,
             },
             tags: TaskTagsVm { tags, virtual_tags },
             dependencies: TaskDependenciesVm {
@@ -439,9 +655,35 @@ impl TaskDetailVm {
                 blocking,
             },
             annotations,
-            udas: Vec::new(),
+            udas,
             metrics: TaskMetricsVm::default(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn formatted_summary(&self) -> String {
+        let id_label = self
+            .identity
+            .working_id
+            .or(self.identity.id)
+            .map(|id| format!("#{}", id))
+            .unwrap_or_else(|| format!("#{}", self.identity.uuid));
+
+        let mut parts = vec![format!("{} {}", id_label, self.overview.description)];
+        if let Some(project) = &self.overview.project {
+            parts.push(format!("project:{}", project));
         }
+        if let Some(due) = self.dates.due {
+            parts.push(format!("due:{}", due.format(DATE_FORMAT)));
+        }
+        if self.overview.priority != TaskPriority::None {
+            parts.push(format!("priority:{}", self.overview.priority));
+        }
+        if !self.tags.tags.is_empty() {
+            parts.push(format!("tags:{}", self.tags.tags.join(",")));
+        }
+
+        parts.join(" | ")
     }
 }
 
@@ -456,18 +698,130 @@ impl From<taskchampion::Task> for Task {
             priority: task.get_priority().into(),
             tags: task.get_tags().map(|t| t.to_string()).collect(),
             due: task.get_due().map(Into::into),
+            scheduled: task
+                .get_value("scheduled")
+                .and_then(|value| value.parse::<i64>().ok())
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
             wait: task.get_wait().map(Into::into),
             entry: task.get_entry().map(Into::into),
             modified: task.get_modified().map(Into::into),
+            start: task
+                .get_value("start")
+                .and_then(|value| value.parse::<i64>().ok())
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+            end: task
+                .get_value("end")
+                .and_then(|value| value.parse::<i64>().ok())
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+            until: task
+                .get_value("until")
+                .and_then(|value| value.parse::<i64>().ok())
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+            recur: task.get_value("recur").map(|v| v.to_string()),
             annotations: task.get_annotations().map(Into::into).collect(),
             dependencies: task.get_dependencies().map(Into::into).collect(),
             is_active: task.is_active(),
             is_blocked: task.is_blocked(),
+            is_blocking: task.is_blocking(),
             working_id: None,
+            udas: user_defined_attributes(
+                task.get_taskmap()
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str())),
+            ),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskHistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub operation: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl TaskHistoryEntry {
+    pub fn from_operations(
+        operations: impl IntoIterator<Item = taskchampion::Operation>,
+        created: Option<DateTime<Utc>>,
+    ) -> Vec<Self> {
+        let mut entries = Vec::new();
+        let mut last_timestamp = created;
+
+        for operation in operations {
+            match operation {
+                taskchampion::Operation::Create { .. } => {
+                    if let Some(timestamp) = created {
+                        entries.push(Self {
+                            timestamp,
+                            operation: "Created".to_string(),
+                            old_value: None,
+                            new_value: None,
+                        });
+                    }
+                }
+                taskchampion::Operation::Update {
+                    property,
+                    old_value,
+                    value,
+                    timestamp,
+                    ..
+                } => {
+                    last_timestamp = Some(timestamp);
+                    entries.push(Self {
+                        timestamp,
+                        operation: describe_update(&property, &old_value, &value),
+                        old_value,
+                        new_value: value,
+                    });
+                }
+                taskchampion::Operation::Delete { .. } => {
+                    if let Some(timestamp) = last_timestamp {
+                        entries.push(Self {
+                            timestamp,
+                            operation: "Deleted".to_string(),
+                            old_value: None,
+                            new_value: None,
+                        });
+                    }
+                }
+                taskchampion::Operation::UndoPoint => {}
+            }
+        }
+
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+}
+
+fn describe_update(property: &str, old_value: &Option<String>, value: &Option<String>) -> String {
+    if let Some(tag) = property.strip_prefix("tag_") {
+        return match value {
+            Some(_) => format!("Added tag {}", tag),
+            None => format!("Removed tag {}", tag),
+        };
+    }
+    if property.starts_with("annotation_") {
+        return match value {
+            Some(_) => "Added annotation".to_string(),
+            None => "Removed annotation".to_string(),
+        };
+    }
+    if let Some(dependency) = property.strip_prefix("dep_") {
+        return match value {
+            Some(_) => format!("Added dependency {}", dependency),
+            None => format!("Removed dependency {}", dependency),
+        };
+    }
+
+    match (old_value, value) {
+        (None, Some(_)) => format!("Set {}", property),
+        (Some(_), None) => format!("Cleared {}", property),
+        _ => format!("Changed {}", property),
+    }
+}
+
 pub struct TaskUpdate {
     pub description: Option<String>,
     pub project: Option<String>,
@@ -479,10 +833,21 @@ pub struct TaskUpdate {
     pub dependencies: Option<Vec<String>>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskDraft {
+    pub description: String,
+    pub project: Option<String>,
+    pub tags: Vec<String>,
+    pub priority: Option<String>,
+    pub due: Option<DateTime<Utc>>,
+    pub wait: Option<DateTime<Utc>>,
+    pub depends: Vec<usize>,
+}
+
 #[derive(Debug, Clone)]
 pub struct TaskOverview {
     pub tasks: Vec<Task>,
-    pub projects: Vec<(String, usize)>,
+    pub projects: Vec<(String, usize, usize)>,
     pub tags: Vec<(String, usize)>,
     pub total_tasks: usize,
     pub pending_tasks: usize,
@@ -502,3 +867,142 @@ impl Default for TaskDetailState {
         TaskDetailState::Idle
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_renamed_project_replaces_the_prefix_of_children() {
+        assert_eq!(
+            renamed_project("Work", "Work", "Personal"),
+            Some("Personal".to_string())
+        );
+        assert_eq!(
+            renamed_project("Work.Backend.API", "Work", "Personal"),
+            Some("Personal.Backend.API".to_string())
+        );
+        assert_eq!(renamed_project("Workshop", "Work", "Personal"), None);
+        assert_eq!(renamed_project("Home", "Work", "Personal"), None);
+    }
+
+    #[test]
+    fn test_history_from_operations() {
+        let uuid = uuid::Uuid::new_v4();
+        let created = Utc::now() - Duration::days(2);
+        let later = created + Duration::hours(1);
+        let operations = vec![
+            taskchampion::Operation::UndoPoint,
+            taskchampion::Operation::Create { uuid },
+            taskchampion::Operation::Update {
+                uuid,
+                property: "description".to_string(),
+                old_value: Some("old".to_string()),
+                value: Some("new".to_string()),
+                timestamp: later,
+            },
+            taskchampion::Operation::Update {
+                uuid,
+                property: "tag_work".to_string(),
+                old_value: None,
+                value: Some("".to_string()),
+                timestamp: later,
+            },
+        ];
+
+        let history = TaskHistoryEntry::from_operations(operations, Some(created));
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].operation, "Created");
+        assert_eq!(history[1].operation, "Changed description");
+        assert_eq!(history[1].old_value.as_deref(), Some("old"));
+        assert_eq!(history[1].new_value.as_deref(), Some("new"));
+        assert_eq!(history[2].operation, "Added tag work");
+    }
+
+    #[test]
+    fn test_user_defined_attributes_skip_standard_keys() {
+        let udas = user_defined_attributes([
+            ("description", "Write report"),
+            ("tag_next", ""),
+            ("annotation_1700000000", "note"),
+            ("dep_5c2d7bb4-77a4-4c1e-a0b5-6f1d2e3c4b5a", "x"),
+            ("estimate", "3h"),
+            ("client", "ACME"),
+            ("empty", ""),
+        ]);
+
+        assert_eq!(udas.len(), 2);
+        assert_eq!(udas.get("estimate").map(String::as_str), Some("3h"));
+        assert_eq!(udas.get("client").map(String::as_str), Some("ACME"));
+    }
+
+    #[test]
+    fn test_detail_dates_come_from_the_task() {
+        let at = |hour: u32| Utc.with_ymd_and_hms(2026, 3, 14, hour, 0, 0).unwrap();
+        let task = Task {
+            uuid: uuid::Uuid::new_v4(),
+            status: TaskStatus::Completed,
+            start: Some(at(9)),
+            end: Some(at(11)),
+            scheduled: Some(at(8)),
+            until: Some(at(23)),
+            ..Task::default()
+        };
+
+        let dates = TaskDetailVm::from_task(&task, &[]).dates;
+        assert_eq!(dates.start, Some(at(9)));
+        assert_eq!(dates.end, Some(at(11)));
+        assert_eq!(dates.scheduled, Some(at(8)));
+        assert_eq!(dates.until, Some(at(23)));
+    }
+
+    #[test]
+    fn test_project_counts_tally_pending_and_completed() {
+        let task = |project: Option<&str>, status: TaskStatus| {
+            TaskSummary::from(&Task {
+                project: project.map(str::to_string),
+                status,
+                ..Task::default()
+            })
+        };
+        let tasks = [
+            task(Some("work"), TaskStatus::Pending),
+            task(Some("work"), TaskStatus::Completed),
+            task(Some("Home"), TaskStatus::Pending),
+            task(Some("Home"), TaskStatus::Deleted),
+            task(Some("Old"), TaskStatus::Deleted),
+            task(None, TaskStatus::Pending),
+        ];
+
+        assert_eq!(
+            TaskSummary::project_counts(&tasks),
+            vec![("Home".to_string(), 1, 0), ("work".to_string(), 1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_mark_blocking_uses_pending_dependents() {
+        let task = |status: TaskStatus| Task {
+            uuid: uuid::Uuid::new_v4(),
+            status,
+            ..Task::default()
+        };
+        let blocker = task(TaskStatus::Pending);
+        let done_blocker = task(TaskStatus::Pending);
+        let mut waiting = task(TaskStatus::Pending);
+        waiting.dependencies.insert(blocker.uuid);
+        let mut finished = task(TaskStatus::Completed);
+        finished.dependencies.insert(done_blocker.uuid);
+
+        let mut summaries: Vec<TaskSummary> = [&blocker, &done_blocker, &waiting, &finished]
+            .into_iter()
+            .map(TaskSummary::from)
+            .collect();
+        TaskSummary::mark_blocking(&mut summaries);
+
+        let blocking: Vec<bool> = summaries.iter().map(|task| task.is_blocking).collect();
+        assert_eq!(blocking, vec![true, false, false, false]);
+    }
+}
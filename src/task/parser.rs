@@ -0,0 +1,660 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+
+use super::date_expr::{DATE_KEYWORDS, resolve_date_expression};
+use super::error::{TaskError, TaskResult};
+use super::filter::{DueDateFilter, TaskFilter};
+use super::model::{TaskDraft, TaskPriority, TaskStatus, is_user_defined_attribute};
+use crate::ui::DATE_FORMAT;
+
+const PROJECT_KEYS: &[&str] = &["project", "p"];
+const TAG_KEYS: &[&str] = &["tag", "t"];
+const STATUS_KEYS: &[&str] = &["status"];
+const PRIORITY_KEYS: &[&str] = &["priority"];
+const DUE_KEYS: &[&str] = &["due"];
+const WAIT_KEYS: &[&str] = &["wait"];
+const ADD_PROJECT_KEYS: &[&str] = &["project", "proj", "pro"];
+const ADD_PRIORITY_KEYS: &[&str] = &["priority", "pri"];
+const ADD_DEPENDS_KEYS: &[&str] = &["depends", "dep"];
+
+const STATUS_VALUES: &[&str] = &["pending", "completed", "deleted", "recurring"];
+const PRIORITY_VALUES: &[&str] = &["high", "medium", "low", "none"];
+const DUE_VALUES: &[&str] = &[
+    "overdue",
+    "today",
+    "soon",
+    "week",
+    "next_week",
+    "next_month",
+    "none",
+];
+const ADD_PRIORITY_VALUES: &[&str] = &["H", "M", "L"];
+
+pub const SEARCH_OPERATORS: &[&str] = &["project:", "tag:", "status:", "priority:", "due:"];
+pub const ADD_ATTRIBUTES: &[&str] = &["project:", "priority:", "due:", "wait:", "depends:"];
+
+pub fn parse_search_query(input: &str) -> (TaskFilter, String) {
+    let mut filter = TaskFilter::new();
+    let mut free_text: Vec<&str> = Vec::new();
+
+    for token in input.split_whitespace() {
+        if !apply_operator(&mut filter, token) {
+            free_text.push(token);
+        }
+    }
+
+    (filter, free_text.join(" "))
+}
+
+fn apply_operator(filter: &mut TaskFilter, token: &str) -> bool {
+    let Some((key, value)) = token.split_once(':') else {
+        return false;
+    };
+    if value.is_empty() {
+        return false;
+    }
+
+    let key = key.to_lowercase();
+    let key = key.as_str();
+
+    if PROJECT_KEYS.contains(&key) {
+        filter.project = Some(value.to_string());
+        filter.project_include_children = true;
+        true
+    } else if TAG_KEYS.contains(&key) {
+        filter.tags.insert(value.to_string());
+        true
+    } else if STATUS_KEYS.contains(&key) {
+        match parse_status(value) {
+            Some(status) => {
+                filter.status = Some(status);
+                true
+            }
+            None => false,
+        }
+    } else if PRIORITY_KEYS.contains(&key) {
+        match parse_priority(value) {
+            Some(priority) => {
+                filter.priority = Some(priority);
+                true
+            }
+            None => false,
+        }
+    } else if DUE_KEYS.contains(&key) {
+        match parse_due(value) {
+            Some(due) => {
+                filter.due_filter = Some(due);
+                true
+            }
+            None => false,
+        }
+    } else {
+        false
+    }
+}
+
+fn parse_status(value: &str) -> Option<TaskStatus> {
+    match value.to_lowercase().as_str() {
+        "pending" | "p" => Some(TaskStatus::Pending),
+        "completed" | "c" | "done" => Some(TaskStatus::Completed),
+        "deleted" | "d" => Some(TaskStatus::Deleted),
+        "recurring" | "r" => Some(TaskStatus::Recurring),
+        _ => None,
+    }
+}
+
+fn parse_priority(value: &str) -> Option<TaskPriority> {
+    match value.to_lowercase().as_str() {
+        "high" | "h" => Some(TaskPriority::High),
+        "medium" | "m" => Some(TaskPriority::Medium),
+        "low" | "l" => Some(TaskPriority::Low),
+        "none" | "n" => Some(TaskPriority::None),
+        _ => None,
+    }
+}
+
+fn parse_due(value: &str) -> Option<DueDateFilter> {
+    match value.to_lowercase().as_str() {
+        "overdue" => Some(DueDateFilter::Overdue),
+        "today" => Some(DueDateFilter::Today),
+        "soon" => Some(DueDateFilter::Soon),
+        "week" | "this_week" => Some(DueDateFilter::ThisWeek),
+        "next_week" => Some(DueDateFilter::NextWeek),
+        "next_month" => Some(DueDateFilter::NextMonth),
+        "none" => Some(DueDateFilter::NoDate),
+        other => NaiveDate::parse_from_str(other, DATE_FORMAT)
+            .ok()
+            .map(DueDateFilter::OnDate),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    pub label: String,
+    pub insert: String,
+    pub range: Range<usize>,
+}
+
+fn token_at_cursor(input: &str, cursor: usize) -> Option<(&str, Range<usize>)> {
+    let cursor = cursor.min(input.len());
+    if !input.is_char_boundary(cursor) {
+        return None;
+    }
+
+    let token_start = input[..cursor]
+        .rfind(char::is_whitespace)
+        .map(|idx| idx + input[idx..].chars().next().map_or(1, char::len_utf8))
+        .unwrap_or(0);
+    let token_end = input[cursor..]
+        .find(char::is_whitespace)
+        .map(|idx| cursor + idx)
+        .unwrap_or(input.len());
+
+    Some((&input[token_start..cursor], token_start..token_end))
+}
+
+pub fn operator_completions(input: &str, cursor: usize) -> Vec<Completion> {
+    let Some((token, range)) = token_at_cursor(input, cursor) else {
+        return Vec::new();
+    };
+
+    let Some((key, partial)) = token.split_once(':') else {
+        return Vec::new();
+    };
+
+    let lower_key = key.to_lowercase();
+    let values: &[&str] = if STATUS_KEYS.contains(&lower_key.as_str()) {
+        STATUS_VALUES
+    } else if PRIORITY_KEYS.contains(&lower_key.as_str()) {
+        PRIORITY_VALUES
+    } else if DUE_KEYS.contains(&lower_key.as_str()) {
+        DUE_VALUES
+    } else if key.is_empty() {
+        return SEARCH_OPERATORS
+            .iter()
+            .map(|op| Completion {
+                label: op.to_string(),
+                insert: op.to_string(),
+                range: range.clone(),
+            })
+            .collect();
+    } else {
+        return Vec::new();
+    };
+
+    let partial = partial.to_lowercase();
+    values
+        .iter()
+        .filter(|value| value.starts_with(&partial) && **value != partial)
+        .map(|value| {
+            let completed = format!("{}:{}", key, value);
+            Completion {
+                label: completed.clone(),
+                insert: completed,
+                range: range.clone(),
+            }
+        })
+        .collect()
+}
+
+pub fn parse_add_command(input: &str) -> TaskResult<TaskDraft> {
+    parse_add_command_at(input, Local::now())
+}
+
+pub fn parse_add_command_at<Tz: TimeZone>(input: &str, now: DateTime<Tz>) -> TaskResult<TaskDraft> {
+    let mut draft = TaskDraft::default();
+    let mut words: Vec<&str> = Vec::new();
+    let mut verbatim = false;
+
+    for token in input.split_whitespace() {
+        if verbatim {
+            words.push(token);
+            continue;
+        }
+        if token == "--" {
+            verbatim = true;
+            continue;
+        }
+        if let Some(tag) = token.strip_prefix('+').filter(|tag| !tag.is_empty()) {
+            if !draft.tags.iter().any(|existing| existing == tag) {
+                draft.tags.push(tag.to_string());
+            }
+            continue;
+        }
+
+        let Some((key, value)) = token.split_once(':') else {
+            words.push(token);
+            continue;
+        };
+
+        let key = key.to_lowercase();
+        let key = key.as_str();
+        if ADD_PROJECT_KEYS.contains(&key) {
+            draft.project = (!value.is_empty()).then(|| value.to_string());
+        } else if ADD_PRIORITY_KEYS.contains(&key) {
+            draft.priority = match parse_priority(value) {
+                Some(TaskPriority::High) => Some("H".to_string()),
+                Some(TaskPriority::Medium) => Some("M".to_string()),
+                Some(TaskPriority::Low) => Some("L".to_string()),
+                Some(TaskPriority::None) => None,
+                None if value.is_empty() => None,
+                None => return Err(TaskError::InvalidPriority(value.to_string())),
+            };
+        } else if DUE_KEYS.contains(&key) {
+            draft.due = parse_add_date(value, now.clone())
+                .ok_or_else(|| TaskError::InvalidDue(value.to_string()))?;
+        } else if WAIT_KEYS.contains(&key) {
+            draft.wait = parse_add_date(value, now.clone())
+                .ok_or_else(|| TaskError::InvalidWait(value.to_string()))?;
+        } else if ADD_DEPENDS_KEYS.contains(&key) {
+            for id in parse_working_ids(value)? {
+                if !draft.depends.contains(&id) {
+                    draft.depends.push(id);
+                }
+            }
+        } else {
+            words.push(token);
+        }
+    }
+
+    draft.description = words.join(" ");
+    if draft.description.is_empty() {
+        return Err(TaskError::InvalidDescription(
+            "description cannot be empty".to_string(),
+        ));
+    }
+
+    Ok(draft)
+}
+
+pub fn parse_uda_assignment(input: &str) -> TaskResult<(String, Option<String>)> {
+    let (key, value) = input
+        .split_once(':')
+        .ok_or_else(|| TaskError::InvalidAttribute(input.trim().to_string()))?;
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return Err(TaskError::InvalidAttribute(input.trim().to_string()));
+    }
+    if !is_user_defined_attribute(key) {
+        return Err(TaskError::InvalidAttribute(format!("{} is read-only", key)));
+    }
+
+    let value = value.trim();
+    Ok((
+        key.to_string(),
+        (!value.is_empty()).then(|| value.to_string()),
+    ))
+}
+
+pub fn parse_working_ids(value: &str) -> TaskResult<Vec<usize>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            id.parse::<usize>()
+                .ok()
+                .filter(|id| *id > 0)
+                .ok_or_else(|| TaskError::InvalidDependency(id.to_string()))
+        })
+        .collect()
+}
+
+fn parse_add_date<Tz: TimeZone>(value: &str, now: DateTime<Tz>) -> Option<Option<DateTime<Utc>>> {
+    if value.is_empty() {
+        return Some(None);
+    }
+    resolve_date_expression(value, now).map(Some)
+}
+
+pub fn add_completions(
+    input: &str,
+    cursor: usize,
+    projects: &[String],
+    tags: &[String],
+) -> Vec<Completion> {
+    let Some((token, range)) = token_at_cursor(input, cursor) else {
+        return Vec::new();
+    };
+    if token.is_empty() {
+        return Vec::new();
+    }
+
+    let complete = |prefix: &str, candidates: &mut dyn Iterator<Item = &str>, partial: &str| {
+        let partial = partial.to_lowercase();
+        candidates
+            .filter(|candidate| {
+                let lower = candidate.to_lowercase();
+                lower.starts_with(&partial) && lower != partial
+            })
+            .map(|candidate| {
+                let completed = format!("{}{}", prefix, candidate);
+                Completion {
+                    label: completed.clone(),
+                    insert: completed,
+                    range: range.clone(),
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    if let Some(partial) = token.strip_prefix('+') {
+        return complete("+", &mut tags.iter().map(String::as_str), partial);
+    }
+
+    let Some((key, partial)) = token.split_once(':') else {
+        return complete("", &mut ADD_ATTRIBUTES.iter().copied(), token);
+    };
+
+    let prefix = format!("{}:", key);
+    let lower_key = key.to_lowercase();
+    let lower_key = lower_key.as_str();
+    if ADD_PROJECT_KEYS.contains(&lower_key) {
+        complete(&prefix, &mut projects.iter().map(String::as_str), partial)
+    } else if ADD_PRIORITY_KEYS.contains(&lower_key) {
+        complete(&prefix, &mut ADD_PRIORITY_VALUES.iter().copied(), partial)
+    } else if DUE_KEYS.contains(&lower_key) || WAIT_KEYS.contains(&lower_key) {
+        complete(&prefix, &mut DATE_KEYWORDS.iter().copied(), partial)
+    } else {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_input() {
+        let (filter, text) = parse_search_query("");
+        assert!(filter.project.is_none());
+        assert!(filter.tags.is_empty());
+        assert!(filter.status.is_none());
+        assert!(filter.priority.is_none());
+        assert!(filter.due_filter.is_none());
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn test_free_text_only() {
+        let (filter, text) = parse_search_query("  buy   milk ");
+        assert!(filter.project.is_none());
+        assert_eq!(text, "buy milk");
+    }
+
+    #[test]
+    fn test_project_operator() {
+        let (filter, text) = parse_search_query("project:Work");
+        assert_eq!(filter.project.as_deref(), Some("Work"));
+        assert!(filter.project_include_children);
+        assert_eq!(text, "");
+
+        let (filter, _) = parse_search_query("p:Home.Garden");
+        assert_eq!(filter.project.as_deref(), Some("Home.Garden"));
+    }
+
+    #[test]
+    fn test_tag_operators_accumulate() {
+        let (filter, _) = parse_search_query("tag:urgent t:home");
+        assert_eq!(filter.tags.len(), 2);
+        assert!(filter.tags.contains("urgent"));
+        assert!(filter.tags.contains("home"));
+    }
+
+    #[test]
+    fn test_status_operator() {
+        let (filter, _) = parse_search_query("status:completed");
+        assert_eq!(filter.status, Some(TaskStatus::Completed));
+
+        let (filter, _) = parse_search_query("STATUS:Pending");
+        assert_eq!(filter.status, Some(TaskStatus::Pending));
+    }
+
+    #[test]
+    fn test_priority_operator() {
+        let (filter, _) = parse_search_query("priority:High");
+        assert_eq!(filter.priority, Some(TaskPriority::High));
+
+        let (filter, _) = parse_search_query("priority:l");
+        assert_eq!(filter.priority, Some(TaskPriority::Low));
+
+        let (filter, _) = parse_search_query("priority:none");
+        assert_eq!(filter.priority, Some(TaskPriority::None));
+    }
+
+    #[test]
+    fn test_due_operator() {
+        let (filter, _) = parse_search_query("due:overdue");
+        assert_eq!(filter.due_filter, Some(DueDateFilter::Overdue));
+
+        let (filter, _) = parse_search_query("due:week");
+        assert_eq!(filter.due_filter, Some(DueDateFilter::ThisWeek));
+
+        let (filter, _) = parse_search_query("due:2025-03-14");
+        assert_eq!(
+            filter.due_filter,
+            Some(DueDateFilter::OnDate(
+                NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_values_fall_back_to_text() {
+        let (filter, text) = parse_search_query("status:bogus priority:urgent due:soon");
+        assert!(filter.status.is_none());
+        assert!(filter.priority.is_none());
+        assert!(filter.due_filter.is_none());
+        assert_eq!(text, "status:bogus priority:urgent due:soon");
+    }
+
+    #[test]
+    fn test_unknown_operator_and_empty_value_are_text() {
+        let (filter, text) = parse_search_query("foo:bar project:");
+        assert!(filter.project.is_none());
+        assert_eq!(text, "foo:bar project:");
+    }
+
+    #[test]
+    fn test_mixed_operators_and_text() {
+        let (filter, text) = parse_search_query("fix project:Work login tag:urgent priority:H bug");
+        assert_eq!(filter.project.as_deref(), Some("Work"));
+        assert!(filter.tags.contains("urgent"));
+        assert_eq!(filter.priority, Some(TaskPriority::High));
+        assert_eq!(text, "fix login bug");
+    }
+
+    #[test]
+    fn test_last_operator_wins() {
+        let (filter, _) = parse_search_query("project:Work project:Home");
+        assert_eq!(filter.project.as_deref(), Some("Home"));
+    }
+
+    #[test]
+    fn test_completions_for_status_values() {
+        let completions = operator_completions("fix status:", 11);
+        let labels: Vec<&str> = completions.iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "status:pending",
+                "status:completed",
+                "status:deleted",
+                "status:recurring"
+            ]
+        );
+        assert_eq!(completions[0].insert, "status:pending");
+        assert_eq!(completions[0].range, 4..11);
+    }
+
+    #[test]
+    fn test_completions_filter_by_partial_value() {
+        let completions = operator_completions("priority:h", 10);
+        assert_eq!(
+            completions,
+            vec![Completion {
+                label: "priority:high".to_string(),
+                insert: "priority:high".to_string(),
+                range: 0..10,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_completions_list_operators_after_bare_colon() {
+        let completions = operator_completions("milk :", 6);
+        assert_eq!(completions.len(), SEARCH_OPERATORS.len());
+        assert_eq!(completions[0].insert, "project:");
+        assert_eq!(completions[0].range, 5..6);
+    }
+
+    #[test]
+    fn test_completions_mid_query_replace_only_the_current_word() {
+        let completions = operator_completions("fix priority:m milk", 14);
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].insert, "priority:medium");
+        assert_eq!(completions[0].range, 4..14);
+
+        let completions = operator_completions("fix priority:mxx milk", 14);
+        assert_eq!(completions[0].range, 4..16);
+    }
+
+    #[test]
+    fn test_no_completions_without_colon() {
+        assert!(operator_completions("project", 7).is_empty());
+        assert!(operator_completions("status:done ", 12).is_empty());
+        assert!(operator_completions("project:Wo", 10).is_empty());
+    }
+
+    fn friday() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 14, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_add_command_extracts_attributes() {
+        let draft = parse_add_command_at(
+            "Fix login project:Work.Backend +bug +urgent priority:H due:tomorrow wait:today",
+            friday(),
+        )
+        .unwrap();
+
+        assert_eq!(draft.description, "Fix login");
+        assert_eq!(draft.project.as_deref(), Some("Work.Backend"));
+        assert_eq!(draft.tags, vec!["bug", "urgent"]);
+        assert_eq!(draft.priority.as_deref(), Some("H"));
+        assert_eq!(
+            draft.due,
+            Utc.with_ymd_and_hms(2026, 10, 17, 0, 0, 0).single()
+        );
+        assert_eq!(
+            draft.wait,
+            Utc.with_ymd_and_hms(2026, 10, 16, 0, 0, 0).single()
+        );
+    }
+
+    #[test]
+    fn test_add_command_keeps_text_between_attributes() {
+        let draft = parse_add_command_at("pro:Home call +phone the bank pri:l", friday()).unwrap();
+        assert_eq!(draft.description, "call the bank");
+        assert_eq!(draft.project.as_deref(), Some("Home"));
+        assert_eq!(draft.priority.as_deref(), Some("L"));
+
+        let draft = parse_add_command_at("ratio 3:1 -- +literal due:x", friday()).unwrap();
+        assert_eq!(draft.description, "ratio 3:1 +literal due:x");
+        assert!(draft.tags.is_empty());
+        assert!(draft.due.is_none());
+    }
+
+    #[test]
+    fn test_add_command_rejects_invalid_values() {
+        let now = friday();
+        assert!(matches!(
+            parse_add_command_at("task priority:urgent", now.clone()),
+            Err(TaskError::InvalidPriority(value)) if value == "urgent"
+        ));
+        assert!(matches!(
+            parse_add_command_at("task due:someday", now.clone()),
+            Err(TaskError::InvalidDue(value)) if value == "someday"
+        ));
+        assert!(matches!(
+            parse_add_command_at("task wait:later", now.clone()),
+            Err(TaskError::InvalidWait(_))
+        ));
+        assert!(matches!(
+            parse_add_command_at("+tag project:Work", now),
+            Err(TaskError::InvalidDescription(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_command_collects_dependency_ids() {
+        let draft = parse_add_command_at("Deploy depends:3,12 dep:3", friday()).unwrap();
+        assert_eq!(draft.description, "Deploy");
+        assert_eq!(draft.depends, vec![3, 12]);
+
+        assert!(matches!(
+            parse_add_command_at("Deploy depends:3,abc", friday()),
+            Err(TaskError::InvalidDependency(value)) if value == "abc"
+        ));
+        assert!(matches!(
+            parse_working_ids("0"),
+            Err(TaskError::InvalidDependency(_))
+        ));
+    }
+
+    #[test]
+    fn test_add_completions() {
+        let projects = vec!["Work".to_string(), "Work.Backend".to_string()];
+        let tags = vec!["urgent".to_string(), "home".to_string()];
+
+        let completions = add_completions("Fix +ur", 7, &projects, &tags);
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].insert, "+urgent");
+        assert_eq!(completions[0].range, 4..7);
+
+        let labels: Vec<String> = add_completions("Fix project:w", 13, &projects, &tags)
+            .into_iter()
+            .map(|c| c.insert)
+            .collect();
+        assert_eq!(labels, vec!["project:Work", "project:Work.Backend"]);
+
+        let completions = add_completions("Fix pr", 6, &projects, &tags);
+        assert_eq!(completions.len(), 2);
+        assert_eq!(completions[0].insert, "project:");
+
+        assert_eq!(
+            add_completions("due:tom", 7, &projects, &tags)[0].insert,
+            "due:tomorrow"
+        );
+        assert!(add_completions("Fix ", 4, &projects, &tags).is_empty());
+    }
+
+    #[test]
+    fn test_parse_uda_assignment() {
+        assert_eq!(
+            parse_uda_assignment(" estimate : 3h ").unwrap(),
+            ("estimate".to_string(), Some("3h".to_string()))
+        );
+        assert_eq!(
+            parse_uda_assignment("client:").unwrap(),
+            ("client".to_string(), None)
+        );
+        assert_eq!(
+            parse_uda_assignment("url:https://example.com").unwrap(),
+            ("url".to_string(), Some("https://example.com".to_string()))
+        );
+        for input in [
+            "estimate",
+            ":3h",
+            "two words:x",
+            "recur:weekly",
+            "tag_next:x",
+        ] {
+            assert!(matches!(
+                parse_uda_assignment(input),
+                Err(TaskError::InvalidAttribute(_))
+            ));
+        }
+    }
+}
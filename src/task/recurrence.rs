@@ -0,0 +1,168 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl RecurrenceFrequency {
+    pub const ALL: [RecurrenceFrequency; 4] = [
+        RecurrenceFrequency::Daily,
+        RecurrenceFrequency::Weekly,
+        RecurrenceFrequency::Monthly,
+        RecurrenceFrequency::Yearly,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+            Self::Yearly => "yearly",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Daily => "Daily",
+            Self::Weekly => "Weekly",
+            Self::Monthly => "Monthly",
+            Self::Yearly => "Yearly",
+        }
+    }
+
+    fn unit(&self) -> &'static str {
+        match self {
+            Self::Daily => "days",
+            Self::Weekly => "weeks",
+            Self::Monthly => "months",
+            Self::Yearly => "years",
+        }
+    }
+
+    fn from_unit(unit: &str) -> Option<Self> {
+        match unit {
+            "d" | "day" | "days" | "daily" => Some(Self::Daily),
+            "w" | "wk" | "wks" | "week" | "weeks" | "weekly" => Some(Self::Weekly),
+            "mo" | "mos" | "month" | "months" | "monthly" => Some(Self::Monthly),
+            "y" | "yr" | "yrs" | "year" | "years" | "yearly" | "annual" => Some(Self::Yearly),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recurrence {
+    pub frequency: RecurrenceFrequency,
+    pub interval: u32,
+}
+
+impl Recurrence {
+    pub fn new(frequency: RecurrenceFrequency, interval: u32) -> Self {
+        Self {
+            frequency,
+            interval: interval.max(1),
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "biweekly" | "fortnight" => {
+                return Some(Self::new(RecurrenceFrequency::Weekly, 2));
+            }
+            "quarterly" => return Some(Self::new(RecurrenceFrequency::Monthly, 3)),
+            "semiannual" => return Some(Self::new(RecurrenceFrequency::Monthly, 6)),
+            _ => {}
+        }
+
+        let split = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (digits, unit) = value.split_at(split);
+        let interval = if digits.is_empty() {
+            1
+        } else {
+            digits.parse().ok()?
+        };
+        if interval == 0 {
+            return None;
+        }
+
+        RecurrenceFrequency::from_unit(unit.trim()).map(|frequency| Self::new(frequency, interval))
+    }
+
+    pub fn to_recur_value(&self) -> String {
+        if self.interval == 1 {
+            self.frequency.as_str().to_string()
+        } else {
+            format!("{}{}", self.interval, self.frequency.unit())
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        if self.interval == 1 {
+            let label = self.frequency.as_str();
+            let mut chars = label.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        } else {
+            format!("Every {} {}", self.interval, self.frequency.unit())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_and_counted_values() {
+        assert_eq!(
+            Recurrence::parse("weekly"),
+            Some(Recurrence::new(RecurrenceFrequency::Weekly, 1))
+        );
+        assert_eq!(
+            Recurrence::parse("2weeks"),
+            Some(Recurrence::new(RecurrenceFrequency::Weekly, 2))
+        );
+        assert_eq!(
+            Recurrence::parse("3d"),
+            Some(Recurrence::new(RecurrenceFrequency::Daily, 3))
+        );
+        assert_eq!(
+            Recurrence::parse("biweekly"),
+            Some(Recurrence::new(RecurrenceFrequency::Weekly, 2))
+        );
+        assert_eq!(Recurrence::parse("0days"), None);
+        assert_eq!(Recurrence::parse("sometimes"), None);
+    }
+
+    #[test]
+    fn test_recur_value_round_trips() {
+        for frequency in RecurrenceFrequency::ALL {
+            for interval in [1, 2, 12] {
+                let recurrence = Recurrence::new(frequency, interval);
+                assert_eq!(
+                    Recurrence::parse(&recurrence.to_recur_value()),
+                    Some(recurrence)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            Recurrence::new(RecurrenceFrequency::Monthly, 1).describe(),
+            "Monthly"
+        );
+        assert_eq!(
+            Recurrence::new(RecurrenceFrequency::Weekly, 2).describe(),
+            "Every 2 weeks"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 
 use chrono::{DateTime, Utc};
 use taskchampion::{
@@ -9,67 +10,38 @@ use uuid::Uuid;
 
 use super::error::{TaskError, TaskResult};
 use super::filter::TaskFilter;
-use super::model::{Task, TaskDetailVm, TaskOverview, TaskStatus, TaskSummary};
+use super::import::ImportedTask;
+use super::model::{
+    Task, TaskDetailVm, TaskDraft, TaskHistoryEntry, TaskOverview, TaskStatus, TaskSummary,
+    is_user_defined_attribute, renamed_project,
+};
+use super::recurrence::Recurrence;
+use super::taskrc::{DataLocationSource, TaskrcColors, read_taskrc_config};
+use super::urgency::UrgencyConfig;
 
 pub struct TaskService {
     replica: Replica,
     taskdb_dir: PathBuf,
-}
-
-fn read_taskrc_config() -> TaskResult<PathBuf> {
-    if let Ok(taskdata) = std::env::var("TASKDATA") {
-        log::info!("Using TASKDATA env var: {}", taskdata);
-        return Ok(PathBuf::from(taskdata));
-    }
-
-    let taskrc_path = if let Ok(taskrc) = std::env::var("TASKRC") {
-        PathBuf::from(taskrc)
-    } else {
-        dirs::home_dir()
-            .ok_or_else(|| TaskError::Config("Cannot find home directory".into()))?
-            .join(".config/task/taskrc")
-    };
-
-    log::debug!("Looking for taskrc at: {:?}", taskrc_path);
-
-    if taskrc_path.exists() {
-        let content = std::fs::read_to_string(&taskrc_path)
-            .map_err(|e| TaskError::Config(format!("Failed to read taskrc: {}", e)))?;
-
-        for line in content.lines() {
-            let line = line.trim();
-            if line.starts_with("data.location=") {
-                let path = line.strip_prefix("data.location=").unwrap().trim();
-
-                let expanded = if path.starts_with("~") {
-                    let home = dirs::home_dir()
-                        .ok_or_else(|| TaskError::Config("Cannot expand ~ in path".into()))?;
-                    PathBuf::from(path.replacen("~", home.to_str().unwrap(), 1))
-                } else {
-                    PathBuf::from(path)
-                };
-
-                log::info!("Found data.location in taskrc: {:?}", expanded);
-                return Ok(expanded);
-            }
-        }
-    }
-
-    log::warn!("No taskrc found or data.location not set, using default ~/.task");
-    let default = dirs::home_dir()
-        .ok_or_else(|| TaskError::Config("Cannot find home directory".into()))?
-        .join(".task");
-    Ok(default)
+    data_location_source: DataLocationSource,
+    urgency_config: UrgencyConfig,
+    taskrc_colors: TaskrcColors,
+    pending_ops: Option<usize>,
+    last_write: Option<Instant>,
 }
 
 impl TaskService {
     pub fn new() -> TaskResult<Self> {
-        let taskdb_dir = read_taskrc_config()?;
+        let config = read_taskrc_config()?;
+        let taskdb_dir = config.data_location;
 
         log::debug!("TaskService: Using taskdb_dir: {:?}", taskdb_dir);
         log::debug!("TaskService: Directory exists: {}", taskdb_dir.exists());
 
-        Self::with_path(taskdb_dir)
+        let mut service = Self::with_path(taskdb_dir)?;
+        service.data_location_source = config.data_location_source;
+        service.urgency_config = config.urgency;
+        service.taskrc_colors = config.colors;
+        Ok(service)
     }
 
     pub fn with_path(taskdb_dir: PathBuf) -> TaskResult<Self> {
@@ -92,10 +64,84 @@ impl TaskService {
         Ok(Self {
             replica,
             taskdb_dir,
+            data_location_source: DataLocationSource::Custom,
+            urgency_config: UrgencyConfig::default(),
+            taskrc_colors: TaskrcColors::default(),
+            pending_ops: None,
+            last_write: None,
         })
     }
 
-    pub fn create_task(&mut self, description: String) -> TaskResult<Task> {
+    pub fn data_location(&self) -> &Path {
+        &self.taskdb_dir
+    }
+
+    pub fn data_location_source(&self) -> &DataLocationSource {
+        &self.data_location_source
+    }
+
+    pub fn urgency_config(&self) -> &UrgencyConfig {
+        &self.urgency_config
+    }
+
+    pub fn set_urgency_config(&mut self, urgency_config: UrgencyConfig) {
+        self.urgency_config = urgency_config;
+    }
+
+    pub fn taskrc_colors(&self) -> &TaskrcColors {
+        &self.taskrc_colors
+    }
+
+    pub fn open_worker(&self) -> TaskResult<Self> {
+        let mut worker = Self::with_path(self.taskdb_dir.clone())?;
+        worker.data_location_source = self.data_location_source.clone();
+        worker.urgency_config = self.urgency_config.clone();
+        worker.taskrc_colors = self.taskrc_colors.clone();
+        Ok(worker)
+    }
+
+    pub fn mark_written(&mut self) {
+        self.pending_ops = None;
+        self.last_write = Some(Instant::now());
+    }
+
+    pub fn written_within(&self, window: Duration) -> bool {
+        self.last_write
+            .is_some_and(|written| written.elapsed() < window)
+    }
+
+    pub fn storage_stamp(&self) -> Option<SystemTime> {
+        std::fs::read_dir(&self.taskdb_dir)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| !entry.file_name().to_string_lossy().ends_with("-shm"))
+            .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+            .max()
+    }
+
+    pub fn forget_cached_counts(&mut self) {
+        self.pending_ops = None;
+    }
+
+    fn commit(&mut self, ops: Operations) -> TaskResult<()> {
+        self.mark_written();
+        self.replica
+            .commit_operations(ops)
+            .map_err(|e| TaskError::Storage(e.to_string()))
+    }
+
+    pub fn create_task(&mut self, draft: TaskDraft) -> TaskResult<Task> {
+        let tags = draft
+            .tags
+            .iter()
+            .map(|tag| Tag::try_from(tag.as_str()).map_err(|_| TaskError::InvalidTag(tag.clone())))
+            .collect::<TaskResult<Vec<Tag>>>()?;
+        let depends = draft
+            .depends
+            .iter()
+            .map(|id| self.uuid_for_working_id(*id))
+            .collect::<TaskResult<Vec<Uuid>>>()?;
+
         let uuid = Uuid::new_v4();
         let mut ops = Operations::new();
 
@@ -104,13 +150,15 @@ impl TaskService {
             .create_task(uuid, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        tc_task
-            .set_description(description, &mut ops)
+        Self::apply_draft(&mut tc_task, draft, &tags, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
+        for depends_on in depends {
+            tc_task
+                .add_dependency(depends_on, &mut ops)
+                .map_err(|e| TaskError::Storage(e.to_string()))?;
+        }
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         let working_set = self
             .replica
@@ -122,6 +170,35 @@ impl TaskService {
         Ok(task)
     }
 
+    fn apply_draft(
+        tc_task: &mut taskchampion::Task,
+        draft: TaskDraft,
+        tags: &[Tag],
+        ops: &mut Operations,
+    ) -> Result<(), taskchampion::Error> {
+        tc_task.set_description(draft.description, ops)?;
+        tc_task.set_status(Status::Pending, ops)?;
+        tc_task.set_entry(Some(Utc::now()), ops)?;
+
+        if let Some(project) = draft.project {
+            tc_task.set_value("project", Some(project), ops)?;
+        }
+        if let Some(priority) = draft.priority {
+            tc_task.set_priority(priority, ops)?;
+        }
+        for tag in tags {
+            tc_task.add_tag(tag, ops)?;
+        }
+        if draft.due.is_some() {
+            tc_task.set_due(draft.due, ops)?;
+        }
+        if draft.wait.is_some() {
+            tc_task.set_wait(draft.wait, ops)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_task(&mut self, uuid: Uuid) -> TaskResult<Option<Task>> {
         let tc_task = self
             .replica
@@ -151,6 +228,16 @@ impl TaskService {
         Ok(TaskDetailVm::from_task(&task, all_tasks))
     }
 
+    pub fn get_task_history(&mut self, uuid: Uuid) -> TaskResult<Vec<TaskHistoryEntry>> {
+        let created = self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))?.entry;
+        let operations = self
+            .replica
+            .get_task_operations(uuid)
+            .map_err(|e| TaskError::Storage(e.to_string()))?;
+
+        Ok(TaskHistoryEntry::from_operations(operations, created))
+    }
+
     pub fn get_all_tasks(&mut self) -> TaskResult<Vec<Task>> {
         log::debug!("TaskService::get_all_tasks: Fetching all tasks from replica");
         let all = self.replica.all_tasks().map_err(|e| {
@@ -191,7 +278,6 @@ impl TaskService {
 
         log::debug!("Processing {} tasks for overview", tasks.len());
 
-        let mut project_counts: HashMap<String, usize> = HashMap::new();
         let mut tag_counts: HashMap<String, usize> = HashMap::new();
         let mut pending_count = 0;
         let mut completed_count = 0;
@@ -204,18 +290,14 @@ impl TaskService {
             }
 
             if matches!(task.status, TaskStatus::Pending) {
-                if let Some(project) = &task.project {
-                    *project_counts.entry(project.clone()).or_insert(0) += 1;
-                }
-
                 for tag in &task.tags {
                     *tag_counts.entry(tag.clone()).or_insert(0) += 1;
                 }
             }
         }
 
-        let mut projects: Vec<(String, usize)> = project_counts.into_iter().collect();
-        projects.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        let summaries: Vec<TaskSummary> = tasks.iter().map(TaskSummary::from).collect();
+        let projects = TaskSummary::project_counts(&summaries);
 
         let mut tags: Vec<(String, usize)> = tag_counts.into_iter().collect();
         tags.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
@@ -243,10 +325,29 @@ impl TaskService {
 
     pub fn get_filtered_tasks(&mut self, filter: &TaskFilter) -> TaskResult<Vec<TaskSummary>> {
         let all = self.get_all_tasks()?;
-        let summaries: Vec<TaskSummary> = all.iter().map(TaskSummary::from).collect();
+        let summaries: Vec<TaskSummary> = all
+            .iter()
+            .map(|task| TaskSummary::from(task).with_urgency(task.urgency(&self.urgency_config)))
+            .collect();
         Ok(filter.apply(&summaries))
     }
 
+    pub fn find_tasks_by_description(&mut self, query: &str) -> TaskResult<Vec<TaskSummary>> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let all = self.get_all_tasks()?;
+        let mut matches: Vec<TaskSummary> = all
+            .iter()
+            .filter(|task| task.description.to_lowercase().contains(&query))
+            .map(|task| TaskSummary::from(task).with_urgency(task.urgency(&self.urgency_config)))
+            .collect();
+        matches.sort_by(|a, b| b.urgency.total_cmp(&a.urgency));
+        Ok(matches)
+    }
+
     pub fn update_task(
         &mut self,
         uuid: Uuid,
@@ -315,9 +416,7 @@ impl TaskService {
             }
         }
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -335,9 +434,7 @@ impl TaskService {
             .done(&mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -355,9 +452,7 @@ impl TaskService {
             .set_status(Status::Pending, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -375,9 +470,224 @@ impl TaskService {
             .set_status(Status::Deleted, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
+
+        Ok(())
+    }
+
+    pub fn bulk_set_project(
+        &mut self,
+        uuids: &[Uuid],
+        project: Option<String>,
+    ) -> TaskResult<BulkResult> {
+        self.bulk_apply(uuids, |tc_task, ops| {
+            tc_task.set_value("project", project.clone(), ops)
+        })
+    }
+
+    pub fn rename_project(&mut self, old: &str, new_name: &str) -> TaskResult<usize> {
+        let old = old.trim();
+        let new_name = new_name.trim();
+        if old.is_empty() {
+            return Err(TaskError::InvalidProject(old.to_string()));
+        }
+        if new_name.is_empty()
+            || new_name.contains(char::is_whitespace)
+            || new_name.starts_with('.')
+            || new_name.ends_with('.')
+        {
+            return Err(TaskError::InvalidProject(new_name.to_string()));
+        }
+
+        let renames: Vec<(Uuid, String)> = self
+            .get_all_tasks()?
+            .into_iter()
+            .filter_map(|task| {
+                let project = task.project.as_deref()?;
+                renamed_project(project, old, new_name).map(|renamed| (task.uuid, renamed))
+            })
+            .collect();
+
+        let mut ops = Operations::new();
+        for (uuid, project) in &renames {
+            let mut tc_task = self
+                .replica
+                .get_task(*uuid)
+                .map_err(|e| TaskError::Storage(e.to_string()))?
+                .ok_or(TaskError::NotFound(*uuid))?;
+            tc_task
+                .set_value("project", Some(project.clone()), &mut ops)
+                .map_err(|e| TaskError::Storage(e.to_string()))?;
+        }
+
+        if !renames.is_empty() {
+            self.commit(ops)?;
+        }
+
+        log::info!(
+            "TaskService::rename_project: Renamed {} to {} on {} tasks",
+            old,
+            new_name,
+            renames.len()
+        );
+        Ok(renames.len())
+    }
+
+    pub fn bulk_add_tag(&mut self, uuids: &[Uuid], tag: &str) -> TaskResult<BulkResult> {
+        let tag = Tag::try_from(tag).map_err(|_| TaskError::InvalidTag(tag.to_string()))?;
+        self.bulk_apply(uuids, |tc_task, ops| tc_task.add_tag(&tag, ops))
+    }
+
+    pub fn bulk_complete(&mut self, uuids: &[Uuid]) -> TaskResult<BulkResult> {
+        self.bulk_apply(uuids, |tc_task, ops| tc_task.done(ops))
+    }
+
+    pub fn bulk_delete(&mut self, uuids: &[Uuid]) -> TaskResult<BulkResult> {
+        self.bulk_apply(uuids, |tc_task, ops| {
+            tc_task.set_status(Status::Deleted, ops)
+        })
+    }
+
+    fn bulk_apply(
+        &mut self,
+        uuids: &[Uuid],
+        mut apply: impl FnMut(
+            &mut taskchampion::Task,
+            &mut Operations,
+        ) -> Result<(), taskchampion::Error>,
+    ) -> TaskResult<BulkResult> {
+        let mut ops = Operations::new();
+        let mut result = BulkResult::default();
+
+        for uuid in uuids {
+            let tc_task = self
+                .replica
+                .get_task(*uuid)
+                .map_err(|e| TaskError::Storage(e.to_string()))?;
+            let Some(mut tc_task) = tc_task else {
+                log::warn!("TaskService::bulk_apply: Task {} not found", uuid);
+                result.failed += 1;
+                continue;
+            };
+
+            let mut task_ops = Operations::new();
+            match apply(&mut tc_task, &mut task_ops) {
+                Ok(()) => {
+                    for op in task_ops {
+                        ops.push(op);
+                    }
+                    result.succeeded += 1;
+                }
+                Err(e) => {
+                    log::warn!("TaskService::bulk_apply: Failed to update {}: {}", uuid, e);
+                    result.failed += 1;
+                }
+            }
+        }
+
+        if result.succeeded > 0 {
+            self.commit(ops)?;
+        }
+
+        Ok(result)
+    }
+
+    pub fn import_tasks(&mut self, tasks: &[ImportedTask]) -> TaskResult<ImportSummary> {
+        let mut ops = Operations::new();
+        let mut summary = ImportSummary::default();
+        let mut seen: HashSet<Uuid> = HashSet::new();
+
+        for imported in tasks {
+            let uuid = imported.uuid.unwrap_or_else(Uuid::new_v4);
+            let exists = self
+                .replica
+                .get_task(uuid)
+                .map_err(|e| TaskError::Storage(e.to_string()))?
+                .is_some();
+            if exists || !seen.insert(uuid) {
+                summary.duplicates += 1;
+                continue;
+            }
+
+            let mut task_ops = Operations::new();
+            match Self::apply_import(&mut self.replica, uuid, imported, &mut task_ops) {
+                Ok(()) => {
+                    for op in task_ops {
+                        ops.push(op);
+                    }
+                    summary.imported += 1;
+                }
+                Err(e) => {
+                    log::warn!(
+                        "TaskService::import_tasks: Failed to import {}: {}",
+                        uuid,
+                        e
+                    );
+                    summary.errors += 1;
+                }
+            }
+        }
+
+        if summary.imported > 0 {
+            self.commit(ops)?;
+        }
+
+        Ok(summary)
+    }
+
+    fn apply_import(
+        replica: &mut Replica,
+        uuid: Uuid,
+        imported: &ImportedTask,
+        ops: &mut Operations,
+    ) -> Result<(), taskchampion::Error> {
+        let mut tc_task = replica.create_task(uuid, ops)?;
+        tc_task.set_description(imported.description.clone(), ops)?;
+        tc_task.set_entry(imported.entry.or_else(|| Some(Utc::now())), ops)?;
+
+        let status = match imported.status {
+            TaskStatus::Completed => Status::Completed,
+            TaskStatus::Deleted => Status::Deleted,
+            TaskStatus::Recurring => Status::Recurring,
+            TaskStatus::Pending | TaskStatus::Unknown(_) => Status::Pending,
+        };
+        tc_task.set_status(status, ops)?;
+
+        if let Some(project) = &imported.project {
+            tc_task.set_value("project", Some(project.clone()), ops)?;
+        }
+        if let Some(priority) = &imported.priority {
+            tc_task.set_priority(priority.clone(), ops)?;
+        }
+        for tag in &imported.tags {
+            match Tag::try_from(tag.as_str()) {
+                Ok(tag) => tc_task.add_tag(&tag, ops)?,
+                Err(_) => log::warn!("TaskService::apply_import: Skipping invalid tag {}", tag),
+            }
+        }
+        if imported.due.is_some() {
+            tc_task.set_due(imported.due, ops)?;
+        }
+        if imported.wait.is_some() {
+            tc_task.set_wait(imported.wait, ops)?;
+        }
+        for (key, date) in [("scheduled", imported.scheduled), ("end", imported.end)] {
+            if let Some(date) = date {
+                tc_task.set_value(key, Some(date.timestamp().to_string()), ops)?;
+            }
+        }
+        for annotation in &imported.annotations {
+            tc_task.add_annotation(
+                taskchampion::Annotation {
+                    entry: annotation.entry,
+                    description: annotation.content.clone(),
+                },
+                ops,
+            )?;
+        }
+        for dependency in &imported.depends {
+            tc_task.add_dependency(*dependency, ops)?;
+        }
 
         Ok(())
     }
@@ -395,9 +705,7 @@ impl TaskService {
             .start(&mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -415,10 +723,86 @@ impl TaskService {
             .stop(&mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
+        self.commit(ops)?;
+
+        self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
+    }
+
+    pub fn set_recurrence(&mut self, uuid: Uuid, recur: &str) -> TaskResult<Task> {
+        let recur = recur.trim();
+        let value = if recur.is_empty() {
+            None
+        } else {
+            let recurrence = Recurrence::parse(recur)
+                .ok_or_else(|| TaskError::InvalidRecurrence(recur.into()))?;
+            Some(recurrence.to_recur_value())
+        };
+
+        let mut ops = Operations::new();
+
+        let mut tc_task = self
+            .replica
+            .get_task(uuid)
+            .map_err(|e| TaskError::Storage(e.to_string()))?
+            .ok_or(TaskError::NotFound(uuid))?;
+
+        if value.is_some() && tc_task.get_due().is_none() {
+            return Err(TaskError::InvalidRecurrence(
+                "a recurring task needs a due date".into(),
+            ));
+        }
+
+        Self::apply_recurrence(&mut tc_task, value, &mut ops)
+            .map_err(|e| TaskError::Storage(e.to_string()))?;
+
+        self.commit(ops)?;
+
+        self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
+    }
+
+    // Taskwarrior only generates instances from a periodic template with a due date.
+    fn apply_recurrence(
+        tc_task: &mut taskchampion::Task,
+        recur: Option<String>,
+        ops: &mut Operations,
+    ) -> Result<(), taskchampion::Error> {
+        match recur {
+            Some(recur) => {
+                tc_task.set_value("recur", Some(recur), ops)?;
+                tc_task.set_value("rtype", Some("periodic".to_string()), ops)?;
+                tc_task.set_status(Status::Recurring, ops)?;
+            }
+            None => {
+                tc_task.set_value("recur", None, ops)?;
+                tc_task.set_value("rtype", None, ops)?;
+                if tc_task.get_status() == Status::Recurring {
+                    tc_task.set_status(Status::Pending, ops)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn set_until(&mut self, uuid: Uuid, until: Option<DateTime<Utc>>) -> TaskResult<Task> {
+        let mut ops = Operations::new();
+
+        let mut tc_task = self
+            .replica
+            .get_task(uuid)
+            .map_err(|e| TaskError::Storage(e.to_string()))?
+            .ok_or(TaskError::NotFound(uuid))?;
+
+        tc_task
+            .set_value(
+                "until",
+                until.map(|until| until.timestamp().to_string()),
+                &mut ops,
+            )
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
+        self.commit(ops)?;
+
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
 
@@ -437,9 +821,7 @@ impl TaskService {
             .add_tag(&tag, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -459,9 +841,7 @@ impl TaskService {
             .remove_tag(&tag, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -529,13 +909,15 @@ impl TaskService {
         Ok(stats)
     }
 
-    pub fn get_projects_for_tree(&mut self) -> TaskResult<Vec<(String, usize)>> {
+    pub fn get_projects_for_tree(&mut self) -> TaskResult<Vec<(String, usize, usize)>> {
         log::debug!("TaskService::get_projects_for_tree: Getting project stats");
-        let stats = self.list_projects()?;
-        let result: Vec<(String, usize)> = stats
-            .into_iter()
-            .map(|(name, _total, pending)| (name, pending))
+        let summaries: Vec<TaskSummary> = self
+            .get_all_tasks()?
+            .iter()
+            .map(TaskSummary::from)
             .collect();
+
+        let result = TaskSummary::project_counts(&summaries);
         log::debug!(
             "TaskService::get_projects_for_tree: Returning {} projects",
             result.len()
@@ -561,9 +943,7 @@ impl TaskService {
             .add_annotation(annotation, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -581,10 +961,30 @@ impl TaskService {
             .remove_annotation(entry, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
+        self.commit(ops)?;
+
+        self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
+    }
+
+    pub fn set_uda(&mut self, uuid: Uuid, key: &str, value: Option<String>) -> TaskResult<Task> {
+        if !is_user_defined_attribute(key) {
+            return Err(TaskError::InvalidAttribute(format!("{} is read-only", key)));
+        }
+
+        let mut ops = Operations::new();
+
+        let mut tc_task = self
+            .replica
+            .get_task(uuid)
+            .map_err(|e| TaskError::Storage(e.to_string()))?
+            .ok_or(TaskError::NotFound(uuid))?;
+
+        tc_task
+            .set_value(key, value, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
+        self.commit(ops)?;
+
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
 
@@ -601,9 +1001,7 @@ impl TaskService {
             .add_dependency(depends_on, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
-            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.commit(ops)?;
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
@@ -621,13 +1019,75 @@ impl TaskService {
             .remove_dependency(depends_on, &mut ops)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
-        self.replica
-            .commit_operations(ops)
+        self.commit(ops)?;
+
+        self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
+    }
+
+    pub fn add_dependency_by_working_id(
+        &mut self,
+        task_id: usize,
+        depends_on_id: usize,
+    ) -> TaskResult<Task> {
+        let uuid = self.uuid_for_working_id(task_id)?;
+        let depends_on = self.uuid_for_working_id(depends_on_id)?;
+        if uuid == depends_on {
+            return Err(TaskError::InvalidDependency(format!(
+                "task {} cannot depend on itself",
+                task_id
+            )));
+        }
+        self.add_dependency(uuid, depends_on)
+    }
+
+    pub fn remove_dependency_by_working_id(
+        &mut self,
+        task_id: usize,
+        depends_on_id: usize,
+    ) -> TaskResult<Task> {
+        let uuid = self.uuid_for_working_id(task_id)?;
+        let depends_on = self.uuid_for_working_id(depends_on_id)?;
+        self.remove_dependency(uuid, depends_on)
+    }
+
+    pub fn set_dependencies_by_working_id(
+        &mut self,
+        task_id: usize,
+        depends_on: &[usize],
+    ) -> TaskResult<Task> {
+        let uuid = self.uuid_for_working_id(task_id)?;
+        for id in depends_on {
+            self.uuid_for_working_id(*id)?;
+        }
+
+        let task = self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))?;
+        let working_set = self
+            .replica
+            .working_set()
             .map_err(|e| TaskError::Storage(e.to_string()))?;
+        // Dependencies on tasks outside the working set have no ID to type, so they stay.
+        let current: Vec<usize> = task
+            .dependencies
+            .iter()
+            .filter_map(|dependency| working_set.by_uuid(*dependency))
+            .collect();
+
+        for id in current.iter().filter(|id| !depends_on.contains(id)) {
+            self.remove_dependency_by_working_id(task_id, *id)?;
+        }
+        for id in depends_on.iter().filter(|id| !current.contains(id)) {
+            self.add_dependency_by_working_id(task_id, *id)?;
+        }
 
         self.get_task(uuid)?.ok_or(TaskError::NotFound(uuid))
     }
 
+    fn uuid_for_working_id(&mut self, id: usize) -> TaskResult<Uuid> {
+        self.get_task_by_working_id(id)?
+            .map(|task| task.uuid)
+            .ok_or_else(|| TaskError::InvalidDependency(format!("no task with id {}", id)))
+    }
+
     pub fn sync(&mut self) -> TaskResult<SyncResult> {
         let server_dir = self.taskdb_dir.join("server");
 
@@ -651,6 +1111,7 @@ impl TaskService {
             .into_server()
             .map_err(|e| TaskError::Storage(e.to_string()))?;
 
+        self.mark_written();
         self.replica
             .sync(&mut server, false)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
@@ -669,13 +1130,28 @@ impl TaskService {
     }
 
     pub fn pending_sync_operations(&mut self) -> TaskResult<usize> {
-        Ok(self
+        if let Some(count) = self.pending_ops {
+            return Ok(count);
+        }
+
+        let count = self
             .replica
             .num_local_operations()
-            .map_err(|e| TaskError::Storage(e.to_string()))?)
+            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        self.pending_ops = Some(count);
+        Ok(count)
+    }
+
+    pub fn working_set_size(&mut self) -> TaskResult<usize> {
+        let ws = self
+            .replica
+            .working_set()
+            .map_err(|e| TaskError::Storage(e.to_string()))?;
+        Ok(ws.len())
     }
 
     pub fn rebuild_working_set(&mut self, renumber: bool) -> TaskResult<()> {
+        self.mark_written();
         self.replica
             .rebuild_working_set(renumber)
             .map_err(|e| TaskError::Storage(e.to_string()))?;
@@ -683,6 +1159,7 @@ impl TaskService {
     }
 
     pub fn expire_tasks(&mut self) -> TaskResult<()> {
+        self.mark_written();
         self.replica
             .expire_tasks()
             .map_err(|e| TaskError::Storage(e.to_string()))?;
@@ -718,6 +1195,19 @@ impl TaskService {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkResult {
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub imported: usize,
+    pub duplicates: usize,
+    pub errors: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct SyncResult {
     pub success: bool,
@@ -725,3 +1215,318 @@ pub struct SyncResult {
     pub local_ops_before: usize,
     pub local_ops_after: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::task::model::TaskPriority;
+    use crate::test_support::TempDir;
+
+    struct TempReplica {
+        service: TaskService,
+        _dir: TempDir,
+    }
+
+    impl TempReplica {
+        fn new() -> Self {
+            let dir = TempDir::new("test");
+            let service = TaskService::with_path(dir.path().to_path_buf()).unwrap();
+            Self { service, _dir: dir }
+        }
+
+        fn summaries(&mut self) -> Vec<TaskSummary> {
+            let mut summaries: Vec<TaskSummary> = self
+                .service
+                .get_all_tasks()
+                .unwrap()
+                .iter()
+                .map(TaskSummary::from)
+                .collect();
+            TaskSummary::mark_blocking(&mut summaries);
+            summaries
+        }
+
+        fn detail(&mut self, uuid: Uuid) -> TaskDetailVm {
+            let summaries = self.summaries();
+            self.service.get_task_detail(uuid, &summaries).unwrap()
+        }
+    }
+
+    fn draft(description: &str) -> TaskDraft {
+        TaskDraft {
+            description: description.to_string(),
+            ..TaskDraft::default()
+        }
+    }
+
+    #[test]
+    fn test_create_then_update_fields() {
+        let mut replica = TempReplica::new();
+        let due = Utc.with_ymd_and_hms(2026, 10, 20, 9, 0, 0).unwrap();
+        let created = replica
+            .service
+            .create_task(TaskDraft {
+                project: Some("Work".to_string()),
+                tags: vec!["bug".to_string()],
+                priority: Some("H".to_string()),
+                due: Some(due),
+                ..draft("Fix login")
+            })
+            .unwrap();
+
+        assert_eq!(created.description, "Fix login");
+        assert_eq!(created.status, TaskStatus::Pending);
+        assert_eq!(created.project.as_deref(), Some("Work"));
+        assert_eq!(created.priority, TaskPriority::High);
+        assert_eq!(created.due, Some(due));
+        assert!(created.tags.contains("bug"));
+        assert_eq!(created.working_id, Some(1));
+
+        let new_due = Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap();
+        let updated = replica
+            .service
+            .update_task(
+                created.uuid,
+                Some("Fix login redirect".to_string()),
+                Some(Some("Work.Backend".to_string())),
+                Some("M".to_string()),
+                Some(HashSet::from(["urgent".to_string()])),
+                Some(Some(new_due)),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(updated.description, "Fix login redirect");
+        assert_eq!(updated.project.as_deref(), Some("Work.Backend"));
+        assert_eq!(updated.priority, TaskPriority::Medium);
+        assert_eq!(updated.due, Some(new_due));
+        assert!(updated.tags.contains("urgent"));
+        assert!(!updated.tags.contains("bug"));
+
+        let cleared = replica
+            .service
+            .update_task(created.uuid, None, Some(None), None, None, Some(None), None)
+            .unwrap();
+        assert!(cleared.project.is_none());
+        assert!(cleared.due.is_none());
+
+        let summaries = replica.summaries();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].uuid, created.uuid);
+        assert_eq!(summaries[0].working_id, Some(1));
+        assert_eq!(summaries[0].priority, TaskPriority::Medium);
+    }
+
+    #[test]
+    fn test_annotations_show_in_detail() {
+        let mut replica = TempReplica::new();
+        let task = replica.service.create_task(draft("Call the bank")).unwrap();
+
+        let annotated = replica
+            .service
+            .add_annotation(task.uuid, "Ask about the fee".to_string())
+            .unwrap();
+        assert_eq!(annotated.annotations.len(), 1);
+
+        let detail = replica.detail(task.uuid);
+        assert_eq!(detail.identity.uuid, task.uuid);
+        assert_eq!(detail.identity.working_id, Some(1));
+        assert_eq!(detail.overview.description, "Call the bank");
+        assert_eq!(detail.annotations.len(), 1);
+        assert_eq!(detail.annotations[0].content, "Ask about the fee");
+
+        let entry = detail.annotations[0].entry;
+        let task = replica.service.remove_annotation(task.uuid, entry).unwrap();
+        assert!(task.annotations.is_empty());
+    }
+
+    #[test]
+    fn test_dependencies_show_in_detail() {
+        let mut replica = TempReplica::new();
+        let blocker = replica.service.create_task(draft("Write spec")).unwrap();
+        let blocked = replica
+            .service
+            .create_task(draft("Implement spec"))
+            .unwrap();
+
+        let task = replica
+            .service
+            .add_dependency(blocked.uuid, blocker.uuid)
+            .unwrap();
+        assert!(task.dependencies.contains(&blocker.uuid));
+
+        let detail = replica.detail(blocked.uuid);
+        assert_eq!(detail.dependencies.depends_on.len(), 1);
+        assert_eq!(detail.dependencies.blocked_by[0].uuid, blocker.uuid);
+        assert_eq!(detail.dependencies.blocked_by[0].id, Some(1));
+
+        let detail = replica.detail(blocker.uuid);
+        assert_eq!(detail.dependencies.blocking[0].uuid, blocked.uuid);
+        assert!(
+            replica
+                .summaries()
+                .iter()
+                .any(|summary| summary.uuid == blocker.uuid && summary.is_blocking)
+        );
+
+        replica.service.complete_task(blocker.uuid).unwrap();
+        let detail = replica.detail(blocked.uuid);
+        assert_eq!(detail.dependencies.depends_on.len(), 1);
+        assert!(detail.dependencies.blocked_by.is_empty());
+    }
+
+    #[test]
+    fn test_dependency_round_trip_by_working_id() {
+        let mut replica = TempReplica::new();
+        let service = &mut replica.service;
+        let blocker = service.create_task(draft("Write spec")).unwrap();
+        let blocked = service.create_task(draft("Implement spec")).unwrap();
+        let blocker_id = blocker.working_id.unwrap();
+        let blocked_id = blocked.working_id.unwrap();
+
+        let task = service
+            .add_dependency_by_working_id(blocked_id, blocker_id)
+            .unwrap();
+        assert!(task.dependencies.contains(&blocker.uuid));
+
+        let task = service
+            .remove_dependency_by_working_id(blocked_id, blocker_id)
+            .unwrap();
+        assert!(task.dependencies.is_empty());
+
+        assert!(matches!(
+            service.add_dependency_by_working_id(blocked_id, 999),
+            Err(TaskError::InvalidDependency(_))
+        ));
+
+        let task = service
+            .set_dependencies_by_working_id(blocked_id, &[blocker_id])
+            .unwrap();
+        assert_eq!(task.dependencies, HashSet::from([blocker.uuid]));
+        let task = service
+            .set_dependencies_by_working_id(blocked_id, &[])
+            .unwrap();
+        assert!(task.dependencies.is_empty());
+
+        let mut with_deps = draft("Ship it");
+        with_deps.depends = vec![blocker_id, blocked_id];
+        let task = service.create_task(with_deps).unwrap();
+        assert_eq!(task.dependencies.len(), 2);
+    }
+
+    #[test]
+    fn test_complete_delete_and_working_set() {
+        let mut replica = TempReplica::new();
+        let service = &mut replica.service;
+        let pending_count = |service: &mut TaskService| {
+            service
+                .get_all_tasks()
+                .unwrap()
+                .iter()
+                .filter(|task| task.status == TaskStatus::Pending)
+                .count()
+        };
+        let done = service.create_task(draft("Buy milk")).unwrap();
+        let dropped = service.create_task(draft("Old idea")).unwrap();
+        let kept = service.create_task(draft("Water plants")).unwrap();
+        assert_eq!(kept.working_id, Some(3));
+        assert_eq!(pending_count(service), 3);
+
+        let completed = service.complete_task(done.uuid).unwrap();
+        assert_eq!(completed.status, TaskStatus::Completed);
+        service.delete_task(dropped.uuid).unwrap();
+        assert_eq!(
+            service.get_task(dropped.uuid).unwrap().unwrap().status,
+            TaskStatus::Deleted
+        );
+
+        let reopened = service.reopen_task(done.uuid).unwrap();
+        assert_eq!(reopened.status, TaskStatus::Pending);
+        service.complete_task(done.uuid).unwrap();
+
+        service.rebuild_working_set(true).unwrap();
+        assert_eq!(service.working_set_size().unwrap(), 1);
+        assert_eq!(pending_count(service), 1);
+        let first = service.get_task_by_working_id(1).unwrap().unwrap();
+        assert_eq!(first.uuid, kept.uuid);
+        assert!(service.get_task_by_working_id(3).unwrap().is_none());
+
+        let working_set = service.working_set().unwrap();
+        assert_eq!(working_set.len(), 1);
+        assert_eq!(working_set[0].0, 1);
+    }
+    #[test]
+    fn test_bulk_apply_drops_operations_of_failed_tasks() {
+        let mut replica = TempReplica::new();
+        let kept = replica.service.create_task(draft("Water plants")).unwrap();
+        let failed = replica.service.create_task(draft("Old idea")).unwrap();
+
+        let synthetic = Tag::try_from("PENDING").unwrap();
+        let mut worker = replica.service.open_worker().unwrap();
+        let result = worker
+            .bulk_apply(&[kept.uuid, failed.uuid], |tc_task, ops| {
+                tc_task.set_value("project", Some("Home".to_string()), ops)?;
+                if tc_task.get_uuid() == failed.uuid {
+                    tc_task.add_tag(&synthetic, ops)?;
+                }
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            BulkResult {
+                succeeded: 1,
+                failed: 1
+            }
+        );
+        let service = &mut replica.service;
+        let kept = service.get_task(kept.uuid).unwrap().unwrap();
+        let failed = service.get_task(failed.uuid).unwrap().unwrap();
+        assert_eq!(kept.project.as_deref(), Some("Home"));
+        assert!(failed.project.is_none());
+    }
+
+    #[test]
+    fn test_recurrence_turns_the_task_into_a_template() {
+        let mut replica = TempReplica::new();
+        let task = replica.service.create_task(draft("Pay rent")).unwrap();
+        assert!(
+            replica
+                .service
+                .set_recurrence(task.uuid, "monthly")
+                .is_err()
+        );
+
+        let due = Utc.with_ymd_and_hms(2026, 11, 1, 9, 0, 0).unwrap();
+        replica
+            .service
+            .update_task(task.uuid, None, None, None, None, Some(Some(due)), None)
+            .unwrap();
+        let recurring = replica.service.set_recurrence(task.uuid, "2w").unwrap();
+        assert_eq!(recurring.status, TaskStatus::Recurring);
+        assert_eq!(recurring.recur.as_deref(), Some("2weeks"));
+
+        let cleared = replica.service.set_recurrence(task.uuid, "").unwrap();
+        assert_eq!(cleared.status, TaskStatus::Pending);
+        assert!(cleared.recur.is_none());
+    }
+
+    #[test]
+    fn test_start_and_end_show_in_detail() {
+        let mut replica = TempReplica::new();
+        let task = replica.service.create_task(draft("Write report")).unwrap();
+        assert!(replica.detail(task.uuid).dates.start.is_none());
+
+        replica.service.start_task(task.uuid).unwrap();
+        let detail = replica.detail(task.uuid);
+        assert!(detail.overview.is_active);
+        assert!(detail.dates.start.is_some());
+        assert!(detail.dates.end.is_none());
+
+        replica.service.complete_task(task.uuid).unwrap();
+        assert!(replica.detail(task.uuid).dates.end.is_some());
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::error::{TaskError, TaskResult};
+use super::urgency::UrgencyConfig;
+
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataLocationSource {
+    Taskrc(PathBuf),
+    TaskdataEnv,
+    Default,
+    Custom,
+}
+
+impl fmt::Display for DataLocationSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DataLocationSource::Taskrc(path) => write!(f, "data.location in {}", path.display()),
+            DataLocationSource::TaskdataEnv => write!(f, "TASKDATA environment variable"),
+            DataLocationSource::Default => write!(f, "default location"),
+            DataLocationSource::Custom => write!(f, "custom path"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskrcColors {
+    pub active: Option<String>,
+    pub overdue: Option<String>,
+    pub due: Option<String>,
+    pub due_today: Option<String>,
+    pub scheduled: Option<String>,
+    pub blocked: Option<String>,
+    pub blocking: Option<String>,
+    pub completed: Option<String>,
+    pub deleted: Option<String>,
+    pub recurring: Option<String>,
+}
+
+impl TaskrcColors {
+    fn apply_setting(&mut self, name: &str, value: &str) -> bool {
+        let slot = match name {
+            "active" => &mut self.active,
+            "overdue" => &mut self.overdue,
+            "due" => &mut self.due,
+            "due.today" => &mut self.due_today,
+            "scheduled" => &mut self.scheduled,
+            "blocked" => &mut self.blocked,
+            "blocking" => &mut self.blocking,
+            "completed" => &mut self.completed,
+            "deleted" => &mut self.deleted,
+            "recurring" => &mut self.recurring,
+            _ => return false,
+        };
+        *slot = (!value.is_empty()).then(|| value.to_string());
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TaskrcConfig {
+    pub data_location: PathBuf,
+    pub data_location_source: DataLocationSource,
+    pub urgency: UrgencyConfig,
+    pub colors: TaskrcColors,
+}
+
+#[derive(Debug, Default)]
+struct TaskrcSettings {
+    data_location: Option<(PathBuf, PathBuf)>,
+    urgency: UrgencyConfig,
+    colors: TaskrcColors,
+}
+
+pub fn taskrc_path() -> TaskResult<PathBuf> {
+    if let Ok(taskrc) = std::env::var("TASKRC") {
+        return Ok(PathBuf::from(taskrc));
+    }
+    Ok(home_dir()?.join(".config/task/taskrc"))
+}
+
+pub fn read_taskrc_config() -> TaskResult<TaskrcConfig> {
+    let taskrc_path = taskrc_path()?;
+    log::debug!("Looking for taskrc at: {:?}", taskrc_path);
+
+    let mut settings = TaskrcSettings::default();
+    if taskrc_path.exists() {
+        read_taskrc_file(&taskrc_path, &mut settings, 0, &mut HashSet::new())?;
+    }
+
+    let (data_location, data_location_source) = if let Ok(taskdata) = std::env::var("TASKDATA") {
+        (PathBuf::from(taskdata), DataLocationSource::TaskdataEnv)
+    } else {
+        match settings.data_location {
+            Some((path, file)) => (path, DataLocationSource::Taskrc(file)),
+            None => (home_dir()?.join(".task"), DataLocationSource::Default),
+        }
+    };
+
+    log::info!(
+        "Using data location {:?} ({})",
+        data_location,
+        data_location_source
+    );
+
+    Ok(TaskrcConfig {
+        data_location,
+        data_location_source,
+        urgency: settings.urgency,
+        colors: settings.colors,
+    })
+}
+
+fn read_taskrc_file(
+    path: &Path,
+    settings: &mut TaskrcSettings,
+    depth: usize,
+    visited: &mut HashSet<PathBuf>,
+) -> TaskResult<()> {
+    if depth > MAX_INCLUDE_DEPTH {
+        log::warn!("Ignoring taskrc include {:?}: nested too deeply", path);
+        return Ok(());
+    }
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if !visited.insert(canonical) {
+        log::warn!("Ignoring taskrc include {:?}: include cycle", path);
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        TaskError::Config(format!("Failed to read taskrc {}: {}", path.display(), e))
+    })?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    for line in content.lines() {
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(target) = line
+            .strip_prefix("include")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+        {
+            let target = resolve_include(unquote(target.trim()), base_dir)?;
+            if target.is_file() {
+                read_taskrc_file(&target, settings, depth + 1, visited)?;
+            } else {
+                log::debug!("Skipping taskrc include {:?}: not found", target);
+            }
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = unquote(value.trim());
+
+        if key == "data.location" {
+            let location = expand_home(value)?;
+            log::debug!("Found data.location {:?} in {:?}", location, path);
+            settings.data_location = Some((location, path.to_path_buf()));
+        } else if key.starts_with("urgency.") && !settings.urgency.apply_setting(key, value) {
+            log::warn!("Ignoring unsupported urgency setting in taskrc: {}", line);
+        } else if let Some(name) = key.strip_prefix("color.") {
+            if !settings.colors.apply_setting(name, value) {
+                log::debug!("Ignoring taskrc color setting: {}", key);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(idx) => &line[..idx],
+        None => line,
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+fn resolve_include(target: &str, base_dir: &Path) -> TaskResult<PathBuf> {
+    let expanded = expand_home(target)?;
+    if expanded.is_absolute() {
+        Ok(expanded)
+    } else {
+        Ok(base_dir.join(expanded))
+    }
+}
+
+fn expand_home(path: &str) -> TaskResult<PathBuf> {
+    match path.strip_prefix('~') {
+        Some(rest) => Ok(home_dir()?.join(rest.trim_start_matches('/'))),
+        None => Ok(PathBuf::from(path)),
+    }
+}
+
+fn home_dir() -> TaskResult<PathBuf> {
+    dirs::home_dir().ok_or_else(|| TaskError::Config("Cannot find home directory".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    struct Fixture {
+        dir: TempDir,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                dir: TempDir::new("rc"),
+            }
+        }
+
+        fn write(&self, name: &str, content: &str) -> PathBuf {
+            let path = self.dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path
+        }
+
+        fn read(&self, name: &str) -> TaskrcSettings {
+            let mut settings = TaskrcSettings::default();
+            read_taskrc_file(
+                &self.dir.path().join(name),
+                &mut settings,
+                0,
+                &mut HashSet::new(),
+            )
+            .unwrap();
+            settings
+        }
+    }
+
+    #[test]
+    fn test_include_provides_data_location() {
+        let fixture = Fixture::new();
+        let local = fixture.write("local.rc", "data.location=/srv/tasks\n");
+        fixture.write(
+            "taskrc",
+            "# main\ninclude local.rc\nurgency.due.coefficient=5\n",
+        );
+
+        let settings = fixture.read("taskrc");
+        assert_eq!(
+            settings.data_location,
+            Some((PathBuf::from("/srv/tasks"), local))
+        );
+    }
+
+    #[test]
+    fn test_later_settings_override_includes() {
+        let fixture = Fixture::new();
+        fixture.write("theme.rc", "data.location = /from/include\n");
+        let main = fixture.write(
+            "taskrc",
+            &format!(
+                "include {}\ndata.location=/from/main\n",
+                fixture.dir.path().join("theme.rc").display()
+            ),
+        );
+
+        let settings = fixture.read("taskrc");
+        assert_eq!(
+            settings.data_location,
+            Some((PathBuf::from("/from/main"), main))
+        );
+    }
+
+    #[test]
+    fn test_quoted_values_and_trailing_comments() {
+        let fixture = Fixture::new();
+        fixture.write("taskrc", "  data.location = \"/tmp/my tasks\"  # synced\n");
+        let settings = fixture.read("taskrc");
+        assert_eq!(
+            settings.data_location.map(|(path, _)| path),
+            Some(PathBuf::from("/tmp/my tasks"))
+        );
+
+        fixture.write("single", "data.location='/tmp/single'\n");
+        let settings = fixture.read("single");
+        assert_eq!(
+            settings.data_location.map(|(path, _)| path),
+            Some(PathBuf::from("/tmp/single"))
+        );
+    }
+
+    #[test]
+    fn test_include_cycles_and_missing_files_are_ignored() {
+        let fixture = Fixture::new();
+        fixture.write("a.rc", "include b.rc\ndata.location=/from/a\n");
+        fixture.write("b.rc", "include a.rc\ninclude dark-256.theme\n");
+
+        let settings = fixture.read("a.rc");
+        assert_eq!(
+            settings.data_location.map(|(path, _)| path),
+            Some(PathBuf::from("/from/a"))
+        );
+    }
+
+    #[test]
+    fn test_color_settings_follow_includes() {
+        let fixture = Fixture::new();
+        fixture.write(
+            "dark-256.theme",
+            "color.active=rgb555 on rgb410\ncolor.overdue=color255 on rgb300\ncolor.due.today=red\n",
+        );
+        fixture.write(
+            "taskrc",
+            "include dark-256.theme\ncolor.overdue=bold red\ncolor.tag.next=yellow\ncolor.due.today=\n",
+        );
+
+        let settings = fixture.read("taskrc");
+        assert_eq!(
+            settings.colors,
+            TaskrcColors {
+                active: Some("rgb555 on rgb410".into()),
+                overdue: Some("bold red".into()),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use super::model::TaskPriority;
+
+const LEGACY_PRIORITY_MEDIUM_RATIO: f32 = 0.65;
+const LEGACY_PRIORITY_LOW_RATIO: f32 = 0.3;
+const DUE_DECAY_DAYS: f32 = 7.0;
+const DUE_MIN_FACTOR: f32 = 0.2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UrgencyConfig {
+    pub priority_high: f32,
+    pub priority_medium: f32,
+    pub priority_low: f32,
+    pub due: f32,
+    pub blocking: f32,
+    pub blocked: f32,
+    pub active: f32,
+    pub tags: f32,
+    pub project: f32,
+    pub annotations: f32,
+    pub age: f32,
+    pub age_max_days: f32,
+    pub tag_coefficients: HashMap<String, f32>,
+}
+
+impl Default for UrgencyConfig {
+    fn default() -> Self {
+        Self {
+            priority_high: 6.0,
+            priority_medium: 3.9,
+            priority_low: 1.8,
+            due: 12.0,
+            blocking: 8.0,
+            blocked: -5.0,
+            active: 4.0,
+            tags: 1.0,
+            project: 1.0,
+            annotations: 1.0,
+            age: 2.0,
+            age_max_days: 365.0,
+            tag_coefficients: HashMap::from([("next".to_string(), 15.0)]),
+        }
+    }
+}
+
+impl UrgencyConfig {
+    pub fn apply_setting(&mut self, key: &str, value: &str) -> bool {
+        let Ok(value) = value.trim().parse::<f32>() else {
+            return false;
+        };
+
+        if key == "urgency.age.max" {
+            self.age_max_days = value;
+            return true;
+        }
+
+        let Some(name) = key
+            .strip_prefix("urgency.")
+            .and_then(|key| key.strip_suffix(".coefficient"))
+        else {
+            return false;
+        };
+
+        match name {
+            "priority" => {
+                self.priority_high = value;
+                self.priority_medium = value * LEGACY_PRIORITY_MEDIUM_RATIO;
+                self.priority_low = value * LEGACY_PRIORITY_LOW_RATIO;
+            }
+            "uda.priority.H" => self.priority_high = value,
+            "uda.priority.M" => self.priority_medium = value,
+            "uda.priority.L" => self.priority_low = value,
+            "due" => self.due = value,
+            "blocking" => self.blocking = value,
+            "blocked" => self.blocked = value,
+            "active" => self.active = value,
+            "tags" => self.tags = value,
+            "project" => self.project = value,
+            "annotations" => self.annotations = value,
+            "age" => self.age = value,
+            _ => match name.strip_prefix("user.tag.") {
+                Some(tag) if !tag.is_empty() => {
+                    self.tag_coefficients.insert(tag.to_string(), value);
+                }
+                _ => return false,
+            },
+        }
+
+        true
+    }
+
+    pub fn priority_coefficient(&self, priority: TaskPriority) -> f32 {
+        match priority {
+            TaskPriority::High => self.priority_high,
+            TaskPriority::Medium => self.priority_medium,
+            TaskPriority::Low => self.priority_low,
+            TaskPriority::None => 0.0,
+        }
+    }
+
+    pub fn tag_coefficient(&self, tag: &str) -> f32 {
+        self.tag_coefficients.get(tag).copied().unwrap_or(0.0)
+    }
+
+    pub fn age_factor(&self, entry: DateTime<Utc>, now: DateTime<Utc>) -> f32 {
+        if self.age_max_days <= 0.0 {
+            return 1.0;
+        }
+        let days = (now - entry).num_seconds() as f32 / 86_400.0;
+        (days / self.age_max_days).clamp(0.0, 1.0)
+    }
+}
+
+pub fn due_factor(due: DateTime<Utc>, now: DateTime<Utc>) -> f32 {
+    let days_until = (due - now).num_seconds() as f32 / 86_400.0;
+    if days_until <= 0.0 {
+        return 1.0;
+    }
+    (-days_until / DUE_DECAY_DAYS).exp().max(DUE_MIN_FACTOR)
+}
+
+pub fn count_factor(count: usize) -> f32 {
+    match count {
+        0 => 0.0,
+        1 => 0.8,
+        2 => 0.9,
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_apply_setting_reads_coefficients() {
+        let mut config = UrgencyConfig::default();
+        assert!(config.apply_setting("urgency.uda.priority.H.coefficient", "10"));
+        assert!(config.apply_setting("urgency.user.tag.work.coefficient", " 2.5 "));
+        assert!(config.apply_setting("urgency.blocked.coefficient", "-1"));
+        assert!(!config.apply_setting("urgency.unknown.coefficient", "1"));
+        assert!(!config.apply_setting("urgency.due.coefficient", "soon"));
+        assert!(!config.apply_setting("data.location", "1"));
+
+        assert_eq!(config.priority_high, 10.0);
+        assert_eq!(config.tag_coefficient("work"), 2.5);
+        assert_eq!(config.tag_coefficient("next"), 15.0);
+        assert_eq!(config.blocked, -1.0);
+    }
+
+    #[test]
+    fn test_legacy_priority_coefficient_scales_levels() {
+        let mut config = UrgencyConfig::default();
+        assert!(config.apply_setting("urgency.priority.coefficient", "10"));
+        assert_eq!(config.priority_coefficient(TaskPriority::High), 10.0);
+        assert!((config.priority_coefficient(TaskPriority::Medium) - 6.5).abs() < 1e-4);
+        assert!((config.priority_coefficient(TaskPriority::Low) - 3.0).abs() < 1e-4);
+        assert_eq!(config.priority_coefficient(TaskPriority::None), 0.0);
+    }
+
+    #[test]
+    fn test_due_factor_decays_with_distance() {
+        let now = Utc::now();
+        assert_eq!(due_factor(now - Duration::days(3), now), 1.0);
+        let tomorrow = due_factor(now + Duration::days(1), now);
+        let next_week = due_factor(now + Duration::days(7), now);
+        assert!(tomorrow > next_week);
+        assert!(tomorrow < 1.0);
+        assert_eq!(due_factor(now + Duration::days(90), now), DUE_MIN_FACTOR);
+    }
+
+    #[test]
+    fn test_age_factor_is_capped() {
+        let config = UrgencyConfig::default();
+        let now = Utc::now();
+        assert_eq!(config.age_factor(now - Duration::days(730), now), 1.0);
+        assert_eq!(config.age_factor(now + Duration::days(1), now), 0.0);
+    }
+}
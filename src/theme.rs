@@ -1,5 +1,18 @@
+use crate::task::taskrc::TaskrcColors;
+
 pub type Color = gpui::Rgba;
 
+const ANSI_COLORS: [u32; 16] = [
+    0x2E3436, 0xCC0000, 0x4E9A06, 0xC4A000, 0x3465A4, 0x75507B, 0x06989A, 0xD3D7CF, 0x555753,
+    0xEF2929, 0x8AE234, 0xFCE94F, 0x729FCF, 0xAD7FA8, 0x34E2E2, 0xEEEEEC,
+];
+
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+const CUBE_LEVELS: [u32; 6] = [0x00, 0x5F, 0x87, 0xAF, 0xD7, 0xFF];
+
 #[derive(Debug, Clone)]
 pub struct Theme {
     pub background: Color,
@@ -36,6 +49,19 @@ pub struct Theme {
     pub medium: Color,
     pub low: Color,
 
+    pub task_active: Color,
+    pub task_overdue: Color,
+    pub task_due: Color,
+    pub task_due_today: Color,
+    pub task_scheduled: Color,
+    pub task_blocked: Color,
+    pub task_blocking: Color,
+    pub task_completed: Color,
+    pub task_deleted: Color,
+    pub task_recurring: Color,
+
+    pub tag_palette: [Color; 12],
+
     pub text_size: Option<gpui::Size<u32>>,
 }
 
@@ -77,6 +103,21 @@ impl Theme {
         let warning = gpui::rgb(0xFFB454);
         let info = gpui::rgb(0x59C2FF);
 
+        let tag_palette = [
+            gpui::rgb(0x59C2FF),
+            gpui::rgb(0xAAD94C),
+            gpui::rgb(0xFFB454),
+            gpui::rgb(0xF07178),
+            gpui::rgb(0xD2A6FF),
+            gpui::rgb(0x95E6CB),
+            gpui::rgb(0xE6B673),
+            gpui::rgb(0x73B8FF),
+            gpui::rgb(0xF29668),
+            gpui::rgb(0xC2D94C),
+            gpui::rgb(0xFF8F40),
+            gpui::rgb(0x7FD962),
+        ];
+
         Self {
             background,
             panel,
@@ -112,6 +153,19 @@ impl Theme {
             medium: warning,
             low: success,
 
+            task_active: success,
+            task_overdue: error,
+            task_due: warning,
+            task_due_today: accent,
+            task_scheduled: info,
+            task_blocked: warning,
+            task_blocking: accent,
+            task_completed: muted,
+            task_deleted: error,
+            task_recurring: info,
+
+            tag_palette,
+
             text_size: Some(gpui::Size::new(14, 14)),
         }
     }
@@ -144,6 +198,21 @@ impl Theme {
         let warning = gpui::rgb(0xF2AE49);
         let info = gpui::rgb(0x399EE6);
 
+        let tag_palette = [
+            gpui::rgb(0x399EE6),
+            gpui::rgb(0x86B300),
+            gpui::rgb(0xF2AE49),
+            gpui::rgb(0xE65050),
+            gpui::rgb(0xA37ACC),
+            gpui::rgb(0x4CBF99),
+            gpui::rgb(0xE6BA7E),
+            gpui::rgb(0x55B4D4),
+            gpui::rgb(0xED9366),
+            gpui::rgb(0x6CBF43),
+            gpui::rgb(0xFA8D3E),
+            gpui::rgb(0xF07171),
+        ];
+
         Self {
             background,
             panel,
@@ -179,10 +248,56 @@ impl Theme {
             medium: warning,
             low: success,
 
+            task_active: success,
+            task_overdue: error,
+            task_due: warning,
+            task_due_today: accent,
+            task_scheduled: info,
+            task_blocked: warning,
+            task_blocking: accent,
+            task_completed: muted,
+            task_deleted: error,
+            task_recurring: info,
+
+            tag_palette,
+
             text_size: Some(gpui::Size::new(14, 14)),
         }
     }
 
+    pub fn with_taskrc_overrides(base: Theme, colors: &TaskrcColors) -> Theme {
+        let mut theme = base;
+        let overrides = [
+            (&colors.active, &mut theme.task_active),
+            (&colors.overdue, &mut theme.task_overdue),
+            (&colors.due, &mut theme.task_due),
+            (&colors.due_today, &mut theme.task_due_today),
+            (&colors.scheduled, &mut theme.task_scheduled),
+            (&colors.blocked, &mut theme.task_blocked),
+            (&colors.blocking, &mut theme.task_blocking),
+            (&colors.completed, &mut theme.task_completed),
+            (&colors.deleted, &mut theme.task_deleted),
+            (&colors.recurring, &mut theme.task_recurring),
+        ];
+        for (spec, slot) in overrides {
+            let Some(spec) = spec else {
+                continue;
+            };
+            match parse_taskwarrior_color(spec) {
+                Some(color) => *slot = color,
+                None => log::warn!("Ignoring unsupported taskrc color: {}", spec),
+            }
+        }
+        theme
+    }
+
+    pub fn tag_color(&self, tag: &str) -> Color {
+        let hash = tag.bytes().fold(0x811C9DC5u32, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        });
+        self.tag_palette[hash as usize % self.tag_palette.len()]
+    }
+
     pub fn global(app: &gpui::App) -> &Self {
         app.global::<Self>()
     }
@@ -190,6 +305,75 @@ impl Theme {
 
 impl gpui::Global for Theme {}
 
+// Taskwarrior color specs look like "bold red on blue", "rgb035", "color214"
+// or "gray12"; only the foreground matters for the GUI.
+pub fn parse_taskwarrior_color(spec: &str) -> Option<Color> {
+    let foreground = spec.split(" on ").next().unwrap_or_default();
+    let mut bright = false;
+    for word in foreground.split_whitespace() {
+        match word {
+            "bold" | "underline" | "inverse" => {}
+            "bright" => bright = true,
+            word => {
+                let index = ansi_index(word)
+                    .map(|idx| if bright { idx + 8 } else { idx })
+                    .or_else(|| palette_index(word))?;
+                return Some(palette_color(index));
+            }
+        }
+    }
+    None
+}
+
+fn ansi_index(name: &str) -> Option<u32> {
+    ANSI_NAMES
+        .iter()
+        .position(|candidate| *candidate == name)
+        .map(|idx| idx as u32)
+}
+
+fn palette_index(word: &str) -> Option<u32> {
+    if let Some(digits) = word.strip_prefix("rgb") {
+        let levels: Vec<u32> = digits.chars().filter_map(|c| c.to_digit(6)).collect();
+        return match levels.as_slice() {
+            [r, g, b] if digits.len() == 3 => Some(16 + r * 36 + g * 6 + b),
+            _ => None,
+        };
+    }
+    if let Some(level) = word
+        .strip_prefix("gray")
+        .or_else(|| word.strip_prefix("grey"))
+    {
+        return level
+            .parse::<u32>()
+            .ok()
+            .filter(|n| *n < 24)
+            .map(|n| 232 + n);
+    }
+    word.strip_prefix("color")?
+        .parse::<u32>()
+        .ok()
+        .filter(|n| *n < 256)
+}
+
+fn palette_color(index: u32) -> Color {
+    let hex = match index {
+        0..=15 => ANSI_COLORS[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            let r = CUBE_LEVELS[(cube / 36) as usize];
+            let g = CUBE_LEVELS[(cube / 6 % 6) as usize];
+            let b = CUBE_LEVELS[(cube % 6) as usize];
+            (r << 16) | (g << 8) | b
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level << 16) | (level << 8) | level
+        }
+    };
+    gpui::rgb(hex)
+}
+
 pub trait ActiveTheme {
     fn theme(&self) -> &Theme;
 }
@@ -200,3 +384,59 @@ impl ActiveTheme for gpui::App {
         Theme::global(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_color_is_stable() {
+        let theme = Theme::dark();
+        assert_eq!(theme.tag_color("work"), theme.tag_color("work"));
+        assert!(theme.tag_palette.contains(&theme.tag_color("")));
+    }
+
+    #[test]
+    fn test_tag_colors_use_the_palette() {
+        let theme = Theme::dark();
+        let tags = ["work", "home", "urgent", "errand", "next", "someday"];
+        let distinct: std::collections::HashSet<String> = tags
+            .iter()
+            .map(|tag| format!("{:?}", theme.tag_color(tag)))
+            .collect();
+        assert!(distinct.len() > 1);
+    }
+
+    #[test]
+    fn test_parse_taskwarrior_colors() {
+        assert_eq!(parse_taskwarrior_color("red"), Some(gpui::rgb(0xCC0000)));
+        assert_eq!(
+            parse_taskwarrior_color("bold bright red on black"),
+            Some(gpui::rgb(0xEF2929))
+        );
+        assert_eq!(parse_taskwarrior_color("rgb500"), Some(gpui::rgb(0xFF0000)));
+        assert_eq!(
+            parse_taskwarrior_color("color21"),
+            Some(gpui::rgb(0x0000FF))
+        );
+        assert_eq!(parse_taskwarrior_color("gray0"), Some(gpui::rgb(0x080808)));
+        assert_eq!(parse_taskwarrior_color("on blue"), None);
+        assert_eq!(parse_taskwarrior_color("rgb9"), None);
+        assert_eq!(parse_taskwarrior_color("color256"), None);
+    }
+
+    #[test]
+    fn test_taskrc_overrides_only_touch_configured_colors() {
+        let colors = TaskrcColors {
+            active: Some("green".into()),
+            overdue: Some("not a color".into()),
+            ..Default::default()
+        };
+        let base = Theme::dark();
+        let theme = Theme::with_taskrc_overrides(base.clone(), &colors);
+        assert_eq!(theme.task_active, gpui::rgb(0x4E9A06));
+        assert_eq!(theme.task_overdue, base.task_overdue);
+        assert_eq!(theme.task_due, base.task_due);
+        assert_eq!(theme.accent, base.accent);
+    }
+}
@@ -1,8 +1,11 @@
+use chrono::{DateTime, Duration, Utc};
 use gpui::prelude::*;
 use gpui::{Pixels, px, rems};
 
 use crate::theme::{Color, Theme};
 
+pub const WINDOW_TITLE: &str = "Taskwarrior";
+
 pub const CARD_RADIUS: Pixels = px(6.0);
 pub const CARD_PADDING: Pixels = px(8.0);
 pub const SECTION_GAP: Pixels = px(12.0);
@@ -12,13 +15,35 @@ pub const CONTROL_RADIUS: Pixels = px(6.0);
 pub const CONTROL_BORDER: Pixels = px(1.0);
 
 pub const SIDEBAR_WIDTH: Pixels = px(250.0);
+pub const SIDEBAR_MIN_WIDTH: Pixels = px(180.0);
+pub const SIDEBAR_MAX_WIDTH: Pixels = px(480.0);
+pub const SIDEBAR_PROJECTS_FRACTION: f32 = 0.5;
+pub const SIDEBAR_SECTION_MIN_FRACTION: f32 = 0.15;
+pub const SIDEBAR_SECTION_EXPANDED_FRACTION: f32 = 0.7;
+pub const SIDEBAR_COMPLETION_BAR_HEIGHT: Pixels = px(3.0);
+
+pub const PREVIEW_PANE_WIDTH: Pixels = px(380.0);
+pub const PREVIEW_PANE_MIN_WIDTH: Pixels = px(260.0);
+pub const PREVIEW_PANE_MAX_WIDTH: Pixels = px(720.0);
+pub const RESIZE_HANDLE_WIDTH: Pixels = px(12.0);
 
-pub const TABLE_MAX_DESCRIPTION_LENGTH: usize = 50;
 pub const TABLE_FILTER_BAR_INITIAL_HEIGHT: Pixels = px(52.0);
+pub const TABLE_SEARCH_HISTORY_CAPACITY: usize = 20;
+pub const TABLE_DATE_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+pub const TABLE_MAX_INLINE_TAGS: usize = 3;
+pub const TABLE_DESCRIPTION_MAX_LINES: usize = 3;
+pub const TABLE_PAGE_SIZE: usize = 20;
+pub const TABLE_PAGE_SIZE_MIN: usize = 5;
+pub const TABLE_PAGE_SIZE_MAX: usize = 200;
+
+pub const DROPDOWN_MENU_MAX_HEIGHT: Pixels = px(280.0);
+pub const DROPDOWN_TYPE_AHEAD_RESET: std::time::Duration = std::time::Duration::from_millis(800);
+
+pub const STATUS_BAR_TIMER_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
 
 #[inline(always)]
 pub fn table_col_id_width() -> gpui::Rems {
-    rems(3.0)
+    rems(4.0)
 }
 
 #[inline(always)]
@@ -36,18 +61,94 @@ pub fn table_col_due_width() -> gpui::Rems {
     rems(7.0)
 }
 
+#[inline(always)]
+pub fn table_col_scheduled_width() -> gpui::Rems {
+    rems(7.0)
+}
+
+#[inline(always)]
+pub fn table_col_entry_width() -> gpui::Rems {
+    rems(7.0)
+}
+
+#[inline(always)]
+pub fn table_col_modified_width() -> gpui::Rems {
+    rems(7.0)
+}
+
 #[inline(always)]
 pub fn table_col_priority_width() -> gpui::Rems {
     rems(5.0)
 }
 
+#[inline(always)]
+pub fn table_col_urgency_width() -> gpui::Rems {
+    rems(4.0)
+}
+
 #[inline(always)]
 pub fn table_col_status_width() -> gpui::Rems {
     rems(6.0)
 }
 
+#[inline(always)]
+pub fn table_col_uda_width() -> gpui::Rems {
+    rems(7.0)
+}
+
+#[inline(always)]
+pub fn table_col_menu_width() -> gpui::Rems {
+    rems(5.0)
+}
+
+#[inline(always)]
+pub fn table_pinned_width() -> gpui::Rems {
+    rems(16.0)
+}
+
+#[inline(always)]
+pub fn table_min_width() -> gpui::Rems {
+    rems(56.0)
+}
+
 pub const DATE_FORMAT: &str = "%Y-%m-%d";
 pub const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+pub const DATE_PREVIEW_FORMAT: &str = "%a %Y-%m-%d %H:%M";
+
+pub fn format_relative_date(date: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let delta = date - now;
+    let sign = if delta < Duration::zero() { "-" } else { "" };
+    let delta = delta.abs();
+
+    let (value, unit) = if delta < Duration::hours(1) {
+        (delta.num_minutes(), "min")
+    } else if delta < Duration::days(1) {
+        (delta.num_hours(), "h")
+    } else if delta < Duration::days(14) {
+        (delta.num_days(), "d")
+    } else if delta < Duration::days(90) {
+        (delta.num_weeks(), "w")
+    } else if delta < Duration::days(365) {
+        (delta.num_days() / 30, "mo")
+    } else {
+        (delta.num_days() / 365, "y")
+    };
+
+    if value == 0 {
+        return "now".to_string();
+    }
+    format!("{}{}{}", sign, value, unit)
+}
+
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let seconds = elapsed.num_seconds().max(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
 
 pub fn card_style(div: gpui::Div, theme: &Theme) -> gpui::Div {
     div.bg(theme.card)
@@ -121,6 +222,19 @@ pub fn priority_badge(priority: &str, theme: &Theme) -> gpui::Div {
         .child(priority.to_string())
 }
 
+pub fn tag_chip(tag: &str, theme: &Theme) -> gpui::Div {
+    let color = theme.tag_color(tag);
+
+    gpui::div()
+        .px_1()
+        .rounded(px(4.0))
+        .bg(Theme::alpha(color, 0.18))
+        .text_color(color)
+        .text_xs()
+        .whitespace_nowrap()
+        .child(tag.to_string())
+}
+
 pub fn control_style(div: gpui::Div, theme: &Theme) -> gpui::Div {
     div.flex()
         .items_center()
@@ -180,3 +294,39 @@ pub fn mix_color(base: Color, tint: Color, amount: f32) -> Color {
         a: 1.0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_relative_date_breakpoints() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let rel = |delta: Duration| format_relative_date(now + delta, now);
+
+        assert_eq!(rel(Duration::seconds(20)), "now");
+        assert_eq!(rel(Duration::minutes(45)), "45min");
+        assert_eq!(rel(Duration::hours(5)), "5h");
+        assert_eq!(rel(Duration::hours(-5)), "-5h");
+        assert_eq!(rel(Duration::days(2)), "2d");
+        assert_eq!(rel(Duration::days(-3)), "-3d");
+        assert_eq!(rel(Duration::days(13)), "13d");
+        assert_eq!(rel(Duration::days(14)), "2w");
+        assert_eq!(rel(Duration::days(89)), "12w");
+        assert_eq!(rel(Duration::days(95)), "3mo");
+        assert_eq!(rel(Duration::days(-200)), "-6mo");
+        assert_eq!(rel(Duration::days(800)), "2y");
+    }
+
+    #[test]
+    fn test_elapsed_is_hours_minutes_seconds() {
+        assert_eq!(format_elapsed(Duration::seconds(0)), "00:00:00");
+        assert_eq!(format_elapsed(Duration::seconds(23 * 60 + 41)), "00:23:41");
+        assert_eq!(
+            format_elapsed(Duration::hours(27) + Duration::seconds(5)),
+            "27:00:05"
+        );
+        assert_eq!(format_elapsed(Duration::seconds(-30)), "00:00:00");
+    }
+}
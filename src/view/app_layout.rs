@@ -3,22 +3,31 @@ use gpui::prelude::*;
 use crate::components::toast::ToastHost;
 use crate::keymap::FocusTarget;
 use crate::theme::Theme;
-use crate::ui::{CARD_PADDING, CARD_RADIUS, ROOT_PADDING, SECTION_GAP, SIDEBAR_WIDTH};
+use crate::ui::{CARD_PADDING, CARD_RADIUS, RESIZE_HANDLE_WIDTH, ROOT_PADDING, SECTION_GAP};
 use crate::view::sidebar::Sidebar;
 use crate::view::status_bar::StatusBar;
+use crate::view::task_preview::TaskPreviewPane;
 use crate::view::task_table::TaskTable;
 
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeSidebar;
+
 pub fn render_app_layout(
     theme: &Theme,
     focus_handle: &gpui::FocusHandle,
     focus_target: FocusTarget,
     sidebar: gpui::Entity<Sidebar>,
+    sidebar_width: Option<gpui::Pixels>,
     task_table: gpui::Entity<TaskTable>,
+    preview: Option<gpui::Entity<TaskPreviewPane>>,
     status_bar: gpui::Entity<StatusBar>,
     toast_host: gpui::Entity<ToastHost>,
     on_root_key_down: impl Fn(&gpui::KeyDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
     on_sidebar_mouse_down: impl Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
     on_table_mouse_down: impl Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+    on_sidebar_resize: impl Fn(&gpui::DragMoveEvent<ResizeSidebar>, &mut gpui::Window, &mut gpui::App)
+    + 'static,
+    on_sidebar_resize_end: impl Fn(&ResizeSidebar, &mut gpui::Window, &mut gpui::App) + 'static,
     modal: Option<gpui::AnyElement>,
 ) -> gpui::AnyElement {
     let sidebar_focused = focus_target.is_sidebar();
@@ -29,18 +38,33 @@ pub fn render_app_layout(
         theme.divider
     };
 
-    let sidebar = gpui::div()
-        .bg(theme.card)
-        .border_2()
-        .border_color(sidebar_border_color)
-        .rounded(CARD_RADIUS)
-        .p(CARD_PADDING)
-        .w(SIDEBAR_WIDTH)
-        .h_full()
-        .flex_shrink_0()
-        .overflow_hidden()
-        .on_mouse_down(gpui::MouseButton::Left, on_sidebar_mouse_down)
-        .child(sidebar);
+    let sidebar = sidebar_width.map(|width| {
+        gpui::div()
+            .bg(theme.card)
+            .border_2()
+            .border_color(sidebar_border_color)
+            .rounded(CARD_RADIUS)
+            .p(CARD_PADDING)
+            .w(width)
+            .h_full()
+            .flex_shrink_0()
+            .overflow_hidden()
+            .on_mouse_down(gpui::MouseButton::Left, on_sidebar_mouse_down)
+            .child(sidebar)
+    });
+
+    let sidebar_handle = sidebar_width.map(|_| {
+        gpui::div()
+            .id("sidebar-resize")
+            .w(RESIZE_HANDLE_WIDTH)
+            .h_full()
+            .flex_shrink_0()
+            .cursor_col_resize()
+            .hover(|s| s.bg(theme.hover))
+            .on_drag(ResizeSidebar, |_handle, _offset, _window, cx| {
+                cx.new(|_cx| gpui::EmptyView)
+            })
+    });
 
     let table_focused = matches!(focus_target, FocusTarget::Table | FocusTarget::TableHeaders);
 
@@ -68,9 +92,12 @@ pub fn render_app_layout(
         .flex()
         .flex_1()
         .min_h_0()
-        .gap(SECTION_GAP)
-        .child(sidebar)
-        .child(main);
+        .on_drag_move(on_sidebar_resize)
+        .on_drop(on_sidebar_resize_end)
+        .children(sidebar)
+        .children(sidebar_handle)
+        .child(main)
+        .children(preview);
 
     let mut root = gpui::div()
         .flex()
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, Days, Months, NaiveDate, Utc};
+use gpui::prelude::*;
+
+use crate::task::{TaskPriority, TaskStatus, TaskSummary};
+use crate::theme::{ActiveTheme, Theme};
+use crate::ui::{CARD_PADDING, CARD_RADIUS};
+
+const GRID_DAYS: usize = 42;
+const WEEKDAY_LABELS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DayStats {
+    pub count: usize,
+    pub overdue: bool,
+    pub high_priority: bool,
+}
+
+pub enum CalendarEvent {
+    SelectDate(NaiveDate),
+    Closed,
+}
+
+pub struct CalendarView {
+    is_open: bool,
+    month: NaiveDate,
+    cursor: NaiveDate,
+    day_stats: HashMap<NaiveDate, DayStats>,
+}
+
+impl CalendarView {
+    pub fn new(_cx: &mut gpui::Context<Self>) -> Self {
+        let today = Utc::now().date_naive();
+        Self {
+            is_open: false,
+            month: first_of_month(today),
+            cursor: today,
+            day_stats: HashMap::new(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn open(&mut self, tasks: &[TaskSummary], cx: &mut gpui::Context<Self>) {
+        let today = Utc::now().date_naive();
+        self.is_open = true;
+        self.cursor = today;
+        self.month = first_of_month(today);
+        self.day_stats = build_day_stats(tasks);
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut gpui::Context<Self>) {
+        if !self.is_open {
+            return;
+        }
+
+        self.is_open = false;
+        cx.emit(CalendarEvent::Closed);
+        cx.notify();
+    }
+
+    pub fn move_cursor(&mut self, days: i64, cx: &mut gpui::Context<Self>) {
+        let moved = if days >= 0 {
+            self.cursor.checked_add_days(Days::new(days as u64))
+        } else {
+            self.cursor.checked_sub_days(Days::new(days.unsigned_abs()))
+        };
+
+        if let Some(cursor) = moved {
+            self.cursor = cursor;
+            self.month = first_of_month(cursor);
+            cx.notify();
+        }
+    }
+
+    pub fn shift_month(&mut self, months: i32, cx: &mut gpui::Context<Self>) {
+        if let Some(cursor) = shift_month(self.cursor, months) {
+            self.cursor = cursor;
+            self.month = first_of_month(cursor);
+            cx.notify();
+        }
+    }
+
+    pub fn select_cursor(&mut self, cx: &mut gpui::Context<Self>) {
+        self.select_date(self.cursor, cx);
+    }
+
+    fn select_date(&mut self, date: NaiveDate, cx: &mut gpui::Context<Self>) {
+        self.cursor = date;
+        cx.emit(CalendarEvent::SelectDate(date));
+        self.close(cx);
+    }
+
+    fn render_day(
+        &self,
+        index: usize,
+        date: NaiveDate,
+        today: NaiveDate,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Stateful<gpui::Div> {
+        let theme = cx.theme();
+        let in_month = date.month() == self.month.month();
+        let is_cursor = date == self.cursor;
+        let is_today = date == today;
+        let stats = self.day_stats.get(&date).copied().unwrap_or_default();
+
+        let day_color = if !in_month {
+            theme.muted
+        } else if stats.overdue {
+            theme.error
+        } else if stats.high_priority {
+            theme.high
+        } else {
+            theme.foreground
+        };
+        let badge_color = if stats.overdue {
+            theme.error
+        } else if stats.high_priority {
+            theme.high
+        } else {
+            theme.accent
+        };
+
+        gpui::div()
+            .id(("calendar-day", index))
+            .flex_1()
+            .h(gpui::rems(3.5))
+            .flex()
+            .flex_col()
+            .justify_between()
+            .p_1()
+            .rounded_sm()
+            .border_1()
+            .border_color(if is_today {
+                theme.focus_ring
+            } else {
+                theme.divider
+            })
+            .cursor_pointer()
+            .when(is_cursor, |el| el.bg(theme.selection))
+            .when(!is_cursor, |el| el.hover(|s| s.bg(theme.hover)))
+            .on_click(cx.listener(move |calendar, _event, _window, cx| {
+                calendar.select_date(date, cx);
+            }))
+            .child(
+                gpui::div()
+                    .text_sm()
+                    .text_color(day_color)
+                    .when(is_today, |el| el.font_weight(gpui::FontWeight::BOLD))
+                    .child(date.day().to_string()),
+            )
+            .when(stats.count > 0, |el| {
+                el.child(
+                    gpui::div().flex().justify_end().child(
+                        gpui::div()
+                            .px_1()
+                            .rounded_sm()
+                            .text_xs()
+                            .bg(Theme::alpha(badge_color, if in_month { 0.25 } else { 0.1 }))
+                            .text_color(if in_month { badge_color } else { theme.muted })
+                            .child(stats.count.to_string()),
+                    ),
+                )
+            })
+    }
+}
+
+impl gpui::EventEmitter<CalendarEvent> for CalendarView {}
+
+impl gpui::Render for CalendarView {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        if !self.is_open {
+            return gpui::div().into_any_element();
+        }
+
+        let theme = cx.theme();
+        let today = Utc::now().date_naive();
+        let days = month_grid(self.month);
+
+        let nav_button = |id: &'static str, label: &'static str, months: i32| {
+            gpui::div()
+                .id(id)
+                .px_2()
+                .rounded_sm()
+                .text_sm()
+                .text_color(theme.muted)
+                .cursor_pointer()
+                .hover(|s| s.text_color(theme.accent).bg(theme.hover))
+                .on_click(cx.listener(move |calendar, _event, _window, cx| {
+                    calendar.shift_month(months, cx);
+                }))
+                .child(label)
+        };
+
+        let header = gpui::div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .child(nav_button("calendar-prev-month", "‹", -1))
+            .child(
+                gpui::div()
+                    .text_base()
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(theme.foreground)
+                    .child(self.month.format("%B %Y").to_string()),
+            )
+            .child(nav_button("calendar-next-month", "›", 1));
+
+        let weekday_row = gpui::div()
+            .flex()
+            .gap_1()
+            .children(WEEKDAY_LABELS.map(|label| {
+                gpui::div()
+                    .flex_1()
+                    .text_xs()
+                    .text_color(theme.muted)
+                    .child(label)
+            }));
+
+        let weeks: Vec<gpui::Div> =
+            days.chunks(7)
+                .enumerate()
+                .map(|(week, dates)| {
+                    gpui::div().flex().gap_1().children(
+                        dates.iter().enumerate().map(|(offset, &date)| {
+                            self.render_day(week * 7 + offset, date, today, cx)
+                        }),
+                    )
+                })
+                .collect();
+
+        let panel = gpui::div()
+            .id("calendar-panel")
+            .w(gpui::rems(34.0))
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p(CARD_PADDING)
+            .bg(theme.card)
+            .border_1()
+            .border_color(theme.border)
+            .rounded(CARD_RADIUS)
+            .shadow_lg()
+            .occlude()
+            .child(header)
+            .child(weekday_row)
+            .children(weeks)
+            .child(
+                gpui::div()
+                    .text_xs()
+                    .text_color(theme.muted)
+                    .child("Arrows move · PgUp/PgDn change month · Enter filters by day"),
+            );
+
+        gpui::div()
+            .id("calendar-view")
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .occlude()
+            .child(
+                gpui::div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .bg(theme.backdrop)
+                    .on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|calendar, _event, _window, cx| {
+                            calendar.close(cx);
+                        }),
+                    ),
+            )
+            .child(
+                gpui::div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(panel),
+            )
+            .into_any_element()
+    }
+}
+
+pub fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+pub fn shift_month(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    if months >= 0 {
+        date.checked_add_months(Months::new(months as u32))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    }
+}
+
+pub fn month_grid(month: NaiveDate) -> Vec<NaiveDate> {
+    let first = first_of_month(month);
+    let leading = first.weekday().num_days_from_sunday() as u64;
+    let start = first.checked_sub_days(Days::new(leading)).unwrap_or(first);
+
+    start.iter_days().take(GRID_DAYS).collect()
+}
+
+pub fn build_day_stats(tasks: &[TaskSummary]) -> HashMap<NaiveDate, DayStats> {
+    let mut stats: HashMap<NaiveDate, DayStats> = HashMap::new();
+
+    for task in tasks {
+        if !matches!(task.status, TaskStatus::Pending) {
+            continue;
+        }
+        let Some(due) = task.due else {
+            continue;
+        };
+
+        let entry = stats.entry(due.date_naive()).or_default();
+        entry.count += 1;
+        entry.overdue |= task.is_overdue();
+        entry.high_priority |= matches!(task.priority, TaskPriority::High);
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_grid_starts_on_sunday_and_covers_month() {
+        let month = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let grid = month_grid(month);
+
+        assert_eq!(grid.len(), GRID_DAYS);
+        assert_eq!(grid[0], NaiveDate::from_ymd_opt(2026, 9, 27).unwrap());
+        assert!(grid.contains(&NaiveDate::from_ymd_opt(2026, 10, 31).unwrap()));
+        assert_eq!(grid[0].weekday(), chrono::Weekday::Sun);
+    }
+
+    #[test]
+    fn test_shift_month_clamps_day() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        assert_eq!(shift_month(date, 1), NaiveDate::from_ymd_opt(2026, 2, 28));
+        assert_eq!(shift_month(date, -2), NaiveDate::from_ymd_opt(2025, 11, 30));
+    }
+}
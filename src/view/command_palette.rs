@@ -0,0 +1,430 @@
+use std::sync::Arc;
+
+use gpui::prelude::*;
+
+use crate::components::input::Input;
+use crate::keymap::{Command, ContextId, FocusTarget, KeyChord};
+use crate::theme::ActiveTheme;
+use crate::ui::{CARD_PADDING, CARD_RADIUS};
+
+const PALETTE_FOCUS_TARGETS: [FocusTarget; 4] = [
+    FocusTarget::Table,
+    FocusTarget::TableHeaders,
+    FocusTarget::SidebarProjects,
+    FocusTarget::SidebarTags,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub command: Command,
+    pub chord: Option<KeyChord>,
+    pub focus: Option<FocusTarget>,
+}
+
+impl PaletteEntry {
+    pub fn focus_command(&self) -> Option<Command> {
+        match self.focus? {
+            FocusTarget::Table => Some(Command::FocusTable),
+            FocusTarget::TableHeaders => Some(Command::FocusTableHeaders),
+            FocusTarget::SidebarProjects => Some(Command::FocusSidebarProjects),
+            FocusTarget::SidebarTags => Some(Command::FocusSidebarTags),
+            FocusTarget::App => None,
+        }
+    }
+}
+
+pub enum CommandPaletteEvent {
+    Execute,
+}
+
+pub struct CommandPalette {
+    is_open: bool,
+    input: gpui::Entity<Input>,
+    entries: Vec<PaletteEntry>,
+    matches: Vec<usize>,
+    selected: usize,
+    context: ContextId,
+    restore_focus: Option<gpui::FocusHandle>,
+    scroll_handle: gpui::ScrollHandle,
+}
+
+impl CommandPalette {
+    pub fn new(cx: &mut gpui::Context<Self>) -> Self {
+        let palette = cx.weak_entity();
+        let input = cx.new(|cx| {
+            Input::new("command-palette-input", cx, "Type a command...").with_on_change(Arc::new(
+                move |value: &str, cx: &mut gpui::Context<Input>| {
+                    let value = value.to_string();
+                    palette
+                        .update(cx, |palette, cx| palette.set_query(&value, cx))
+                        .ok();
+                },
+            ))
+        });
+
+        Self {
+            is_open: false,
+            input,
+            entries: Vec::new(),
+            matches: Vec::new(),
+            selected: 0,
+            context: ContextId::Global,
+            restore_focus: None,
+            scroll_handle: gpui::ScrollHandle::new(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn context(&self) -> ContextId {
+        self.context
+    }
+
+    pub fn open(
+        &mut self,
+        entries: Vec<PaletteEntry>,
+        context: ContextId,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.is_open = true;
+        self.entries = entries;
+        self.context = context;
+        self.restore_focus = window.focused(cx);
+        self.input.update(cx, |input, cx| {
+            input.clear(cx);
+            input.focus(window, cx);
+        });
+        self.set_query("", cx);
+    }
+
+    pub fn close(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        if !self.is_open {
+            return;
+        }
+
+        self.is_open = false;
+        if let Some(handle) = self.restore_focus.take() {
+            window.focus(&handle);
+        }
+        cx.notify();
+    }
+
+    pub fn selected_entry(&self) -> Option<PaletteEntry> {
+        self.matches
+            .get(self.selected)
+            .and_then(|&index| self.entries.get(index))
+            .copied()
+    }
+
+    pub fn select_next(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+        self.selected = (self.selected + 1) % self.matches.len();
+        self.scroll_handle.scroll_to_item(self.selected);
+        cx.notify();
+    }
+
+    pub fn select_prev(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+        self.selected = if self.selected == 0 {
+            self.matches.len() - 1
+        } else {
+            self.selected - 1
+        };
+        self.scroll_handle.scroll_to_item(self.selected);
+        cx.notify();
+    }
+
+    fn set_query(&mut self, query: &str, cx: &mut gpui::Context<Self>) {
+        let mut scored: Vec<(i32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                fuzzy_score(query, &entry.command.display_name()).map(|score| (score, index))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        self.matches = scored.into_iter().map(|(_, index)| index).collect();
+        self.selected = 0;
+        self.scroll_handle.scroll_to_item(0);
+        cx.notify();
+    }
+
+    fn handle_entry_click(&mut self, position: usize, cx: &mut gpui::Context<Self>) {
+        self.selected = position;
+        cx.emit(CommandPaletteEvent::Execute);
+        cx.notify();
+    }
+}
+
+impl gpui::EventEmitter<CommandPaletteEvent> for CommandPalette {}
+
+impl gpui::Render for CommandPalette {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        if !self.is_open {
+            return gpui::div().into_any_element();
+        }
+
+        let theme = cx.theme();
+
+        let rows: Vec<gpui::Stateful<gpui::Div>> = self
+            .matches
+            .iter()
+            .enumerate()
+            .map(|(position, &index)| {
+                let entry = &self.entries[index];
+                let is_selected = position == self.selected;
+                let hint = match (entry.chord, entry.focus) {
+                    (Some(chord), Some(focus)) => {
+                        format!("{} in {}", chord, focus.to_context().as_str())
+                    }
+                    (Some(chord), None) => chord.to_string(),
+                    (None, _) => String::new(),
+                };
+
+                gpui::div()
+                    .id(("palette-entry", position))
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_3()
+                    .px_2()
+                    .py_1()
+                    .rounded_sm()
+                    .cursor_pointer()
+                    .when(is_selected, |el| el.bg(theme.selection))
+                    .when(!is_selected, |el| el.hover(|s| s.bg(theme.hover)))
+                    .on_click(cx.listener(move |palette, _event, _window, cx| {
+                        palette.handle_entry_click(position, cx);
+                    }))
+                    .child(
+                        gpui::div()
+                            .text_sm()
+                            .text_color(theme.foreground)
+                            .child(entry.command.display_name()),
+                    )
+                    .child(
+                        gpui::div()
+                            .flex_shrink_0()
+                            .text_xs()
+                            .text_color(theme.muted)
+                            .child(hint),
+                    )
+            })
+            .collect();
+
+        let list = if rows.is_empty() {
+            gpui::div()
+                .id("command-palette-list")
+                .p(CARD_PADDING)
+                .text_sm()
+                .text_color(theme.muted)
+                .child("No matching commands")
+        } else {
+            gpui::div()
+                .id("command-palette-list")
+                .flex_1()
+                .min_h_0()
+                .overflow_y_scroll()
+                .track_scroll(&self.scroll_handle)
+                .p(CARD_PADDING)
+                .children(rows)
+        };
+
+        let panel = gpui::div()
+            .id("command-palette-panel")
+            .w(gpui::rems(36.0))
+            .max_h(gpui::relative(0.6))
+            .flex()
+            .flex_col()
+            .bg(theme.card)
+            .border_1()
+            .border_color(theme.border)
+            .rounded(CARD_RADIUS)
+            .shadow_lg()
+            .occlude()
+            .child(
+                gpui::div()
+                    .p(CARD_PADDING)
+                    .border_b_1()
+                    .border_color(theme.divider)
+                    .child(self.input.clone()),
+            )
+            .child(list);
+
+        gpui::div()
+            .id("command-palette")
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .occlude()
+            .child(
+                gpui::div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .bg(theme.backdrop)
+                    .on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|palette, _event, window, cx| {
+                            palette.close(window, cx);
+                        }),
+                    ),
+            )
+            .child(
+                gpui::div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .flex()
+                    .justify_center()
+                    .pt(gpui::rems(6.0))
+                    .child(panel),
+            )
+            .into_any_element()
+    }
+}
+
+pub fn build_palette_entries(
+    bindings: &[(ContextId, KeyChord, Command)],
+    context: ContextId,
+) -> Vec<PaletteEntry> {
+    let chord_in = |command: Command, context: ContextId| {
+        bindings
+            .iter()
+            .find(|(c, _, cmd)| *c == context && *cmd == command)
+            .map(|(_, chord, _)| *chord)
+    };
+
+    let mut entries: Vec<PaletteEntry> = Command::all()
+        .iter()
+        .filter(|command| !matches!(command, Command::TogglePalette | Command::PaletteExecute))
+        .filter_map(|&command| {
+            if let Some(chord) =
+                chord_in(command, context).or_else(|| chord_in(command, ContextId::Global))
+            {
+                return Some(PaletteEntry {
+                    command,
+                    chord: Some(chord),
+                    focus: None,
+                });
+            }
+
+            PALETTE_FOCUS_TARGETS.iter().find_map(|&target| {
+                chord_in(command, target.to_context()).map(|chord| PaletteEntry {
+                    command,
+                    chord: Some(chord),
+                    focus: Some(target),
+                })
+            })
+        })
+        .collect();
+
+    entries.sort_by_key(|entry| entry.command.display_name());
+    entries
+}
+
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut prev: Option<usize> = None;
+
+    for ch in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = (pos..text.len()).find(|&i| text[i] == ch)?;
+
+        score += 1;
+        if prev.is_some_and(|prev| prev + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || text[index - 1] == ' ' {
+            score += 3;
+        }
+        score -= (index - pos) as i32;
+
+        prev = Some(index);
+        pos = index + 1;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keymap::{Key, Mods};
+
+    #[test]
+    fn test_fuzzy_score_requires_subsequence() {
+        assert!(fuzzy_score("snr", "Select next row").is_some());
+        assert!(fuzzy_score("rns", "Select next row").is_none());
+        assert_eq!(fuzzy_score("", "Sync"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_contiguous_and_word_starts() {
+        let sync = fuzzy_score("sync", "Sync").unwrap();
+        let scattered = fuzzy_score("sync", "Select your next column").unwrap();
+        assert!(sync > scattered);
+
+        let initials = fuzzy_score("cf", "Clear filters").unwrap();
+        let inner = fuzzy_score("cf", "Focus table headers").unwrap_or(i32::MIN);
+        assert!(initials > inner);
+    }
+
+    #[test]
+    fn test_build_palette_entries_resolves_focus() {
+        let bindings = vec![
+            (
+                ContextId::Global,
+                KeyChord::new(Key::Char('r'), Mods::ctrl()),
+                Command::Sync,
+            ),
+            (
+                ContextId::Table,
+                KeyChord::new(Key::Char('j'), Mods::none()),
+                Command::SelectNextRow,
+            ),
+            (
+                ContextId::SidebarTags,
+                KeyChord::new(Key::Char('m'), Mods::none()),
+                Command::ToggleTagsMode,
+            ),
+            (
+                ContextId::Modal,
+                KeyChord::new(Key::Escape, Mods::none()),
+                Command::CloseModal,
+            ),
+        ];
+
+        let entries = build_palette_entries(&bindings, ContextId::Table);
+        let commands: Vec<Command> = entries.iter().map(|entry| entry.command).collect();
+        assert_eq!(
+            commands,
+            vec![
+                Command::SelectNextRow,
+                Command::Sync,
+                Command::ToggleTagsMode
+            ]
+        );
+        assert_eq!(entries[0].focus, None);
+        assert_eq!(entries[1].focus, None);
+        assert_eq!(entries[2].focus, Some(FocusTarget::SidebarTags));
+        assert_eq!(entries[2].focus_command(), Some(Command::FocusSidebarTags));
+    }
+}
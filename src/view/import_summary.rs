@@ -0,0 +1,129 @@
+use std::path::Path;
+
+use gpui::prelude::*;
+
+use crate::task::ImportSummary;
+use crate::theme::Theme;
+use crate::ui::{CARD_PADDING, CARD_RADIUS};
+
+pub fn render_import_summary(
+    theme: &Theme,
+    summary: ImportSummary,
+    path: &Path,
+    on_close: impl Fn(&mut gpui::Window, &mut gpui::App) + Clone + 'static,
+) -> gpui::AnyElement {
+    let on_backdrop = on_close.clone();
+    let stat = |label: &'static str, value: usize, color: gpui::Rgba| {
+        gpui::div()
+            .flex()
+            .flex_col()
+            .flex_1()
+            .items_center()
+            .gap_1()
+            .p_2()
+            .rounded_md()
+            .bg(Theme::alpha(color, 0.1))
+            .child(
+                gpui::div()
+                    .text_xl()
+                    .font_weight(gpui::FontWeight::BOLD)
+                    .text_color(color)
+                    .child(value.to_string()),
+            )
+            .child(gpui::div().text_xs().text_color(theme.muted).child(label))
+    };
+
+    let panel = gpui::div()
+        .id("import-summary-panel")
+        .w(gpui::rems(26.0))
+        .flex()
+        .flex_col()
+        .gap_3()
+        .p(CARD_PADDING)
+        .bg(theme.card)
+        .border_1()
+        .border_color(theme.border)
+        .rounded(CARD_RADIUS)
+        .shadow_lg()
+        .occlude()
+        .child(
+            gpui::div()
+                .text_color(theme.foreground)
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .child("Import finished"),
+        )
+        .child(
+            gpui::div()
+                .text_xs()
+                .text_color(theme.muted)
+                .child(path.display().to_string()),
+        )
+        .child(
+            gpui::div()
+                .flex()
+                .gap_2()
+                .child(stat("imported", summary.imported, theme.success))
+                .child(stat(
+                    "skipped duplicates",
+                    summary.duplicates,
+                    theme.warning,
+                ))
+                .child(stat("errors", summary.errors, theme.error)),
+        )
+        .child(
+            gpui::div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    gpui::div()
+                        .text_xs()
+                        .text_color(theme.muted)
+                        .child("Enter or Esc to close"),
+                )
+                .child(
+                    gpui::div()
+                        .id("import-summary-close")
+                        .px_3()
+                        .py_1()
+                        .rounded_md()
+                        .text_sm()
+                        .bg(theme.accent)
+                        .text_color(theme.background)
+                        .cursor_pointer()
+                        .on_click(move |_event, window, app| on_close(window, app))
+                        .child("OK"),
+                ),
+        );
+
+    gpui::div()
+        .id("import-summary")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .occlude()
+        .child(
+            gpui::div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .bg(theme.backdrop)
+                .on_mouse_down(gpui::MouseButton::Left, move |_event, window, app| {
+                    on_backdrop(window, app)
+                }),
+        )
+        .child(
+            gpui::div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(panel),
+        )
+        .into_any_element()
+}
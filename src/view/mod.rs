@@ -1,5 +1,12 @@
 pub mod app_layout;
+pub mod calendar_view;
+pub mod command_palette;
+pub mod import_summary;
+pub mod settings_modal;
+pub mod setup_screen;
+pub mod shortcuts_overlay;
 pub mod sidebar;
 pub mod status_bar;
 pub mod task_detail_modal;
+pub mod task_preview;
 pub mod task_table;
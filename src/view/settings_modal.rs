@@ -0,0 +1,477 @@
+use gpui::prelude::*;
+
+use crate::config::{AppConfig, UrgencyOverrides};
+use crate::task::UrgencyConfig;
+use crate::theme::{ActiveTheme, Theme};
+use crate::ui::{CARD_PADDING, CARD_RADIUS};
+
+const PAGE_SIZE_STEP: usize = 5;
+const SIDEBAR_WIDTH_STEP: f32 = 10.0;
+const URGENCY_STEP: f32 = 0.5;
+const AUTO_SYNC_OPTIONS: [Option<u64>; 5] = [None, Some(5), Some(15), Some(30), Some(60)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsField {
+    Theme,
+    PageSize,
+    RelativeDates,
+    ShowInlineTags,
+    SidebarWidth,
+    AutoSync,
+    ConfirmDestructive,
+    UrgencyDue,
+    UrgencyPriority,
+    UrgencyBlocking,
+    UrgencyBlocked,
+    UrgencyActive,
+    UrgencyAge,
+}
+
+impl SettingsField {
+    pub const ALL: [SettingsField; 13] = [
+        Self::Theme,
+        Self::PageSize,
+        Self::RelativeDates,
+        Self::ShowInlineTags,
+        Self::SidebarWidth,
+        Self::AutoSync,
+        Self::ConfirmDestructive,
+        Self::UrgencyDue,
+        Self::UrgencyPriority,
+        Self::UrgencyBlocking,
+        Self::UrgencyBlocked,
+        Self::UrgencyActive,
+        Self::UrgencyAge,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Theme => "Theme",
+            Self::PageSize => "Rows per page",
+            Self::RelativeDates => "Relative dates",
+            Self::ShowInlineTags => "Inline tags",
+            Self::SidebarWidth => "Sidebar width",
+            Self::AutoSync => "Auto sync",
+            Self::ConfirmDestructive => "Confirm deletions",
+            Self::UrgencyDue => "Urgency: due",
+            Self::UrgencyPriority => "Urgency: priority H",
+            Self::UrgencyBlocking => "Urgency: blocking",
+            Self::UrgencyBlocked => "Urgency: blocked",
+            Self::UrgencyActive => "Urgency: active",
+            Self::UrgencyAge => "Urgency: age",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Theme => "Color scheme of the window",
+            Self::PageSize => "Tasks shown on each table page",
+            Self::RelativeDates => "Show due and scheduled as \"in 3d\" instead of a date",
+            Self::ShowInlineTags => "Show tag chips next to the description",
+            Self::SidebarWidth => "Width of the project and tag sidebar",
+            Self::AutoSync => "Reload tasks periodically in the background",
+            Self::ConfirmDestructive => "Ask before deleting tasks and annotations",
+            Self::UrgencyDue => "Overrides urgency.due.coefficient",
+            Self::UrgencyPriority => "Overrides urgency.uda.priority.H.coefficient",
+            Self::UrgencyBlocking => "Overrides urgency.blocking.coefficient",
+            Self::UrgencyBlocked => "Overrides urgency.blocked.coefficient",
+            Self::UrgencyActive => "Overrides urgency.active.coefficient",
+            Self::UrgencyAge => "Overrides urgency.age.coefficient",
+        }
+    }
+
+    fn toggle_state(&self, config: &AppConfig) -> Option<bool> {
+        match self {
+            Self::RelativeDates => Some(config.relative_dates),
+            Self::ShowInlineTags => Some(config.show_inline_tags),
+            Self::ConfirmDestructive => Some(config.confirm_destructive),
+            _ => None,
+        }
+    }
+
+    pub fn value(&self, config: &AppConfig) -> String {
+        let on_off = |value: bool| if value { "On" } else { "Off" }.to_string();
+        match self {
+            Self::Theme => config.theme.label().to_string(),
+            Self::PageSize => config.page_size.to_string(),
+            Self::RelativeDates => on_off(config.relative_dates),
+            Self::ShowInlineTags => on_off(config.show_inline_tags),
+            Self::ConfirmDestructive => on_off(config.confirm_destructive),
+            Self::SidebarWidth => format!("{:.0}px", f32::from(config.sidebar.width)),
+            Self::AutoSync => match config.auto_sync_interval_minutes {
+                Some(minutes) if minutes > 0 => format!("{} min", minutes),
+                _ => "Off".to_string(),
+            },
+            _ => self
+                .urgency_value(&config.urgency)
+                .map(|value| format!("{:.1}", value))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn urgency_value(&self, urgency: &UrgencyConfig) -> Option<f32> {
+        match self {
+            Self::UrgencyDue => Some(urgency.due),
+            Self::UrgencyPriority => Some(urgency.priority_high),
+            Self::UrgencyBlocking => Some(urgency.blocking),
+            Self::UrgencyBlocked => Some(urgency.blocked),
+            Self::UrgencyActive => Some(urgency.active),
+            Self::UrgencyAge => Some(urgency.age),
+            _ => None,
+        }
+    }
+
+    fn urgency_override<'a>(
+        &self,
+        overrides: &'a mut UrgencyOverrides,
+    ) -> Option<&'a mut Option<f32>> {
+        match self {
+            Self::UrgencyDue => Some(&mut overrides.due),
+            Self::UrgencyPriority => Some(&mut overrides.priority_high),
+            Self::UrgencyBlocking => Some(&mut overrides.blocking),
+            Self::UrgencyBlocked => Some(&mut overrides.blocked),
+            Self::UrgencyActive => Some(&mut overrides.active),
+            Self::UrgencyAge => Some(&mut overrides.age),
+            _ => None,
+        }
+    }
+
+    pub fn adjust(&self, config: &mut AppConfig, step: i32) {
+        match self {
+            Self::Theme => config.theme = config.theme.toggle(),
+            Self::PageSize => {
+                let page_size = if step >= 0 {
+                    config.page_size.saturating_add(PAGE_SIZE_STEP)
+                } else {
+                    config.page_size.saturating_sub(PAGE_SIZE_STEP)
+                };
+                config.set_page_size(page_size);
+            }
+            Self::RelativeDates => config.relative_dates = !config.relative_dates,
+            Self::ShowInlineTags => config.show_inline_tags = !config.show_inline_tags,
+            Self::ConfirmDestructive => config.confirm_destructive = !config.confirm_destructive,
+            Self::SidebarWidth => {
+                let width = f32::from(config.sidebar.width) + SIDEBAR_WIDTH_STEP * step as f32;
+                config.sidebar.set_width(gpui::px(width));
+            }
+            Self::AutoSync => {
+                let current = AUTO_SYNC_OPTIONS
+                    .iter()
+                    .position(|option| *option == config.auto_sync_interval_minutes)
+                    .unwrap_or(0);
+                let next = (current as i32 + step).clamp(0, AUTO_SYNC_OPTIONS.len() as i32 - 1);
+                config.auto_sync_interval_minutes = AUTO_SYNC_OPTIONS[next as usize];
+            }
+            Self::UrgencyDue
+            | Self::UrgencyPriority
+            | Self::UrgencyBlocking
+            | Self::UrgencyBlocked
+            | Self::UrgencyActive
+            | Self::UrgencyAge => {
+                let Some(current) = self.urgency_value(&config.urgency) else {
+                    return;
+                };
+                let mut overrides = config.urgency_overrides;
+                if let Some(value) = self.urgency_override(&mut overrides) {
+                    let next = current + URGENCY_STEP * step as f32;
+                    *value = Some((next * 10.0).round() / 10.0);
+                }
+                config.set_urgency_overrides(overrides);
+            }
+        }
+    }
+}
+
+pub enum SettingsModalEvent {
+    Closed(AppConfig),
+}
+
+pub struct SettingsModal {
+    is_open: bool,
+    draft: AppConfig,
+    selected: usize,
+}
+
+impl SettingsModal {
+    pub fn new(_cx: &mut gpui::Context<Self>) -> Self {
+        Self {
+            is_open: false,
+            draft: AppConfig::default(),
+            selected: 0,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn open(&mut self, config: AppConfig, cx: &mut gpui::Context<Self>) {
+        self.is_open = true;
+        self.draft = config;
+        self.selected = 0;
+        cx.notify();
+    }
+
+    pub fn close(&mut self, cx: &mut gpui::Context<Self>) {
+        if !self.is_open {
+            return;
+        }
+
+        self.is_open = false;
+        cx.emit(SettingsModalEvent::Closed(self.draft.clone()));
+        cx.notify();
+    }
+
+    pub fn select_next(&mut self, cx: &mut gpui::Context<Self>) {
+        self.selected = (self.selected + 1) % SettingsField::ALL.len();
+        cx.notify();
+    }
+
+    pub fn select_prev(&mut self, cx: &mut gpui::Context<Self>) {
+        self.selected = self
+            .selected
+            .checked_sub(1)
+            .unwrap_or(SettingsField::ALL.len() - 1);
+        cx.notify();
+    }
+
+    pub fn adjust_selected(&mut self, step: i32, cx: &mut gpui::Context<Self>) {
+        SettingsField::ALL[self.selected].adjust(&mut self.draft, step);
+        cx.notify();
+    }
+
+    fn render_field(
+        &self,
+        index: usize,
+        field: SettingsField,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Stateful<gpui::Div> {
+        let theme = cx.theme();
+        let is_selected = index == self.selected;
+        let value = field.value(&self.draft);
+
+        let control = if let Some(enabled) = field.toggle_state(&self.draft) {
+            gpui::div()
+                .id(("settings-toggle", index))
+                .px_3()
+                .py_1()
+                .rounded_md()
+                .text_sm()
+                .cursor_pointer()
+                .bg(if enabled {
+                    Theme::alpha(theme.accent, 0.2)
+                } else {
+                    theme.field_bg
+                })
+                .text_color(if enabled { theme.accent } else { theme.muted })
+                .on_click(cx.listener(move |modal, _event, _window, cx| {
+                    modal.selected = index;
+                    modal.adjust_selected(1, cx);
+                }))
+                .child(value)
+                .into_any_element()
+        } else {
+            let stepper = |id: &'static str, label: &'static str, step: i32| {
+                gpui::div()
+                    .id((id, index))
+                    .px_2()
+                    .rounded_sm()
+                    .text_color(theme.muted)
+                    .cursor_pointer()
+                    .hover(|s| s.text_color(theme.accent).bg(theme.hover))
+                    .on_click(cx.listener(move |modal, _event, _window, cx| {
+                        modal.selected = index;
+                        modal.adjust_selected(step, cx);
+                    }))
+                    .child(label)
+            };
+
+            gpui::div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(stepper("settings-decrease", "‹", -1))
+                .child(
+                    gpui::div()
+                        .min_w(gpui::rems(4.5))
+                        .flex()
+                        .justify_center()
+                        .text_sm()
+                        .text_color(theme.foreground)
+                        .child(value),
+                )
+                .child(stepper("settings-increase", "›", 1))
+                .into_any_element()
+        };
+
+        gpui::div()
+            .id(("settings-field", index))
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap_3()
+            .px_2()
+            .py_1p5()
+            .rounded_md()
+            .when(is_selected, |el| el.bg(theme.selection))
+            .when(!is_selected, |el| el.hover(|s| s.bg(theme.hover)))
+            .on_click(cx.listener(move |modal, _event, _window, cx| {
+                modal.selected = index;
+                cx.notify();
+            }))
+            .child(
+                gpui::div()
+                    .flex()
+                    .flex_col()
+                    .min_w_0()
+                    .child(
+                        gpui::div()
+                            .text_sm()
+                            .text_color(theme.foreground)
+                            .child(field.label()),
+                    )
+                    .child(
+                        gpui::div()
+                            .text_xs()
+                            .text_color(theme.muted)
+                            .child(field.description()),
+                    ),
+            )
+            .child(control)
+    }
+}
+
+impl gpui::EventEmitter<SettingsModalEvent> for SettingsModal {}
+
+impl gpui::Render for SettingsModal {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        if !self.is_open {
+            return gpui::div().into_any_element();
+        }
+
+        let theme = cx.theme();
+        let path = AppConfig::path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "Settings are not saved: no config directory".to_string());
+
+        let panel = gpui::div()
+            .id("settings-panel")
+            .w(gpui::rems(32.0))
+            .max_h(gpui::relative(0.9))
+            .overflow_y_scroll()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p(CARD_PADDING)
+            .bg(theme.card)
+            .border_1()
+            .border_color(theme.border)
+            .rounded(CARD_RADIUS)
+            .shadow_lg()
+            .occlude()
+            .child(
+                gpui::div()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        gpui::div()
+                            .text_base()
+                            .font_weight(gpui::FontWeight::SEMIBOLD)
+                            .text_color(theme.foreground)
+                            .child("Settings"),
+                    )
+                    .child(gpui::div().text_xs().text_color(theme.muted).child(path)),
+            )
+            .children(
+                SettingsField::ALL
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| self.render_field(index, *field, cx)),
+            )
+            .child(
+                gpui::div()
+                    .text_xs()
+                    .text_color(theme.muted)
+                    .child("↑/↓ select · ←/→ change · Enter or Esc saves and closes"),
+            );
+
+        gpui::div()
+            .id("settings-modal")
+            .absolute()
+            .top_0()
+            .left_0()
+            .size_full()
+            .occlude()
+            .child(
+                gpui::div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .bg(theme.backdrop)
+                    .on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|modal, _event, _window, cx| {
+                            modal.close(cx);
+                        }),
+                    ),
+            )
+            .child(
+                gpui::div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .size_full()
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .child(panel),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{SIDEBAR_MAX_WIDTH, TABLE_PAGE_SIZE_MIN};
+
+    #[test]
+    fn test_adjust_clamps_numeric_fields() {
+        let mut config = AppConfig::default();
+        config.set_page_size(TABLE_PAGE_SIZE_MIN);
+        SettingsField::PageSize.adjust(&mut config, -1);
+        assert_eq!(config.page_size, TABLE_PAGE_SIZE_MIN);
+
+        config.sidebar.set_width(SIDEBAR_MAX_WIDTH);
+        SettingsField::SidebarWidth.adjust(&mut config, 1);
+        assert_eq!(config.sidebar.width, SIDEBAR_MAX_WIDTH);
+    }
+
+    #[test]
+    fn test_auto_sync_steps_through_options() {
+        let mut config = AppConfig::default();
+        SettingsField::AutoSync.adjust(&mut config, 1);
+        assert_eq!(config.auto_sync_interval_minutes, Some(5));
+
+        SettingsField::AutoSync.adjust(&mut config, -1);
+        SettingsField::AutoSync.adjust(&mut config, -1);
+        assert_eq!(config.auto_sync_interval_minutes, None);
+        assert_eq!(SettingsField::AutoSync.value(&config), "Off");
+    }
+
+    #[test]
+    fn test_urgency_fields_store_overrides() {
+        let mut config = AppConfig::default();
+        SettingsField::UrgencyDue.adjust(&mut config, 1);
+
+        assert_eq!(config.urgency_overrides.due, Some(12.5));
+        assert_eq!(config.urgency.due, 12.5);
+        assert_eq!(SettingsField::UrgencyDue.value(&config), "12.5");
+        assert_eq!(config.urgency_overrides.active, None);
+    }
+}
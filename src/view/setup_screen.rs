@@ -0,0 +1,168 @@
+use std::rc::Rc;
+
+use gpui::prelude::*;
+
+use crate::components::input::Input;
+use crate::task::{TaskError, TaskService, export, taskrc};
+use crate::theme::{ActiveTheme, Theme};
+use crate::ui::{CARD_PADDING, CARD_RADIUS};
+
+fn setup_hint() -> String {
+    let taskrc = taskrc::taskrc_path()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| "your taskrc".to_string());
+    format!(
+        "Check {} and its data.location, or point to another data directory. Leave the path \
+         empty to retry with the taskrc location.",
+        taskrc
+    )
+}
+
+type OnReady = Rc<dyn Fn(TaskService, &mut gpui::Window, &mut gpui::App)>;
+
+pub struct SetupScreen {
+    error: String,
+    data_location: Option<String>,
+    path_input: gpui::Entity<Input>,
+    on_ready: OnReady,
+}
+
+impl SetupScreen {
+    pub fn new(
+        error: TaskError,
+        on_ready: impl Fn(TaskService, &mut gpui::Window, &mut gpui::App) + 'static,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Self {
+        let path_input = cx.new(|cx| Input::new("setup-data-path", cx, "~/.task"));
+        path_input.update(cx, |input, cx| input.focus(window, cx));
+
+        let data_location = taskrc::read_taskrc_config().ok().map(|config| {
+            format!(
+                "Tried {} ({})",
+                config.data_location.display(),
+                config.data_location_source
+            )
+        });
+
+        Self {
+            error: error.to_string(),
+            data_location,
+            path_input,
+            on_ready: Rc::new(on_ready),
+        }
+    }
+
+    fn retry(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        let path = self.path_input.read(cx).value().trim().to_string();
+        let result = if path.is_empty() {
+            TaskService::new()
+        } else {
+            TaskService::with_path(export::expand_path(&path))
+        };
+
+        match result {
+            Ok(service) => {
+                let on_ready = self.on_ready.clone();
+                window.defer(cx, move |window, cx| on_ready(service, window, cx));
+            }
+            Err(e) => {
+                log::error!("[SetupScreen] Failed to initialize TaskService: {}", e);
+                self.error = e.to_string();
+                cx.notify();
+            }
+        }
+    }
+
+    fn handle_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if event.keystroke.key == "enter" {
+            self.retry(window, cx);
+        }
+    }
+}
+
+impl gpui::Render for SetupScreen {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let theme = cx.theme();
+
+        let panel = gpui::div()
+            .w(gpui::rems(32.0))
+            .flex()
+            .flex_col()
+            .gap_3()
+            .p(CARD_PADDING)
+            .bg(theme.card)
+            .border_1()
+            .border_color(theme.border)
+            .rounded(CARD_RADIUS)
+            .shadow_lg()
+            .child(
+                gpui::div()
+                    .text_color(theme.foreground)
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .child("Could not open the task database"),
+            )
+            .child(
+                gpui::div()
+                    .p_2()
+                    .rounded_md()
+                    .text_sm()
+                    .bg(Theme::alpha(theme.error, 0.12))
+                    .text_color(theme.error)
+                    .child(self.error.clone()),
+            )
+            .children(self.data_location.clone().map(|location| {
+                gpui::div()
+                    .text_xs()
+                    .text_color(theme.muted)
+                    .child(location)
+            }))
+            .child(
+                gpui::div()
+                    .text_sm()
+                    .text_color(theme.muted)
+                    .child(setup_hint()),
+            )
+            .child(
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .on_key_down(cx.listener(Self::handle_key_down))
+                    .child(gpui::div().flex_1().child(self.path_input.clone()))
+                    .child(
+                        gpui::div()
+                            .id("setup-retry")
+                            .px_3()
+                            .py_1()
+                            .rounded_md()
+                            .text_sm()
+                            .bg(theme.accent)
+                            .text_color(theme.background)
+                            .cursor_pointer()
+                            .on_click(cx.listener(|screen, _event, window, cx| {
+                                screen.retry(window, cx);
+                            }))
+                            .child("Retry"),
+                    ),
+            );
+
+        gpui::div()
+            .size_full()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(theme.background)
+            .text_color(theme.foreground)
+            .child(panel)
+    }
+}
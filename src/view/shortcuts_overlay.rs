@@ -0,0 +1,126 @@
+use gpui::prelude::*;
+
+use crate::keymap::{Command, ContextId, KeyChord};
+use crate::theme::Theme;
+use crate::ui::{CARD_PADDING, CARD_RADIUS, section_header};
+
+pub fn render_shortcuts_overlay(
+    theme: &Theme,
+    bindings: &[(ContextId, KeyChord, Command)],
+    scroll_handle: &gpui::ScrollHandle,
+    on_close: impl Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+) -> gpui::AnyElement {
+    let groups = ContextId::ALL.iter().filter_map(|context| {
+        let rows: Vec<gpui::Div> = bindings
+            .iter()
+            .filter(|(c, _, _)| c == context)
+            .map(|(_, chord, command)| {
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .px_2()
+                    .py_0p5()
+                    .child(
+                        gpui::div()
+                            .w(gpui::rems(9.0))
+                            .flex_shrink_0()
+                            .text_sm()
+                            .text_color(theme.accent)
+                            .child(chord.to_string()),
+                    )
+                    .child(
+                        gpui::div()
+                            .text_sm()
+                            .text_color(theme.foreground)
+                            .child(command.display_name()),
+                    )
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return None;
+        }
+
+        Some(
+            gpui::div()
+                .flex()
+                .flex_col()
+                .pb_2()
+                .child(section_header(context.as_str(), theme))
+                .children(rows),
+        )
+    });
+
+    let panel = gpui::div()
+        .id("shortcuts-panel")
+        .w(gpui::rems(32.0))
+        .max_h(gpui::relative(0.8))
+        .flex()
+        .flex_col()
+        .bg(theme.card)
+        .border_1()
+        .border_color(theme.border)
+        .rounded(CARD_RADIUS)
+        .shadow_lg()
+        .occlude()
+        .child(
+            gpui::div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .p(CARD_PADDING)
+                .border_b_1()
+                .border_color(theme.divider)
+                .child(
+                    gpui::div()
+                        .text_color(theme.foreground)
+                        .child("Keyboard Shortcuts"),
+                )
+                .child(
+                    gpui::div()
+                        .text_xs()
+                        .text_color(theme.muted)
+                        .child("? or Esc to close"),
+                ),
+        )
+        .child(
+            gpui::div()
+                .id("shortcuts-list")
+                .flex_1()
+                .min_h_0()
+                .overflow_y_scroll()
+                .track_scroll(scroll_handle)
+                .p(CARD_PADDING)
+                .children(groups),
+        );
+
+    gpui::div()
+        .id("shortcuts-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .occlude()
+        .child(
+            gpui::div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .bg(theme.backdrop)
+                .on_mouse_down(gpui::MouseButton::Left, on_close),
+        )
+        .child(
+            gpui::div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(panel),
+        )
+        .into_any_element()
+}
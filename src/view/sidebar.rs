@@ -1,11 +1,24 @@
-use crate::keymap::{Command, CommandDispatcher};
-use crate::models::{FilterState, ProjectTree};
-use crate::theme::ActiveTheme;
-use crate::ui::{divider_h, section_header};
+use crate::components::context_menu::{ContextMenu, ContextMenuEvent, ContextMenuItem};
+use crate::components::input::Input;
+use crate::components::tooltip::Tooltip;
+use crate::config::{ActiveConfig, AppConfig};
+use crate::keymap::{Command, CommandDispatcher, Key};
+use crate::models::{FilterState, INBOX_PROJECT, ProjectNode, ProjectTree};
+use crate::task::{FilterPreset, TagsFilterMode};
+use crate::theme::{ActiveTheme, Theme};
+use crate::ui::{
+    RESIZE_HANDLE_WIDTH, SIDEBAR_COMPLETION_BAR_HEIGHT, SIDEBAR_SECTION_EXPANDED_FRACTION,
+    divider_h, section_header,
+};
 use gpui::{
     Context, Div, Entity, IntoElement, ScrollHandle, Stateful, Window, div, prelude::*, px,
+    relative,
 };
 
+const INBOX_ROW: usize = 0;
+const ALL_PROJECTS_ROW: usize = 1;
+const PROJECT_ROWS_OFFSET: usize = 2;
+
 #[derive(Debug, Clone)]
 pub struct TagItem {
     pub name: String,
@@ -20,16 +33,28 @@ pub enum SidebarSection {
 
 pub enum SidebarEvent {
     Focused(SidebarSection),
+    Command(Command),
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeSidebarSections;
+
 pub struct Sidebar {
     project_tree: ProjectTree,
+    inbox_count: usize,
+    today_count: usize,
     tags: Vec<TagItem>,
     filter_state: Entity<FilterState>,
     selected_section: SidebarSection,
     selected_index: Option<usize>,
     projects_scroll_handle: ScrollHandle,
     tags_scroll_handle: ScrollHandle,
+    focus_handle: gpui::FocusHandle,
+    project_filter_input: Entity<Input>,
+    project_filter: String,
+    project_search_open: bool,
+    context_menu: Entity<ContextMenu>,
+    projects_height_fraction: f32,
 }
 
 impl Sidebar {
@@ -44,17 +69,124 @@ impl Sidebar {
         })
         .detach();
 
+        let project_filter_input =
+            cx.new(|cx| Input::new("sidebar-project-search", cx, "Filter projects..."));
+        cx.observe(&project_filter_input, |sidebar, input, cx| {
+            let value = input.read(cx).value().to_string();
+            if sidebar.project_filter != value {
+                sidebar.selected_index = Some(if value.is_empty() {
+                    INBOX_ROW
+                } else {
+                    PROJECT_ROWS_OFFSET
+                });
+                sidebar.project_filter = value;
+                sidebar.scroll_to_selected();
+            }
+            cx.notify();
+        })
+        .detach();
+
+        let context_menu = cx.new(|_cx| ContextMenu::new("sidebar-context-menu"));
+        cx.subscribe(&context_menu, |_sidebar, _menu, event, cx| match event {
+            ContextMenuEvent::Select(command) => {
+                cx.emit(SidebarEvent::Command(*command));
+            }
+        })
+        .detach();
+
         Self {
             project_tree,
+            inbox_count: 0,
+            today_count: 0,
             tags,
             filter_state,
             selected_section: SidebarSection::Projects,
             selected_index: Some(0),
             projects_scroll_handle: ScrollHandle::new(),
             tags_scroll_handle: ScrollHandle::new(),
+            focus_handle: cx.focus_handle(),
+            project_filter_input,
+            project_filter: String::new(),
+            project_search_open: false,
+            context_menu,
+            projects_height_fraction: cx.config().sidebar.projects_height_fraction,
         }
     }
 
+    pub fn set_projects_height_fraction(&mut self, fraction: f32, cx: &mut Context<Self>) {
+        cx.update_global::<AppConfig, _>(|config, _cx| {
+            config.sidebar.set_projects_height_fraction(fraction);
+        });
+
+        let fraction = cx.config().sidebar.projects_height_fraction;
+        if self.projects_height_fraction != fraction {
+            self.projects_height_fraction = fraction;
+            cx.notify();
+        }
+    }
+
+    fn save_projects_height_fraction(&mut self, cx: &mut Context<Self>) {
+        if let Err(e) = cx.config().save() {
+            log::error!("[Sidebar] Failed to save section split: {}", e);
+        }
+    }
+
+    fn handle_section_resize(
+        &mut self,
+        event: &gpui::DragMoveEvent<ResizeSidebarSections>,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let height = f32::from(event.bounds.size.height);
+        if height <= 0.0 {
+            return;
+        }
+        let offset = f32::from(event.event.position.y - event.bounds.top());
+        self.set_projects_height_fraction(offset / height, cx);
+    }
+
+    pub fn toggle_project_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.project_search_open {
+            self.project_filter_input
+                .update(cx, |input, cx| input.clear(cx));
+            self.close_project_search(window, cx);
+            return;
+        }
+
+        self.project_search_open = true;
+        self.selected_section = SidebarSection::Projects;
+        self.project_filter_input
+            .update(cx, |input, cx| input.focus(window, cx));
+        cx.notify();
+    }
+
+    fn close_project_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.project_search_open = false;
+        window.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    fn handle_project_search_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        cx.stop_propagation();
+
+        match event.keystroke.key.as_str() {
+            "escape" => self.close_project_search(window, cx),
+            "up" => self.select_prev(cx),
+            "down" => self.select_next(cx),
+            "enter" => self.activate_selected(cx),
+            _ => {}
+        }
+    }
+
+    fn visible_projects(&self) -> Vec<(usize, &ProjectNode)> {
+        self.project_tree.iter_matching(&self.project_filter)
+    }
+
     pub fn update_projects(&mut self, mut project_tree: ProjectTree, cx: &mut Context<Self>) {
         let expanded_paths = self.project_tree.get_expanded_paths();
         project_tree.restore_expanded_paths(expanded_paths);
@@ -63,6 +195,24 @@ impl Sidebar {
         cx.notify();
     }
 
+    pub fn set_inbox_count(&mut self, count: usize, cx: &mut Context<Self>) {
+        if self.inbox_count != count {
+            self.inbox_count = count;
+            cx.notify();
+        }
+    }
+
+    pub fn set_today_count(&mut self, count: usize, cx: &mut Context<Self>) {
+        if self.today_count != count {
+            self.today_count = count;
+            cx.notify();
+        }
+    }
+
+    pub fn project_tree(&self) -> &ProjectTree {
+        &self.project_tree
+    }
+
     pub fn update_tags(&mut self, tags: Vec<TagItem>, cx: &mut Context<Self>) {
         self.tags = tags;
         cx.notify();
@@ -94,6 +244,44 @@ impl Sidebar {
         cx.notify();
     }
 
+    pub fn selected_project(&self) -> Option<String> {
+        if self.selected_section != SidebarSection::Projects {
+            return None;
+        }
+        let idx = self.selected_index?.checked_sub(PROJECT_ROWS_OFFSET)?;
+        self.visible_projects()
+            .get(idx)
+            .map(|(_, node)| node.full_path.clone())
+    }
+
+    fn open_project_context_menu(
+        &mut self,
+        row: usize,
+        position: gpui::Point<gpui::Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        cx.emit(SidebarEvent::Focused(SidebarSection::Projects));
+        self.selected_section = SidebarSection::Projects;
+        self.selected_index = Some(row);
+        let items = vec![ContextMenuItem::action(
+            "Rename project...",
+            Command::RenameProject,
+        )];
+        self.context_menu.update(cx, |menu, cx| {
+            menu.open(position, items, cx);
+        });
+        cx.notify();
+    }
+
+    pub fn is_context_menu_open(&self, cx: &gpui::App) -> bool {
+        self.context_menu.read(cx).is_open()
+    }
+
+    pub fn handle_context_menu_key(&mut self, key: Key, cx: &mut Context<Self>) {
+        self.context_menu
+            .update(cx, |menu, cx| menu.handle_key(key, cx));
+    }
+
     fn handle_expand_toggle(&mut self, full_path: String, cx: &mut Context<Self>) {
         self.project_tree.toggle_expansion(&full_path);
         cx.notify();
@@ -126,6 +314,38 @@ impl Sidebar {
         cx.notify();
     }
 
+    fn handle_remove_tag(
+        &mut self,
+        tag_name: String,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        cx.emit(SidebarEvent::Focused(SidebarSection::Tags));
+        self.filter_state.update(cx, |filter, cx| {
+            filter.toggle_tag(tag_name);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    fn handle_toggle_tags_mode(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        cx.emit(SidebarEvent::Focused(SidebarSection::Tags));
+        self.filter_state.update(cx, |filter, cx| {
+            filter.toggle_tags_mode();
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    fn handle_toggle_subprojects(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        cx.emit(SidebarEvent::Focused(SidebarSection::Projects));
+        self.filter_state.update(cx, |filter, cx| {
+            filter.toggle_exclude_subprojects();
+            cx.notify();
+        });
+        cx.notify();
+    }
+
     fn handle_clear_project(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
         cx.emit(SidebarEvent::Focused(SidebarSection::Projects));
         self.filter_state.update(cx, |filter, cx| {
@@ -137,7 +357,7 @@ impl Sidebar {
 
     fn get_items_count(&self) -> usize {
         match self.selected_section {
-            SidebarSection::Projects => 1 + self.project_tree.iter_visible().len(),
+            SidebarSection::Projects => PROJECT_ROWS_OFFSET + self.visible_projects().len(),
             SidebarSection::Tags => self.tags.len(),
         }
     }
@@ -212,9 +432,9 @@ impl Sidebar {
             None => return,
         };
 
-        if idx > 0 {
-            let visible = self.project_tree.iter_visible();
-            let should_expand = if let Some((_, node)) = visible.get(idx - 1) {
+        if let Some(project_idx) = idx.checked_sub(PROJECT_ROWS_OFFSET) {
+            let visible = self.visible_projects();
+            let should_expand = if let Some((_, node)) = visible.get(project_idx) {
                 if node.has_children() && !node.is_expanded {
                     Some(node.full_path.clone())
                 } else {
@@ -242,9 +462,9 @@ impl Sidebar {
             None => return,
         };
 
-        if idx > 0 {
-            let visible = self.project_tree.iter_visible();
-            let should_collapse = if let Some((_, node)) = visible.get(idx - 1) {
+        if let Some(project_idx) = idx.checked_sub(PROJECT_ROWS_OFFSET) {
+            let visible = self.visible_projects();
+            let should_collapse = if let Some((_, node)) = visible.get(project_idx) {
                 if node.has_children() && node.is_expanded {
                     Some(node.full_path.clone())
                 } else {
@@ -270,14 +490,19 @@ impl Sidebar {
 
         match self.selected_section {
             SidebarSection::Projects => {
-                if idx == 0 {
+                if idx == INBOX_ROW {
+                    self.filter_state.update(cx, |filter, cx| {
+                        filter.select_project(Some(INBOX_PROJECT.to_string()));
+                        cx.notify();
+                    });
+                } else if idx == ALL_PROJECTS_ROW {
                     self.filter_state.update(cx, |filter, cx| {
                         filter.select_project(None);
                         cx.notify();
                     });
                 } else {
-                    let visible = self.project_tree.iter_visible();
-                    if let Some((_, node)) = visible.get(idx - 1) {
+                    let visible = self.visible_projects();
+                    if let Some((_, node)) = visible.get(idx - PROJECT_ROWS_OFFSET) {
                         self.filter_state.update(cx, |filter, cx| {
                             filter.select_project(Some(node.full_path.clone()));
                             cx.notify();
@@ -298,18 +523,134 @@ impl Sidebar {
         cx.notify();
     }
 
+    fn render_today(&self, cx: &mut Context<Self>) -> Stateful<Div> {
+        let theme = cx.theme();
+        let is_selected = self.filter_state.read(cx).preset == Some(FilterPreset::TodayFocus);
+
+        div()
+            .id("sidebar-today")
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .justify_between()
+            .mx_2()
+            .mt_2()
+            .px_3()
+            .py_1()
+            .rounded_sm()
+            .cursor_pointer()
+            .when(is_selected, |this| this.bg(theme.selection))
+            .when(!is_selected, |this| this.hover(|s| s.bg(theme.hover)))
+            .tooltip(Tooltip::text(
+                "Due today, active, or high priority and due within 3 days (Ctrl+Shift+T)",
+            ))
+            .on_click(cx.listener(|view, _event, _window, cx| {
+                view.filter_state.update(cx, |filter, cx| {
+                    filter.toggle_preset(FilterPreset::TodayFocus);
+                    cx.notify();
+                });
+            }))
+            .child(
+                div()
+                    .text_color(if is_selected {
+                        theme.foreground
+                    } else {
+                        theme.muted
+                    })
+                    .child("Today"),
+            )
+            .child(
+                div()
+                    .px_1p5()
+                    .rounded_full()
+                    .text_xs()
+                    .bg(if self.today_count > 0 {
+                        theme.accent
+                    } else {
+                        theme.border
+                    })
+                    .text_color(if self.today_count > 0 {
+                        theme.background
+                    } else {
+                        theme.muted
+                    })
+                    .child(self.today_count.to_string()),
+            )
+    }
+
     fn render_projects(&self, cx: &mut Context<Self>) -> Vec<Stateful<Div>> {
         let theme = cx.theme();
         let filter = self.filter_state.read(cx);
         let mut elements = Vec::new();
 
+        let is_inbox_selected = filter.selected_project.as_deref() == Some(INBOX_PROJECT);
+        let is_keyboard_selected = self.selected_section == SidebarSection::Projects
+            && self.selected_index == Some(INBOX_ROW);
+
+        elements.push(
+            div()
+                .id(("project", INBOX_ROW))
+                .flex()
+                .items_center()
+                .gap_1()
+                .px_3()
+                .py_1()
+                .rounded_sm()
+                .cursor_pointer()
+                .when(is_inbox_selected, |this| this.bg(theme.selection))
+                .when(!is_inbox_selected && is_keyboard_selected, |this| {
+                    this.bg(theme.hover)
+                })
+                .when(!is_inbox_selected && !is_keyboard_selected, |this| {
+                    this.hover(|s| s.bg(theme.hover))
+                })
+                .tooltip(Tooltip::text(format!(
+                    "Tasks without a project: {} pending",
+                    self.inbox_count
+                )))
+                .on_click(cx.listener(|view, _event, window, cx| {
+                    view.handle_project_click(
+                        Some(INBOX_PROJECT.to_string()),
+                        Some(INBOX_ROW),
+                        window,
+                        cx,
+                    );
+                }))
+                .child(
+                    div()
+                        .w_4()
+                        .text_color(theme.accent)
+                        .child(if is_keyboard_selected { ">" } else { " " }),
+                )
+                .child(
+                    div()
+                        .w_3()
+                        .h_3()
+                        .rounded_full()
+                        .border_1()
+                        .border_color(theme.accent)
+                        .when(is_inbox_selected, |this| this.bg(theme.accent)),
+                )
+                .child(
+                    div()
+                        .text_color(if is_inbox_selected {
+                            theme.foreground
+                        } else if self.inbox_count == 0 {
+                            theme.disabled_fg
+                        } else {
+                            theme.muted
+                        })
+                        .child(format!("Inbox {}", self.inbox_count)),
+                ),
+        );
+
         let is_all_selected = filter.selected_project.is_none();
-        let is_keyboard_selected =
-            self.selected_section == SidebarSection::Projects && self.selected_index == Some(0);
+        let is_keyboard_selected = self.selected_section == SidebarSection::Projects
+            && self.selected_index == Some(ALL_PROJECTS_ROW);
 
         elements.push(
             div()
-                .id(("project", 0usize))
+                .id(("project", ALL_PROJECTS_ROW))
                 .flex()
                 .items_center()
                 .gap_1()
@@ -324,12 +665,9 @@ impl Sidebar {
                 .when(!is_all_selected && !is_keyboard_selected, |this| {
                     this.hover(|s| s.bg(theme.hover))
                 })
-                .on_mouse_down(
-                    gpui::MouseButton::Left,
-                    cx.listener(|view, _event, window, cx| {
-                        view.handle_project_click(None, Some(0), window, cx);
-                    }),
-                )
+                .on_click(cx.listener(|view, _event, window, cx| {
+                    view.handle_project_click(None, Some(ALL_PROJECTS_ROW), window, cx);
+                }))
                 .child(
                     div()
                         .w_4()
@@ -356,7 +694,8 @@ impl Sidebar {
                 ),
         );
 
-        for (idx, (_tree_idx, node)) in self.project_tree.iter_visible().iter().enumerate() {
+        for (idx, (_tree_idx, node)) in self.visible_projects().iter().enumerate() {
+            let row = idx + PROJECT_ROWS_OFFSET;
             let is_selected = filter
                 .selected_project
                 .as_ref()
@@ -364,17 +703,32 @@ impl Sidebar {
                 .unwrap_or(false);
 
             let is_keyboard_selected = self.selected_section == SidebarSection::Projects
-                && self.selected_index == Some(idx + 1);
+                && self.selected_index == Some(row);
 
             let indent = node.level * 16;
             let full_path = node.full_path.clone();
             let full_path_for_expand = node.full_path.clone();
             let has_children = node.has_children();
             let is_expanded = node.is_expanded;
+            let completion_ratio = node.completion_ratio();
+            let is_done = node.task_count == 0;
+            let project_label = format!(
+                "{} {}/{}",
+                node.name,
+                node.task_count,
+                node.total_task_count()
+            );
+            let project_tooltip = format!(
+                "{}: {} pending, {} completed ({:.0}%)",
+                node.full_path,
+                node.task_count,
+                node.completed_task_count,
+                completion_ratio * 100.0
+            );
 
             elements.push(
                 div()
-                    .id(("project", idx + 1))
+                    .id(("project", row))
                     .flex()
                     .items_center()
                     .gap_1()
@@ -389,6 +743,13 @@ impl Sidebar {
                     .when(!is_selected && !is_keyboard_selected, |this| {
                         this.hover(|s| s.bg(theme.hover))
                     })
+                    .tooltip(Tooltip::text(project_tooltip))
+                    .on_mouse_down(
+                        gpui::MouseButton::Right,
+                        cx.listener(move |view, event: &gpui::MouseDownEvent, _window, cx| {
+                            view.open_project_context_menu(row, event.position, cx);
+                        }),
+                    )
                     .child(
                         div()
                             .w_4()
@@ -398,18 +759,16 @@ impl Sidebar {
                     .child(div().w(px(indent as f32)))
                     .child(
                         div()
+                            .id(("project-expand", row))
                             .w_4()
                             .h_4()
                             .flex()
                             .items_center()
                             .justify_center()
                             .when(has_children, |this| {
-                                this.on_mouse_down(
-                                    gpui::MouseButton::Left,
-                                    cx.listener(move |view, _event, _window, cx| {
-                                        view.handle_expand_toggle(full_path_for_expand.clone(), cx);
-                                    }),
-                                )
+                                this.on_click(cx.listener(move |view, _event, _window, cx| {
+                                    view.handle_expand_toggle(full_path_for_expand.clone(), cx);
+                                }))
                             })
                             .child(if has_children {
                                 div()
@@ -422,21 +781,19 @@ impl Sidebar {
                     )
                     .child(
                         div()
+                            .id(("project-label", row))
                             .flex()
                             .flex_1()
                             .items_center()
                             .gap_2()
-                            .on_mouse_down(
-                                gpui::MouseButton::Left,
-                                cx.listener(move |view, _event, window, cx| {
-                                    view.handle_project_click(
-                                        Some(full_path.clone()),
-                                        Some(idx + 1),
-                                        window,
-                                        cx,
-                                    );
-                                }),
-                            )
+                            .on_click(cx.listener(move |view, _event, window, cx| {
+                                view.handle_project_click(
+                                    Some(full_path.clone()),
+                                    Some(row),
+                                    window,
+                                    cx,
+                                );
+                            }))
                             .child(
                                 div()
                                     .w_3()
@@ -448,17 +805,52 @@ impl Sidebar {
                             )
                             .child(
                                 div()
-                                    .text_color(if is_selected {
-                                        theme.foreground
-                                    } else {
-                                        theme.muted
-                                    })
-                                    .child(format!("{} ({})", node.name, node.task_count)),
+                                    .flex()
+                                    .flex_col()
+                                    .flex_1()
+                                    .gap_0p5()
+                                    .child(
+                                        div()
+                                            .text_color(if is_selected {
+                                                theme.foreground
+                                            } else if is_done {
+                                                theme.disabled_fg
+                                            } else {
+                                                theme.muted
+                                            })
+                                            .child(project_label),
+                                    )
+                                    .child(
+                                        div()
+                                            .h(SIDEBAR_COMPLETION_BAR_HEIGHT)
+                                            .w_full()
+                                            .rounded_full()
+                                            .overflow_hidden()
+                                            .bg(theme.border)
+                                            .child(
+                                                div()
+                                                    .h_full()
+                                                    .w(relative(completion_ratio))
+                                                    .bg(theme.success),
+                                            ),
+                                    ),
                             ),
                     ),
             );
         }
 
+        if elements.len() == 1 && !self.project_filter.trim().is_empty() {
+            elements.push(
+                div()
+                    .id("project-no-matches")
+                    .px_3()
+                    .py_1()
+                    .text_sm()
+                    .text_color(theme.muted)
+                    .child("No matching projects"),
+            );
+        }
+
         elements
     }
 
@@ -490,12 +882,9 @@ impl Sidebar {
                     .when(!is_active && !is_keyboard_selected, |this| {
                         this.hover(|s| s.bg(theme.hover))
                     })
-                    .on_mouse_down(
-                        gpui::MouseButton::Left,
-                        cx.listener(move |view, _event, window, cx| {
-                            view.handle_tag_click(tag_name.clone(), idx, window, cx);
-                        }),
-                    )
+                    .on_click(cx.listener(move |view, _event, window, cx| {
+                        view.handle_tag_click(tag_name.clone(), idx, window, cx);
+                    }))
                     .child(
                         div()
                             .w_4()
@@ -531,6 +920,67 @@ impl Sidebar {
 
         elements
     }
+
+    fn render_active_tags(&self, cx: &mut Context<Self>) -> Option<Div> {
+        let theme = cx.theme();
+        let filter = self.filter_state.read(cx);
+        if filter.active_tags.is_empty() {
+            return None;
+        }
+
+        let mut tags: Vec<String> = filter.active_tags.iter().cloned().collect();
+        tags.sort();
+        let joiner = match filter.tags_mode {
+            TagsFilterMode::And => "and",
+            TagsFilterMode::Or => "or",
+        };
+
+        let mut row = div().flex().flex_wrap().items_center().gap_1();
+        for (idx, tag) in tags.into_iter().enumerate() {
+            if idx > 0 {
+                row = row.child(div().text_color(theme.muted).child(joiner));
+            }
+            let color = theme.tag_color(&tag);
+            let tag_name = tag.clone();
+            row = row.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_1()
+                    .rounded_sm()
+                    .bg(Theme::alpha(color, 0.18))
+                    .text_color(color)
+                    .child(format!("+{}", tag))
+                    .child(
+                        div()
+                            .id(("remove-tag", idx))
+                            .cursor_pointer()
+                            .text_color(theme.muted)
+                            .hover(|s| s.text_color(theme.error))
+                            .on_click(cx.listener(move |view, _, window, cx| {
+                                view.handle_remove_tag(tag_name.clone(), window, cx);
+                            }))
+                            .child("×"),
+                    ),
+            );
+        }
+
+        Some(
+            div()
+                .flex_shrink_0()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .px_3()
+                .py_2()
+                .border_t_1()
+                .border_color(theme.border)
+                .text_xs()
+                .child(div().text_color(theme.muted).child("Tag Filter"))
+                .child(row),
+        )
+    }
 }
 
 impl CommandDispatcher for Sidebar {
@@ -564,6 +1014,16 @@ impl CommandDispatcher for Sidebar {
                 self.collapse_selected(cx);
                 true
             }
+            Command::SidebarExpandProjects => {
+                self.set_projects_height_fraction(SIDEBAR_SECTION_EXPANDED_FRACTION, cx);
+                self.save_projects_height_fraction(cx);
+                true
+            }
+            Command::SidebarExpandTags => {
+                self.set_projects_height_fraction(1.0 - SIDEBAR_SECTION_EXPANDED_FRACTION, cx);
+                self.save_projects_height_fraction(cx);
+                true
+            }
             _ => false,
         }
     }
@@ -574,21 +1034,55 @@ impl gpui::EventEmitter<SidebarEvent> for Sidebar {}
 impl Render for Sidebar {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = cx.theme().clone();
+        let today = self.render_today(cx);
         let projects = self.render_projects(cx);
         let tags = self.render_tags(cx);
+        let active_tags = self.render_active_tags(cx);
         let filter = self.filter_state.read(cx);
         let has_project = filter.selected_project.is_some();
         let has_tags = !filter.active_tags.is_empty();
+        let subprojects_label = if filter.exclude_subprojects {
+            "Exact"
+        } else {
+            "+children"
+        };
+        let tags_mode_label = match filter.tags_mode {
+            TagsFilterMode::And => "Match: All",
+            TagsFilterMode::Or => "Match: Any",
+        };
+
+        let projects_fraction = self.projects_height_fraction;
+        let section_handle = div()
+            .id("sidebar-section-resize")
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .h(RESIZE_HANDLE_WIDTH)
+            .cursor_row_resize()
+            .hover(|s| s.bg(theme.hover))
+            .on_drag(ResizeSidebarSections, |_handle, _offset, _window, cx| {
+                cx.new(|_cx| gpui::EmptyView)
+            })
+            .child(divider_h(&theme));
 
         div()
+            .track_focus(&self.focus_handle)
             .flex()
             .flex_col()
             .size_full()
+            .on_drag_move(cx.listener(Self::handle_section_resize))
+            .on_drop(
+                cx.listener(|sidebar, _: &ResizeSidebarSections, _window, cx| {
+                    sidebar.save_projects_height_fraction(cx);
+                }),
+            )
+            .child(today)
             .child(
                 div()
                     .flex()
                     .flex_col()
-                    .flex_1()
+                    .flex_basis(relative(projects_fraction))
+                    .flex_shrink()
                     .min_h_0()
                     .overflow_hidden()
                     .child(
@@ -603,21 +1097,52 @@ impl Render for Sidebar {
                             .when(has_project, |this| {
                                 this.child(
                                     div()
-                                        .id("clear-project")
-                                        .text_xs()
-                                        .text_color(theme.muted)
-                                        .cursor_pointer()
-                                        .hover(|s| s.text_color(theme.accent))
-                                        .on_mouse_down(
-                                            gpui::MouseButton::Left,
-                                            cx.listener(|view, _, window, cx| {
-                                                view.handle_clear_project(window, cx);
-                                            }),
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .child(
+                                            div()
+                                                .id("toggle-subprojects")
+                                                .text_xs()
+                                                .text_color(theme.muted)
+                                                .cursor_pointer()
+                                                .hover(|s| s.text_color(theme.accent))
+                                                .tooltip(Tooltip::text(
+                                                    "Toggle whether sub-projects are included",
+                                                ))
+                                                .on_click(cx.listener(|view, _, window, cx| {
+                                                    view.handle_toggle_subprojects(window, cx);
+                                                }))
+                                                .child(subprojects_label),
                                         )
-                                        .child("Clear"),
+                                        .child(
+                                            div()
+                                                .id("clear-project")
+                                                .text_xs()
+                                                .text_color(theme.muted)
+                                                .cursor_pointer()
+                                                .hover(|s| s.text_color(theme.accent))
+                                                .on_click(cx.listener(|view, _, window, cx| {
+                                                    view.handle_clear_project(window, cx);
+                                                }))
+                                                .child("Clear"),
+                                        ),
                                 )
                             }),
                     )
+                    .when(
+                        self.project_search_open || !self.project_filter.is_empty(),
+                        |this| {
+                            this.child(
+                                div()
+                                    .flex_shrink_0()
+                                    .px_2()
+                                    .pb_2()
+                                    .on_key_down(cx.listener(Self::handle_project_search_key_down))
+                                    .child(self.project_filter_input.clone()),
+                            )
+                        },
+                    )
                     .child(
                         div()
                             .id("sidebar-projects")
@@ -628,12 +1153,13 @@ impl Render for Sidebar {
                             .children(projects),
                     ),
             )
-            .child(divider_h(&theme).my_1())
+            .child(section_handle)
             .child(
                 div()
                     .flex()
                     .flex_col()
-                    .flex_1()
+                    .flex_basis(relative(1.0 - projects_fraction))
+                    .flex_shrink()
                     .min_h_0()
                     .overflow_hidden()
                     .child(
@@ -645,23 +1171,38 @@ impl Render for Sidebar {
                             .px_2()
                             .py_2()
                             .child(section_header("Tags", &theme))
-                            .when(has_tags, |this| {
-                                this.child(
-                                    div()
-                                        .id("clear-tags")
-                                        .text_xs()
-                                        .text_color(theme.muted)
-                                        .cursor_pointer()
-                                        .hover(|s| s.text_color(theme.accent))
-                                        .on_mouse_down(
-                                            gpui::MouseButton::Left,
-                                            cx.listener(|view, _, window, cx| {
-                                                view.handle_clear_tags(window, cx);
-                                            }),
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .id("toggle-tags-mode")
+                                            .text_xs()
+                                            .text_color(theme.muted)
+                                            .cursor_pointer()
+                                            .hover(|s| s.text_color(theme.accent))
+                                            .on_click(cx.listener(|view, _, window, cx| {
+                                                view.handle_toggle_tags_mode(window, cx);
+                                            }))
+                                            .child(tags_mode_label),
+                                    )
+                                    .when(has_tags, |this| {
+                                        this.child(
+                                            div()
+                                                .id("clear-tags")
+                                                .text_xs()
+                                                .text_color(theme.muted)
+                                                .cursor_pointer()
+                                                .hover(|s| s.text_color(theme.accent))
+                                                .on_click(cx.listener(|view, _, window, cx| {
+                                                    view.handle_clear_tags(window, cx);
+                                                }))
+                                                .child("Clear"),
                                         )
-                                        .child("Clear"),
-                                )
-                            }),
+                                    }),
+                            ),
                     )
                     .child(
                         div()
@@ -671,7 +1212,9 @@ impl Render for Sidebar {
                             .overflow_y_scroll()
                             .track_scroll(&self.tags_scroll_handle)
                             .children(tags),
-                    ),
+                    )
+                    .children(active_tags),
             )
+            .child(self.context_menu.clone())
     }
 }
@@ -1,8 +1,12 @@
-use gpui::{Context, IntoElement, MouseButton, Render, Window, div, prelude::*, rems};
+use chrono::{DateTime, Utc};
+use gpui::{Context, IntoElement, Render, Window, div, prelude::*, rems};
 
 use crate::components::label::Label;
+use crate::components::tooltip::Tooltip;
+use crate::config::{ActiveConfig, TableDensity};
+use crate::task::{TaskStatus, TaskSummary};
 use crate::theme::{ActiveTheme, Theme};
-use crate::ui::divider_v;
+use crate::ui::{STATUS_BAR_TIMER_INTERVAL, WINDOW_TITLE, divider_v, format_elapsed};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SyncState {
@@ -18,19 +22,77 @@ impl Default for SyncState {
     }
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TaskCounts {
+    pub pending: usize,
+    pub due_today: usize,
+    pub overdue: usize,
+}
+
+impl TaskCounts {
+    pub fn from_tasks(tasks: &[TaskSummary]) -> Self {
+        let mut counts = Self::default();
+        for task in tasks {
+            if !matches!(task.status, TaskStatus::Pending) {
+                continue;
+            }
+            counts.pending += 1;
+            if task.is_overdue() {
+                counts.overdue += 1;
+            } else if task.is_due_today() {
+                counts.due_today += 1;
+            }
+        }
+        counts
+    }
+
+    pub fn window_title(&self) -> String {
+        if self.overdue > 0 {
+            format!(
+                "{} — {} pending, {} overdue",
+                WINDOW_TITLE, self.pending, self.overdue
+            )
+        } else {
+            format!("{} — {} pending", WINDOW_TITLE, self.pending)
+        }
+    }
+}
+
 pub struct StatusBar {
     sync_state: SyncState,
     last_sync_message: String,
     error_message: Option<String>,
+    auto_sync_interval_minutes: Option<u64>,
+    table_density: TableDensity,
+    counts: TaskCounts,
+    filter_summary: Option<(usize, usize)>,
+    pending_sync_ops: usize,
+    replica_stats: Option<(usize, usize)>,
+    data_location: Option<String>,
+    project_breadcrumb: String,
+    active_task: Option<(uuid::Uuid, String, DateTime<Utc>)>,
+    _active_timer: Option<gpui::Task<()>>,
 }
 
 impl StatusBar {
-    pub fn new(_cx: &mut Context<Self>) -> Self {
-        Self {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let mut bar = Self {
             sync_state: SyncState::default(),
             last_sync_message: String::new(),
             error_message: None,
-        }
+            auto_sync_interval_minutes: None,
+            table_density: cx.config().table_density,
+            counts: TaskCounts::default(),
+            filter_summary: None,
+            pending_sync_ops: 0,
+            replica_stats: None,
+            data_location: None,
+            project_breadcrumb: String::new(),
+            active_task: None,
+            _active_timer: None,
+        };
+        bar.schedule_active_timer(cx);
+        bar
     }
 
     pub fn set_sync_state(&mut self, state: SyncState, cx: &mut Context<Self>) {
@@ -59,6 +121,90 @@ impl StatusBar {
         cx.notify();
     }
 
+    pub fn set_auto_sync_interval(&mut self, minutes: Option<u64>, cx: &mut Context<Self>) {
+        self.auto_sync_interval_minutes = minutes;
+        cx.notify();
+    }
+
+    pub fn set_table_density(&mut self, density: TableDensity, cx: &mut Context<Self>) {
+        self.table_density = density;
+        cx.notify();
+    }
+
+    pub fn set_counts(&mut self, counts: TaskCounts, cx: &mut Context<Self>) {
+        self.counts = counts;
+        cx.notify();
+    }
+
+    pub fn set_filter_summary(&mut self, summary: Option<(usize, usize)>, cx: &mut Context<Self>) {
+        self.filter_summary = summary;
+        cx.notify();
+    }
+
+    pub fn set_pending_sync_ops(&mut self, count: usize, cx: &mut Context<Self>) {
+        if self.pending_sync_ops != count {
+            self.pending_sync_ops = count;
+            cx.notify();
+        }
+    }
+
+    pub fn set_replica_stats(&mut self, stats: Option<(usize, usize)>, cx: &mut Context<Self>) {
+        if self.replica_stats != stats {
+            self.replica_stats = stats;
+            cx.notify();
+        }
+    }
+
+    pub fn set_data_location(&mut self, label: String, cx: &mut Context<Self>) {
+        self.data_location = Some(label);
+        cx.notify();
+    }
+
+    pub fn set_project_breadcrumb(&mut self, label: String, cx: &mut Context<Self>) {
+        if self.project_breadcrumb != label {
+            self.project_breadcrumb = label;
+            cx.notify();
+        }
+    }
+
+    pub fn set_active_task(
+        &mut self,
+        active_task: Option<(uuid::Uuid, String, DateTime<Utc>)>,
+        cx: &mut Context<Self>,
+    ) {
+        if self.active_task == active_task {
+            return;
+        }
+
+        self.active_task = active_task;
+        self.schedule_active_timer(cx);
+        cx.notify();
+    }
+
+    fn schedule_active_timer(&mut self, cx: &mut Context<Self>) {
+        if self.active_task.is_none() {
+            self._active_timer = None;
+            return;
+        }
+        if self._active_timer.is_some() {
+            return;
+        }
+
+        self._active_timer = Some(cx.spawn(async move |bar, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(STATUS_BAR_TIMER_INTERVAL)
+                    .await;
+                let refreshed = bar.update(cx, |_bar, cx| {
+                    cx.notify();
+                });
+                if refreshed.is_err() {
+                    break;
+                }
+            }
+        }));
+    }
+
     fn sync_icon(&self) -> &'static str {
         match self.sync_state {
             SyncState::Idle => "↻",
@@ -82,6 +228,7 @@ impl Render for StatusBar {
         };
 
         let sync_button = div()
+            .id("status-sync")
             .flex()
             .items_center()
             .gap_1()
@@ -93,16 +240,24 @@ impl Render for StatusBar {
             .when(!is_syncing, |d| {
                 d.cursor_pointer()
                     .hover(|s| s.bg(theme.hover))
-                    .on_mouse_down(
-                        MouseButton::Left,
-                        cx.listener(|_this, _event, _window, cx| {
-                            cx.emit(StatusBarEvent::SyncRequested);
-                        }),
-                    )
+                    .on_click(cx.listener(|_this, _event, _window, cx| {
+                        cx.emit(StatusBarEvent::SyncRequested);
+                    }))
             })
             .when(is_syncing, |d| d.cursor_not_allowed())
             .child(Label::new(self.sync_icon()).text_color(sync_color))
-            .child(Label::new("Sync").text_color(sync_color));
+            .child(Label::new("Sync").text_color(sync_color))
+            .when(self.pending_sync_ops > 0, |d| {
+                d.child(
+                    div()
+                        .px_1()
+                        .rounded_sm()
+                        .bg(Theme::alpha(theme.accent, 0.18))
+                        .text_xs()
+                        .text_color(theme.accent)
+                        .child(self.pending_sync_ops.to_string()),
+                )
+            });
 
         let status_text = if !self.last_sync_message.is_empty() {
             Label::new(self.last_sync_message.clone()).text_color(theme.muted)
@@ -110,6 +265,93 @@ impl Render for StatusBar {
             Label::new("")
         };
 
+        let separator = || Label::new("·").text_color(theme.disabled_fg);
+        let overdue_color = if self.counts.overdue > 0 {
+            theme.error
+        } else {
+            theme.muted
+        };
+        let counts = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .child(Label::new(format!("{} pending", self.counts.pending)).text_color(theme.muted))
+            .child(separator())
+            .child(
+                Label::new(format!("{} due today", self.counts.due_today)).text_color(theme.muted),
+            )
+            .child(separator())
+            .child(
+                div()
+                    .id("status-overdue")
+                    .cursor_pointer()
+                    .text_color(overdue_color)
+                    .hover(|s| s.text_color(theme.accent))
+                    .on_click(cx.listener(|_this, _event, _window, cx| {
+                        cx.emit(StatusBarEvent::ShowOverdue);
+                    }))
+                    .child(format!("{} overdue", self.counts.overdue)),
+            )
+            .when_some(self.filter_summary, |el, (shown, total)| {
+                el.child(separator()).child(
+                    Label::new(format!("filtered: {} of {}", shown, total))
+                        .text_color(theme.accent),
+                )
+            });
+
+        let breadcrumb = (!self.project_breadcrumb.is_empty())
+            .then(|| Label::new(self.project_breadcrumb.clone()).text_color(theme.foreground));
+
+        let active_timer = self.active_task.as_ref().map(|(task_id, label, start)| {
+            let task_id = *task_id;
+            div()
+                .id("status-active-task")
+                .flex()
+                .items_center()
+                .gap_1()
+                .cursor_pointer()
+                .text_color(theme.success)
+                .hover(|s| s.text_color(theme.accent))
+                .on_click(cx.listener(move |_this, _event, _window, cx| {
+                    cx.emit(StatusBarEvent::OpenTask(task_id));
+                }))
+                .child("▶")
+                .child(label.clone())
+                .child(format_elapsed(Utc::now() - *start))
+        });
+
+        let replica_label = self.replica_stats.map(|(working_set, pending)| {
+            let mut label = format!("{} tasks", working_set);
+            if self.pending_sync_ops > 0 {
+                label.push_str(&format!(" | {} pending ops", self.pending_sync_ops));
+            }
+            div()
+                .id("status-replica-stats")
+                .text_xs()
+                .text_color(theme.muted)
+                .tooltip(Tooltip::text(match self.data_location {
+                    Some(ref location) => format!(
+                        "{} tasks in the working set, {} pending\nData: {}",
+                        working_set, pending, location
+                    ),
+                    None => format!(
+                        "{} tasks in the working set, {} pending",
+                        working_set, pending
+                    ),
+                }))
+                .child(label)
+        });
+
+        let auto_sync_label = self.auto_sync_interval_minutes.map(|minutes| {
+            Label::new(format!("Auto-sync: every {}m", minutes))
+                .text_color(theme.muted)
+                .text_xs()
+        });
+
+        let density_label = Label::new(format!("Density: {}", self.table_density.label()))
+            .text_color(theme.muted)
+            .text_xs();
+
         let error_banner = if let Some(ref error) = self.error_message {
             Some(
                 div()
@@ -133,17 +375,15 @@ impl Render for StatusBar {
                     )
                     .child(
                         div()
+                            .id("status-error-dismiss")
                             .px_2()
                             .py_1()
                             .rounded_sm()
                             .cursor_pointer()
                             .hover(|s| s.bg(Theme::alpha(theme.error, 0.2)))
-                            .on_mouse_down(
-                                MouseButton::Left,
-                                cx.listener(|this, _event, _window, cx| {
-                                    this.clear_error(cx);
-                                }),
-                            )
+                            .on_click(cx.listener(|this, _event, _window, cx| {
+                                this.clear_error(cx);
+                            }))
                             .child(Label::new("✕").text_color(theme.error).text_sm()),
                     ),
             )
@@ -168,6 +408,9 @@ impl Render for StatusBar {
                     .items_center()
                     .gap_3()
                     .text_xs()
+                    .children(breadcrumb)
+                    .child(counts)
+                    .children(active_timer)
                     .child(status_text),
             )
             .child(
@@ -175,6 +418,9 @@ impl Render for StatusBar {
                     .flex()
                     .items_center()
                     .gap_2()
+                    .children(replica_label)
+                    .children(auto_sync_label)
+                    .child(density_label)
                     .child(divider_v(&theme).h(rems(1.0)))
                     .child(sync_button),
             );
@@ -196,6 +442,26 @@ impl Render for StatusBar {
 
 pub enum StatusBarEvent {
     SyncRequested,
+    ShowOverdue,
+    OpenTask(uuid::Uuid),
 }
 
 impl gpui::EventEmitter<StatusBarEvent> for StatusBar {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_title_mentions_overdue_only_when_present() {
+        let mut counts = TaskCounts {
+            pending: 42,
+            due_today: 2,
+            overdue: 0,
+        };
+        assert_eq!(counts.window_title(), "Taskwarrior — 42 pending");
+
+        counts.overdue = 3;
+        assert_eq!(counts.window_title(), "Taskwarrior — 42 pending, 3 overdue");
+    }
+}
@@ -1,16 +1,76 @@
+use chrono::{DateTime, Utc};
 use gpui::prelude::*;
+use std::ops::Range;
 use std::sync::Arc;
 
+use crate::components::input::Input;
 use crate::components::label::Label;
 use crate::components::modal::ModalFrame;
 use crate::components::toast::{ToastGlobal, ToastKind};
+use crate::config::ActiveConfig;
 use crate::task::model::TaskLinkVm;
 use crate::task::{self, TaskDetailState, TaskDetailVm};
 use crate::theme::{ActiveTheme, Theme};
-use crate::ui::{DATE_FORMAT, DATE_TIME_FORMAT};
+use crate::ui::{DATE_FORMAT, DATE_TIME_FORMAT, format_relative_date};
 
 pub enum TaskDetailModalEvent {
     Closed,
+    OpenTask(uuid::Uuid),
+    AnnotationDeleted(uuid::Uuid, DateTime<Utc>),
+    UdaChanged(uuid::Uuid, String, Option<String>),
+}
+
+pub type OpenTaskHandler = Arc<dyn Fn(uuid::Uuid, &mut gpui::Window, &mut gpui::App)>;
+
+pub struct AnnotationDeleteActions {
+    pending: Option<DateTime<Utc>>,
+    on_delete: Arc<dyn Fn(DateTime<Utc>, &mut gpui::Window, &mut gpui::App)>,
+    on_cancel: Arc<dyn Fn(&mut gpui::Window, &mut gpui::App)>,
+}
+
+pub struct UdaEditor {
+    input: gpui::AnyElement,
+    on_edit: Arc<dyn Fn(&str, &str, &mut gpui::Window, &mut gpui::App)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailSection {
+    Overview,
+    Tags,
+    Dependencies,
+    Annotations,
+    Dates,
+    Metadata,
+}
+
+impl DetailSection {
+    pub const ALL: [DetailSection; 6] = [
+        DetailSection::Overview,
+        DetailSection::Tags,
+        DetailSection::Dependencies,
+        DetailSection::Annotations,
+        DetailSection::Dates,
+        DetailSection::Metadata,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        Self::ALL[index.min(Self::ALL.len() - 1)]
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            DetailSection::Overview => "Overview",
+            DetailSection::Tags => "Tags",
+            DetailSection::Dependencies => "Dependencies",
+            DetailSection::Annotations => "Annotations",
+            DetailSection::Dates => "Dates",
+            DetailSection::Metadata => "Metadata",
+        }
+    }
 }
 
 pub struct TaskDetailModal {
@@ -18,15 +78,37 @@ pub struct TaskDetailModal {
     is_open: bool,
     focus_handle: gpui::FocusHandle,
     scroll_handle: gpui::ScrollHandle,
+    jumped_section: Option<DetailSection>,
+    history_expanded: bool,
+    annotation_input: gpui::Entity<Input>,
+    annotation_filter: String,
+    pending_annotation_delete: Option<DateTime<Utc>>,
+    uda_input: gpui::Entity<Input>,
 }
 
 impl TaskDetailModal {
     pub fn new(cx: &mut gpui::Context<Self>) -> Self {
+        let annotation_input =
+            cx.new(|cx| Input::new("annotation-search", cx, "Search annotations..."));
+        cx.observe(&annotation_input, |modal, input, cx| {
+            modal.annotation_filter = input.read(cx).value().to_string();
+            cx.notify();
+        })
+        .detach();
+        let uda_input =
+            cx.new(|cx| Input::new("uda-editor", cx, "key:value to set, key: to remove"));
+
         Self {
             state: TaskDetailState::default(),
             is_open: false,
             focus_handle: cx.focus_handle(),
             scroll_handle: gpui::ScrollHandle::new(),
+            jumped_section: None,
+            history_expanded: false,
+            annotation_input,
+            annotation_filter: String::new(),
+            pending_annotation_delete: None,
+            uda_input,
         }
     }
 
@@ -38,6 +120,13 @@ impl TaskDetailModal {
         &self.focus_handle
     }
 
+    pub fn detail(&self) -> Option<&TaskDetailVm> {
+        match &self.state {
+            TaskDetailState::Ready(detail) => Some(detail),
+            _ => None,
+        }
+    }
+
     pub fn open_with_detail(
         &mut self,
         detail: TaskDetailVm,
@@ -51,7 +140,13 @@ impl TaskDetailModal {
         self.is_open = true;
         self.scroll_handle = gpui::ScrollHandle::new();
         self.scroll_handle.scroll_to_item(0);
+        self.jumped_section = None;
+        self.history_expanded = false;
+        self.pending_annotation_delete = None;
         self.state = TaskDetailState::Ready(detail);
+        self.annotation_input
+            .update(cx, |input, cx| input.clear(cx));
+        self.uda_input.update(cx, |input, cx| input.clear(cx));
         cx.notify();
     }
 
@@ -69,6 +164,7 @@ impl TaskDetailModal {
         self.is_open = true;
         self.scroll_handle = gpui::ScrollHandle::new();
         self.scroll_handle.scroll_to_item(0);
+        self.jumped_section = None;
         self.state = TaskDetailState::Error(task_id, error);
         cx.notify();
     }
@@ -86,11 +182,13 @@ impl TaskDetailModal {
         self.is_open = true;
         self.scroll_handle = gpui::ScrollHandle::new();
         self.scroll_handle.scroll_to_item(0);
+        self.jumped_section = None;
         self.state = TaskDetailState::Loading(task_id);
         cx.notify();
     }
 
     pub fn set_detail(&mut self, detail: TaskDetailVm, cx: &mut gpui::Context<Self>) {
+        self.pending_annotation_delete = None;
         self.state = TaskDetailState::Ready(detail);
         cx.notify();
     }
@@ -111,7 +209,7 @@ impl TaskDetailModal {
         cx.notify();
     }
 
-    pub fn scroll(&self, delta: i32, cx: &mut gpui::Context<Self>) {
+    pub fn scroll(&mut self, delta: i32, cx: &mut gpui::Context<Self>) {
         let handle = &self.scroll_handle;
         let current = if delta > 0 {
             handle.bottom_item()
@@ -125,8 +223,140 @@ impl TaskDetailModal {
         };
 
         handle.scroll_to_item(next);
+        self.jumped_section = None;
         cx.notify();
     }
+
+    pub fn jump_to_section(&mut self, section: DetailSection, cx: &mut gpui::Context<Self>) {
+        if !matches!(self.state, TaskDetailState::Ready(_)) {
+            return;
+        }
+
+        self.scroll_handle.scroll_to_item(section.index());
+        self.jumped_section = Some(section);
+        cx.notify();
+    }
+
+    pub fn toggle_history(&mut self, cx: &mut gpui::Context<Self>) {
+        self.history_expanded = !self.history_expanded;
+        cx.notify();
+    }
+
+    pub fn focus_annotation_search(
+        &mut self,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let has_annotations = self
+            .detail()
+            .is_some_and(|detail| !detail.annotations.is_empty());
+        if !has_annotations {
+            return;
+        }
+
+        self.jump_to_section(DetailSection::Annotations, cx);
+        self.annotation_input
+            .update(cx, |input, cx| input.focus(window, cx));
+    }
+
+    fn request_annotation_delete(&mut self, entry: DateTime<Utc>, cx: &mut gpui::Context<Self>) {
+        let Some(task_id) = self.detail().map(|detail| detail.identity.uuid) else {
+            return;
+        };
+
+        if cx.config().confirm_destructive && self.pending_annotation_delete != Some(entry) {
+            self.pending_annotation_delete = Some(entry);
+            cx.notify();
+            return;
+        }
+
+        self.pending_annotation_delete = None;
+        cx.emit(TaskDetailModalEvent::AnnotationDeleted(task_id, entry));
+        cx.notify();
+    }
+
+    fn cancel_annotation_delete(&mut self, cx: &mut gpui::Context<Self>) {
+        self.pending_annotation_delete = None;
+        cx.notify();
+    }
+
+    fn handle_annotation_search_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        cx.stop_propagation();
+
+        if event.keystroke.key == "escape" {
+            if self.annotation_filter.is_empty() {
+                window.focus(&self.focus_handle);
+            } else {
+                self.annotation_input
+                    .update(cx, |input, cx| input.clear(cx));
+            }
+        }
+    }
+
+    fn edit_uda(
+        &mut self,
+        key: &str,
+        value: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.uda_input.update(cx, |input, cx| {
+            input.set_value(format!("{}:{}", key, value), cx);
+            input.focus(window, cx);
+        });
+    }
+
+    fn handle_uda_editor_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        cx.stop_propagation();
+
+        match event.keystroke.key.as_str() {
+            "escape" => {
+                if self.uda_input.read(cx).value().is_empty() {
+                    window.focus(&self.focus_handle);
+                } else {
+                    self.uda_input.update(cx, |input, cx| input.clear(cx));
+                }
+            }
+            "enter" => {
+                let Some(task_id) = self.detail().map(|detail| detail.identity.uuid) else {
+                    return;
+                };
+                let value = self.uda_input.read(cx).value().trim().to_string();
+                if value.is_empty() {
+                    return;
+                }
+
+                match task::parser::parse_uda_assignment(&value) {
+                    Ok((key, value)) => {
+                        self.uda_input.update(cx, |input, cx| input.clear(cx));
+                        cx.emit(TaskDetailModalEvent::UdaChanged(task_id, key, value));
+                    }
+                    Err(e) => {
+                        let toast_host = cx.global::<ToastGlobal>().host.clone();
+                        toast_host.update(cx, |host, cx| {
+                            host.push(ToastKind::Error, e.to_string(), cx);
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn current_section(&self) -> DetailSection {
+        self.jumped_section
+            .unwrap_or_else(|| DetailSection::from_index(self.scroll_handle.top_item()))
+    }
 }
 
 impl gpui::EventEmitter<TaskDetailModalEvent> for TaskDetailModal {}
@@ -146,17 +376,68 @@ impl gpui::Render for TaskDetailModal {
         let on_close_backdrop = cx.listener(|modal, _event: &gpui::MouseDownEvent, _window, cx| {
             modal.close(cx);
         });
-        let on_close_click = cx.listener(|modal, _event: &gpui::MouseDownEvent, _window, cx| {
+        let on_close_click = cx.listener(|modal, _event: &gpui::ClickEvent, _window, cx| {
             modal.close(cx);
         });
+        let on_toggle_history = cx.listener(|modal, _event: &gpui::ClickEvent, _window, cx| {
+            modal.toggle_history(cx);
+        });
+        let annotation_search = gpui::div()
+            .on_key_down(cx.listener(Self::handle_annotation_search_key_down))
+            .child(self.annotation_input.clone())
+            .into_any_element();
+        let modal = cx.entity().downgrade();
+        let on_open_task: OpenTaskHandler = Arc::new(move |uuid, _window, app| {
+            modal
+                .update(app, |_modal, cx| {
+                    cx.emit(TaskDetailModalEvent::OpenTask(uuid));
+                })
+                .ok();
+        });
+        let delete_target = cx.entity().downgrade();
+        let cancel_target = delete_target.clone();
+        let annotation_delete = AnnotationDeleteActions {
+            pending: self.pending_annotation_delete,
+            on_delete: Arc::new(move |entry, _window, app| {
+                delete_target
+                    .update(app, |modal, cx| modal.request_annotation_delete(entry, cx))
+                    .ok();
+            }),
+            on_cancel: Arc::new(move |_window, app| {
+                cancel_target
+                    .update(app, |modal, cx| modal.cancel_annotation_delete(cx))
+                    .ok();
+            }),
+        };
+
+        let edit_target = cx.entity().downgrade();
+        let uda_editor = UdaEditor {
+            input: gpui::div()
+                .on_key_down(cx.listener(Self::handle_uda_editor_key_down))
+                .child(self.uda_input.clone())
+                .into_any_element(),
+            on_edit: Arc::new(move |key, value, window, app| {
+                edit_target
+                    .update(app, |modal, cx| modal.edit_uda(key, value, window, cx))
+                    .ok();
+            }),
+        };
 
         render_task_detail_modal(
             &self.state,
             &self.focus_handle,
             &self.scroll_handle,
+            self.current_section(),
+            self.history_expanded,
+            annotation_search,
+            &self.annotation_filter,
+            annotation_delete,
+            uda_editor,
+            on_open_task,
             theme,
             on_close_backdrop,
             on_close_click,
+            on_toggle_history,
         )
     }
 }
@@ -165,14 +446,33 @@ fn render_task_detail_modal(
     detail_state: &TaskDetailState,
     focus_handle: &gpui::FocusHandle,
     scroll_handle: &gpui::ScrollHandle,
+    current_section: DetailSection,
+    history_expanded: bool,
+    annotation_search: gpui::AnyElement,
+    annotation_filter: &str,
+    annotation_delete: AnnotationDeleteActions,
+    uda_editor: UdaEditor,
+    on_open_task: OpenTaskHandler,
     theme: &Theme,
     on_close_out: impl Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
-    on_close_click: impl Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+    on_close_click: impl Fn(&gpui::ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+    on_toggle_history: impl Fn(&gpui::ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static,
 ) -> gpui::AnyElement {
     let panel = match detail_state {
-        TaskDetailState::Ready(detail) => {
-            render_task_detail_panel(detail, scroll_handle, theme, on_close_click)
-        }
+        TaskDetailState::Ready(detail) => render_task_detail_panel(
+            detail,
+            scroll_handle,
+            current_section,
+            history_expanded,
+            annotation_search,
+            annotation_filter,
+            annotation_delete,
+            uda_editor,
+            on_open_task,
+            theme,
+            on_close_click,
+            on_toggle_history,
+        ),
         TaskDetailState::Error(_, message) => {
             render_task_detail_placeholder_panel("Task Details", message, theme, on_close_click)
         }
@@ -199,7 +499,7 @@ fn render_task_detail_placeholder_panel<OnCloseClick>(
     on_close_click: OnCloseClick,
 ) -> gpui::AnyElement
 where
-    OnCloseClick: Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+    OnCloseClick: Fn(&gpui::ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static,
 {
     let on_close_click = Arc::new(on_close_click);
     let on_close_header = on_close_click.clone();
@@ -211,7 +511,7 @@ where
         .text_color(theme.muted)
         .cursor_pointer()
         .hover(|s| s.bg(theme.hover).text_color(theme.foreground))
-        .on_mouse_down(gpui::MouseButton::Left, move |event, window, app| {
+        .on_click(move |event, window, app| {
             (on_close_header)(event, window, app);
         })
         .child("X");
@@ -249,7 +549,7 @@ where
         .text_color(theme.foreground)
         .cursor_pointer()
         .hover(|s| s.bg(theme.hover))
-        .on_mouse_down(gpui::MouseButton::Left, move |event, window, app| {
+        .on_click(move |event, window, app| {
             (on_close_footer)(event, window, app);
         })
         .child(Label::new("Cancel (Esc)"));
@@ -281,34 +581,170 @@ where
         .into_any_element()
 }
 
-fn render_task_detail_panel<OnCloseClick>(
+fn render_task_detail_panel<OnCloseClick, OnToggleHistory>(
     detail: &task::TaskDetailVm,
     scroll_handle: &gpui::ScrollHandle,
+    current_section: DetailSection,
+    history_expanded: bool,
+    annotation_search: gpui::AnyElement,
+    annotation_filter: &str,
+    annotation_delete: AnnotationDeleteActions,
+    uda_editor: UdaEditor,
+    on_open_task: OpenTaskHandler,
     theme: &Theme,
     on_close_click: OnCloseClick,
+    on_toggle_history: OnToggleHistory,
 ) -> gpui::AnyElement
 where
-    OnCloseClick: Fn(&gpui::MouseDownEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+    OnCloseClick: Fn(&gpui::ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+    OnToggleHistory: Fn(&gpui::ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static,
 {
-    let status_label = if detail.overview.is_active {
+    let title = detail_title(detail);
+
+    let on_close_click = Arc::new(on_close_click);
+    let on_close_header = on_close_click.clone();
+    let close_button = gpui::div()
+        .id("task-detail-close")
+        .px(gpui::rems(0.5))
+        .py(gpui::rems(0.25))
+        .rounded_md()
+        .text_color(theme.muted)
+        .cursor_pointer()
+        .hover(|s| s.bg(theme.hover).text_color(theme.foreground))
+        .on_click(move |event, window, app| {
+            (on_close_header)(event, window, app);
+        })
+        .child("X");
+
+    let header = gpui::div()
+        .flex()
+        .items_start()
+        .justify_between()
+        .gap_4()
+        .px(gpui::rems(1.0))
+        .py(gpui::rems(0.75))
+        .border_b_1()
+        .border_color(theme.divider)
+        .child(
+            gpui::div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .child(
+                    Label::new(title)
+                        .text_color(theme.foreground)
+                        .font_weight(gpui::FontWeight::BOLD),
+                )
+                .child(render_detail_badges(detail, theme))
+                .child(render_section_indicator(current_section, theme)),
+        )
+        .child(close_button);
+
+    let body = gpui::div()
+        .id("task-detail-body")
+        .flex()
+        .flex_col()
+        .flex_1()
+        .min_h_0()
+        .overflow_y_scroll()
+        .track_scroll(scroll_handle)
+        .px(gpui::rems(1.0))
+        .py(gpui::rems(0.75))
+        .gap_4()
+        .children(render_detail_sections(
+            detail,
+            theme,
+            Some(annotation_search),
+            annotation_filter,
+            Some(annotation_delete),
+            Some(uda_editor),
+            Some(on_open_task),
+        ))
+        .child(render_history_section(
+            detail,
+            history_expanded,
+            theme,
+            on_toggle_history,
+        ));
+
+    let on_close_footer = on_close_click.clone();
+    let footer = gpui::div()
+        .flex()
+        .items_center()
+        .justify_end()
+        .px(gpui::rems(1.0))
+        .py(gpui::rems(0.5))
+        .border_t_1()
+        .border_color(theme.divider)
+        .child(
+            gpui::div()
+                .id("task-detail-cancel")
+                .px(gpui::rems(0.75))
+                .py(gpui::rems(0.35))
+                .rounded_md()
+                .border_1()
+                .border_color(theme.divider)
+                .bg(theme.raised)
+                .text_color(theme.foreground)
+                .cursor_pointer()
+                .hover(|s| s.bg(theme.hover))
+                .on_click(move |event, window, app| {
+                    (on_close_footer)(event, window, app);
+                })
+                .child(Label::new("Cancel (Esc)")),
+        );
+
+    gpui::div()
+        .id("task-detail-panel")
+        .flex()
+        .flex_col()
+        .w(gpui::rems(48.0))
+        .h(gpui::rems(40.0))
+        .bg(theme.panel)
+        .border_1()
+        .border_color(theme.border)
+        .rounded_md()
+        .block_mouse_except_scroll()
+        .child(header)
+        .child(body)
+        .child(footer)
+        .into_any_element()
+}
+
+fn detail_status_label(detail: &task::TaskDetailVm) -> String {
+    if detail.overview.is_active {
         "Active".to_string()
     } else {
         detail.overview.status.clone().into()
-    };
+    }
+}
 
-    let priority_label: String = detail.overview.priority.into();
+pub fn detail_title(detail: &task::TaskDetailVm) -> String {
+    let id_label = detail
+        .identity
+        .working_id
+        .or(detail.identity.id)
+        .map(|id| format!("#{}", id))
+        .unwrap_or_else(|| format!("#{}", detail.identity.uuid));
 
-    let chip = |label: &str, bg: gpui::Rgba, fg: gpui::Rgba| {
-        gpui::div()
-            .px(gpui::rems(0.5))
-            .py(gpui::rems(0.125))
-            .rounded(gpui::rems(0.25))
-            .bg(bg)
-            .text_color(fg)
-            .text_xs()
-            .font_weight(gpui::FontWeight::MEDIUM)
-            .child(label.to_string())
-    };
+    format!("{} {}", id_label, detail.overview.description)
+}
+
+fn chip(label: &str, bg: gpui::Rgba, fg: gpui::Rgba) -> gpui::Div {
+    gpui::div()
+        .px(gpui::rems(0.5))
+        .py(gpui::rems(0.125))
+        .rounded(gpui::rems(0.25))
+        .bg(bg)
+        .text_color(fg)
+        .text_xs()
+        .font_weight(gpui::FontWeight::MEDIUM)
+        .child(label.to_string())
+}
+
+pub fn render_detail_badges(detail: &task::TaskDetailVm, theme: &Theme) -> gpui::Div {
+    let status_label = detail_status_label(detail);
+    let priority_label: String = detail.overview.priority.into();
 
     let status_color = match status_label.as_str() {
         "Active" => theme.success,
@@ -348,52 +784,93 @@ where
         ));
     }
 
-    let id_label = detail
-        .identity
-        .working_id
-        .or(detail.identity.id)
-        .map(|id| format!("#{}", id))
-        .unwrap_or_else(|| format!("#{}", detail.identity.uuid));
+    gpui::div().flex().gap_2().children(badges)
+}
+
+fn annotation_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    if query.is_empty() {
+        return ranges;
+    }
 
-    let title = format!("{} {}", id_label, detail.overview.description);
+    let mut start = 0;
+    while let Some(ch) = text[start..].chars().next() {
+        match match_len_at(&text[start..], query) {
+            Some(len) => {
+                ranges.push(start..start + len);
+                start += len;
+            }
+            None => start += ch.len_utf8(),
+        }
+    }
+    ranges
+}
 
-    let on_close_click = Arc::new(on_close_click);
-    let on_close_header = on_close_click.clone();
-    let close_button = gpui::div()
-        .id("task-detail-close")
-        .px(gpui::rems(0.5))
-        .py(gpui::rems(0.25))
-        .rounded_md()
-        .text_color(theme.muted)
-        .cursor_pointer()
-        .hover(|s| s.bg(theme.hover).text_color(theme.foreground))
-        .on_mouse_down(gpui::MouseButton::Left, move |event, window, app| {
-            (on_close_header)(event, window, app);
-        })
-        .child("X");
+fn match_len_at(haystack: &str, query: &str) -> Option<usize> {
+    let mut chars = haystack.char_indices();
+    for expected in query.chars() {
+        let (_, ch) = chars.next()?;
+        if !ch.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(haystack.len(), |(index, _)| index))
+}
 
-    let header = gpui::div()
-        .flex()
-        .items_start()
-        .justify_between()
-        .gap_4()
-        .px(gpui::rems(1.0))
-        .py(gpui::rems(0.75))
-        .border_b_1()
-        .border_color(theme.divider)
-        .child(
+fn render_highlighted_line(
+    line: &str,
+    query: &str,
+    color: gpui::Rgba,
+    theme: &Theme,
+) -> gpui::AnyElement {
+    let ranges = annotation_matches(line, query);
+    if ranges.is_empty() {
+        let text = if line.is_empty() { " " } else { line };
+        return Label::new(text.to_string())
+            .text_sm()
+            .text_color(color)
+            .into_any_element();
+    }
+
+    let mut segments = Vec::new();
+    let mut last = 0;
+    for range in ranges {
+        if range.start > last {
+            segments.push(gpui::div().child(line[last..range.start].to_string()));
+        }
+        segments.push(
             gpui::div()
-                .flex()
-                .flex_col()
-                .gap_2()
-                .child(
-                    Label::new(title)
-                        .text_color(theme.foreground)
-                        .font_weight(gpui::FontWeight::BOLD),
-                )
-                .child(gpui::div().flex().gap_2().children(badges)),
-        )
-        .child(close_button);
+                .rounded_sm()
+                .bg(theme.selection)
+                .child(line[range.clone()].to_string()),
+        );
+        last = range.end;
+    }
+    if last < line.len() {
+        segments.push(gpui::div().child(line[last..].to_string()));
+    }
+
+    gpui::div()
+        .flex()
+        .flex_wrap()
+        .whitespace_nowrap()
+        .text_sm()
+        .text_color(color)
+        .children(segments)
+        .into_any_element()
+}
+
+pub fn render_detail_sections(
+    detail: &task::TaskDetailVm,
+    theme: &Theme,
+    annotation_search: Option<gpui::AnyElement>,
+    annotation_filter: &str,
+    annotation_delete: Option<AnnotationDeleteActions>,
+    uda_editor: Option<UdaEditor>,
+    on_open_task: Option<OpenTaskHandler>,
+) -> Vec<gpui::Div> {
+    let status_label = detail_status_label(detail);
+    let priority_label: String = detail.overview.priority.into();
 
     let label_color = Theme::alpha(theme.foreground, 0.72);
     let value_color = theme.foreground;
@@ -443,11 +920,26 @@ where
         .map(|d| d.format(DATE_FORMAT).to_string())
         .unwrap_or_else(|| "-".to_string());
 
+    let active_text = detail
+        .dates
+        .start
+        .filter(|_| detail.overview.is_active)
+        .map(|start| {
+            let now = Utc::now();
+            match format_relative_date(now, start.min(now)) {
+                elapsed if elapsed == "now" => "just started".to_string(),
+                elapsed => format!("for {}", elapsed),
+            }
+        });
+
     let overview_grid = gpui::div()
         .flex()
         .flex_col()
         .gap_2()
         .child(kv_row("Status", value_label(status_label.clone())))
+        .when_some(active_text, |grid, active| {
+            grid.child(kv_row("Active", value_label(active)))
+        })
         .child(kv_row(
             "Description",
             value_label(detail.overview.description.clone()),
@@ -463,40 +955,57 @@ where
             ),
         ))
         .child(kv_row("Priority", value_label(priority_label.clone())))
-        .child(kv_row("Due", value_label(due_text)));
+        .child(kv_row("Due", value_label(due_text)))
+        .when_some(detail.overview.recur.as_deref(), |grid, recur| {
+            let recurrence = task::Recurrence::parse(recur)
+                .map(|recurrence| recurrence.describe())
+                .unwrap_or_else(|| recur.to_string());
+            grid.child(kv_row("Recurrence", value_label(recurrence)))
+        });
 
     let mut overview_section = section("Overview", overview_grid);
 
     if !detail.dependencies.blocked_by.is_empty() || !detail.dependencies.blocking.is_empty() {
-        let mut info = Vec::new();
+        let mut info = gpui::div()
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .gap_1()
+            .text_sm()
+            .text_color(label_color);
         if !detail.dependencies.blocked_by.is_empty() {
-            info.push(format!(
-                "Blocked by {} task(s)",
-                detail.dependencies.blocked_by.len()
-            ));
+            info = info
+                .child(format!(
+                    "Blocked by {} task(s):",
+                    detail.dependencies.blocked_by.len()
+                ))
+                .children(detail.dependencies.blocked_by.iter().map(|link| {
+                    render_task_link(
+                        "overview-blocked-by",
+                        link,
+                        link_id(link),
+                        on_open_task.as_ref(),
+                        theme,
+                    )
+                }));
         }
         if !detail.dependencies.blocking.is_empty() {
-            info.push(format!(
+            info = info.child(format!(
                 "Blocking {} task(s)",
                 detail.dependencies.blocking.len()
             ));
         }
 
-        overview_section = overview_section.child(
-            gpui::div()
-                .text_sm()
-                .text_color(label_color)
-                .child(info.join(" / ")),
-        );
+        overview_section = overview_section.child(info);
     }
 
     let tags_content = if detail.tags.tags.is_empty() {
         value_label("-".to_string())
     } else {
-        let chips =
-            detail.tags.tags.iter().map(|tag| {
-                chip(tag, Theme::alpha(theme.info, 0.18), theme.info).into_any_element()
-            });
+        let chips = detail.tags.tags.iter().map(|tag| {
+            let color = theme.tag_color(tag);
+            chip(tag, Theme::alpha(color, 0.18), color).into_any_element()
+        });
         gpui::div()
             .flex()
             .gap_2()
@@ -567,23 +1076,22 @@ where
     let meta_section = section("Metadata", meta_grid);
 
     let format_link = |link: &TaskLinkVm| {
-        let id = link
-            .id
-            .map(|id| format!("#{}", id))
-            .unwrap_or_else(|| link.uuid.to_string());
         let status: String = link.status.clone().into();
-        format!("{} {} ({})", id, link.description, status)
+        format!("{} {} ({})", link_id(link), link.description, status)
     };
 
-    let render_links = |links: &[TaskLinkVm]| {
+    let render_links = |id_prefix: &'static str, links: &[TaskLinkVm]| {
         if links.is_empty() {
             value_label("-".to_string())
         } else {
             let items = links.iter().map(|link| {
-                Label::new(format_link(link))
-                    .text_sm()
-                    .text_color(value_color)
-                    .into_any_element()
+                render_task_link(
+                    id_prefix,
+                    link,
+                    format_link(link),
+                    on_open_task.as_ref(),
+                    theme,
+                )
             });
             gpui::div()
                 .flex()
@@ -601,15 +1109,15 @@ where
         .gap_2()
         .child(kv_row(
             "Depends On",
-            render_links(&detail.dependencies.depends_on),
+            render_links("depends-on", &detail.dependencies.depends_on),
         ))
         .child(kv_row(
             "Blocked By",
-            render_links(&detail.dependencies.blocked_by),
+            render_links("blocked-by", &detail.dependencies.blocked_by),
         ))
         .child(kv_row(
             "Blocking",
-            render_links(&detail.dependencies.blocking),
+            render_links("blocking", &detail.dependencies.blocking),
         ));
 
     let deps_section = section("Dependencies", deps_grid);
@@ -625,63 +1133,130 @@ where
                 .child("No annotations"),
         )
     } else {
-        let count = detail.annotations.len();
-        let items = detail
+        let query = annotation_filter.trim();
+        let visible: Vec<_> = detail
             .annotations
             .iter()
-            .enumerate()
-            .map(|(index, annotation)| {
-                let timestamp = annotation.entry.format(DATE_TIME_FORMAT).to_string();
-                let content_for_copy = annotation.content.clone();
-                let copy_action = gpui::div()
+            .filter(|annotation| {
+                query.is_empty() || !annotation_matches(&annotation.content, query).is_empty()
+            })
+            .collect();
+        let count = visible.len();
+        let items = visible.into_iter().enumerate().map(|(index, annotation)| {
+            let timestamp = annotation.entry.format(DATE_TIME_FORMAT).to_string();
+            let content_for_copy = annotation.content.clone();
+            let copy_action = gpui::div()
+                .id(("annotation-copy", index))
+                .text_xs()
+                .text_color(theme.muted)
+                .cursor_pointer()
+                .hover(|s| s.text_color(theme.accent))
+                .on_click(move |_event, _window, app| {
+                    app.write_to_clipboard(gpui::ClipboardItem::new_string(
+                        content_for_copy.clone(),
+                    ));
+                    let toast_host = app.global::<ToastGlobal>().host.clone();
+                    app.update_entity(&toast_host, |host, cx| {
+                        host.push(ToastKind::Info, "Annotation copied", cx);
+                    });
+                })
+                .child(Label::new("Copy"));
+
+            let entry = annotation.entry;
+            let delete_action = annotation_delete.as_ref().map(|actions| {
+                let on_delete = actions.on_delete.clone();
+                let delete = gpui::div()
+                    .id(("annotation-delete", index))
                     .text_xs()
-                    .text_color(theme.muted)
                     .cursor_pointer()
-                    .hover(|s| s.text_color(theme.accent))
-                    .on_mouse_down(gpui::MouseButton::Left, move |_event, _window, app| {
-                        app.write_to_clipboard(gpui::ClipboardItem::new_string(
-                            content_for_copy.clone(),
-                        ));
-                        let toast_host = app.global::<ToastGlobal>().host.clone();
-                        app.update_entity(&toast_host, |host, cx| {
-                            host.push(ToastKind::Info, "Annotation copied", cx);
-                        });
-                    })
-                    .child(Label::new("Copy"));
-
-                let lines = annotation.content.split('\n').map(|line| {
-                    let text = if line.is_empty() { " " } else { line };
-                    Label::new(text.to_string())
-                        .text_sm()
-                        .text_color(value_color)
-                        .into_any_element()
-                });
+                    .on_click(move |_event, window, app| {
+                        (on_delete)(entry, window, app);
+                    });
+
+                if actions.pending != Some(entry) {
+                    return delete
+                        .text_color(theme.muted)
+                        .hover(|s| s.text_color(theme.error))
+                        .child(Label::new("Delete"))
+                        .into_any_element();
+                }
 
-                let mut item = gpui::div()
+                let on_cancel = actions.on_cancel.clone();
+                gpui::div()
                     .flex()
-                    .flex_col()
-                    .gap_1()
-                    .min_w_0()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        delete
+                            .text_color(theme.error)
+                            .font_weight(gpui::FontWeight::BOLD)
+                            .child(Label::new("Confirm delete")),
+                    )
                     .child(
                         gpui::div()
-                            .flex()
-                            .items_center()
-                            .justify_between()
-                            .child(Label::new(timestamp).text_xs().text_color(theme.muted))
-                            .child(copy_action),
+                            .id(("annotation-delete-cancel", index))
+                            .text_xs()
+                            .text_color(theme.muted)
+                            .cursor_pointer()
+                            .hover(|s| s.text_color(theme.foreground))
+                            .on_click(move |_event, window, app| {
+                                (on_cancel)(window, app);
+                            })
+                            .child(Label::new("Cancel")),
                     )
-                    .child(gpui::div().flex().flex_col().gap_1().children(lines));
+                    .into_any_element()
+            });
 
-                if index + 1 < count {
-                    item = item.child(gpui::div().mt_2().h(gpui::px(1.0)).bg(theme.divider));
-                }
+            let lines = annotation
+                .content
+                .split('\n')
+                .map(|line| render_highlighted_line(line, query, value_color, theme));
 
-                item.into_any_element()
-            });
+            let mut item = gpui::div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .min_w_0()
+                .child(
+                    gpui::div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .child(Label::new(timestamp).text_xs().text_color(theme.muted))
+                        .child(
+                            gpui::div()
+                                .flex()
+                                .items_center()
+                                .gap_3()
+                                .child(copy_action)
+                                .children(delete_action),
+                        ),
+                )
+                .child(gpui::div().flex().flex_col().gap_1().children(lines));
+
+            if index + 1 < count {
+                item = item.child(gpui::div().mt_2().h(gpui::px(1.0)).bg(theme.divider));
+            }
+
+            item.into_any_element()
+        });
 
         section(
             "Annotations",
-            gpui::div().flex().flex_col().gap_3().children(items),
+            gpui::div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .children(annotation_search)
+                .children(items)
+                .when(count == 0, |list| {
+                    list.child(
+                        gpui::div()
+                            .text_sm()
+                            .text_color(theme.muted)
+                            .child("No matching annotations"),
+                    )
+                }),
         )
     };
     sections.push(annotations_section);
@@ -689,71 +1264,192 @@ where
     sections.push(dates_section);
     sections.push(meta_section);
 
-    if !detail.udas.is_empty() {
-        let rows = detail
-            .udas
-            .iter()
-            .map(|(key, value)| kv_row(key, value_label(value.clone())).into_any_element());
+    if !detail.udas.is_empty() || uda_editor.is_some() {
+        let (uda_input, on_edit) = match uda_editor {
+            Some(editor) => (Some(editor.input), Some(editor.on_edit)),
+            None => (None, None),
+        };
+        let rows = detail.udas.iter().map(|(key, value)| {
+            let Some(on_edit) = on_edit.clone() else {
+                return kv_row(key, value_label(value.clone())).into_any_element();
+            };
+            let (edit_key, edit_value) = (key.clone(), value.clone());
+            gpui::div()
+                .id(gpui::SharedString::from(format!("uda-{}", key)))
+                .cursor_pointer()
+                .rounded_sm()
+                .hover(|s| s.bg(theme.hover))
+                .on_click(move |_event, window, app| {
+                    (on_edit)(&edit_key, &edit_value, window, app);
+                })
+                .child(kv_row(key, value_label(value.clone())))
+                .into_any_element()
+        });
         let udas_section = section(
             "Extras",
-            gpui::div().flex().flex_col().gap_2().children(rows),
+            gpui::div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .children(rows)
+                .children(uda_input),
         );
         sections.push(udas_section);
     }
 
-    let body = gpui::div()
-        .id("task-detail-body")
-        .flex()
-        .flex_col()
-        .flex_1()
-        .min_h_0()
-        .overflow_y_scroll()
-        .track_scroll(scroll_handle)
-        .px(gpui::rems(1.0))
-        .py(gpui::rems(0.75))
-        .gap_4()
-        .children(sections);
+    sections
+}
 
-    let on_close_footer = on_close_click.clone();
-    let footer = gpui::div()
+fn link_id(link: &TaskLinkVm) -> String {
+    link.id
+        .map(|id| format!("#{}", id))
+        .unwrap_or_else(|| link.uuid.to_string())
+}
+
+fn render_task_link(
+    id_prefix: &'static str,
+    link: &TaskLinkVm,
+    text: String,
+    on_open_task: Option<&OpenTaskHandler>,
+    theme: &Theme,
+) -> gpui::AnyElement {
+    let Some(on_open_task) = on_open_task.cloned() else {
+        return Label::new(text)
+            .text_sm()
+            .text_color(theme.foreground)
+            .into_any_element();
+    };
+
+    let uuid = link.uuid;
+    gpui::div()
+        .id(gpui::SharedString::from(format!("{}-{}", id_prefix, uuid)))
+        .text_sm()
+        .text_color(theme.accent)
+        .cursor_pointer()
+        .hover(|s| s.underline())
+        .on_click(move |_event, window, app| {
+            (on_open_task)(uuid, window, app);
+        })
+        .child(text)
+        .into_any_element()
+}
+
+fn render_history_section(
+    detail: &task::TaskDetailVm,
+    expanded: bool,
+    theme: &Theme,
+    on_toggle: impl Fn(&gpui::ClickEvent, &mut gpui::Window, &mut gpui::App) + 'static,
+) -> gpui::Div {
+    let arrow = if expanded { "▾" } else { "▸" };
+    let header = gpui::div()
+        .id("task-detail-history-toggle")
         .flex()
         .items_center()
-        .justify_end()
-        .px(gpui::rems(1.0))
-        .py(gpui::rems(0.5))
-        .border_t_1()
-        .border_color(theme.divider)
+        .gap_2()
+        .cursor_pointer()
+        .on_click(on_toggle)
+        .child(Label::new(arrow).text_sm().text_color(theme.muted))
         .child(
-            gpui::div()
-                .id("task-detail-cancel")
-                .px(gpui::rems(0.75))
-                .py(gpui::rems(0.35))
-                .rounded_md()
-                .border_1()
-                .border_color(theme.divider)
-                .bg(theme.raised)
-                .text_color(theme.foreground)
-                .cursor_pointer()
-                .hover(|s| s.bg(theme.hover))
-                .on_mouse_down(gpui::MouseButton::Left, move |event, window, app| {
-                    (on_close_footer)(event, window, app);
-                })
-                .child(Label::new("Cancel (Esc)")),
+            Label::new(format!("HISTORY ({})", detail.history.len()))
+                .text_sm()
+                .text_color(Theme::alpha(theme.foreground, 0.88))
+                .font_weight(gpui::FontWeight::BOLD),
         );
 
+    let format_value = |value: &Option<String>| match value {
+        Some(value) if !value.is_empty() => value.clone(),
+        _ => "-".to_string(),
+    };
+
+    let entries = detail.history.iter().map(|entry| {
+        let show_values = entry.old_value.is_some() || entry.new_value.is_some();
+        gpui::div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .min_w_0()
+            .child(
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        Label::new(entry.timestamp.format(DATE_TIME_FORMAT).to_string())
+                            .text_xs()
+                            .text_color(theme.muted),
+                    )
+                    .child(
+                        Label::new(entry.operation.clone())
+                            .text_sm()
+                            .text_color(theme.foreground),
+                    ),
+            )
+            .when(show_values, |item| {
+                item.child(
+                    Label::new(format!(
+                        "{} → {}",
+                        format_value(&entry.old_value),
+                        format_value(&entry.new_value)
+                    ))
+                    .text_xs()
+                    .text_color(Theme::alpha(theme.foreground, 0.72)),
+                )
+            })
+    });
+
     gpui::div()
-        .id("task-detail-panel")
         .flex()
         .flex_col()
-        .w(gpui::rems(48.0))
-        .h(gpui::rems(40.0))
-        .bg(theme.panel)
+        .gap_2()
+        .bg(theme.raised)
         .border_1()
-        .border_color(theme.border)
+        .border_color(theme.divider)
         .rounded_md()
-        .block_mouse_except_scroll()
+        .px(gpui::rems(0.75))
+        .py(gpui::rems(0.5))
         .child(header)
-        .child(body)
-        .child(footer)
-        .into_any_element()
+        .when(expanded && detail.history.is_empty(), |section| {
+            section.child(
+                gpui::div()
+                    .text_sm()
+                    .text_color(theme.muted)
+                    .child("No recorded operations"),
+            )
+        })
+        .when(expanded, |section| section.children(entries))
+}
+
+fn render_section_indicator(current: DetailSection, theme: &Theme) -> gpui::Div {
+    let items = DetailSection::ALL.iter().map(|section| {
+        let is_current = *section == current;
+        gpui::div()
+            .px(gpui::rems(0.375))
+            .rounded(gpui::rems(0.25))
+            .text_xs()
+            .when(is_current, |el| {
+                el.bg(Theme::alpha(theme.accent, 0.15))
+                    .text_color(theme.accent)
+            })
+            .when(!is_current, |el| el.text_color(theme.muted))
+            .child(format!("{} {}", section.index() + 1, section.title()))
+    });
+
+    gpui::div().flex().items_center().gap_1().children(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotation_matches_are_case_insensitive() {
+        assert_eq!(
+            annotation_matches("Call Bob, then call Alice", "call"),
+            vec![0..4, 15..19]
+        );
+        assert_eq!(annotation_matches("Café crème", "CRÈME"), vec![6..12]);
+        assert_eq!(annotation_matches("aaa", "aa"), vec![0..2]);
+        assert!(annotation_matches("notes", "").is_empty());
+        assert!(annotation_matches("notes", "x").is_empty());
+    }
 }
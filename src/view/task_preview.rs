@@ -0,0 +1,174 @@
+use gpui::prelude::*;
+
+use crate::components::label::Label;
+use crate::task::TaskDetailVm;
+use crate::theme::ActiveTheme;
+use crate::ui::{
+    CARD_RADIUS, PREVIEW_PANE_MAX_WIDTH, PREVIEW_PANE_MIN_WIDTH, PREVIEW_PANE_WIDTH,
+    RESIZE_HANDLE_WIDTH,
+};
+use crate::view::task_detail_modal::{detail_title, render_detail_badges, render_detail_sections};
+
+#[derive(Debug, Clone, Copy)]
+struct ResizePreviewPane;
+
+pub struct TaskPreviewPane {
+    is_open: bool,
+    width: gpui::Pixels,
+    detail: Option<TaskDetailVm>,
+    error: Option<String>,
+    scroll_handle: gpui::ScrollHandle,
+}
+
+impl TaskPreviewPane {
+    pub fn new(_cx: &mut gpui::Context<Self>) -> Self {
+        Self {
+            is_open: false,
+            width: PREVIEW_PANE_WIDTH,
+            detail: None,
+            error: None,
+            scroll_handle: gpui::ScrollHandle::new(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn toggle(&mut self, cx: &mut gpui::Context<Self>) {
+        self.is_open = !self.is_open;
+        if !self.is_open {
+            self.detail = None;
+            self.error = None;
+        }
+        cx.notify();
+    }
+
+    pub fn task_uuid(&self) -> Option<uuid::Uuid> {
+        self.detail.as_ref().map(|detail| detail.identity.uuid)
+    }
+
+    pub fn set_detail(&mut self, detail: TaskDetailVm, cx: &mut gpui::Context<Self>) {
+        if self.task_uuid() != Some(detail.identity.uuid) {
+            self.scroll_handle = gpui::ScrollHandle::new();
+        }
+        self.detail = Some(detail);
+        self.error = None;
+        cx.notify();
+    }
+
+    pub fn set_error(&mut self, error: String, cx: &mut gpui::Context<Self>) {
+        self.detail = None;
+        self.error = Some(error);
+        cx.notify();
+    }
+
+    pub fn clear(&mut self, cx: &mut gpui::Context<Self>) {
+        self.detail = None;
+        self.error = None;
+        cx.notify();
+    }
+
+    fn set_width(&mut self, width: gpui::Pixels, cx: &mut gpui::Context<Self>) {
+        let width = width.clamp(PREVIEW_PANE_MIN_WIDTH, PREVIEW_PANE_MAX_WIDTH);
+        if width != self.width {
+            self.width = width;
+            cx.notify();
+        }
+    }
+}
+
+impl gpui::Render for TaskPreviewPane {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let theme = cx.theme();
+
+        let handle = gpui::div()
+            .id("task-preview-resize")
+            .w(RESIZE_HANDLE_WIDTH)
+            .h_full()
+            .flex_shrink_0()
+            .cursor_col_resize()
+            .hover(|s| s.bg(theme.hover))
+            .on_drag(ResizePreviewPane, |_handle, _offset, _window, cx| {
+                cx.new(|_cx| gpui::EmptyView)
+            });
+
+        let content = match (&self.detail, &self.error) {
+            (Some(detail), _) => gpui::div()
+                .flex()
+                .flex_col()
+                .size_full()
+                .child(
+                    gpui::div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .px(gpui::rems(0.75))
+                        .py(gpui::rems(0.5))
+                        .border_b_1()
+                        .border_color(theme.divider)
+                        .child(
+                            Label::new(detail_title(detail))
+                                .text_color(theme.foreground)
+                                .font_weight(gpui::FontWeight::BOLD),
+                        )
+                        .child(render_detail_badges(detail, theme)),
+                )
+                .child(
+                    gpui::div()
+                        .id("task-preview-body")
+                        .flex()
+                        .flex_col()
+                        .flex_1()
+                        .min_h_0()
+                        .overflow_y_scroll()
+                        .track_scroll(&self.scroll_handle)
+                        .p(gpui::rems(0.75))
+                        .gap_3()
+                        .children(render_detail_sections(
+                            detail, theme, None, "", None, None, None,
+                        )),
+                ),
+            (None, error) => gpui::div()
+                .flex()
+                .size_full()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(theme.muted)
+                .child(
+                    error
+                        .clone()
+                        .unwrap_or_else(|| "Select a task to preview it".to_string()),
+                ),
+        };
+
+        gpui::div()
+            .flex()
+            .h_full()
+            .w(self.width)
+            .flex_shrink_0()
+            .on_drag_move(cx.listener(
+                |pane, event: &gpui::DragMoveEvent<ResizePreviewPane>, _window, cx| {
+                    pane.set_width(event.bounds.right() - event.event.position.x, cx);
+                },
+            ))
+            .child(handle)
+            .child(
+                gpui::div()
+                    .flex_1()
+                    .min_w_0()
+                    .h_full()
+                    .bg(theme.card)
+                    .border_2()
+                    .border_color(theme.divider)
+                    .rounded(CARD_RADIUS)
+                    .overflow_hidden()
+                    .child(content),
+            )
+    }
+}
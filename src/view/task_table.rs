@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
-use std::collections::BTreeSet;
-use std::sync::Arc;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 
 use gpui::prelude::*;
 
@@ -8,108 +9,187 @@ use crate::{
     components::{
         self,
         button::{Dropdown, DropdownItem},
-        input::Input,
+        context_menu::{ContextMenu, ContextMenuEvent, ContextMenuItem},
+        input::{Input, Suggestion},
+        toast::{ToastGlobal, ToastKind},
+        tooltip::Tooltip,
+    },
+    config::{ActiveConfig, AppConfig, TableConfig, TableDensity},
+    keymap::{Command, CommandDispatcher, Key},
+    models::{
+        DueFilter, FilterChip, FilterState, INBOX_PROJECT, PriorityFilter, SortColumn,
+        SortDirection, SortState, StatusFilter,
     },
-    keymap::{Command, CommandDispatcher},
-    models::{DueFilter, FilterState, PriorityFilter, StatusFilter},
     task::{self, TaskFilter, TaskService, TaskSummary},
-    theme::{self, ActiveTheme},
+    theme::{self, ActiveTheme, Theme},
     ui::{
-        DATE_FORMAT, TABLE_FILTER_BAR_INITIAL_HEIGHT, TABLE_MAX_DESCRIPTION_LENGTH, priority_badge,
-        table_col_desc_min_width, table_col_due_width, table_col_id_width,
-        table_col_priority_width, table_col_project_width, table_col_status_width,
+        DATE_FORMAT, DATE_PREVIEW_FORMAT, DATE_TIME_FORMAT, SIDEBAR_COMPLETION_BAR_HEIGHT,
+        TABLE_DATE_REFRESH_INTERVAL, TABLE_FILTER_BAR_INITIAL_HEIGHT, TABLE_MAX_INLINE_TAGS,
+        TABLE_PAGE_SIZE, TABLE_SEARCH_HISTORY_CAPACITY, format_relative_date, mix_color,
+        priority_badge, table_col_desc_min_width, table_col_due_width, table_col_entry_width,
+        table_col_id_width, table_col_menu_width, table_col_modified_width,
+        table_col_priority_width, table_col_project_width, table_col_scheduled_width,
+        table_col_status_width, table_col_uda_width, table_col_urgency_width, table_min_width,
+        table_pinned_width, tag_chip,
     },
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SortColumn {
-    Id,
-    Description,
-    Project,
-    Due,
-    Priority,
-    Status,
-}
-
 impl SortColumn {
-    const COLUMN_ORDER: [Self; 6] = [
-        Self::Id,
-        Self::Description,
-        Self::Project,
-        Self::Due,
-        Self::Priority,
-        Self::Status,
-    ];
-
-    pub fn label(&self) -> &'static str {
+    fn is_pinned(&self) -> bool {
+        matches!(self, SortColumn::Id | SortColumn::Description)
+    }
+
+    fn header_id(&self) -> &'static str {
         match self {
-            SortColumn::Id => "ID",
-            SortColumn::Description => "Description",
-            SortColumn::Project => "Project",
-            SortColumn::Due => "Due",
-            SortColumn::Priority => "Priority",
-            SortColumn::Status => "Status",
+            SortColumn::Id => "header-id",
+            SortColumn::Description => "header-desc",
+            SortColumn::Project => "header-project",
+            SortColumn::Due => "header-due",
+            SortColumn::Scheduled => "header-scheduled",
+            SortColumn::EntryDate => "header-entry",
+            SortColumn::ModifiedDate => "header-modified",
+            SortColumn::Priority => "header-priority",
+            SortColumn::Urgency => "header-urgency",
+            SortColumn::Status => "header-status",
         }
     }
 
-    fn next(self) -> Self {
-        let idx = Self::COLUMN_ORDER
-            .iter()
-            .position(|&c| c == self)
-            .unwrap_or(0);
-        Self::COLUMN_ORDER[(idx + 1) % Self::COLUMN_ORDER.len()]
+    fn next(self, columns: &[Self]) -> Self {
+        let Some(idx) = columns.iter().position(|&c| c == self) else {
+            return columns.first().copied().unwrap_or(self);
+        };
+        columns[(idx + 1) % columns.len()]
     }
 
-    fn prev(self) -> Self {
-        let idx = Self::COLUMN_ORDER
-            .iter()
-            .position(|&c| c == self)
-            .unwrap_or(0);
-        Self::COLUMN_ORDER[(idx + Self::COLUMN_ORDER.len() - 1) % Self::COLUMN_ORDER.len()]
+    fn prev(self, columns: &[Self]) -> Self {
+        let Some(idx) = columns.iter().position(|&c| c == self) else {
+            return columns.last().copied().unwrap_or(self);
+        };
+        columns[(idx + columns.len() - 1) % columns.len()]
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SortDirection {
-    Asc,
-    Desc,
-}
+const MAX_SORT_LEVELS: usize = 3;
+
+impl SortState {
+    fn compare(&self, a: &task::TaskSummary, b: &task::TaskSummary) -> Ordering {
+        let ordering = match self.column {
+            SortColumn::Id => a.working_id.unwrap_or(0).cmp(&b.working_id.unwrap_or(0)),
+            SortColumn::Description => a
+                .description
+                .cmp(&b.description)
+                .then_with(|| a.annotation_count.cmp(&b.annotation_count)),
+            SortColumn::Project => {
+                let a_proj = a.project.as_deref().unwrap_or("");
+                let b_proj = b.project.as_deref().unwrap_or("");
+                a_proj.cmp(b_proj)
+            }
+            SortColumn::Due => compare_dates(&a.due, &b.due),
+            SortColumn::Scheduled => compare_dates(&a.scheduled, &b.scheduled),
+            SortColumn::EntryDate => compare_dates(&a.entry, &b.entry),
+            SortColumn::ModifiedDate => compare_dates(&a.modified, &b.modified),
+            SortColumn::Priority => {
+                let a_order: usize = a.priority.into();
+                let b_order: usize = b.priority.into();
+                a_order.cmp(&b_order)
+            }
+            SortColumn::Urgency => a.urgency.total_cmp(&b.urgency),
+            SortColumn::Status => {
+                let a_status: String = a.status.clone().into();
+                let b_status: String = b.status.clone().into();
+                a_status.cmp(&b_status)
+            }
+        };
 
-impl SortDirection {
-    pub fn toggle(&self) -> Self {
-        match self {
-            SortDirection::Asc => SortDirection::Desc,
-            SortDirection::Desc => SortDirection::Asc,
+        match self.direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
         }
     }
+}
 
-    pub fn arrow(&self) -> &'static str {
-        match self {
-            SortDirection::Asc => "↑",
-            SortDirection::Desc => "↓",
-        }
+fn compare_dates<T: Ord>(a: &Option<T>, b: &Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
     }
 }
 
-impl Default for SortDirection {
-    fn default() -> Self {
-        Self::Desc
-    }
+fn sort_tasks(tasks: &mut [task::TaskSummary], levels: &[SortState], pinned: &HashSet<uuid::Uuid>) {
+    tasks.sort_by(|a, b| {
+        pinned
+            .contains(&b.uuid)
+            .cmp(&pinned.contains(&a.uuid))
+            .then_with(|| {
+                levels.iter().fold(Ordering::Equal, |ordering, level| {
+                    ordering.then_with(|| level.compare(a, b))
+                })
+            })
+            .then_with(|| compare_dates(&a.due, &b.due))
+            .then_with(|| a.uuid.cmp(&b.uuid))
+    });
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct SortState {
-    pub column: SortColumn,
-    pub direction: SortDirection,
+fn restore_selection(
+    tasks: &[task::TaskSummary],
+    selected: Option<uuid::Uuid>,
+    previous_idx: Option<usize>,
+    previous_uuids: &HashSet<uuid::Uuid>,
+) -> Option<usize> {
+    let last_idx = tasks.len().checked_sub(1)?;
+
+    if let Some(idx) =
+        selected.and_then(|selected| tasks.iter().position(|task| task.uuid == selected))
+    {
+        return Some(idx);
+    }
+
+    let list_kept = tasks.iter().any(|task| previous_uuids.contains(&task.uuid));
+    match previous_idx {
+        Some(idx) if list_kept => Some(idx.min(last_idx)),
+        _ => Some(0),
+    }
 }
 
-impl Default for SortState {
-    fn default() -> Self {
-        Self {
-            column: SortColumn::Priority,
+fn toggle_sort_level(levels: &mut Vec<SortState>, column: SortColumn) {
+    if let Some(level) = levels.iter_mut().find(|level| level.column == column) {
+        level.direction = level.direction.toggle();
+        return;
+    }
+
+    levels.insert(
+        0,
+        SortState {
+            column,
             direction: SortDirection::Desc,
+        },
+    );
+    levels.truncate(MAX_SORT_LEVELS);
+}
+
+fn set_secondary_sort_level(levels: &mut Vec<SortState>, column: SortColumn) {
+    match levels.iter().position(|level| level.column == column) {
+        Some(0) => return,
+        Some(1) => {
+            levels[1].direction = levels[1].direction.toggle();
+            return;
+        }
+        Some(index) => {
+            levels.remove(index);
         }
+        None => {}
     }
+
+    levels.insert(
+        1.min(levels.len()),
+        SortState {
+            column,
+            direction: SortDirection::Asc,
+        },
+    );
+    levels.truncate(MAX_SORT_LEVELS);
 }
 
 pub struct PaginationState {
@@ -122,7 +202,7 @@ impl Default for PaginationState {
     fn default() -> Self {
         Self {
             current_page: 1,
-            page_size: 20,
+            page_size: TABLE_PAGE_SIZE,
             total_items: 0,
         }
     }
@@ -189,57 +269,129 @@ pub struct TaskRow {
     pub id_display: String,
     pub description: String,
     pub project: String,
+    pub tags: Vec<String>,
+    pub annotation_count: usize,
     pub due: String,
+    pub due_absolute: String,
+    pub wait: Option<String>,
+    pub wait_absolute: String,
+    pub scheduled: String,
+    pub scheduled_absolute: String,
+    pub entry: String,
+    pub entry_absolute: String,
+    pub modified: String,
+    pub modified_absolute: String,
     pub priority: String,
+    pub urgency: String,
     pub status: String,
     pub is_due_today: bool,
+    pub is_due_soon: bool,
     pub is_overdue: bool,
     pub is_active: bool,
+    pub is_blocked: bool,
+    pub is_blocking: bool,
+    pub scheduled_future: bool,
+    pub is_pinned: bool,
+    pub udas: HashMap<String, String>,
 }
 
 impl TaskRow {
-    fn truncate(desc: &str, max_len: usize) -> String {
-        if desc.len() <= max_len {
-            desc.to_string()
-        } else {
-            format!("{}...", &desc[..max_len - 3])
+    fn format_date(date: &Option<chrono::DateTime<chrono::Utc>>, relative: bool) -> String {
+        match date {
+            None => "-".to_string(),
+            Some(dt) if relative => format_relative_date(*dt, chrono::Utc::now()),
+            Some(dt) => dt.format(DATE_FORMAT).to_string(),
         }
     }
 
-    fn format_date(due: &Option<chrono::DateTime<chrono::Utc>>, is_today: bool) -> String {
-        match due {
-            None => "-".to_string(),
-            Some(dt) => {
-                if is_today {
-                    "Today".to_string()
-                } else {
-                    dt.format(DATE_FORMAT).to_string()
-                }
-            }
-        }
+    fn format_absolute_date(date: &Option<chrono::DateTime<chrono::Utc>>) -> String {
+        date.map(|dt| dt.format(DATE_TIME_FORMAT).to_string())
+            .unwrap_or_default()
     }
-}
 
-impl From<&task::TaskSummary> for TaskRow {
-    fn from(value: &task::TaskSummary) -> Self {
+    fn from_summary(
+        value: &task::TaskSummary,
+        relative_dates: bool,
+        due_soon: chrono::Duration,
+    ) -> Self {
         let status = if value.is_active {
             "Active".to_string()
         } else {
             value.status.clone().into()
         };
+        let mut tags: Vec<String> = value.tags.iter().cloned().collect();
+        tags.sort();
 
         Self {
             uuid: value.uuid,
             id_display: value.working_id.unwrap_or(0).to_string(),
-            description: Self::truncate(&value.description, TABLE_MAX_DESCRIPTION_LENGTH),
+            description: value.description.clone(),
             project: value.project.clone().unwrap_or(String::new()),
-            due: Self::format_date(&value.due, value.is_due_today()),
+            tags,
+            annotation_count: value.annotation_count,
+            due: Self::format_date(&value.due, relative_dates),
+            due_absolute: Self::format_absolute_date(&value.due),
+            wait: value
+                .is_waiting()
+                .then(|| format!("wait {}", Self::format_date(&value.wait, relative_dates))),
+            wait_absolute: Self::format_absolute_date(&value.wait),
+            scheduled: Self::format_date(&value.scheduled, relative_dates),
+            scheduled_absolute: Self::format_absolute_date(&value.scheduled),
+            entry: Self::format_date(&value.entry, relative_dates),
+            entry_absolute: Self::format_absolute_date(&value.entry),
+            modified: Self::format_date(&value.modified, relative_dates),
+            modified_absolute: Self::format_absolute_date(&value.modified),
             priority: value.priority.into(),
+            urgency: format!("{:.1}", value.urgency),
             status,
             is_due_today: value.is_due_today(),
+            is_due_soon: value.is_due_soon(due_soon),
             is_overdue: value.is_overdue(),
             is_active: value.is_active,
+            is_blocked: value.is_blocked,
+            is_blocking: value.is_blocking,
+            scheduled_future: value.is_scheduled_future(),
+            is_pinned: false,
+            udas: value.udas.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ProjectProgress {
+    project: String,
+    pending: usize,
+    completed: usize,
+}
+
+impl ProjectProgress {
+    fn from_tasks(project: &str, tasks: &[task::TaskSummary], include_children: bool) -> Self {
+        let prefix = format!("{}.", project);
+        let mut progress = Self {
+            project: project.to_string(),
+            pending: 0,
+            completed: 0,
+        };
+
+        for (name, pending, completed) in task::TaskSummary::project_counts(tasks) {
+            if name == project || (include_children && name.starts_with(&prefix)) {
+                progress.pending += pending;
+                progress.completed += completed;
+            }
+        }
+
+        progress
+    }
+
+    fn total(&self) -> usize {
+        self.pending + self.completed
+    }
+
+    fn ratio(&self) -> f32 {
+        if self.total() == 0 {
+            return 0.0;
         }
+        self.completed as f32 / self.total() as f32
     }
 }
 
@@ -250,6 +402,7 @@ pub enum FilterBarFocus {
     StatusDropdown,
     PriorityDropdown,
     DueDropdown,
+    Chip(usize),
 }
 
 impl Default for FilterBarFocus {
@@ -258,17 +411,84 @@ impl Default for FilterBarFocus {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskPromptKind {
+    QuickAdd,
+    Annotation,
+    Project,
+    Due,
+    Snooze,
+    Recurrence,
+    Until,
+    Dependencies,
+    BulkProject,
+    BulkTag,
+    Export,
+    Import,
+    RenameProject,
+}
+
+impl TaskPromptKind {
+    fn label(&self) -> &'static str {
+        match self {
+            TaskPromptKind::QuickAdd => "Add",
+            TaskPromptKind::Annotation => "Annotate",
+            TaskPromptKind::Project => "Project",
+            TaskPromptKind::Due => "Due",
+            TaskPromptKind::Snooze => "Snooze for",
+            TaskPromptKind::Recurrence => "Repeat",
+            TaskPromptKind::Until => "Until",
+            TaskPromptKind::Dependencies => "Depends on",
+            TaskPromptKind::BulkProject => "Set project",
+            TaskPromptKind::BulkTag => "Add tag",
+            TaskPromptKind::Export => "Export to",
+            TaskPromptKind::Import => "Import from",
+            TaskPromptKind::RenameProject => "Rename to",
+        }
+    }
+
+    fn is_bulk(&self) -> bool {
+        matches!(self, TaskPromptKind::BulkProject | TaskPromptKind::BulkTag)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TaskPrompt {
+    kind: TaskPromptKind,
+    uuid: Option<uuid::Uuid>,
+}
+
+struct FilterDropdowns {
+    status_index: usize,
+    priority_index: usize,
+    due_items: Vec<DropdownItem>,
+    due_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkAction {
+    Complete,
+    Delete,
+    SetProject(Option<String>),
+    AddTag(String),
+}
+
 pub struct TaskTable {
     id: gpui::ElementId,
     filter_state: gpui::Entity<FilterState>,
     cached_tasks: Vec<task::TaskSummary>,
     cached_rows: Vec<TaskRow>,
-    sort_state: SortState,
+    sort_levels: Vec<SortState>,
+    preset_sort: Option<(task::FilterPreset, Vec<SortState>)>,
     pagination: PaginationState,
     selected_page_idx: Option<usize>,
     selected_global_idx: Option<usize>,
     need_reload: bool,
+    project_progress: Option<ProjectProgress>,
     filter_bar_height: gpui::Pixels,
+    row_bounds: Vec<gpui::Bounds<gpui::Pixels>>,
+    horizontal_scroll: gpui::ScrollHandle,
+    filter_summary: String,
     search_input: gpui::Entity<Input>,
     status_dropdown: gpui::Entity<Dropdown>,
     priority_dropdown: gpui::Entity<Dropdown>,
@@ -277,6 +497,29 @@ pub struct TaskTable {
     filter_bar_focus_handle: gpui::FocusHandle,
     focused_header: Option<SortColumn>,
     header_focus_handle: gpui::FocusHandle,
+    sort_picker: Option<usize>,
+    sort_picker_focus_handle: gpui::FocusHandle,
+    jump_input: gpui::Entity<Input>,
+    jump_open: bool,
+    columns_menu_open: bool,
+    context_menu: gpui::Entity<ContextMenu>,
+    prompt_input: gpui::Entity<Input>,
+    project_input: gpui::Entity<Input>,
+    due_input: gpui::Entity<Input>,
+    add_input: gpui::Entity<Input>,
+    recurrence_dropdown: gpui::Entity<Dropdown>,
+    recurrence_input: gpui::Entity<Input>,
+    prompt: Option<TaskPrompt>,
+    expanded_task: Option<uuid::Uuid>,
+    emitted_selection: Option<uuid::Uuid>,
+    marked_tasks: HashSet<uuid::Uuid>,
+    bulk_busy: bool,
+    known_projects: Arc<RwLock<Vec<String>>>,
+    known_tags: Arc<RwLock<Vec<String>>>,
+    last_export_path: String,
+    last_import_path: String,
+    project_rename: Option<(String, usize)>,
+    _date_refresh_task: gpui::Task<()>,
 }
 
 impl TaskTable {
@@ -288,16 +531,140 @@ impl TaskTable {
         let search_input = {
             let filter_state = filter_state.clone();
             cx.new(|cx| {
-                Input::new("filter-search", cx, "Search...").with_on_change(Arc::new(
-                    move |value: &str, cx: &mut gpui::Context<Input>| {
-                        cx.update_entity(&filter_state, |filter, cx| {
-                            filter.search_text = value.to_string();
-                            cx.notify();
-                        });
+                Input::new("filter-search", cx, "Search...")
+                    .with_history(TABLE_SEARCH_HISTORY_CAPACITY)
+                    .with_suggest(Arc::new(|value: &str, cursor: usize| {
+                        task::parser::operator_completions(value, cursor)
+                            .into_iter()
+                            .map(|completion| {
+                                Suggestion::new(completion.label, completion.insert)
+                                    .with_replace(completion.range)
+                            })
+                            .collect()
+                    }))
+                    .with_on_change(Arc::new(
+                        move |value: &str, cx: &mut gpui::Context<Input>| {
+                            cx.update_entity(&filter_state, |filter, cx| {
+                                filter.search_text = value.to_string();
+                                cx.notify();
+                            });
+                        },
+                    ))
+            })
+        };
+
+        let jump_input = cx.new(|cx| Input::new("jump-to-id", cx, "Task ID..."));
+        let prompt_input = cx.new(|cx| Input::new("task-prompt", cx, ""));
+        let known_projects: Arc<RwLock<Vec<String>>> = Arc::default();
+        let project_input = {
+            let known_projects = known_projects.clone();
+            cx.new(|cx| {
+                Input::new("task-project-prompt", cx, "Project...").with_suggest(Arc::new(
+                    move |value: &str, _cursor: usize| {
+                        let prefix = value.trim().to_lowercase();
+                        let Ok(projects) = known_projects.read() else {
+                            return Vec::new();
+                        };
+                        projects
+                            .iter()
+                            .filter(|project| {
+                                project.to_lowercase().starts_with(&prefix)
+                                    && project.as_str() != value.trim()
+                            })
+                            .map(|project| Suggestion::simple(project.clone()))
+                            .collect()
                     },
                 ))
             })
         };
+        let due_input = cx.new(|cx| {
+            Input::new(
+                "task-due-prompt",
+                cx,
+                "tomorrow, friday, eom, +3d, 2026-07-01",
+            )
+            .with_suggest(Arc::new(|value: &str, _cursor: usize| {
+                let prefix = value.trim().to_lowercase();
+                if prefix.is_empty() {
+                    return Vec::new();
+                }
+                task::date_expr::DATE_KEYWORDS
+                    .iter()
+                    .filter(|keyword| keyword.starts_with(&prefix) && **keyword != prefix)
+                    .map(|keyword| Suggestion::simple(*keyword))
+                    .collect()
+            }))
+        });
+        cx.observe(&due_input, |_table, _input, cx| cx.notify())
+            .detach();
+        let recurrence_input = cx.new(|cx| Input::new("task-recurrence-prompt", cx, "1"));
+        cx.observe(&recurrence_input, |_table, _input, cx| cx.notify())
+            .detach();
+        let recurrence_dropdown = cx.new(|_cx| {
+            Dropdown::new("task-recurrence-frequency")
+                .items(std::iter::once(DropdownItem::with_value("None", "")).chain(
+                    task::RecurrenceFrequency::ALL.iter().map(|frequency| {
+                        DropdownItem::with_value(frequency.label(), frequency.as_str())
+                    }),
+                ))
+                .selected_index(0)
+        });
+        cx.observe(&recurrence_dropdown, |_table, _dropdown, cx| cx.notify())
+            .detach();
+        cx.observe(&filter_state, |table, filter_state, cx| {
+            table.filter_summary = TaskFilter::from(filter_state.read(cx)).to_display_string();
+            cx.notify();
+        })
+        .detach();
+        let filter_summary = TaskFilter::from(filter_state.read(cx)).to_display_string();
+        let known_tags: Arc<RwLock<Vec<String>>> = Arc::default();
+        let add_input = {
+            let known_projects = known_projects.clone();
+            let known_tags = known_tags.clone();
+            cx.new(|cx| {
+                Input::new(
+                    "task-add-prompt",
+                    cx,
+                    "Description project:Work +tag priority:H due:friday",
+                )
+                .with_suggest(Arc::new(move |value: &str, cursor: usize| {
+                    let (Ok(projects), Ok(tags)) = (known_projects.read(), known_tags.read())
+                    else {
+                        return Vec::new();
+                    };
+                    task::parser::add_completions(value, cursor, &projects, &tags)
+                        .into_iter()
+                        .map(|completion| {
+                            Suggestion::new(completion.label, completion.insert)
+                                .with_replace(completion.range)
+                        })
+                        .collect()
+                }))
+            })
+        };
+
+        let date_refresh_task = cx.spawn(async move |table, cx| {
+            loop {
+                cx.background_executor()
+                    .timer(TABLE_DATE_REFRESH_INTERVAL)
+                    .await;
+                let refreshed = table.update(cx, |table, cx| {
+                    table.recalculate_rows(cx);
+                    cx.notify();
+                });
+                if refreshed.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let context_menu = cx.new(|_cx| ContextMenu::new("task-context-menu"));
+        cx.subscribe(&context_menu, |_table, _menu, event, cx| match event {
+            ContextMenuEvent::Select(command) => {
+                cx.emit(TaskTableEvent::Command(*command));
+            }
+        })
+        .detach();
 
         let status_items = StatusFilter::all_variants()
             .iter()
@@ -345,6 +712,7 @@ impl TaskTable {
             let filter_state = filter_state.clone();
             cx.new(|_cx| {
                 Dropdown::new("filter-due")
+                    .with_search(true)
                     .items(vec![DropdownItem::with_value("All", "all")])
                     .label_prefix("Due")
                     .selected_index(0)
@@ -364,12 +732,17 @@ impl TaskTable {
             filter_state,
             cached_tasks: vec![],
             cached_rows: vec![],
-            sort_state: SortState::default(),
-            pagination: PaginationState::default(),
+            sort_levels: cx.config().table.sort.clone(),
+            preset_sort: None,
+            pagination: PaginationState::new(1, cx.config().page_size, 0),
             selected_page_idx: None,
             selected_global_idx: None,
             need_reload: true,
+            project_progress: None,
             filter_bar_height: TABLE_FILTER_BAR_INITIAL_HEIGHT,
+            row_bounds: Vec::new(),
+            horizontal_scroll: gpui::ScrollHandle::new(),
+            filter_summary,
             search_input,
             status_dropdown,
             priority_dropdown,
@@ -378,57 +751,88 @@ impl TaskTable {
             filter_bar_focus_handle: cx.focus_handle(),
             focused_header: None,
             header_focus_handle: cx.focus_handle(),
+            sort_picker: None,
+            sort_picker_focus_handle: cx.focus_handle(),
+            jump_input,
+            jump_open: false,
+            columns_menu_open: false,
+            context_menu,
+            prompt_input,
+            project_input,
+            due_input,
+            add_input,
+            recurrence_dropdown,
+            recurrence_input,
+            prompt: None,
+            expanded_task: None,
+            emitted_selection: None,
+            marked_tasks: HashSet::new(),
+            bulk_busy: false,
+            known_projects,
+            known_tags,
+            last_export_path: task::export::DEFAULT_EXPORT_PATH.to_string(),
+            last_import_path: task::export::DEFAULT_EXPORT_PATH.to_string(),
+            project_rename: None,
+            _date_refresh_task: date_refresh_task,
         }
     }
 
     pub fn set_sort(&mut self, column: SortColumn, cx: &mut gpui::Context<Self>) {
-        if self.sort_state.column == column {
-            self.sort_state.direction = self.sort_state.direction.toggle();
-        } else {
-            self.sort_state.column = column;
-            self.sort_state.direction = SortDirection::Desc;
-        }
-        self.apply_sort();
-        self.recalculate_rows();
-        cx.notify();
+        toggle_sort_level(self.active_sort_levels_mut(), column);
+        self.sort_levels_changed(cx);
     }
 
-    fn apply_sort(&mut self) {
-        let direction = self.sort_state.direction;
-        let column = self.sort_state.column;
+    pub fn set_secondary_sort(&mut self, column: SortColumn, cx: &mut gpui::Context<Self>) {
+        set_secondary_sort_level(self.active_sort_levels_mut(), column);
+        self.sort_levels_changed(cx);
+    }
 
-        self.cached_tasks.sort_by(|a, b| {
-            let ordering = match column {
-                SortColumn::Id => a.working_id.unwrap_or(0).cmp(&b.working_id.unwrap_or(0)),
-                SortColumn::Description => a.description.cmp(&b.description),
-                SortColumn::Project => {
-                    let a_proj = a.project.as_deref().unwrap_or("");
-                    let b_proj = b.project.as_deref().unwrap_or("");
-                    a_proj.cmp(b_proj)
-                }
-                SortColumn::Due => match (&a.due, &b.due) {
-                    (Some(a_due), Some(b_due)) => a_due.cmp(b_due),
-                    (Some(_), None) => Ordering::Less,
-                    (None, Some(_)) => Ordering::Greater,
-                    (None, None) => Ordering::Equal,
-                },
-                SortColumn::Priority => {
-                    let a_order: usize = a.priority.into();
-                    let b_order: usize = b.priority.into();
-                    a_order.cmp(&b_order)
-                }
-                SortColumn::Status => {
-                    let a_status: String = a.status.clone().into();
-                    let b_status: String = b.status.clone().into();
-                    a_status.cmp(&b_status)
-                }
-            };
+    fn active_sort_levels(&self) -> &[SortState] {
+        match &self.preset_sort {
+            Some((_, levels)) => levels,
+            None => &self.sort_levels,
+        }
+    }
 
-            match direction {
-                SortDirection::Asc => ordering,
-                SortDirection::Desc => ordering.reverse(),
+    fn active_sort_levels_mut(&mut self) -> &mut Vec<SortState> {
+        match &mut self.preset_sort {
+            Some((_, levels)) => levels,
+            None => &mut self.sort_levels,
+        }
+    }
+
+    fn sort_levels_changed(&mut self, cx: &mut gpui::Context<Self>) {
+        // A preset's sort is dropped along with the preset, so it is never saved.
+        if self.preset_sort.is_none() {
+            let levels = self.sort_levels.clone();
+            cx.update_global::<AppConfig, _>(|config, _cx| config.table.sort = levels);
+            if let Err(e) = cx.config().save() {
+                log::error!("[TaskTable] Failed to save sort order: {}", e);
             }
-        });
+        }
+
+        self.apply_sort(cx);
+        self.recalculate_rows(cx);
+        cx.notify();
+    }
+
+    fn apply_sort(&mut self, cx: &gpui::App) {
+        let preset = self.filter_state.read(cx).preset;
+        if preset != self.preset_sort.as_ref().map(|(preset, _)| *preset) {
+            self.preset_sort = preset.map(|preset| {
+                let by_urgency = SortState {
+                    column: SortColumn::Urgency,
+                    direction: SortDirection::Desc,
+                };
+                (preset, vec![by_urgency])
+            });
+        }
+
+        let levels = match &self.preset_sort {
+            Some((_, levels)) => levels,
+            None => &self.sort_levels,
+        };
+        sort_tasks(&mut self.cached_tasks, levels, &cx.config().pinned_tasks);
     }
 
     fn get_current_page_rows(&self) -> &[TaskRow] {
@@ -440,41 +844,150 @@ impl TaskTable {
 
     pub fn reload_tasks_from_all(
         &mut self,
-        all_tasks: Vec<task::TaskSummary>,
+        mut all_tasks: Vec<task::TaskSummary>,
         cx: &mut gpui::Context<Self>,
     ) {
+        task::TaskSummary::mark_blocking(&mut all_tasks);
         let filter_state = self.filter_state.read(cx).clone();
 
-        let task_filter = TaskFilter::from(&filter_state);
+        let mut task_filter = TaskFilter::from(&filter_state);
         let mut due_filter = task_filter.clone();
         due_filter.due_filter = None;
+        task_filter.pinned = cx.config().pinned_tasks.clone();
+        task_filter.due_soon = cx.config().due_soon_threshold();
 
         let filtered_tasks = task_filter.apply(&all_tasks);
         let due_tasks = due_filter.apply(&all_tasks);
+        self.project_progress = filter_state
+            .selected_project
+            .as_deref()
+            .filter(|project| *project != INBOX_PROJECT)
+            .map(|project| {
+                ProjectProgress::from_tasks(project, &all_tasks, !filter_state.exclude_subprojects)
+            });
+
+        let projects: BTreeSet<String> = all_tasks
+            .iter()
+            .filter_map(|task| task.project.clone())
+            .collect();
+        if let Ok(mut known) = self.known_projects.write() {
+            *known = projects.into_iter().collect();
+        }
+        let tags: BTreeSet<String> = all_tasks
+            .iter()
+            .flat_map(|task| task.tags.iter().cloned())
+            .collect();
+        if let Ok(mut known) = self.known_tags.write() {
+            *known = tags.into_iter().collect();
+        }
 
+        let previous_selection = self.selected_task_uuid();
+        let previous_idx = self.selected_global_idx;
+        let previous_uuids: HashSet<uuid::Uuid> =
+            self.cached_tasks.iter().map(|task| task.uuid).collect();
+
+        self.marked_tasks
+            .retain(|uuid| filtered_tasks.iter().any(|task| task.uuid == *uuid));
         self.cached_tasks = filtered_tasks;
-        self.apply_sort();
+        self.apply_sort(cx);
         self.pagination.total_items(self.cached_tasks.len());
         self.pagination.current_page(1);
         self.selected_global_idx = None;
         self.selected_page_idx = None;
 
-        self.recalculate_rows();
+        self.recalculate_rows(cx);
 
-        if !self.cached_rows.is_empty() {
-            self.selected_page_idx = Some(0);
-            self.selected_global_idx = Some(0);
+        let restored = restore_selection(
+            &self.cached_tasks,
+            previous_selection,
+            previous_idx,
+            &previous_uuids,
+        );
+        if let Some(global_idx) = restored {
+            self.select_global_idx(global_idx);
         }
 
         self.sync_filter_dropdowns(&due_tasks, &filter_state, cx);
 
         self.need_reload = false;
 
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
-    fn recalculate_rows(&mut self) {
-        self.cached_rows = self.cached_tasks.iter().map(TaskRow::from).collect();
+    pub fn visible_tasks(&self) -> &[task::TaskSummary] {
+        &self.cached_tasks
+    }
+
+    pub fn visible_rows_csv(&self, cx: &gpui::App) -> String {
+        let columns = cx.config().table.columns();
+        let extra_columns = &cx.config().extra_columns;
+        let headers: Vec<&str> = columns
+            .iter()
+            .map(|column| column.label())
+            .chain(extra_columns.iter().map(String::as_str))
+            .collect();
+        let rows: Vec<Vec<String>> = self
+            .cached_rows
+            .iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| match column {
+                        SortColumn::Id => row.id_display.clone(),
+                        SortColumn::Description => row.description.clone(),
+                        SortColumn::Project => row.project.clone(),
+                        SortColumn::Due => row.due_absolute.clone(),
+                        SortColumn::Scheduled => row.scheduled_absolute.clone(),
+                        SortColumn::EntryDate => row.entry_absolute.clone(),
+                        SortColumn::ModifiedDate => row.modified_absolute.clone(),
+                        SortColumn::Priority => row.priority.clone(),
+                        SortColumn::Urgency => row.urgency.clone(),
+                        SortColumn::Status => row.status.clone(),
+                    })
+                    .chain(
+                        extra_columns
+                            .iter()
+                            .map(|key| row.udas.get(key).cloned().unwrap_or_default()),
+                    )
+                    .collect()
+            })
+            .collect();
+
+        task::export::rows_to_csv(&headers, &rows)
+    }
+
+    pub fn filtered_count(&self) -> usize {
+        self.cached_tasks.len()
+    }
+
+    fn recalculate_rows(&mut self, cx: &gpui::App) {
+        let relative_dates = cx.config().relative_dates;
+        let due_soon = cx.config().due_soon_threshold();
+        let pinned = &cx.config().pinned_tasks;
+        self.cached_rows = self
+            .cached_tasks
+            .iter()
+            .map(|task| TaskRow {
+                is_pinned: pinned.contains(&task.uuid),
+                ..TaskRow::from_summary(task, relative_dates, due_soon)
+            })
+            .collect();
+    }
+
+    pub fn apply_config(&mut self, cx: &mut gpui::Context<Self>) {
+        let page_size = cx.config().page_size.max(1);
+        if page_size != self.pagination.page_size {
+            let global_idx = self.selected_global_idx.unwrap_or(0);
+            self.pagination.page_size(page_size);
+            self.pagination.current_page(global_idx / page_size + 1);
+            self.selected_page_idx = self
+                .selected_global_idx
+                .map(|idx| idx - self.pagination.first_item_index());
+        }
+
+        self.recalculate_rows(cx);
+        cx.notify();
     }
 
     fn sync_filter_dropdowns(
@@ -483,49 +996,90 @@ impl TaskTable {
         filter_state: &FilterState,
         cx: &mut gpui::Context<Self>,
     ) {
-        let status_index = filter_state.status_filter.to_index();
+        let dropdowns =
+            Self::filter_dropdowns(due_tasks, filter_state, cx.config().due_soon_threshold());
+
         self.status_dropdown.update(cx, |dropdown, cx| {
-            dropdown.set_selected_index(status_index, cx);
+            dropdown.set_selected_index(dropdowns.status_index, cx);
         });
-
-        let priority_index = filter_state.priority_filter.to_index();
         self.priority_dropdown.update(cx, |dropdown, cx| {
-            dropdown.set_selected_index(priority_index, cx);
+            dropdown.set_selected_index(dropdowns.priority_index, cx);
+        });
+        self.due_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_items(dropdowns.due_items, cx);
+            dropdown.set_selected_index(dropdowns.due_index.unwrap_or(0), cx);
         });
 
-        let mut due_items = Self::build_due_items(due_tasks);
-        let selected_key = filter_state.due_filter.value_key();
-        let mut selected_index = due_items
-            .iter()
-            .position(|item| item.value.as_ref() == selected_key);
+        if dropdowns.due_index.is_none() {
+            self.filter_state.update(cx, |state, cx| {
+                state.due_filter = DueFilter::All;
+                cx.notify();
+            });
+        }
+    }
 
-        if selected_index.is_none() && filter_state.due_filter != DueFilter::All {
-            if let Some(item) = Self::due_item_from_filter(&filter_state.due_filter) {
-                due_items.push(item);
-                selected_index = Some(due_items.len() - 1);
-            }
+    fn filter_dropdowns(
+        due_tasks: &[task::TaskSummary],
+        filter_state: &FilterState,
+        due_soon: chrono::Duration,
+    ) -> FilterDropdowns {
+        let mut due_items = Self::build_due_items(due_tasks, due_soon);
+        let due_index = Self::select_due_item(&mut due_items, &filter_state.due_filter);
+        FilterDropdowns {
+            status_index: filter_state.status_filter.to_index(),
+            priority_index: filter_state.priority_filter.to_index(),
+            due_items,
+            due_index,
         }
+    }
 
-        self.due_dropdown.update(cx, |dropdown, cx| {
-            dropdown.set_items(due_items, cx);
-            if let Some(index) = selected_index {
-                dropdown.set_selected_index(index, cx);
+    fn select_due_item(items: &mut Vec<DropdownItem>, filter: &DueFilter) -> Option<usize> {
+        let selected_key = filter.value_key();
+        if let Some(index) = items
+            .iter()
+            .position(|item| item.value.as_ref() == selected_key)
+        {
+            return Some(index);
+        }
+
+        match filter {
+            DueFilter::All | DueFilter::OnDate(_) => None,
+            _ => {
+                items.push(Self::due_item_from_filter(filter)?);
+                Some(items.len() - 1)
             }
-        });
+        }
     }
 
-    fn build_due_items(tasks: &[task::TaskSummary]) -> Vec<DropdownItem> {
+    fn build_due_items(
+        tasks: &[task::TaskSummary],
+        due_soon: chrono::Duration,
+    ) -> Vec<DropdownItem> {
         let now = chrono::Utc::now();
         let today = now.date_naive();
         let week_end = now + chrono::Duration::days(7);
+        let next_week = task::next_week_range(today);
+        let next_month = task::next_month_range(today);
 
         let mut dates = BTreeSet::new();
         let mut has_no_date = false;
         let mut has_overdue = false;
         let mut has_today = false;
+        let mut has_soon = false;
         let mut has_this_week = false;
+        let mut has_next_week = false;
+        let mut has_next_month = false;
+        let mut has_scheduled_today = false;
+        let mut has_scheduled_this_week = false;
 
         for task in tasks {
+            if let Some(scheduled) = task.scheduled {
+                let date = scheduled.date_naive();
+                has_scheduled_today |= date == today;
+                has_scheduled_this_week |=
+                    date >= today && date <= today + chrono::Duration::days(7);
+            }
+
             match task.due {
                 None => {
                     has_no_date = true;
@@ -539,9 +1093,14 @@ impl TaskTable {
                     if date == today {
                         has_today = true;
                     }
+                    if due >= now && due <= now + due_soon {
+                        has_soon = true;
+                    }
                     if due >= now && due <= week_end {
                         has_this_week = true;
                     }
+                    has_next_week |= date >= next_week.0 && date <= next_week.1;
+                    has_next_month |= date >= next_month.0 && date <= next_month.1;
                 }
             }
         }
@@ -556,9 +1115,30 @@ impl TaskTable {
         if has_today {
             items.push(DropdownItem::with_value("Today", "today"));
         }
+        if has_soon {
+            items.push(DropdownItem::with_value("Due Soon", "soon"));
+        }
         if has_this_week {
             items.push(DropdownItem::with_value("This Week", "this_week"));
         }
+        if has_next_week {
+            items.push(DropdownItem::with_value("Next Week", "next_week"));
+        }
+        if has_next_month {
+            items.push(DropdownItem::with_value("Next Month", "next_month"));
+        }
+        if has_scheduled_today {
+            items.push(DropdownItem::with_value(
+                "Scheduled Today",
+                "scheduled_today",
+            ));
+        }
+        if has_scheduled_this_week {
+            items.push(DropdownItem::with_value(
+                "Scheduled This Week",
+                "scheduled_this_week",
+            ));
+        }
 
         for date in dates {
             if date == today {
@@ -596,9 +1176,11 @@ impl TaskTable {
         let theme = cx.theme();
 
         if row.is_due_today {
-            theme.accent
+            theme.task_due_today
         } else if row.is_overdue {
-            theme.error
+            theme.task_overdue
+        } else if row.is_due_soon {
+            theme.task_due
         } else {
             theme.foreground
         }
@@ -608,11 +1190,11 @@ impl TaskTable {
         let theme = cx.theme();
 
         match row.status.as_str() {
-            "Active" => theme.success,
+            "Active" => theme.task_active,
             "Pending" => theme.warning,
-            "Completed" => theme.muted,
-            "Deleted" => theme.error,
-            "Recurring" => theme.info,
+            "Completed" => theme.task_completed,
+            "Deleted" => theme.task_deleted,
+            "Recurring" => theme.task_recurring,
             _ => theme.muted,
         }
     }
@@ -620,6 +1202,7 @@ impl TaskTable {
     pub fn select_row(&mut self, idx: usize, cx: &mut gpui::Context<Self>) {
         self.selected_page_idx = Some(idx);
         self.selected_global_idx = Some(self.pagination.first_item_index() + idx);
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
@@ -641,6 +1224,7 @@ impl TaskTable {
         if self.selected_page_idx.is_none() {
             self.selected_page_idx = Some(0);
             self.selected_global_idx = Some(self.pagination.first_item_index());
+            self.emit_selection_changed(cx);
             cx.notify();
             return;
         }
@@ -664,6 +1248,7 @@ impl TaskTable {
             self.selected_global_idx = Some(next_global_idx);
         }
 
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
@@ -684,6 +1269,7 @@ impl TaskTable {
 
             self.selected_page_idx = Some(page_idx);
             self.selected_global_idx = Some(last_global_idx);
+            self.emit_selection_changed(cx);
             cx.notify();
             return;
         }
@@ -710,6 +1296,7 @@ impl TaskTable {
             self.selected_global_idx = Some(prev_global_idx);
         }
 
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
@@ -721,9 +1308,21 @@ impl TaskTable {
         self.pagination.current_page(1);
         self.selected_page_idx = Some(0);
         self.selected_global_idx = Some(0);
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
+    pub fn select_task(&mut self, uuid: uuid::Uuid, cx: &mut gpui::Context<Self>) -> bool {
+        let Some(global_idx) = self.cached_tasks.iter().position(|task| task.uuid == uuid) else {
+            return false;
+        };
+
+        self.select_global_idx(global_idx);
+        self.emit_selection_changed(cx);
+        cx.notify();
+        true
+    }
+
     pub fn select_last_row(&mut self, cx: &mut gpui::Context<Self>) {
         if self.cached_rows.is_empty() {
             return;
@@ -740,111 +1339,868 @@ impl TaskTable {
 
         self.selected_page_idx = Some(page_idx);
         self.selected_global_idx = Some(last_global_idx);
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
     pub fn clear_selection(&mut self, cx: &mut gpui::Context<Self>) {
         self.selected_page_idx = None;
         self.selected_global_idx = None;
+        self.marked_tasks.clear();
+        self.emit_selection_changed(cx);
         cx.notify();
     }
 
-    pub fn selected_task_uuid(&self) -> Option<uuid::Uuid> {
-        self.selected_global_idx
-            .and_then(|idx| self.cached_tasks.get(idx))
+    pub fn toggle_task_mark(&mut self, uuid: uuid::Uuid, cx: &mut gpui::Context<Self>) {
+        if !self.marked_tasks.remove(&uuid) {
+            self.marked_tasks.insert(uuid);
+        }
+        cx.notify();
+    }
+
+    fn toggle_selected_mark(&mut self, cx: &mut gpui::Context<Self>) {
+        if let Some(uuid) = self.selected_task_uuid() {
+            self.toggle_task_mark(uuid, cx);
+        }
+    }
+
+    pub fn marked_uuids(&self) -> Vec<uuid::Uuid> {
+        self.cached_tasks
+            .iter()
+            .filter(|task| self.marked_tasks.contains(&task.uuid))
             .map(|task| task.uuid)
+            .collect()
     }
 
-    pub fn focus_search_input(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
-        self.filter_bar_focus = FilterBarFocus::SearchInput;
-        self.search_input.update(cx, |input, cx| {
-            input.focus(window, cx);
-        });
-        cx.notify();
+    pub fn clear_marks(&mut self, cx: &mut gpui::Context<Self>) {
+        if !self.marked_tasks.is_empty() {
+            self.marked_tasks.clear();
+            cx.notify();
+        }
     }
 
-    pub fn blur_search_input(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
-        window.focus(&self.filter_bar_focus_handle);
+    pub fn set_bulk_busy(&mut self, busy: bool, cx: &mut gpui::Context<Self>) {
+        self.bulk_busy = busy;
         cx.notify();
     }
 
-    pub fn clear_search_input(&mut self, cx: &mut gpui::Context<Self>) {
-        self.search_input.update(cx, |input, cx| {
-            input.clear(cx);
-        });
+    fn request_bulk_action(&mut self, action: BulkAction, cx: &mut gpui::Context<Self>) {
+        let uuids = self.marked_uuids();
+        if uuids.is_empty() || self.bulk_busy {
+            return;
+        }
+        cx.emit(TaskTableEvent::BulkAction { action, uuids });
     }
 
-    pub fn reset_dropdowns(&mut self, cx: &mut gpui::Context<Self>) {
-        self.status_dropdown.update(cx, |dropdown, cx| {
-            dropdown.set_selected_index(1, cx);
-        });
-        self.priority_dropdown.update(cx, |dropdown, cx| {
-            dropdown.set_selected_index(0, cx);
-        });
-        self.due_dropdown.update(cx, |dropdown, cx| {
-            dropdown.set_selected_index(0, cx);
-        });
+    pub fn selected_task_uuid(&self) -> Option<uuid::Uuid> {
+        self.selected_global_idx
+            .and_then(|idx| self.cached_tasks.get(idx))
+            .map(|task| task.uuid)
     }
 
-    pub fn get_filter_bar_focus(&self) -> FilterBarFocus {
-        self.filter_bar_focus
+    fn emit_selection_changed(&mut self, cx: &mut gpui::Context<Self>) {
+        let selection = self.selected_task_uuid();
+        if selection != self.emitted_selection {
+            self.emitted_selection = selection;
+            cx.emit(TaskTableEvent::SelectionChanged(selection));
+        }
     }
 
-    pub fn set_filter_bar_focus(&mut self, focus: FilterBarFocus, cx: &mut gpui::Context<Self>) {
-        self.filter_bar_focus = focus;
+    pub fn jump_to_working_id(&mut self, id: usize, cx: &mut gpui::Context<Self>) -> bool {
+        let Some(global_idx) = self
+            .cached_tasks
+            .iter()
+            .position(|task| task.working_id == Some(id))
+        else {
+            return false;
+        };
+
+        self.select_global_idx(global_idx);
+        self.emit_selection_changed(cx);
         cx.notify();
+        true
     }
 
-    pub fn focus_table_headers(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
-        self.focused_header = Some(SortColumn::Id);
-        window.focus(&self.header_focus_handle);
-        cx.notify();
+    fn select_global_idx(&mut self, global_idx: usize) {
+        let page_size = self.pagination.page_size.max(1);
+        self.pagination.current_page(global_idx / page_size + 1);
+        self.selected_global_idx = Some(global_idx);
+        self.selected_page_idx = Some(global_idx - self.pagination.first_item_index());
     }
 
-    pub fn blur_table_headers(&mut self, cx: &mut gpui::Context<Self>) {
-        self.focused_header = None;
+    pub fn open_jump_to_id(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        self.jump_open = true;
+        self.jump_input.update(cx, |input, cx| {
+            input.clear(cx);
+            input.focus(window, cx);
+        });
         cx.notify();
     }
 
-    pub fn header_move_next(&mut self, cx: &mut gpui::Context<Self>) {
-        self.focused_header = Some(self.focused_header.unwrap_or(SortColumn::Id).next());
+    fn close_jump_to_id(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        self.jump_open = false;
+        window.focus(&self.filter_bar_focus_handle);
+        cx.notify();
+    }
+
+    fn handle_jump_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        cx.stop_propagation();
+
+        match event.keystroke.key.as_str() {
+            "escape" => self.close_jump_to_id(window, cx),
+            "enter" => {
+                let value = self.jump_input.read(cx).value().trim().to_string();
+                let found = value
+                    .trim_start_matches('#')
+                    .parse::<usize>()
+                    .map(|id| self.jump_to_working_id(id, cx))
+                    .unwrap_or(false);
+
+                if found {
+                    self.close_jump_to_id(window, cx);
+                } else {
+                    let toast_host = cx.global::<ToastGlobal>().host.clone();
+                    toast_host.update(cx, |host, cx| {
+                        host.push(ToastKind::Error, format!("No task with ID {}", value), cx);
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn toggle_selected_expansion(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(uuid) = self.selected_task_uuid() else {
+            return;
+        };
+
+        self.expanded_task = if self.expanded_task == Some(uuid) {
+            None
+        } else {
+            Some(uuid)
+        };
+        cx.notify();
+    }
+
+    pub fn selected_task(&self) -> Option<&task::TaskSummary> {
+        self.selected_global_idx
+            .and_then(|idx| self.cached_tasks.get(idx))
+    }
+
+    fn open_context_menu(
+        &mut self,
+        idx: usize,
+        position: gpui::Point<gpui::Pixels>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.select_row(idx, cx);
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+
+        let items = Self::context_menu_items(task, cx.config().pinned_tasks.contains(&task.uuid));
+        self.context_menu.update(cx, |menu, cx| {
+            menu.open(position, items, cx);
+        });
+    }
+
+    fn open_selected_context_menu(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(idx) = self.selected_page_idx else {
+            return;
+        };
+        let Some(bounds) = self.row_bounds.get(idx) else {
+            return;
+        };
+
+        let position = gpui::point(bounds.left() + gpui::px(32.0), bounds.bottom());
+        self.open_context_menu(idx, position, cx);
+        self.context_menu
+            .update(cx, |menu, cx| menu.highlight_first(cx));
+    }
+
+    pub fn is_context_menu_open(&self, cx: &gpui::App) -> bool {
+        self.context_menu.read(cx).is_open()
+    }
+
+    pub fn handle_context_menu_key(&mut self, key: Key, cx: &mut gpui::Context<Self>) {
+        self.context_menu
+            .update(cx, |menu, cx| menu.handle_key(key, cx));
+    }
+
+    fn context_menu_items(task: &task::TaskSummary, is_pinned: bool) -> Vec<ContextMenuItem> {
+        let is_pending = matches!(task.status, task::TaskStatus::Pending);
+        let mut items = vec![
+            ContextMenuItem::action("Open Details", Command::OpenSelectedTask),
+            ContextMenuItem::action(
+                if is_pinned { "Unpin" } else { "Pin to Top" },
+                Command::TogglePin,
+            ),
+            ContextMenuItem::Separator,
+        ];
+
+        if is_pending {
+            items.push(ContextMenuItem::action("Complete", Command::CompleteTask));
+            items.push(if task.is_active {
+                ContextMenuItem::action("Stop", Command::StopTask)
+            } else {
+                ContextMenuItem::action("Start", Command::StartTask)
+            });
+        }
+        if !matches!(task.status, task::TaskStatus::Deleted) {
+            items.push(ContextMenuItem::action("Delete", Command::DeleteTask));
+        }
+
+        items.extend([
+            ContextMenuItem::Separator,
+            ContextMenuItem::action("Copy UUID", Command::CopyTaskUuid),
+            ContextMenuItem::action("Copy Description", Command::CopyTaskDescription),
+            ContextMenuItem::Separator,
+            ContextMenuItem::action("Add Annotation", Command::AddAnnotation),
+            ContextMenuItem::submenu(
+                "Set Priority",
+                vec![
+                    ContextMenuItem::action("High", Command::SetPriorityHigh),
+                    ContextMenuItem::action("Medium", Command::SetPriorityMedium),
+                    ContextMenuItem::action("Low", Command::SetPriorityLow),
+                    ContextMenuItem::action("None", Command::SetPriorityNone),
+                ],
+            ),
+            ContextMenuItem::action("Set Project", Command::SetProject),
+            ContextMenuItem::action("Set Due Date", Command::SetDue),
+            ContextMenuItem::action("Set Recurrence", Command::SetRecurrence),
+            ContextMenuItem::action("Set Until Date", Command::SetUntil),
+            ContextMenuItem::action("Set Dependencies", Command::SetDependencies),
+        ]);
+        if is_pending {
+            items.push(ContextMenuItem::action("Snooze...", Command::SnoozeTask));
+        }
+
+        items
+    }
+
+    pub fn open_task_prompt(
+        &mut self,
+        kind: TaskPromptKind,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (uuid, initial) = if kind.is_bulk() {
+            if self.marked_tasks.is_empty() {
+                return;
+            }
+            (None, String::new())
+        } else if kind == TaskPromptKind::QuickAdd {
+            (None, String::new())
+        } else if kind == TaskPromptKind::Export {
+            (None, self.last_export_path.clone())
+        } else if kind == TaskPromptKind::Import {
+            (None, self.last_import_path.clone())
+        } else {
+            let Some(task) = self.selected_task() else {
+                return;
+            };
+            let initial = match kind {
+                TaskPromptKind::Project => task.project.clone().unwrap_or_default(),
+                TaskPromptKind::Due => task
+                    .due
+                    .map(|due| {
+                        due.with_timezone(&chrono::Local)
+                            .format(DATE_FORMAT)
+                            .to_string()
+                    })
+                    .unwrap_or_default(),
+                TaskPromptKind::Recurrence => {
+                    let recurrence = task.recur.as_deref().and_then(task::Recurrence::parse);
+                    let index = recurrence
+                        .and_then(|recurrence| {
+                            task::RecurrenceFrequency::ALL
+                                .iter()
+                                .position(|frequency| *frequency == recurrence.frequency)
+                        })
+                        .map_or(0, |position| position + 1);
+                    self.recurrence_dropdown.update(cx, |dropdown, cx| {
+                        dropdown.set_selected_index(index, cx);
+                    });
+                    recurrence
+                        .map_or(1, |recurrence| recurrence.interval)
+                        .to_string()
+                }
+                TaskPromptKind::Until => task
+                    .until
+                    .map(|until| {
+                        until
+                            .with_timezone(&chrono::Local)
+                            .format(DATE_FORMAT)
+                            .to_string()
+                    })
+                    .unwrap_or_default(),
+                TaskPromptKind::Dependencies => {
+                    let mut ids: Vec<usize> = task
+                        .dependencies
+                        .iter()
+                        .filter_map(|uuid| {
+                            self.cached_tasks
+                                .iter()
+                                .find(|task| task.uuid == *uuid)
+                                .and_then(|task| task.working_id)
+                        })
+                        .collect();
+                    ids.sort_unstable();
+                    ids.iter()
+                        .map(usize::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }
+                _ => String::new(),
+            };
+            (Some(task.uuid), initial)
+        };
+
+        self.prompt = Some(TaskPrompt { kind, uuid });
+        self.prompt_input_for(kind).update(cx, |input, cx| {
+            input.set_value(initial, cx);
+            input.focus(window, cx);
+        });
+        cx.notify();
+    }
+
+    pub fn open_project_rename_prompt(
+        &mut self,
+        project: String,
+        task_count: usize,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let kind = TaskPromptKind::RenameProject;
+        self.prompt = Some(TaskPrompt { kind, uuid: None });
+        self.prompt_input_for(kind).update(cx, |input, cx| {
+            input.set_value(project.clone(), cx);
+            input.focus(window, cx);
+        });
+        self.project_rename = Some((project, task_count));
+        cx.notify();
+    }
+
+    fn prompt_input_for(&self, kind: TaskPromptKind) -> &gpui::Entity<Input> {
+        match kind {
+            TaskPromptKind::Project
+            | TaskPromptKind::BulkProject
+            | TaskPromptKind::RenameProject => &self.project_input,
+            TaskPromptKind::Due | TaskPromptKind::Snooze | TaskPromptKind::Until => &self.due_input,
+            TaskPromptKind::QuickAdd => &self.add_input,
+            TaskPromptKind::Recurrence => &self.recurrence_input,
+            TaskPromptKind::Annotation
+            | TaskPromptKind::Dependencies
+            | TaskPromptKind::BulkTag
+            | TaskPromptKind::Export
+            | TaskPromptKind::Import => &self.prompt_input,
+        }
+    }
+
+    fn recurrence_prompt_value(&self, cx: &gpui::App) -> Option<String> {
+        let frequency = self
+            .recurrence_dropdown
+            .read(cx)
+            .selected_index_value()
+            .and_then(|index| index.checked_sub(1))
+            .and_then(|index| task::RecurrenceFrequency::ALL.get(index).copied());
+        let Some(frequency) = frequency else {
+            return Some(String::new());
+        };
+
+        let interval = self.recurrence_input.read(cx).value().trim();
+        let interval = if interval.is_empty() {
+            1
+        } else {
+            interval
+                .parse::<u32>()
+                .ok()
+                .filter(|interval| *interval > 0)?
+        };
+        Some(task::Recurrence::new(frequency, interval).to_recur_value())
+    }
+
+    fn step_recurrence_frequency(&mut self, forward: bool, cx: &mut gpui::Context<Self>) {
+        let count = task::RecurrenceFrequency::ALL.len() + 1;
+        self.recurrence_dropdown.update(cx, |dropdown, cx| {
+            let current = dropdown.selected_index_value().unwrap_or(0);
+            let next = if forward {
+                (current + 1) % count
+            } else {
+                (current + count - 1) % count
+            };
+            dropdown.set_selected_index(next, cx);
+        });
+    }
+
+    fn prompt_task(&self, prompt: TaskPrompt) -> Option<&task::TaskSummary> {
+        let uuid = prompt.uuid?;
+        self.cached_tasks.iter().find(|task| task.uuid == uuid)
+    }
+
+    fn close_task_prompt(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        self.prompt = None;
+        window.focus(&self.filter_bar_focus_handle);
+        cx.notify();
+    }
+
+    fn handle_prompt_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        cx.stop_propagation();
+
+        match event.keystroke.key.as_str() {
+            "escape" => self.close_task_prompt(window, cx),
+            "enter" => {
+                let Some(prompt) = self.prompt else {
+                    return;
+                };
+                let value = if prompt.kind == TaskPromptKind::Recurrence {
+                    let Some(value) = self.recurrence_prompt_value(cx) else {
+                        return;
+                    };
+                    value
+                } else {
+                    self.prompt_input_for(prompt.kind)
+                        .read(cx)
+                        .value()
+                        .trim()
+                        .to_string()
+                };
+                if value.is_empty()
+                    && matches!(
+                        prompt.kind,
+                        TaskPromptKind::QuickAdd
+                            | TaskPromptKind::Annotation
+                            | TaskPromptKind::BulkTag
+                            | TaskPromptKind::Export
+                            | TaskPromptKind::Import
+                            | TaskPromptKind::RenameProject
+                    )
+                {
+                    return;
+                }
+                if matches!(prompt.kind, TaskPromptKind::Due | TaskPromptKind::Until)
+                    && !value.is_empty()
+                    && task::parse_date_expression(&value).is_none()
+                {
+                    return;
+                }
+                if prompt.kind == TaskPromptKind::Recurrence
+                    && !value.is_empty()
+                    && self
+                        .prompt_task(prompt)
+                        .is_none_or(|task| task.due.is_none())
+                {
+                    return;
+                }
+                if prompt.kind == TaskPromptKind::Dependencies
+                    && task::parser::parse_working_ids(&value).is_err()
+                {
+                    return;
+                }
+                if prompt.kind == TaskPromptKind::Snooze
+                    && task::parse_date_expression(&value)
+                        .is_none_or(|wait| wait <= chrono::Utc::now())
+                {
+                    return;
+                }
+
+                self.close_task_prompt(window, cx);
+                match (prompt.kind, prompt.uuid) {
+                    (TaskPromptKind::QuickAdd, _) => {
+                        cx.emit(TaskTableEvent::AddTask(value));
+                    }
+                    (TaskPromptKind::BulkProject, _) => {
+                        let project = (!value.is_empty()).then_some(value);
+                        self.request_bulk_action(BulkAction::SetProject(project), cx);
+                    }
+                    (TaskPromptKind::BulkTag, _) => {
+                        self.request_bulk_action(BulkAction::AddTag(value), cx);
+                    }
+                    (TaskPromptKind::Export, _) => {
+                        let path = task::export::expand_path(&value);
+                        self.last_export_path = value;
+                        cx.emit(TaskTableEvent::Export(path));
+                    }
+                    (TaskPromptKind::Import, _) => {
+                        let path = task::export::expand_path(&value);
+                        self.last_import_path = value;
+                        cx.emit(TaskTableEvent::Import(path));
+                    }
+                    (TaskPromptKind::RenameProject, _) => match self.project_rename.take() {
+                        Some((from, _)) if from != value => {
+                            cx.emit(TaskTableEvent::RenameProject { from, to: value });
+                        }
+                        _ => {}
+                    },
+                    (kind, Some(uuid)) => {
+                        cx.emit(TaskTableEvent::PromptSubmitted { kind, uuid, value });
+                    }
+                    (_, None) => {}
+                }
+            }
+            "up" | "down"
+                if self
+                    .prompt
+                    .is_some_and(|prompt| prompt.kind == TaskPromptKind::Recurrence) =>
+            {
+                self.step_recurrence_frequency(event.keystroke.key == "down", cx);
+            }
+            _ => {}
+        }
+    }
+
+    fn render_task_prompt(&self, cx: &gpui::Context<Self>) -> gpui::AnyElement {
+        let Some(prompt) = self.prompt else {
+            return gpui::div().into_any_element();
+        };
+
+        let theme = cx.theme();
+        let due_preview = matches!(
+            prompt.kind,
+            TaskPromptKind::Due | TaskPromptKind::Snooze | TaskPromptKind::Until
+        )
+        .then(|| {
+            let value = self.due_input.read(cx).value().trim().to_string();
+            let (text, color) = if value.is_empty() && prompt.kind == TaskPromptKind::Snooze {
+                ("1d, 3d, 1w or a date".to_string(), theme.muted)
+            } else if value.is_empty() && prompt.kind == TaskPromptKind::Until {
+                ("No until date".to_string(), theme.muted)
+            } else if value.is_empty() {
+                ("No due date".to_string(), theme.muted)
+            } else {
+                match task::parse_date_expression(&value) {
+                    Some(due) => (
+                        format!(
+                            "→ {}",
+                            due.with_timezone(&chrono::Local)
+                                .format(DATE_PREVIEW_FORMAT)
+                        ),
+                        theme.accent,
+                    ),
+                    None => ("Unrecognized date".to_string(), theme.error),
+                }
+            };
+            components::label::Label::new(text)
+                .text_sm()
+                .text_color(color)
+        });
+        let recurrence_preview = (prompt.kind == TaskPromptKind::Recurrence).then(|| {
+            let has_due = self
+                .prompt_task(prompt)
+                .is_some_and(|task| task.due.is_some());
+            let (text, color) = match self.recurrence_prompt_value(cx) {
+                Some(value) if value.is_empty() => ("No recurrence".to_string(), theme.muted),
+                Some(_) if !has_due => ("Set a due date first".to_string(), theme.error),
+                Some(value) => match task::Recurrence::parse(&value) {
+                    Some(recurrence) => (format!("→ {}", recurrence.describe()), theme.accent),
+                    None => ("Unrecognized recurrence".to_string(), theme.error),
+                },
+                None => ("Enter how many periods".to_string(), theme.error),
+            };
+            components::label::Label::new(text)
+                .text_sm()
+                .text_color(color)
+        });
+        let rename_preview = (prompt.kind == TaskPromptKind::RenameProject)
+            .then_some(self.project_rename.as_ref())
+            .flatten()
+            .map(|(project, task_count)| {
+                components::label::Label::new(format!(
+                    "{} {} in {} and its sub-projects · Enter to confirm",
+                    task_count,
+                    if *task_count == 1 { "task" } else { "tasks" },
+                    project
+                ))
+                .text_sm()
+                .text_color(theme.warning)
+            });
+
+        gpui::div()
+            .absolute()
+            .bottom_12()
+            .left_0()
+            .right_0()
+            .flex()
+            .justify_center()
+            .child(
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .min_w(gpui::rems(24.0))
+                    .bg(theme.raised)
+                    .border_1()
+                    .border_color(theme.border)
+                    .rounded_md()
+                    .shadow_lg()
+                    .on_key_down(cx.listener(Self::handle_prompt_key_down))
+                    .child(
+                        components::label::Label::new(prompt.kind.label())
+                            .text_sm()
+                            .text_color(theme.muted),
+                    )
+                    .when(prompt.kind == TaskPromptKind::Recurrence, |row| {
+                        row.child(self.recurrence_dropdown.clone()).child(
+                            components::label::Label::new("every")
+                                .text_sm()
+                                .text_color(theme.muted),
+                        )
+                    })
+                    .child(
+                        gpui::div()
+                            .flex_1()
+                            .child(self.prompt_input_for(prompt.kind).clone()),
+                    )
+                    .children(due_preview)
+                    .children(recurrence_preview)
+                    .children(rename_preview),
+            )
+            .into_any_element()
+    }
+
+    fn render_jump_to_id(&self, cx: &gpui::Context<Self>) -> gpui::AnyElement {
+        if !self.jump_open {
+            return gpui::div().into_any_element();
+        }
+
+        let theme = cx.theme();
+
+        gpui::div()
+            .absolute()
+            .bottom_12()
+            .left_0()
+            .right_0()
+            .flex()
+            .justify_center()
+            .child(
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .bg(theme.raised)
+                    .border_1()
+                    .border_color(theme.border)
+                    .rounded_md()
+                    .shadow_lg()
+                    .on_key_down(cx.listener(Self::handle_jump_key_down))
+                    .child(
+                        components::label::Label::new("Go to #")
+                            .text_sm()
+                            .text_color(theme.muted),
+                    )
+                    .child(self.jump_input.clone()),
+            )
+            .into_any_element()
+    }
+
+    pub fn focus_search_input(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        self.filter_bar_focus = FilterBarFocus::SearchInput;
+        self.search_input.update(cx, |input, cx| {
+            input.focus(window, cx);
+        });
+        cx.notify();
+    }
+
+    pub fn blur_search_input(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        window.focus(&self.filter_bar_focus_handle);
+        cx.notify();
+    }
+
+    pub fn clear_search_input(&mut self, cx: &mut gpui::Context<Self>) {
+        self.search_input.update(cx, |input, cx| {
+            input.clear(cx);
+        });
+    }
+
+    fn clear_search(&mut self, cx: &mut gpui::Context<Self>) {
+        self.clear_search_input(cx);
+        self.filter_state.update(cx, |filter, cx| {
+            filter.search_text.clear();
+            cx.notify();
+        });
+    }
+
+    pub fn set_search_input(&mut self, text: String, cx: &mut gpui::Context<Self>) {
+        self.search_input.update(cx, |input, cx| {
+            input.set_value(text, cx);
+        });
+    }
+
+    pub fn reset_dropdowns(&mut self, cx: &mut gpui::Context<Self>) {
+        self.status_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_selected_index(1, cx);
+        });
+        self.priority_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_selected_index(0, cx);
+        });
+        self.due_dropdown.update(cx, |dropdown, cx| {
+            dropdown.set_selected_index(0, cx);
+        });
+    }
+
+    pub fn get_filter_bar_focus(&self) -> FilterBarFocus {
+        self.filter_bar_focus
+    }
+
+    pub fn set_filter_bar_focus(&mut self, focus: FilterBarFocus, cx: &mut gpui::Context<Self>) {
+        self.filter_bar_focus = focus;
+        cx.notify();
+    }
+
+    pub fn focus_table_headers(&mut self, window: &mut gpui::Window, cx: &mut gpui::Context<Self>) {
+        let density = cx.config().table_density;
+        self.focused_header = Self::density_columns(density, cx).first().copied();
+        window.focus(&self.header_focus_handle);
+        cx.notify();
+    }
+
+    pub fn blur_table_headers(&mut self, cx: &mut gpui::Context<Self>) {
+        self.focused_header = None;
+        cx.notify();
+    }
+
+    pub fn header_move_next(&mut self, cx: &mut gpui::Context<Self>) {
+        let columns = Self::density_columns(cx.config().table_density, cx);
+        self.focused_header = self
+            .focused_header
+            .map(|column| column.next(&columns))
+            .or_else(|| columns.first().copied());
         cx.notify();
     }
 
     pub fn header_move_prev(&mut self, cx: &mut gpui::Context<Self>) {
-        self.focused_header = Some(self.focused_header.unwrap_or(SortColumn::Id).prev());
+        let columns = Self::density_columns(cx.config().table_density, cx);
+        self.focused_header = self
+            .focused_header
+            .map(|column| column.prev(&columns))
+            .or_else(|| columns.first().copied());
         cx.notify();
     }
 
     pub fn header_cycle_sort_order(&mut self, cx: &mut gpui::Context<Self>) {
         if let Some(column) = self.focused_header {
-            if self.sort_state.column == column {
-                self.sort_state.direction = self.sort_state.direction.toggle();
-            } else {
-                self.sort_state.column = column;
-                self.sort_state.direction = SortDirection::Desc;
-            }
-            self.apply_sort();
-            self.recalculate_rows();
+            self.set_sort(column, cx);
+        }
+    }
+
+    fn secondary_sort_choices(&self, cx: &gpui::Context<Self>) -> Vec<SortColumn> {
+        let primary = self.active_sort_levels().first().map(|level| level.column);
+        Self::density_columns(cx.config().table_density, cx)
+            .into_iter()
+            .filter(|column| Some(*column) != primary)
+            .collect()
+    }
+
+    pub fn open_secondary_sort_picker(
+        &mut self,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let choices = self.secondary_sort_choices(cx);
+        let current = self
+            .focused_header
+            .or_else(|| self.active_sort_levels().get(1).map(|level| level.column));
+        let highlighted = current
+            .and_then(|current| choices.iter().position(|column| *column == current))
+            .unwrap_or(0);
+        self.sort_picker = Some(highlighted);
+        window.focus(&self.sort_picker_focus_handle);
+        cx.notify();
+    }
+
+    fn close_secondary_sort_picker(
+        &mut self,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if self.sort_picker.take().is_some() {
+            window.focus(&self.header_focus_handle);
             cx.notify();
         }
     }
 
+    fn pick_secondary_sort(
+        &mut self,
+        column: SortColumn,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.close_secondary_sort_picker(window, cx);
+        self.set_secondary_sort(column, cx);
+    }
+
+    fn handle_sort_picker_key_down(
+        &mut self,
+        event: &gpui::KeyDownEvent,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(highlighted) = self.sort_picker else {
+            return;
+        };
+        cx.stop_propagation();
+
+        let choices = self.secondary_sort_choices(cx);
+        match event.keystroke.key.as_str() {
+            "escape" => self.close_secondary_sort_picker(window, cx),
+            "enter" => {
+                if let Some(&column) = choices.get(highlighted) {
+                    self.pick_secondary_sort(column, window, cx);
+                }
+            }
+            "up" | "k" => {
+                self.sort_picker = Some(highlighted.saturating_sub(1));
+                cx.notify();
+            }
+            "down" | "j" => {
+                self.sort_picker = Some((highlighted + 1).min(choices.len().saturating_sub(1)));
+                cx.notify();
+            }
+            _ => {}
+        }
+    }
+
     fn close_all_dropdowns(&mut self, cx: &mut gpui::Context<Self>) {
         self.status_dropdown.update(cx, |d, cx| d.close(cx));
         self.priority_dropdown.update(cx, |d, cx| d.close(cx));
         self.due_dropdown.update(cx, |d, cx| d.close(cx));
     }
 
+    pub fn release_filter_bar(&mut self, cx: &mut gpui::Context<Self>) {
+        self.close_all_dropdowns(cx);
+        if self.filter_bar_focus != FilterBarFocus::None {
+            self.filter_bar_focus = FilterBarFocus::None;
+            cx.notify();
+        }
+    }
+
     pub fn focus_filter_next(&mut self, cx: &mut gpui::Context<Self>) {
         use FilterBarFocus::*;
         self.close_all_dropdowns(cx);
 
+        let chip_count = self.filter_state.read(cx).active_chips().len();
+
         self.filter_bar_focus = match self.filter_bar_focus {
             None | SearchInput => StatusDropdown,
             StatusDropdown => PriorityDropdown,
             PriorityDropdown => DueDropdown,
-            DueDropdown => SearchInput,
+            DueDropdown if chip_count > 0 => Chip(0),
+            Chip(idx) if idx + 1 < chip_count => Chip(idx + 1),
+            DueDropdown | Chip(_) => SearchInput,
         };
         cx.notify();
     }
@@ -853,8 +2209,12 @@ impl TaskTable {
         use FilterBarFocus::*;
         self.close_all_dropdowns(cx);
 
+        let chip_count = self.filter_state.read(cx).active_chips().len();
+
         self.filter_bar_focus = match self.filter_bar_focus {
-            None | SearchInput => DueDropdown,
+            None | SearchInput if chip_count > 0 => Chip(chip_count - 1),
+            Chip(idx) if idx > 0 && idx <= chip_count => Chip(idx - 1),
+            None | SearchInput | Chip(_) => DueDropdown,
             DueDropdown => PriorityDropdown,
             PriorityDropdown => StatusDropdown,
             StatusDropdown => SearchInput,
@@ -878,10 +2238,38 @@ impl TaskTable {
             StatusDropdown => toggle(&self.status_dropdown, cx),
             PriorityDropdown => toggle(&self.priority_dropdown, cx),
             DueDropdown => toggle(&self.due_dropdown, cx),
+            Chip(idx) => self.remove_filter_chip(idx, cx),
             _ => {}
         }
     }
 
+    fn remove_filter_chip(&mut self, index: usize, cx: &mut gpui::Context<Self>) {
+        let chips = self.filter_state.read(cx).active_chips();
+        let Some(chip) = chips.get(index).cloned() else {
+            return;
+        };
+
+        if matches!(chip, FilterChip::Search(_)) {
+            self.search_input.update(cx, |input, cx| {
+                input.clear(cx);
+            });
+        }
+        self.filter_state.update(cx, |filter, cx| {
+            filter.remove_chip(&chip);
+            cx.notify();
+        });
+
+        if let FilterBarFocus::Chip(focused) = self.filter_bar_focus {
+            let remaining = chips.len() - 1;
+            self.filter_bar_focus = if remaining == 0 {
+                FilterBarFocus::DueDropdown
+            } else {
+                FilterBarFocus::Chip(focused.min(remaining - 1))
+            };
+        }
+        cx.notify();
+    }
+
     pub fn select_next_dropdown_option(&mut self, cx: &mut gpui::Context<Self>) {
         use FilterBarFocus::*;
         let select_next = |d: &gpui::Entity<Dropdown>, cx: &mut gpui::Context<Self>| {
@@ -920,6 +2308,17 @@ impl TaskTable {
         }
     }
 
+    pub fn type_ahead_focused_dropdown(&mut self, ch: char, cx: &mut gpui::Context<Self>) -> bool {
+        use FilterBarFocus::*;
+        let dropdown = match self.filter_bar_focus {
+            StatusDropdown => &self.status_dropdown,
+            PriorityDropdown => &self.priority_dropdown,
+            DueDropdown => &self.due_dropdown,
+            _ => return false,
+        };
+        dropdown.update(cx, |d, cx| d.type_ahead(ch, cx))
+    }
+
     pub fn blur_filter_bar(&mut self, cx: &mut gpui::Context<Self>) {
         self.close_all_dropdowns(cx);
         self.filter_bar_focus = FilterBarFocus::None;
@@ -930,7 +2329,7 @@ impl TaskTable {
         use FilterBarFocus::*;
         match self.filter_bar_focus {
             SearchInput => Some(crate::keymap::ContextId::TextInput),
-            StatusDropdown | PriorityDropdown | DueDropdown => {
+            StatusDropdown | PriorityDropdown | DueDropdown | Chip(_) => {
                 Some(crate::keymap::ContextId::FilterBar)
             }
             FilterBarFocus::None => Option::None,
@@ -968,12 +2367,9 @@ impl TaskTable {
                     .text_color(theme.error)
                     .cursor_pointer()
                     .hover(|s| s.bg(theme.hover))
-                    .on_mouse_down(
-                        gpui::MouseButton::Left,
-                        cx.listener(|table, _, _, cx| {
-                            table.handle_clear_filters(cx);
-                        }),
-                    )
+                    .on_click(cx.listener(|table, _, _, cx| {
+                        table.handle_clear_filters(cx);
+                    }))
                     .child("✕ Clear");
             } else {
                 btn = btn.child(gpui::div().opacity(0.0).child("✕ Clear"));
@@ -982,6 +2378,62 @@ impl TaskTable {
             btn
         };
 
+        let search_annotations = filter.search_annotations;
+        let annotation_toggle = gpui::div()
+            .id("toggle-annotation-search")
+            .flex_shrink_0()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .text_sm()
+            .cursor_pointer()
+            .when(search_annotations, |this| {
+                this.bg(theme.selection).text_color(theme.accent)
+            })
+            .when(!search_annotations, |this| {
+                this.text_color(theme.muted).hover(|s| s.bg(theme.hover))
+            })
+            .tooltip(Tooltip::text(if search_annotations {
+                "Searching annotations (Ctrl+Shift+A)"
+            } else {
+                "Search annotations (Ctrl+Shift+A)"
+            }))
+            .on_click(cx.listener(|table, _, _, cx| {
+                table.filter_state.update(cx, |filter, cx| {
+                    filter.toggle_search_annotations();
+                    cx.notify();
+                });
+            }))
+            .child("⌕✎");
+
+        let hide_scheduled = filter.hide_scheduled;
+        let scheduled_toggle = gpui::div()
+            .id("toggle-hide-scheduled")
+            .flex_shrink_0()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .text_sm()
+            .cursor_pointer()
+            .when(hide_scheduled, |this| {
+                this.bg(theme.selection).text_color(theme.info)
+            })
+            .when(!hide_scheduled, |this| {
+                this.text_color(theme.muted).hover(|s| s.bg(theme.hover))
+            })
+            .tooltip(Tooltip::text(if hide_scheduled {
+                "Hiding tasks scheduled in the future from Pending"
+            } else {
+                "Hide tasks scheduled in the future from Pending"
+            }))
+            .on_click(cx.listener(|table, _, _, cx| {
+                table.filter_state.update(cx, |filter, cx| {
+                    filter.toggle_hide_scheduled();
+                    cx.notify();
+                });
+            }))
+            .child("⏲");
+
         use FilterBarFocus::*;
 
         let status_has_focus = matches!(self.filter_bar_focus, StatusDropdown);
@@ -1060,13 +2512,112 @@ impl TaskTable {
                     )
                     .child(self.search_input.clone()),
             )
+            .child(annotation_toggle)
+            .child(scheduled_toggle)
             .child(status_wrapper)
             .child(priority_wrapper)
             .child(due_wrapper)
             .child(clear_button);
 
+        let chips = filter.active_chips();
+        let chip_row = (!chips.is_empty()).then(|| {
+            gpui::div()
+                .id("filter-chips")
+                .flex()
+                .flex_wrap()
+                .gap_2()
+                .items_center()
+                .px_4()
+                .pb_2()
+                .children(chips.iter().enumerate().map(|(idx, chip)| {
+                    let has_focus =
+                        matches!(self.filter_bar_focus, Chip(focused) if focused == idx);
+                    gpui::div()
+                        .id(("filter-chip", idx))
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .px_2()
+                        .rounded_md()
+                        .border_1()
+                        .border_color(if has_focus {
+                            theme.focus_ring
+                        } else {
+                            theme.border
+                        })
+                        .bg(theme.panel)
+                        .text_xs()
+                        .text_color(theme.foreground)
+                        .child(chip.label())
+                        .child(
+                            gpui::div()
+                                .id(("filter-chip-remove", idx))
+                                .text_color(theme.muted)
+                                .cursor_pointer()
+                                .hover(|s| s.text_color(theme.error))
+                                .on_click(cx.listener(move |table, _, _, cx| {
+                                    table.remove_filter_chip(idx, cx);
+                                }))
+                                .child("×"),
+                        )
+                }))
+        });
+
+        let summary_row = (has_filters && !self.filter_summary.is_empty()).then(|| {
+            gpui::div()
+                .id("filter-summary")
+                .px_4()
+                .pb_2()
+                .text_xs()
+                .text_color(theme.muted)
+                .truncate()
+                .child(format!("Filtering: {}", self.filter_summary))
+        });
+
+        let progress_row = self.project_progress.as_ref().map(|progress| {
+            gpui::div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .px_4()
+                .pb_2()
+                .text_xs()
+                .text_color(theme.muted)
+                .child(
+                    gpui::div()
+                        .text_color(theme.foreground)
+                        .child(progress.project.clone()),
+                )
+                .child(
+                    gpui::div()
+                        .w(gpui::rems(10.0))
+                        .h(SIDEBAR_COMPLETION_BAR_HEIGHT)
+                        .rounded_full()
+                        .overflow_hidden()
+                        .bg(theme.border)
+                        .child(
+                            gpui::div()
+                                .h_full()
+                                .w(gpui::relative(progress.ratio()))
+                                .bg(theme.success),
+                        ),
+                )
+                .child(format!(
+                    "{}/{} done ({:.0}%)",
+                    progress.completed,
+                    progress.total(),
+                    progress.ratio() * 100.0
+                ))
+        });
+
         gpui::div()
-            .child(bar)
+            .child(
+                gpui::div()
+                    .child(bar)
+                    .children(progress_row)
+                    .children(chip_row)
+                    .children(summary_row),
+            )
             .on_children_prepainted(move |bounds, _, cx| {
                 let Some(bounds) = bounds.first() else {
                     return;
@@ -1088,13 +2639,17 @@ impl TaskTable {
         cx: &gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         let theme = cx.theme();
-        let is_sorted = self.sort_state.column == column;
+        let sort_levels = self.active_sort_levels();
+        let sort_level = sort_levels.iter().position(|level| level.column == column);
+        let is_sorted = sort_level.is_some();
+        let is_primary = sort_level == Some(0);
         let is_focused = self.focused_header == Some(column);
-        let arrow = if is_sorted {
-            self.sort_state.direction.arrow()
-        } else {
-            ""
-        };
+        let arrow = sort_level
+            .map(|level| sort_levels[level].direction.arrow())
+            .unwrap_or("");
+        let level_badge = sort_level
+            .filter(|_| sort_levels.len() > 1)
+            .map(|level| (level + 1).to_string());
 
         gpui::div()
             .id(id)
@@ -1110,12 +2665,26 @@ impl TaskTable {
                     .mx(gpui::px(-1.0))
             })
             .hover(|s| s.text_color(theme.foreground))
-            .on_mouse_down(
-                gpui::MouseButton::Left,
-                cx.listener(move |table, _, _, cx| {
+            .on_click(cx.listener(move |table, event: &gpui::ClickEvent, _, cx| {
+                if event.modifiers().shift {
+                    table.set_secondary_sort(column, cx);
+                } else {
                     table.set_sort(column, cx);
-                }),
-            )
+                }
+            }))
+            .on_drag(DraggedColumn(column), |dragged, _offset, _window, cx| {
+                cx.new(|_cx| DraggedColumnView {
+                    label: dragged.0.label(),
+                })
+            })
+            .drag_over::<DraggedColumn>(move |style, _dragged, _window, cx| {
+                style.border_l_2().border_color(cx.theme().accent)
+            })
+            .on_drop(cx.listener(move |table, dragged: &DraggedColumn, _, cx| {
+                table.update_table_config(cx, |config| {
+                    config.move_column(dragged.0, column);
+                });
+            }))
             .child(
                 components::label::Label::new(column.label()).text_color(if is_sorted {
                     theme.accent
@@ -1124,136 +2693,804 @@ impl TaskTable {
                 }),
             )
             .when(!arrow.is_empty(), |div| {
-                div.child(components::label::Label::new(arrow).text_color(theme.accent))
+                div.child(if is_primary {
+                    components::label::Label::new(arrow).text_color(theme.accent)
+                } else {
+                    components::label::Label::new(arrow)
+                        .text_color(theme.muted)
+                        .text_xs()
+                })
+            })
+            .when_some(level_badge, |div, badge| {
+                div.child(
+                    gpui::div()
+                        .px_1()
+                        .rounded_sm()
+                        .text_xs()
+                        .bg(Theme::alpha(theme.accent, 0.2))
+                        .text_color(theme.accent)
+                        .child(badge),
+                )
+            })
+    }
+
+    fn column_cell(column: SortColumn) -> gpui::Div {
+        let cell = gpui::div();
+        match column {
+            SortColumn::Id => cell.min_w(table_col_id_width()),
+            SortColumn::Description => cell.flex_1().min_w(table_col_desc_min_width()),
+            SortColumn::Project => cell.w(table_col_project_width()),
+            SortColumn::Due => cell.w(table_col_due_width()),
+            SortColumn::Scheduled => cell.w(table_col_scheduled_width()),
+            SortColumn::EntryDate => cell.w(table_col_entry_width()),
+            SortColumn::ModifiedDate => cell.w(table_col_modified_width()),
+            SortColumn::Priority => cell.w(table_col_priority_width()),
+            SortColumn::Urgency => cell.w(table_col_urgency_width()),
+            SortColumn::Status => cell.w(table_col_status_width()),
+        }
+    }
+
+    fn extra_column_cell() -> gpui::Div {
+        gpui::div()
+            .w(table_col_uda_width())
+            .flex_shrink_0()
+            .overflow_x_hidden()
+    }
+
+    fn toggle_columns_menu(&mut self, cx: &mut gpui::Context<Self>) {
+        self.columns_menu_open = !self.columns_menu_open;
+        cx.notify();
+    }
+
+    fn close_columns_menu(&mut self, cx: &mut gpui::Context<Self>) {
+        if self.columns_menu_open {
+            self.columns_menu_open = false;
+            cx.notify();
+        }
+    }
+
+    fn update_table_config(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+        update: impl FnOnce(&mut TableConfig),
+    ) {
+        cx.update_global::<AppConfig, _>(|config, _cx| update(&mut config.table));
+        if let Err(e) = cx.config().save() {
+            log::error!("[TaskTable] Failed to save table columns: {}", e);
+        }
+
+        let columns = cx.config().table.columns();
+        if let Some(focused) = self.focused_header {
+            if !columns.contains(&focused) {
+                self.focused_header = columns.first().copied();
+            }
+        }
+        cx.notify();
+    }
+
+    fn render_columns_menu(&self, cx: &gpui::Context<Self>) -> gpui::Div {
+        let theme = cx.theme();
+        let table_config = &cx.config().table;
+
+        let trigger = gpui::div()
+            .id("columns-menu-trigger")
+            .px_1()
+            .rounded_sm()
+            .text_color(theme.muted)
+            .cursor_pointer()
+            .hover(|s| s.text_color(theme.accent))
+            .tooltip(Tooltip::text("Show, hide and reorder columns"))
+            .on_click(cx.listener(|table, _, _, cx| table.toggle_columns_menu(cx)))
+            .child(components::label::Label::new("Columns ▾"));
+
+        let rows = table_config
+            .column_order
+            .iter()
+            .enumerate()
+            .map(|(index, &column)| {
+                let visible = table_config.is_visible(column);
+                let locked = column == SortColumn::Description;
+                let arrow = |id: &'static str, label: &'static str, offset: isize| {
+                    gpui::div()
+                        .id((id, index))
+                        .px_1()
+                        .text_color(theme.muted)
+                        .cursor_pointer()
+                        .hover(|s| s.text_color(theme.accent))
+                        .on_click(cx.listener(move |table, _, _, cx| {
+                            table.update_table_config(cx, |config| {
+                                config.shift_column(column, offset);
+                            });
+                        }))
+                        .child(label)
+                };
+
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .px_2()
+                    .py_1()
+                    .rounded_sm()
+                    .hover(|s| s.bg(theme.hover))
+                    .child(
+                        gpui::div()
+                            .id(("column-toggle", index))
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .when(!locked, |el| {
+                                el.cursor_pointer()
+                                    .on_click(cx.listener(move |table, _, _, cx| {
+                                        table.update_table_config(cx, |config| {
+                                            config.toggle_column(column);
+                                        });
+                                    }))
+                            })
+                            .child(
+                                components::label::Label::new(if visible { "[x]" } else { "[ ]" })
+                                    .text_color(if locked { theme.muted } else { theme.accent }),
+                            )
+                            .child(
+                                components::label::Label::new(column.label())
+                                    .text_color(theme.foreground),
+                            ),
+                    )
+                    .child(
+                        gpui::div()
+                            .flex()
+                            .child(arrow("column-up", "↑", -1))
+                            .child(arrow("column-down", "↓", 1)),
+                    )
+            });
+
+        gpui::div()
+            .relative()
+            .flex()
+            .justify_end()
+            .flex_shrink_0()
+            .w(table_col_menu_width())
+            .child(trigger)
+            .when(self.columns_menu_open, |el| {
+                el.on_mouse_down_out(cx.listener(|table, _, _, cx| {
+                    table.close_columns_menu(cx);
+                }))
+                .child(
+                    gpui::div()
+                        .id("columns-menu")
+                        .absolute()
+                        .top_full()
+                        .right_0()
+                        .mt_1()
+                        .min_w(gpui::rems(12.0))
+                        .p_1()
+                        .occlude()
+                        .border_1()
+                        .border_color(theme.border)
+                        .bg(theme.background)
+                        .rounded_md()
+                        .shadow_lg()
+                        .font_weight(gpui::FontWeight::NORMAL)
+                        .children(rows),
+                )
             })
     }
 
-    fn render_header(&self, cx: &gpui::Context<Self>) -> gpui::Div {
+    fn render_header_cell(&self, column: SortColumn, cx: &gpui::Context<Self>) -> gpui::Div {
+        let theme = cx.theme();
+        let cell = Self::column_cell(column);
+        let cell = if column == SortColumn::Id {
+            cell.flex()
+                .items_center()
+                .gap_1()
+                .child(components::label::Label::new(" ").text_color(theme.muted))
+        } else {
+            cell
+        };
+        cell.child(self.render_header_column(column, column.header_id(), cx))
+    }
+
+    fn render_header(
+        &self,
+        density: TableDensity,
+        pinned_offset: Option<gpui::Pixels>,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Div {
         let theme = cx.theme();
+        let columns = Self::density_columns(density, cx);
+        let pinned_header = pinned_offset.map(|offset| {
+            gpui::div()
+                .id("task-table-pinned-header")
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .left(offset)
+                .w(table_pinned_width())
+                .flex()
+                .items_center()
+                .gap_2()
+                .pl_4()
+                .bg(theme.raised)
+                .border_r_1()
+                .border_color(theme.divider)
+                .children(
+                    columns
+                        .iter()
+                        .filter(|column| column.is_pinned())
+                        .map(|column| self.render_header_cell(*column, cx)),
+                )
+        });
 
         gpui::div()
             .track_focus(&self.header_focus_handle)
+            .relative()
             .flex()
             .flex_shrink_0()
             .items_center()
             .gap_2()
             .px_4()
-            .py_2()
+            .map(|d| match density {
+                TableDensity::Compact => d.py_1().text_xs(),
+                TableDensity::Normal | TableDensity::Comfortable => d.py_2().text_sm(),
+            })
             .bg(theme.raised)
             .border_b_1()
             .border_color(theme.divider)
-            .text_sm()
             .font_weight(gpui::FontWeight::MEDIUM)
-            .child(
-                gpui::div()
-                    .min_w(table_col_id_width())
+            .children(
+                columns
+                    .into_iter()
+                    .map(|column| self.render_header_cell(column, cx)),
+            )
+            .children(cx.config().extra_columns.iter().map(|key| {
+                Self::extra_column_cell()
+                    .child(components::label::Label::new(key.clone()).text_color(theme.muted))
+            }))
+            .child(self.render_columns_menu(cx))
+            .children(pinned_header)
+            .children(self.render_secondary_sort_picker(cx))
+    }
+
+    fn render_secondary_sort_picker(
+        &self,
+        cx: &gpui::Context<Self>,
+    ) -> Option<gpui::Stateful<gpui::Div>> {
+        let highlighted = self.sort_picker?;
+        let theme = cx.theme();
+        let secondary = self.active_sort_levels().get(1).copied();
+
+        let rows =
+            self.secondary_sort_choices(cx)
+                .into_iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    let arrow = secondary
+                        .filter(|level| level.column == column)
+                        .map(|level| level.direction.arrow())
+                        .unwrap_or("");
+                    gpui::div()
+                        .id(("secondary-sort-choice", index))
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .gap_2()
+                        .px_2()
+                        .py_1()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .when(index == highlighted, |el| el.bg(theme.hover))
+                        .hover(|s| s.bg(theme.hover))
+                        .on_click(cx.listener(move |table, _, window, cx| {
+                            table.pick_secondary_sort(column, window, cx);
+                        }))
+                        .child(
+                            components::label::Label::new(column.label())
+                                .text_color(theme.foreground),
+                        )
+                        .child(components::label::Label::new(arrow).text_color(theme.muted))
+                });
+
+        Some(
+            gpui::div()
+                .id("secondary-sort-picker")
+                .track_focus(&self.sort_picker_focus_handle)
+                .on_key_down(cx.listener(Self::handle_sort_picker_key_down))
+                .on_mouse_down_out(cx.listener(|table, _, window, cx| {
+                    table.close_secondary_sort_picker(window, cx);
+                }))
+                .absolute()
+                .top_full()
+                .left_4()
+                .mt_1()
+                .min_w(gpui::rems(12.0))
+                .p_1()
+                .occlude()
+                .border_1()
+                .border_color(theme.border)
+                .bg(theme.background)
+                .rounded_md()
+                .shadow_lg()
+                .font_weight(gpui::FontWeight::NORMAL)
+                .child(
+                    gpui::div()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(theme.muted)
+                        .child("Then sort by"),
+                )
+                .children(rows),
+        )
+    }
+
+    fn render_row_cell(
+        &self,
+        column: SortColumn,
+        idx: usize,
+        row: &TaskRow,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::AnyElement {
+        let theme = cx.theme();
+        let cell = Self::column_cell(column);
+
+        match column {
+            SortColumn::Id => {
+                let selected = self.selected_page_idx == Some(idx);
+                let dependency_hint = match (row.is_blocked, row.is_blocking) {
+                    (true, true) => Some("Blocked, and blocking other tasks"),
+                    (true, false) => Some("Blocked by pending tasks"),
+                    (false, true) => Some("Blocking other tasks"),
+                    (false, false) => None,
+                };
+                cell.id(("row-id", idx))
                     .flex()
                     .items_center()
                     .gap_1()
-                    .child(components::label::Label::new(" ").text_color(theme.muted))
-                    .child(self.render_header_column(SortColumn::Id, "header-id", cx)),
-            )
-            .child(
-                gpui::div()
-                    .flex_1()
-                    .min_w(table_col_desc_min_width())
-                    .child(self.render_header_column(SortColumn::Description, "header-desc", cx)),
-            )
-            .child(
-                gpui::div()
-                    .w(table_col_project_width())
-                    .child(self.render_header_column(SortColumn::Project, "header-project", cx)),
-            )
-            .child(
-                gpui::div()
-                    .w(table_col_due_width())
-                    .child(self.render_header_column(SortColumn::Due, "header-due", cx)),
-            )
-            .child(
-                gpui::div()
-                    .w(table_col_priority_width())
-                    .child(self.render_header_column(SortColumn::Priority, "header-priority", cx)),
-            )
-            .child(
-                gpui::div()
-                    .w(gpui::rems(6.0))
-                    .child(self.render_header_column(SortColumn::Status, "header-status", cx)),
-            )
+                    .when_some(dependency_hint, |d, hint| d.tooltip(Tooltip::text(hint)))
+                    .child(
+                        components::label::Label::new(if row.is_pinned {
+                            "★"
+                        } else if selected {
+                            ">"
+                        } else {
+                            " "
+                        })
+                        .text_color(if row.is_pinned {
+                            theme.warning
+                        } else {
+                            theme.accent
+                        }),
+                    )
+                    .child(components::label::Label::new(row.id_display.clone()))
+                    .when(row.is_blocked, |d| {
+                        d.child(
+                            gpui::div()
+                                .text_xs()
+                                .text_color(theme.task_blocked)
+                                .child("⊘"),
+                        )
+                    })
+                    .when(row.is_blocking, |d| {
+                        d.child(
+                            gpui::div()
+                                .text_xs()
+                                .text_color(theme.task_blocking)
+                                .child("◆"),
+                        )
+                    })
+                    .into_any_element()
+            }
+            SortColumn::Description => {
+                let show_inline_tags = cx.config().show_inline_tags && !row.tags.is_empty();
+                let is_expanded = self.expanded_task == Some(row.uuid);
+                let selected = self.selected_page_idx == Some(idx);
+                let wrap_lines = cx
+                    .config()
+                    .wrap_descriptions
+                    .then_some(cx.config().max_description_display_lines);
+
+                cell.id(("row-description", idx))
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .overflow_x_hidden()
+                    .when(!is_expanded, |d| {
+                        d.tooltip(Tooltip::text(row.description.clone()))
+                    })
+                    .child(
+                        components::label::Label::new(row.description.clone())
+                            .min_w_0()
+                            .overflow_hidden()
+                            .when(row.scheduled_future && !selected, |label| {
+                                label.text_color(theme.task_scheduled)
+                            })
+                            .when(!is_expanded, |label| match wrap_lines {
+                                Some(lines) => {
+                                    label.whitespace_normal().text_ellipsis().line_clamp(lines)
+                                }
+                                None => label.text_ellipsis().whitespace_nowrap(),
+                            }),
+                    )
+                    .when(row.annotation_count > 0, |d| {
+                        d.child(
+                            gpui::div()
+                                .flex_shrink_0()
+                                .text_xs()
+                                .text_color(theme.muted)
+                                .child(format!("📎 {}", row.annotation_count)),
+                        )
+                    })
+                    .when(show_inline_tags, |d| {
+                        let hidden = row.tags.len().saturating_sub(TABLE_MAX_INLINE_TAGS);
+                        d.children(
+                            row.tags
+                                .iter()
+                                .take(TABLE_MAX_INLINE_TAGS)
+                                .map(|tag| tag_chip(tag, theme).flex_shrink_0()),
+                        )
+                        .when(hidden > 0, |d| {
+                            d.child(
+                                gpui::div()
+                                    .flex_shrink_0()
+                                    .text_xs()
+                                    .text_color(theme.muted)
+                                    .child(format!("+{}", hidden)),
+                            )
+                        })
+                    })
+                    .into_any_element()
+            }
+            SortColumn::Project => cell
+                .id(("row-project", idx))
+                .overflow_x_hidden()
+                .when(!row.project.is_empty(), |d| {
+                    d.tooltip(Tooltip::text(row.project.clone()))
+                })
+                .child(
+                    components::label::Label::new(row.project.clone())
+                        .text_color(theme.muted)
+                        .text_ellipsis()
+                        .whitespace_nowrap(),
+                )
+                .into_any_element(),
+            SortColumn::Due => {
+                let tooltip = match &row.wait {
+                    Some(_) if row.due_absolute.is_empty() => {
+                        format!("Waiting until {}", row.wait_absolute)
+                    }
+                    Some(_) => {
+                        format!("{} (waiting until {})", row.due_absolute, row.wait_absolute)
+                    }
+                    None => row.due_absolute.clone(),
+                };
+                cell.id(("row-due", idx))
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .overflow_x_hidden()
+                    .whitespace_nowrap()
+                    .when(!tooltip.is_empty(), |d| d.tooltip(Tooltip::text(tooltip)))
+                    .when(row.wait.is_none() || !row.due_absolute.is_empty(), |d| {
+                        d.child(
+                            components::label::Label::new(row.due.clone())
+                                .text_color(self.due_color(row, cx)),
+                        )
+                    })
+                    .when_some(row.wait.clone(), |d, wait| {
+                        d.child(
+                            components::label::Label::new(wait)
+                                .text_xs()
+                                .text_color(theme.info),
+                        )
+                    })
+                    .into_any_element()
+            }
+            SortColumn::Scheduled => cell
+                .id(("row-scheduled", idx))
+                .when(!row.scheduled_absolute.is_empty(), |d| {
+                    d.tooltip(Tooltip::text(row.scheduled_absolute.clone()))
+                })
+                .child(
+                    components::label::Label::new(row.scheduled.clone()).text_color(
+                        if row.scheduled_future {
+                            theme.task_scheduled
+                        } else {
+                            theme.muted
+                        },
+                    ),
+                )
+                .into_any_element(),
+            SortColumn::EntryDate => cell
+                .id(("row-entry", idx))
+                .when(!row.entry_absolute.is_empty(), |d| {
+                    d.tooltip(Tooltip::text(row.entry_absolute.clone()))
+                })
+                .child(components::label::Label::new(row.entry.clone()).text_color(theme.muted))
+                .into_any_element(),
+            SortColumn::ModifiedDate => cell
+                .id(("row-modified", idx))
+                .when(!row.modified_absolute.is_empty(), |d| {
+                    d.tooltip(Tooltip::text(row.modified_absolute.clone()))
+                })
+                .child(components::label::Label::new(row.modified.clone()).text_color(theme.muted))
+                .into_any_element(),
+            SortColumn::Priority => cell
+                .child(priority_badge(&row.priority, theme))
+                .into_any_element(),
+            SortColumn::Urgency => cell
+                .child(components::label::Label::new(row.urgency.clone()).text_color(theme.muted))
+                .into_any_element(),
+            SortColumn::Status => cell
+                .child(
+                    components::label::Label::new(row.status.clone())
+                        .text_color(self.status_color(row, cx)),
+                )
+                .into_any_element(),
+        }
     }
 
-    fn render_row(&self, idx: usize, row: &TaskRow, cx: &gpui::Context<Self>) -> gpui::Div {
+    fn density_columns(density: TableDensity, cx: &gpui::Context<Self>) -> Vec<SortColumn> {
+        cx.config()
+            .table
+            .columns()
+            .into_iter()
+            .filter(|column| density.shows_column(*column))
+            .collect()
+    }
+
+    fn render_row(
+        &self,
+        idx: usize,
+        row: &TaskRow,
+        density: TableDensity,
+        pinned_offset: Option<gpui::Pixels>,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Stateful<gpui::Div> {
         let theme = cx.theme();
         let selected = self.selected_page_idx == Some(idx);
+        let marked = self.marked_tasks.contains(&row.uuid);
         let row_uuid = row.uuid;
+        let columns = Self::density_columns(density, cx);
+        let pinned = pinned_offset.map(|offset| {
+            let pinned_columns: Vec<SortColumn> = columns
+                .iter()
+                .copied()
+                .filter(|column| column.is_pinned())
+                .collect();
+            self.render_pinned_cells(idx, row, &pinned_columns, offset, density, cx)
+        });
 
         gpui::div()
+            .id(("task-row", idx))
+            .relative()
             .flex()
             .items_center()
             .gap_2()
             .px_4()
-            .py_1()
+            .map(|d| match density {
+                TableDensity::Compact => d.py_0().text_xs(),
+                TableDensity::Normal => d.py_1(),
+                TableDensity::Comfortable => d.py_2(),
+            })
             .border_b_1()
             .border_color(theme.divider)
             .text_color(theme.foreground)
+            .when(marked && !selected, |d| {
+                d.bg(Theme::alpha(theme.accent, 0.12))
+            })
             .when(selected, |d| {
                 d.bg(theme.selection).text_color(theme.selection_foreground)
             })
             .when(!selected, |d| d.hover(|s| s.bg(theme.hover)))
-            .cursor_pointer()
-            .on_mouse_down(
-                gpui::MouseButton::Left,
-                cx.listener(move |table, event: &gpui::MouseDownEvent, _window, cx| {
+            .map(|d| Self::with_row_handlers(d, idx, row_uuid, cx))
+            .children(
+                columns
+                    .into_iter()
+                    .map(|column| self.render_row_cell(column, idx, row, cx)),
+            )
+            .children(
+                cx.config()
+                    .extra_columns
+                    .iter()
+                    .enumerate()
+                    .map(|(column, key)| {
+                        let value = row.udas.get(key).cloned();
+                        Self::extra_column_cell()
+                            .id(("row-uda", column))
+                            .when_some(value.clone(), |d, value| d.tooltip(Tooltip::text(value)))
+                            .child(
+                                components::label::Label::new(
+                                    value.unwrap_or_else(|| "-".to_string()),
+                                )
+                                .text_color(theme.muted),
+                            )
+                    }),
+            )
+            .child(gpui::div().w(table_col_menu_width()).flex_shrink_0())
+            .children(pinned)
+    }
+
+    fn with_row_handlers(
+        row: gpui::Stateful<gpui::Div>,
+        idx: usize,
+        row_uuid: uuid::Uuid,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Stateful<gpui::Div> {
+        row.cursor_pointer()
+            .on_click(
+                cx.listener(move |table, event: &gpui::ClickEvent, _window, cx| {
+                    if event.modifiers().secondary() {
+                        table.toggle_task_mark(row_uuid, cx);
+                        return;
+                    }
                     table.select_row(idx, cx);
-                    if event.click_count >= 2 {
+                    if event.click_count() >= 2 {
                         cx.emit(TaskTableEvent::OpenTask(row_uuid));
                     }
                 }),
             )
-            .child(
-                gpui::div()
-                    .min_w(table_col_id_width())
-                    .flex()
-                    .items_center()
-                    .gap_1()
-                    .child(
-                        components::label::Label::new(if selected { ">" } else { " " })
-                            .text_color(theme.accent),
-                    )
-                    .child(components::label::Label::new(row.id_display.clone())),
-            )
-            .child(
-                gpui::div()
-                    .flex_1()
-                    .min_w(table_col_desc_min_width())
-                    .overflow_x_hidden()
-                    .child(
-                        components::label::Label::new(row.description.clone())
-                            .text_ellipsis()
-                            .whitespace_nowrap(),
-                    ),
-            )
-            .child(
-                gpui::div().w(gpui::rems(10.0)).overflow_x_hidden().child(
-                    components::label::Label::new(row.project.clone())
-                        .text_color(theme.muted)
-                        .text_ellipsis()
-                        .whitespace_nowrap(),
-                ),
+            .on_mouse_down(
+                gpui::MouseButton::Right,
+                cx.listener(move |table, event: &gpui::MouseDownEvent, _window, cx| {
+                    table.open_context_menu(idx, event.position, cx);
+                }),
             )
-            .child(gpui::div().w(gpui::rems(7.0)).child(
-                components::label::Label::new(row.due.clone()).text_color(self.due_color(row, cx)),
-            ))
-            .child(
-                gpui::div()
-                    .w(table_col_priority_width())
-                    .child(priority_badge(&row.priority, theme)),
+    }
+
+    // Pinned cells are drawn over the scrolled row, so they need an opaque background.
+    fn render_pinned_cells(
+        &self,
+        idx: usize,
+        row: &TaskRow,
+        columns: &[SortColumn],
+        offset: gpui::Pixels,
+        density: TableDensity,
+        cx: &gpui::Context<Self>,
+    ) -> gpui::Stateful<gpui::Div> {
+        let theme = cx.theme();
+        let selected = self.selected_page_idx == Some(idx);
+        let marked = self.marked_tasks.contains(&row.uuid);
+        let background = if selected {
+            theme.selection
+        } else if marked {
+            mix_color(theme.background, theme.accent, 0.12)
+        } else {
+            theme.background
+        };
+
+        gpui::div()
+            .id(("task-row-pinned", idx))
+            .absolute()
+            .left(offset)
+            .top_0()
+            .bottom_0()
+            .w(table_pinned_width())
+            .flex()
+            .items_center()
+            .gap_2()
+            .pl_4()
+            .when(density == TableDensity::Compact, |d| d.text_xs())
+            .border_r_1()
+            .border_color(theme.divider)
+            .bg(background)
+            .text_color(if selected {
+                theme.selection_foreground
+            } else {
+                theme.foreground
+            })
+            .children(
+                columns
+                    .iter()
+                    .map(|column| self.render_row_cell(*column, idx, row, cx)),
             )
-            .child(
-                gpui::div().w(table_col_status_width()).child(
-                    components::label::Label::new(row.status.clone())
-                        .text_color(self.status_color(row, cx)),
+    }
+
+    fn render_bulk_bar(&self, cx: &gpui::Context<Self>) -> Option<gpui::Div> {
+        let count = self.marked_tasks.len();
+        if count == 0 {
+            return None;
+        }
+
+        let theme = cx.theme();
+        let busy = self.bulk_busy;
+        let button = |id: &'static str, label: String| {
+            gpui::div()
+                .id(id)
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .border_1()
+                .border_color(theme.divider)
+                .text_color(if busy {
+                    theme.disabled_fg
+                } else {
+                    theme.foreground
+                })
+                .when(!busy, |d| d.cursor_pointer().hover(|s| s.bg(theme.hover)))
+                .when(busy, |d| d.cursor_not_allowed())
+                .child(components::label::Label::new(label))
+        };
+
+        Some(
+            gpui::div()
+                .flex()
+                .flex_shrink_0()
+                .justify_between()
+                .items_center()
+                .px_4()
+                .py_2()
+                .border_t_1()
+                .border_color(theme.divider)
+                .bg(Theme::alpha(theme.accent, 0.08))
+                .text_sm()
+                .child(
+                    gpui::div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .child(
+                            components::label::Label::new(format!("{} selected", count))
+                                .text_color(theme.foreground),
+                        )
+                        .when(busy, |d| {
+                            d.child(
+                                components::label::Label::new("◌ Working…").text_color(theme.muted),
+                            )
+                        }),
+                )
+                .child(
+                    gpui::div()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .child(
+                            button("bulk-complete-btn", format!("Complete ({})", count)).on_click(
+                                cx.listener(|table, _, _, cx| {
+                                    table.request_bulk_action(BulkAction::Complete, cx)
+                                }),
+                            ),
+                        )
+                        .child(
+                            button("bulk-delete-btn", format!("Delete ({})", count)).on_click(
+                                cx.listener(|table, _, _, cx| {
+                                    table.request_bulk_action(BulkAction::Delete, cx)
+                                }),
+                            ),
+                        )
+                        .child(
+                            button("bulk-project-btn", "Set Project…".to_string()).on_click(
+                                cx.listener(|table, _, window, cx| {
+                                    if !table.bulk_busy {
+                                        table.open_task_prompt(
+                                            TaskPromptKind::BulkProject,
+                                            window,
+                                            cx,
+                                        );
+                                    }
+                                }),
+                            ),
+                        )
+                        .child(button("bulk-tag-btn", "Add Tag…".to_string()).on_click(
+                            cx.listener(|table, _, window, cx| {
+                                if !table.bulk_busy {
+                                    table.open_task_prompt(TaskPromptKind::BulkTag, window, cx);
+                                }
+                            }),
+                        ))
+                        .child(
+                            gpui::div()
+                                .id("bulk-clear-btn")
+                                .px_2()
+                                .py_1()
+                                .text_color(theme.muted)
+                                .cursor_pointer()
+                                .hover(|s| s.text_color(theme.accent))
+                                .on_click(cx.listener(|table, _, _, cx| table.clear_marks(cx)))
+                                .child(components::label::Label::new("Clear")),
+                        ),
                 ),
-            )
+        )
     }
 
     fn render_footer(&self, cx: &gpui::Context<Self>) -> gpui::Div {
@@ -1262,6 +3499,24 @@ impl TaskTable {
         let can_next = self.pagination.can_next();
         let pages = (self.pagination.total_items + self.pagination.page_size - 1)
             / self.pagination.page_size.max(1);
+        let prompt_button =
+            |id: &'static str, label: &'static str, tooltip: &'static str, kind: TaskPromptKind| {
+                gpui::div()
+                    .id(id)
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(theme.divider)
+                    .text_color(theme.foreground)
+                    .cursor_pointer()
+                    .hover(|s| s.bg(theme.hover))
+                    .tooltip(Tooltip::text(tooltip))
+                    .on_click(cx.listener(move |table, _, window, cx| {
+                        table.open_task_prompt(kind, window, cx);
+                    }))
+                    .child(components::label::Label::new(label))
+            };
 
         gpui::div()
             .flex()
@@ -1275,13 +3530,37 @@ impl TaskTable {
             .bg(theme.raised)
             .text_sm()
             .child(
-                components::label::Label::new(format!(
-                    "Showing {}-{} of {}",
-                    self.pagination.first_item_index() + 1,
-                    self.pagination.last_item_display(),
-                    self.pagination.total_items
-                ))
-                .text_color(theme.muted),
+                gpui::div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        components::label::Label::new(format!(
+                            "Showing {}-{} of {}",
+                            self.pagination.first_item_index() + 1,
+                            self.pagination.last_item_display(),
+                            self.pagination.total_items
+                        ))
+                        .text_color(theme.muted),
+                    )
+                    .child(prompt_button(
+                        "add-task-btn",
+                        "Add",
+                        "Add a task (project:, +tag, priority:, due:, wait:)",
+                        TaskPromptKind::QuickAdd,
+                    ))
+                    .child(prompt_button(
+                        "export-btn",
+                        "Export",
+                        "Export the filtered tasks (.json or .csv)",
+                        TaskPromptKind::Export,
+                    ))
+                    .child(prompt_button(
+                        "import-btn",
+                        "Import",
+                        "Import tasks from a Taskwarrior JSON export",
+                        TaskPromptKind::Import,
+                    )),
             )
             .child(
                 gpui::div()
@@ -1317,8 +3596,7 @@ impl TaskTable {
                                         d.cursor_pointer().hover(|s| s.bg(theme.hover))
                                     })
                                     .when(!can_prev, |d| d.cursor_not_allowed())
-                                    .on_mouse_down(
-                                        gpui::MouseButton::Left,
+                                    .on_click(
                                         cx.listener(|table, _, _, cx| table.go_previous_page(cx)),
                                     )
                                     .child(components::label::Label::new("← Prev")),
@@ -1340,10 +3618,7 @@ impl TaskTable {
                                         d.cursor_pointer().hover(|s| s.bg(theme.hover))
                                     })
                                     .when(!can_next, |d| d.cursor_not_allowed())
-                                    .on_mouse_down(
-                                        gpui::MouseButton::Left,
-                                        cx.listener(|table, _, _, cx| table.go_next_page(cx)),
-                                    )
+                                    .on_click(cx.listener(|table, _, _, cx| table.go_next_page(cx)))
                                     .child(components::label::Label::new("Next →")),
                             ),
                     ),
@@ -1382,6 +3657,18 @@ impl CommandDispatcher for TaskTable {
                 self.clear_selection(cx);
                 true
             }
+            Command::ToggleRowExpansion => {
+                self.toggle_selected_expansion(cx);
+                true
+            }
+            Command::ToggleTaskMark => {
+                self.toggle_selected_mark(cx);
+                true
+            }
+            Command::OpenContextMenu => {
+                self.open_selected_context_menu(cx);
+                true
+            }
             Command::FocusFilterNext | Command::FocusFilterPrev => false,
             Command::ToggleDropdown => {
                 self.toggle_focused_dropdown(cx);
@@ -1396,7 +3683,12 @@ impl CommandDispatcher for TaskTable {
                 true
             }
             Command::BlurInput => {
-                self.blur_filter_bar(cx);
+                let has_search = !self.search_input.read(cx).value().is_empty();
+                if self.filter_bar_focus == FilterBarFocus::SearchInput && has_search {
+                    self.clear_search(cx);
+                } else {
+                    self.blur_filter_bar(cx);
+                }
                 true
             }
             Command::ExpandProject | Command::CollapseProject => false,
@@ -1405,12 +3697,66 @@ impl CommandDispatcher for TaskTable {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+struct DraggedColumn(SortColumn);
+
+struct DraggedColumnView {
+    label: &'static str,
+}
+
+impl gpui::Render for DraggedColumnView {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> impl gpui::IntoElement {
+        let theme = cx.theme();
+
+        gpui::div()
+            .px_2()
+            .py_1()
+            .bg(theme.raised)
+            .border_1()
+            .border_color(theme.accent)
+            .rounded_md()
+            .shadow_lg()
+            .text_sm()
+            .text_color(theme.foreground)
+            .child(self.label)
+    }
+}
+
 pub enum TaskTableEvent {
     OpenTask(uuid::Uuid),
+    SelectionChanged(Option<uuid::Uuid>),
+    Command(Command),
+    AddTask(String),
+    PromptSubmitted {
+        kind: TaskPromptKind,
+        uuid: uuid::Uuid,
+        value: String,
+    },
+    BulkAction {
+        action: BulkAction,
+        uuids: Vec<uuid::Uuid>,
+    },
+    Export(PathBuf),
+    Import(PathBuf),
+    RenameProject {
+        from: String,
+        to: String,
+    },
 }
 
 impl gpui::EventEmitter<TaskTableEvent> for TaskTable {}
 
+// Keeps the nested table scrollers from turning vertical wheel deltas into
+// horizontal scrolling (and vice versa).
+fn restrict_scroll_to_axis(mut div: gpui::Stateful<gpui::Div>) -> gpui::Stateful<gpui::Div> {
+    div.style().restrict_scroll_to_axis = Some(true);
+    div
+}
+
 impl gpui::Render for TaskTable {
     fn render(
         &mut self,
@@ -1437,16 +3783,24 @@ impl gpui::Render for TaskTable {
             );
         }
 
-        let current_page = self.get_current_page_rows();
-        let rows: Vec<gpui::Div> = current_page
+        let density = cx.config().table_density;
+
+        // Horizontal scroll offsets are negative; ID and Description follow the
+        // viewport's left edge once the table is scrolled sideways.
+        let scroll_x = -self.horizontal_scroll.offset().x;
+        let pinned_offset = (scroll_x > gpui::px(0.0)).then_some(scroll_x);
+        let rows: Vec<gpui::Stateful<gpui::Div>> = self
+            .get_current_page_rows()
             .iter()
             .enumerate()
-            .map(|(index, row)| self.render_row(index, row, cx))
+            .map(|(index, row)| self.render_row(index, row, density, pinned_offset, cx))
             .collect();
 
         let filter_bar = self.render_filter_bar(cx);
-        let header = self.render_header(cx);
+        let header = self.render_header(density, pinned_offset, cx);
+        let bulk_bar = self.render_bulk_bar(cx);
         let footer = self.render_footer(cx);
+        let view = cx.entity().clone();
 
         let body = gpui::div()
             .flex()
@@ -1457,29 +3811,206 @@ impl gpui::Render for TaskTable {
             .bg(theme.background)
             .child(gpui::div().h(self.filter_bar_height))
             .child(gpui::div().h_4())
-            .child(header)
             .child(
                 gpui::div()
-                    .id("task-table-content")
+                    .id("task-table-hscroll")
+                    .flex()
+                    .flex_col()
                     .flex_1()
                     .min_h_0()
-                    .overflow_y_scroll()
-                    .child(gpui::div().flex().flex_col().children(rows)),
+                    .overflow_x_scroll()
+                    .track_scroll(&self.horizontal_scroll)
+                    .map(restrict_scroll_to_axis)
+                    .child(
+                        gpui::div()
+                            .flex()
+                            .flex_col()
+                            .flex_1()
+                            .min_h_0()
+                            .min_w(table_min_width())
+                            .child(header)
+                            .child(
+                                gpui::div()
+                                    .id("task-table-content")
+                                    .flex_1()
+                                    .min_h_0()
+                                    .overflow_y_scroll()
+                                    .map(restrict_scroll_to_axis)
+                                    .child(
+                                        gpui::div()
+                                            .flex()
+                                            .flex_col()
+                                            .children(rows)
+                                            .on_children_prepainted(move |bounds, _, cx| {
+                                                cx.update_entity(&view, |table, _cx| {
+                                                    table.row_bounds = bounds;
+                                                });
+                                            }),
+                                    ),
+                            ),
+                    ),
             )
+            .children(bulk_bar)
             .child(footer);
 
         gpui::div()
             .size_full()
             .track_focus(&self.filter_bar_focus_handle)
             .child(
-                panel.child(body).child(
-                    gpui::div()
-                        .absolute()
-                        .top_0()
-                        .left_0()
-                        .right_0()
-                        .child(filter_bar),
-                ),
+                panel
+                    .child(body)
+                    .child(
+                        gpui::div()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .right_0()
+                            .child(filter_bar),
+                    )
+                    .child(self.render_jump_to_id(cx))
+                    .child(self.render_task_prompt(cx))
+                    .child(self.context_menu.clone()),
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn due_in(days: i64) -> task::TaskSummary {
+        task::TaskSummary {
+            uuid: uuid::Uuid::new_v4(),
+            description: "due".to_string(),
+            due: Some(chrono::Utc::now() + chrono::Duration::days(days)),
+            ..task::TaskSummary::default()
+        }
+    }
+
+    #[test]
+    fn test_project_progress_follows_subproject_toggle() {
+        let task = |project: &str, status: task::TaskStatus| task::TaskSummary {
+            uuid: uuid::Uuid::new_v4(),
+            project: Some(project.to_string()),
+            status,
+            ..task::TaskSummary::default()
+        };
+        let tasks = vec![
+            task("Work", task::TaskStatus::Pending),
+            task("Work.Backend", task::TaskStatus::Completed),
+            task("Workshop", task::TaskStatus::Completed),
+        ];
+
+        let with_children = ProjectProgress::from_tasks("Work", &tasks, true);
+        assert_eq!((with_children.pending, with_children.completed), (1, 1));
+
+        let own_only = ProjectProgress::from_tasks("Work", &tasks, false);
+        assert_eq!((own_only.pending, own_only.completed), (1, 0));
+    }
+
+    #[test]
+    fn test_vanished_due_date_falls_back_to_all() {
+        let kept = (chrono::Utc::now() + chrono::Duration::days(3)).date_naive();
+        let gone = (chrono::Utc::now() + chrono::Duration::days(5)).date_naive();
+        let tasks = vec![due_in(3)];
+        let due_soon = chrono::Duration::days(3);
+        let mut state = FilterState {
+            status_filter: StatusFilter::Completed,
+            priority_filter: PriorityFilter::High,
+            due_filter: DueFilter::OnDate(kept),
+            ..FilterState::default()
+        };
+
+        let dropdowns = TaskTable::filter_dropdowns(&tasks, &state, due_soon);
+        assert_eq!(dropdowns.status_index, StatusFilter::Completed.to_index());
+        assert_eq!(dropdowns.priority_index, PriorityFilter::High.to_index());
+        assert_eq!(
+            dropdowns
+                .due_index
+                .map(|index| dropdowns.due_items[index].value.to_string()),
+            Some(DueFilter::OnDate(kept).value_key())
+        );
+
+        state.due_filter = DueFilter::OnDate(gone);
+        let dropdowns = TaskTable::filter_dropdowns(&tasks, &state, due_soon);
+        assert_eq!(dropdowns.due_index, None);
+        assert_eq!(
+            dropdowns.due_items.len(),
+            TaskTable::build_due_items(&tasks, due_soon).len()
+        );
+        assert_eq!(
+            dropdowns.due_items[0].value.as_ref(),
+            DueFilter::All.value_key()
+        );
+    }
+
+    #[test]
+    fn test_named_due_filter_stays_selected_without_matches() {
+        let mut items = TaskTable::build_due_items(&[due_in(3)], chrono::Duration::days(3));
+        let index = TaskTable::select_due_item(&mut items, &DueFilter::Overdue).unwrap();
+        assert_eq!(items[index].value.as_ref(), "overdue");
+    }
+
+    #[test]
+    fn test_pinned_tasks_sort_before_the_rest() {
+        let mut tasks = vec![due_in(1), due_in(2), due_in(3)];
+        let pinned = HashSet::from([tasks[2].uuid]);
+        let expected = vec![tasks[2].uuid, tasks[0].uuid, tasks[1].uuid];
+
+        sort_tasks(&mut tasks, &[SortState::default()], &pinned);
+        let order: Vec<uuid::Uuid> = tasks.iter().map(|task| task.uuid).collect();
+        assert_eq!(order, expected);
+    }
+
+    #[test]
+    fn test_secondary_sort_breaks_primary_ties() {
+        let mut levels = SortState::default_levels();
+        set_secondary_sort_level(&mut levels, SortColumn::Due);
+        assert_eq!(
+            levels.iter().map(SortState::value).collect::<Vec<_>>(),
+            vec!["priority:desc", "due:asc", "id:asc"]
+        );
+
+        set_secondary_sort_level(&mut levels, SortColumn::Due);
+        assert_eq!(levels[1].direction, SortDirection::Desc);
+        set_secondary_sort_level(&mut levels, SortColumn::Priority);
+        assert_eq!(levels[0].column, SortColumn::Priority);
+
+        let mut later = due_in(5);
+        later.priority = task::TaskPriority::High;
+        let mut sooner = due_in(1);
+        sooner.priority = task::TaskPriority::High;
+        let levels = [levels[0], levels[1]];
+        assert_eq!(levels[0].compare(&later, &sooner), Ordering::Equal);
+
+        let mut tasks = vec![sooner.clone(), later.clone()];
+        sort_tasks(&mut tasks, &levels, &HashSet::new());
+        assert_eq!(tasks[0].uuid, later.uuid);
+        assert_eq!(SortState::from_value("due:desc"), Some(levels[1]));
+        assert_eq!(SortState::from_value("size:asc"), None);
+    }
+
+    #[test]
+    fn test_sort_by_modified_puts_recent_first() {
+        let now = chrono::Utc::now();
+        let mut older = due_in(1);
+        older.modified = Some(now - chrono::Duration::days(2));
+        let mut recent = due_in(1);
+        recent.modified = Some(now);
+        let untouched = due_in(1);
+
+        let sort = SortState {
+            column: SortColumn::ModifiedDate,
+            direction: SortDirection::Desc,
+        };
+        assert_eq!(sort.compare(&recent, &older), Ordering::Less);
+
+        let sort = SortState {
+            column: SortColumn::EntryDate,
+            direction: SortDirection::Asc,
+        };
+        let mut entered = due_in(1);
+        entered.entry = Some(now);
+        assert_eq!(sort.compare(&entered, &untouched), Ordering::Less);
+    }
+}